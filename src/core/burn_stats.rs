@@ -0,0 +1,85 @@
+//! App-wide MEMO burn totals
+//!
+//! Chat groups and projects each keep their own burn leaderboard; the
+//! overview adds up both leaderboards' totals and shows them next to the
+//! minted supply. Each part is fetched on its own, so one failing read
+//! still leaves the others to show.
+
+use super::rpc_base::RpcConnection;
+use super::cache;
+
+/// Burn totals and supply in lamports; None where the read failed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BurnOverview {
+    pub chat_burned: Option<u64>,
+    pub project_burned: Option<u64>,
+    pub supply: Option<u64>,
+    /// One line per failed read, for the partial-data warning
+    pub failures: Vec<String>,
+}
+
+impl BurnOverview {
+    /// Burned across chat groups and projects, counting whichever loaded
+    pub fn total_burned(&self) -> Option<u64> {
+        match (self.chat_burned, self.project_burned) {
+            (None, None) => None,
+            (chat, project) => Some(chat.unwrap_or(0).saturating_add(project.unwrap_or(0))),
+        }
+    }
+
+    /// Whether every part loaded
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Read both burn leaderboards and the supply concurrently
+pub async fn fetch_burn_overview(rpc: &RpcConnection) -> BurnOverview {
+    let (chat, project, supply) = futures::join!(
+        rpc.get_burn_leaderboard(),
+        rpc.get_project_burn_leaderboard(),
+        cache::get_token_supply(rpc),
+    );
+
+    let mut overview = BurnOverview::default();
+    match chat {
+        Ok(leaderboard) => overview.chat_burned = Some(leaderboard.total_burned_tokens),
+        Err(e) => overview.failures.push(format!("chat burns: {}", e)),
+    }
+    match project {
+        Ok(leaderboard) => overview.project_burned = Some(leaderboard.total_burned_tokens),
+        Err(e) => overview.failures.push(format!("project burns: {}", e)),
+    }
+    match supply {
+        Ok(supply) => overview.supply = Some(supply),
+        Err(e) => overview.failures.push(format!("token supply: {}", e)),
+    }
+    overview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_burned_counts_loaded_parts() {
+        let overview = BurnOverview {
+            chat_burned: Some(1_000),
+            project_burned: Some(2_500),
+            supply: Some(10_000),
+            failures: vec![],
+        };
+        assert_eq!(overview.total_burned(), Some(3_500));
+        assert!(overview.is_complete());
+
+        let partial = BurnOverview {
+            project_burned: None,
+            failures: vec!["project burns: timeout".to_string()],
+            ..overview
+        };
+        assert_eq!(partial.total_burned(), Some(1_000));
+        assert!(!partial.is_complete());
+
+        assert_eq!(BurnOverview::default().total_burned(), None);
+    }
+}
@@ -0,0 +1,444 @@
+//! Short-lived in-memory caches for slowly changing chain data
+//!
+//! Values are shared across pages for the lifetime of the app and expire
+//! after a fixed TTL. Each entry remembers the network it was fetched on,
+//! so switching networks never serves a stale value. Identical reads that
+//! are in flight at the same time share one request, see `InFlight`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::RwLock;
+
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use once_cell::sync::Lazy;
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::network_config::{get_network, NetworkType};
+use super::rpc_base::{RpcConnection, RpcError};
+use super::rpc_chat::{ChatGroupInfo, GroupParticipants};
+use super::rpc_mint::{MintConfig, SupplyTier};
+use super::rpc_project::ProjectInfo;
+use super::burn_stats::{self, BurnOverview};
+
+/// Token supply (and the mint reward derived from it) changes slowly
+const TOKEN_SUPPLY_TTL_MS: f64 = 60_000.0;
+
+/// Group infos back the leaderboard, the group lists and the chat room
+/// header; counts move with every message, so keep this short
+const CHAT_GROUP_INFO_TTL_MS: f64 = 20_000.0;
+
+/// Participant counts scan a page of messages per group card, and change
+/// far less often than message counts
+const PARTICIPANTS_TTL_MS: f64 = 300_000.0;
+
+/// Counting holders scans every token account of the mint
+const HOLDER_COUNT_TTL_MS: f64 = 600_000.0;
+
+/// Burn totals change with every burn, but the overview is only a summary
+const BURN_OVERVIEW_TTL_MS: f64 = 60_000.0;
+
+/// Fees only move with the priority fee setting and network load
+const FEE_ESTIMATE_TTL_MS: f64 = 30_000.0;
+
+/// Fallback shown when the mint reward cannot be fetched
+pub const DEFAULT_MINT_REWARD_DISPLAY: &str = "+1 MEMO";
+
+struct CacheEntry<K, V> {
+    key: K,
+    value: V,
+    expires_at_ms: f64,
+}
+
+/// A single keyed value with an expiry time
+pub struct TtlCache<K, V> {
+    ttl_ms: f64,
+    entry: RwLock<Option<CacheEntry<K, V>>>,
+}
+
+impl<K: PartialEq, V: Clone> TtlCache<K, V> {
+    pub const fn new(ttl_ms: f64) -> Self {
+        Self {
+            ttl_ms,
+            entry: RwLock::new(None),
+        }
+    }
+
+    /// Get the cached value for `key` if it has not expired at `now_ms`
+    pub fn get_at(&self, key: &K, now_ms: f64) -> Option<V> {
+        let entry = self.entry.read().ok()?;
+        entry
+            .as_ref()
+            .filter(|e| e.key == *key && now_ms < e.expires_at_ms)
+            .map(|e| e.value.clone())
+    }
+
+    /// Store a value for `key`, expiring `ttl_ms` after `now_ms`
+    pub fn insert_at(&self, key: K, value: V, now_ms: f64) {
+        if let Ok(mut entry) = self.entry.write() {
+            *entry = Some(CacheEntry {
+                key,
+                value,
+                expires_at_ms: now_ms + self.ttl_ms,
+            });
+        }
+    }
+
+    /// Drop the cached value so the next read fetches fresh data
+    pub fn invalidate(&self) {
+        if let Ok(mut entry) = self.entry.write() {
+            *entry = None;
+        }
+    }
+}
+
+/// Many keyed values, each with its own expiry time
+pub struct TtlMap<K, V> {
+    ttl_ms: f64,
+    entries: RwLock<HashMap<K, (V, f64)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlMap<K, V> {
+    pub fn new(ttl_ms: f64) -> Self {
+        Self {
+            ttl_ms,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the cached value for `key` if it has not expired at `now_ms`
+    pub fn get_at(&self, key: &K, now_ms: f64) -> Option<V> {
+        let entries = self.entries.read().ok()?;
+        entries
+            .get(key)
+            .filter(|(_, expires_at_ms)| now_ms < *expires_at_ms)
+            .map(|(value, _)| value.clone())
+    }
+
+    /// Store a value for `key`, expiring `ttl_ms` after `now_ms`
+    pub fn insert_at(&self, key: K, value: V, now_ms: f64) {
+        if let Ok(mut entries) = self.entries.write() {
+            // expired entries are only dropped here, so the map stays bounded by what is in use
+            entries.retain(|_, (_, expires_at_ms)| now_ms < *expires_at_ms);
+            entries.insert(key, (value, now_ms + self.ttl_ms));
+        }
+    }
+
+    /// Drop the cached value for `key`
+    pub fn invalidate(&self, key: &K) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.remove(key);
+        }
+    }
+
+    /// Drop every cached value
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+}
+
+/// Requests in flight by key. A read for a key that is already being
+/// fetched awaits that fetch instead of starting another one; the entry is
+/// dropped once it resolves, so later reads fetch again.
+pub struct InFlight<K, V> {
+    pending: RefCell<HashMap<K, Shared<LocalBoxFuture<'static, V>>>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone + 'static> InFlight<K, V> {
+    pub fn new() -> Self {
+        Self {
+            pending: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Resolve with the in-flight request for `key`, or start one with `fetch`
+    pub async fn coalesce<F, Fut>(&self, key: K, fetch: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V> + 'static,
+    {
+        let request = self
+            .pending
+            .borrow_mut()
+            .entry(key.clone())
+            .or_insert_with(|| fetch().boxed_local().shared())
+            .clone();
+        let value = request.clone().await;
+        let mut pending = self.pending.borrow_mut();
+        // the first waiter to finish removes it, unless a newer request took its place
+        if pending.get(&key).is_some_and(|current| current.ptr_eq(&request)) {
+            pending.remove(&key);
+        }
+        value
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone + 'static> Default for InFlight<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TOKEN_SUPPLY_CACHE: TtlCache<Option<NetworkType>, u64> = TtlCache::new(TOKEN_SUPPLY_TTL_MS);
+
+/// Get the token supply, fetching it only when the cached value has expired
+pub async fn get_token_supply(rpc: &RpcConnection) -> Result<u64, RpcError> {
+    let network = get_network();
+    if let Some(supply) = TOKEN_SUPPLY_CACHE.get_at(&network, js_sys::Date::now()) {
+        return Ok(supply);
+    }
+
+    let supply = rpc.get_token_supply().await?;
+    TOKEN_SUPPLY_CACHE.insert_at(network, supply, js_sys::Date::now());
+    Ok(supply)
+}
+
+/// Get the current supply and tier, using the cached supply
+pub async fn get_current_supply_tier_info(rpc: &RpcConnection) -> Result<(u64, SupplyTier), RpcError> {
+    let supply = get_token_supply(rpc).await?;
+    Ok((supply, MintConfig::get_current_supply_tier(supply)))
+}
+
+/// Get the formatted current mint reward, falling back to "+1 MEMO" on error
+pub async fn get_current_mint_reward_formatted() -> String {
+    let rpc = RpcConnection::new();
+    match get_token_supply(&rpc).await {
+        Ok(supply) => MintConfig::format_mint_reward(MintConfig::calculate_mint_reward(supply)),
+        Err(e) => {
+            log::warn!("Failed to get current mint reward: {}", e);
+            DEFAULT_MINT_REWARD_DISPLAY.to_string()
+        }
+    }
+}
+
+/// Invalidate the cached supply/mint reward, e.g. when an epoch boundary is expected
+pub fn invalidate_mint_reward() {
+    TOKEN_SUPPLY_CACHE.invalidate();
+}
+
+static BURN_OVERVIEW_CACHE: TtlCache<Option<NetworkType>, BurnOverview> = TtlCache::new(BURN_OVERVIEW_TTL_MS);
+
+/// Get the app-wide burn totals. Only a complete overview is cached, so a
+/// partial one is read again on the next call.
+pub async fn get_burn_overview(rpc: &RpcConnection) -> BurnOverview {
+    let network = get_network();
+    if let Some(overview) = BURN_OVERVIEW_CACHE.get_at(&network, js_sys::Date::now()) {
+        return overview;
+    }
+
+    let overview = burn_stats::fetch_burn_overview(rpc).await;
+    if overview.is_complete() {
+        BURN_OVERVIEW_CACHE.insert_at(network, overview.clone(), js_sys::Date::now());
+    }
+    overview
+}
+
+static HOLDER_COUNT_CACHE: TtlCache<Option<NetworkType>, u64> = TtlCache::new(HOLDER_COUNT_TTL_MS);
+
+/// Get the number of MEMO holders, fetched at most every ten minutes
+pub async fn get_token_holder_count(rpc: &RpcConnection) -> Result<u64, RpcError> {
+    let network = get_network();
+    if let Some(count) = HOLDER_COUNT_CACHE.get_at(&network, js_sys::Date::now()) {
+        return Ok(count);
+    }
+
+    let count = rpc.get_token_holder_count().await?;
+    HOLDER_COUNT_CACHE.insert_at(network, count, js_sys::Date::now());
+    Ok(count)
+}
+
+/// Drop the cached burn totals so the next read fetches them
+pub fn invalidate_burn_overview() {
+    BURN_OVERVIEW_CACHE.invalidate();
+}
+
+static FEE_ESTIMATE_CACHE: TtlCache<Option<NetworkType>, u64> = TtlCache::new(FEE_ESTIMATE_TTL_MS);
+
+/// Estimated fee (lamports) of a chat message or burn, cached briefly so the
+/// composer can show it without a request per keystroke
+pub async fn get_fee_estimate(rpc: &RpcConnection, payer: &Pubkey) -> Result<u64, RpcError> {
+    let network = get_network();
+    if let Some(fee) = FEE_ESTIMATE_CACHE.get_at(&network, js_sys::Date::now()) {
+        return Ok(fee);
+    }
+
+    let fee = rpc.estimate_memo_fee(payer).await?;
+    FEE_ESTIMATE_CACHE.insert_at(network, fee, js_sys::Date::now());
+    Ok(fee)
+}
+
+static CHAT_GROUP_INFO_CACHE: Lazy<TtlMap<(Option<NetworkType>, u64), ChatGroupInfo>> =
+    Lazy::new(|| TtlMap::new(CHAT_GROUP_INFO_TTL_MS));
+
+/// In-flight reads of an account keyed by network and id
+type InfoRequests<T> = Rc<InFlight<(Option<NetworkType>, u64), Result<T, RpcError>>>;
+
+// Futures of in-flight reads are not `Send`; the app runs on one thread
+thread_local! {
+    static CHAT_GROUP_INFO_REQUESTS: InfoRequests<ChatGroupInfo> = Rc::new(InFlight::new());
+    static PROJECT_INFO_REQUESTS: InfoRequests<ProjectInfo> = Rc::new(InFlight::new());
+}
+
+/// Get a chat group's info, served from the cache while it is fresh. Calls
+/// for the same group while it is being fetched share that fetch, which
+/// `RpcConnection` retries on transient failures.
+///
+/// A full leaderboard load fetches one info per ranked group (up to 100
+/// calls). Before this cache, opening one of those groups, jumping to it by
+/// id, or paging Latest/Oldest over the same groups repeated the call each
+/// time; within the TTL they now cost nothing, so browsing the leaderboard
+/// and its groups makes the 100 calls once instead of once per view.
+pub async fn get_chat_group_info(rpc: &RpcConnection, group_id: u64) -> Result<ChatGroupInfo, RpcError> {
+    let key = (get_network(), group_id);
+    if let Some(info) = CHAT_GROUP_INFO_CACHE.get_at(&key, js_sys::Date::now()) {
+        return Ok(info);
+    }
+
+    let requests = CHAT_GROUP_INFO_REQUESTS.with(Rc::clone);
+    let rpc = rpc.clone();
+    let info = requests
+        .coalesce(key, move || async move { rpc.get_chat_group_info(group_id).await })
+        .await?;
+    CHAT_GROUP_INFO_CACHE.insert_at(key, info.clone(), js_sys::Date::now());
+    Ok(info)
+}
+
+/// Get a project's info. Not cached, but calls for the same project while
+/// it is being fetched (leaderboard rows, details, burn dialogs) share it.
+pub async fn get_project_info(rpc: &RpcConnection, project_id: u64) -> Result<ProjectInfo, RpcError> {
+    let requests = PROJECT_INFO_REQUESTS.with(Rc::clone);
+    let rpc = rpc.clone();
+    requests
+        .coalesce((get_network(), project_id), move || async move { rpc.get_project_info(project_id).await })
+        .await
+}
+
+static PARTICIPANTS_CACHE: Lazy<TtlMap<(Option<NetworkType>, u64), GroupParticipants>> =
+    Lazy::new(|| TtlMap::new(PARTICIPANTS_TTL_MS));
+
+/// Get the senders among a group's most recent messages, served from the
+/// cache while fresh so paging back to a group list rescans nothing
+pub async fn get_chat_group_participants(rpc: &RpcConnection, group_id: u64) -> Result<GroupParticipants, RpcError> {
+    let key = (get_network(), group_id);
+    if let Some(participants) = PARTICIPANTS_CACHE.get_at(&key, js_sys::Date::now()) {
+        return Ok(participants);
+    }
+
+    let participants = rpc.get_chat_group_participants(group_id, None).await?;
+    PARTICIPANTS_CACHE.insert_at(key, participants.clone(), js_sys::Date::now());
+    Ok(participants)
+}
+
+/// Forget a group's cached info, after a message, burn or update in it
+pub fn invalidate_chat_group_info(group_id: u64) {
+    CHAT_GROUP_INFO_CACHE.invalidate(&(get_network(), group_id));
+}
+
+/// Forget all cached group infos, for an explicit refresh
+pub fn clear_chat_group_infos() {
+    CHAT_GROUP_INFO_CACHE.clear();
+}
+
+/// Forget every cached value, for the settings "clear cache" action
+pub fn clear_all() {
+    TOKEN_SUPPLY_CACHE.invalidate();
+    BURN_OVERVIEW_CACHE.invalidate();
+    HOLDER_COUNT_CACHE.invalidate();
+    CHAT_GROUP_INFO_CACHE.clear();
+    PARTICIPANTS_CACHE.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ttl_cache_expiry() {
+        let cache: TtlCache<u8, u64> = TtlCache::new(1_000.0);
+        assert_eq!(cache.get_at(&1, 0.0), None);
+
+        cache.insert_at(1, 42, 0.0);
+        assert_eq!(cache.get_at(&1, 999.0), Some(42));
+        assert_eq!(cache.get_at(&1, 1_000.0), None);
+    }
+
+    #[test]
+    fn test_ttl_cache_key_mismatch() {
+        let cache: TtlCache<u8, u64> = TtlCache::new(1_000.0);
+        cache.insert_at(1, 42, 0.0);
+        assert_eq!(cache.get_at(&2, 10.0), None);
+    }
+
+    #[test]
+    fn test_ttl_cache_invalidate() {
+        let cache: TtlCache<u8, u64> = TtlCache::new(1_000.0);
+        cache.insert_at(1, 42, 0.0);
+        cache.invalidate();
+        assert_eq!(cache.get_at(&1, 10.0), None);
+    }
+
+    #[test]
+    fn test_ttl_map_hit_and_expiry() {
+        let cache: TtlMap<u64, &str> = TtlMap::new(1_000.0);
+        cache.insert_at(7, "group 7", 0.0);
+        cache.insert_at(8, "group 8", 500.0);
+        assert_eq!(cache.get_at(&7, 999.0), Some("group 7"));
+        assert_eq!(cache.get_at(&8, 999.0), Some("group 8"));
+        assert_eq!(cache.get_at(&7, 1_000.0), None);
+        assert_eq!(cache.get_at(&9, 10.0), None);
+    }
+
+    #[test]
+    fn test_ttl_map_invalidate_one_key() {
+        let cache: TtlMap<u64, u64> = TtlMap::new(1_000.0);
+        cache.insert_at(1, 10, 0.0);
+        cache.insert_at(2, 20, 0.0);
+        cache.invalidate(&1);
+        assert_eq!(cache.get_at(&1, 10.0), None);
+        assert_eq!(cache.get_at(&2, 10.0), Some(20));
+        cache.clear();
+        assert_eq!(cache.get_at(&2, 10.0), None);
+    }
+
+    #[test]
+    fn test_concurrent_reads_share_one_fetch() {
+        let requests: InFlight<u64, u64> = InFlight::new();
+        let fetches = RefCell::new(0);
+        let (tx, rx) = futures::channel::oneshot::channel::<u64>();
+
+        let first = requests.coalesce(7, || {
+            *fetches.borrow_mut() += 1;
+            async move { rx.await.unwrap_or(0) }
+        });
+        let second = requests.coalesce(7, || {
+            *fetches.borrow_mut() += 1;
+            async { 0 }
+        });
+        let resolve = async {
+            assert_eq!(requests.pending.borrow().len(), 1);
+            tx.send(42).unwrap();
+        };
+        let (a, b, ()) = futures::executor::block_on(async { futures::join!(first, second, resolve) });
+
+        assert_eq!((a, b), (42, 42));
+        assert_eq!(*fetches.borrow(), 1);
+        assert!(requests.pending.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_reads_after_completion_fetch_again() {
+        let requests: InFlight<u64, u64> = InFlight::new();
+        let first = futures::executor::block_on(requests.coalesce(1, || async { 10 }));
+        let second = futures::executor::block_on(requests.coalesce(1, || async { 20 }));
+        assert_eq!((first, second), (10, 20));
+        // other keys never share
+        let other = futures::executor::block_on(requests.coalesce(2, || async { 30 }));
+        assert_eq!(other, 30);
+        assert!(requests.pending.borrow().is_empty());
+    }
+}
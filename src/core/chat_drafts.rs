@@ -0,0 +1,112 @@
+//! Unsent chat composer text, remembered per group
+//!
+//! Leaving a room keeps what was typed in the message and burn inputs so it
+//! is back when the room is opened again. The two inputs are drafted
+//! separately. Drafts are kept per network in localStorage via
+//! `storage_base`, most recently saved last, and capped at `MAX_DRAFT_GROUPS`.
+
+use serde::{Deserialize, Serialize};
+
+use super::storage_base;
+
+const CHAT_DRAFTS_KEY: &str = "chat_drafts";
+
+/// Maximum groups with a draft; the least recently saved are dropped first
+pub const MAX_DRAFT_GROUPS: usize = 100;
+
+/// Unsent text of a group's composer
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatDraft {
+    pub group_id: u64,
+    /// Text of the chat message input
+    #[serde(default)]
+    pub message: String,
+    /// Text of the burn message input
+    #[serde(default)]
+    pub burn_message: String,
+}
+
+impl ChatDraft {
+    /// Nothing but whitespace in either input
+    pub fn is_empty(&self) -> bool {
+        self.message.trim().is_empty() && self.burn_message.trim().is_empty()
+    }
+}
+
+fn load_all() -> Vec<ChatDraft> {
+    storage_base::load_network_list(CHAT_DRAFTS_KEY)
+}
+
+/// Replace the group's draft and move it to the end, dropping the oldest
+/// beyond the cap. An empty draft just removes the group's entry.
+fn put_draft(drafts: &mut Vec<ChatDraft>, draft: ChatDraft) {
+    if draft.is_empty() {
+        drafts.retain(|d| d.group_id != draft.group_id);
+        return;
+    }
+    storage_base::push_bounded(drafts, draft, MAX_DRAFT_GROUPS, |a, b| a.group_id == b.group_id);
+}
+
+/// The group's saved draft, empty if there is none
+pub fn load_draft(group_id: u64) -> ChatDraft {
+    load_all()
+        .into_iter()
+        .find(|d| d.group_id == group_id)
+        .unwrap_or(ChatDraft { group_id, ..Default::default() })
+}
+
+/// Change the group's draft in place, forgetting it once both inputs are empty
+pub fn update_draft(group_id: u64, f: impl FnOnce(&mut ChatDraft)) -> Result<(), String> {
+    let key = storage_base::network_key(CHAT_DRAFTS_KEY).ok_or_else(|| "Network is not initialized".to_string())?;
+    let mut drafts = load_all();
+    let mut draft = drafts
+        .iter()
+        .find(|d| d.group_id == group_id)
+        .cloned()
+        .unwrap_or(ChatDraft { group_id, ..Default::default() });
+    f(&mut draft);
+    draft.group_id = group_id;
+    put_draft(&mut drafts, draft);
+    storage_base::save_json(&key, &drafts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft(group_id: u64, message: &str, burn_message: &str) -> ChatDraft {
+        ChatDraft {
+            group_id,
+            message: message.to_string(),
+            burn_message: burn_message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_put_draft_replaces_and_moves_to_end() {
+        let mut drafts = vec![draft(1, "hi", ""), draft(2, "", "burn")];
+        put_draft(&mut drafts, draft(1, "hello", ""));
+        assert_eq!(drafts, vec![draft(2, "", "burn"), draft(1, "hello", "")]);
+    }
+
+    #[test]
+    fn test_put_empty_draft_removes_entry() {
+        let mut drafts = vec![draft(1, "hi", ""), draft(2, "", "burn")];
+        put_draft(&mut drafts, draft(2, "  ", ""));
+        assert_eq!(drafts, vec![draft(1, "hi", "")]);
+        // clearing one input keeps the other
+        put_draft(&mut drafts, draft(1, "", "still here"));
+        assert_eq!(drafts, vec![draft(1, "", "still here")]);
+    }
+
+    #[test]
+    fn test_put_draft_is_bounded() {
+        let mut drafts: Vec<ChatDraft> = (0..MAX_DRAFT_GROUPS as u64)
+            .map(|group_id| draft(group_id, "x", ""))
+            .collect();
+        put_draft(&mut drafts, draft(10_000, "x", ""));
+        assert_eq!(drafts.len(), MAX_DRAFT_GROUPS);
+        assert_eq!(drafts.first().map(|d| d.group_id), Some(1));
+        assert_eq!(drafts.last().map(|d| d.group_id), Some(10_000));
+    }
+}
@@ -30,3 +30,29 @@ pub const MIN_COMPUTE_UNITS: u64 = 200_000;
 
 /// Common burn memo version used across all burn operations
 pub const BURN_MEMO_VERSION: u8 = 1;
+
+// ============================================================================
+// Token Amounts
+// ============================================================================
+
+/// Lamports (smallest token units) per whole MEMO token - the mint has 6 decimals
+pub const LAMPORTS_PER_TOKEN: u64 = 1_000_000;
+
+/// Largest burn accepted in a single transaction, in whole MEMO tokens
+/// (far above any real balance, and its lamport amount still fits in a u64)
+pub const MAX_BURN_TOKENS: u64 = 1_000_000_000_000;
+
+// ============================================================================
+// Fees
+// ============================================================================
+
+/// Minimum native (XNT) balance for sending a chat message or burn; below it
+/// the fee may not be covered, so the chat composer is disabled
+pub const MIN_FEE_BALANCE_XNT: f64 = 0.01;
+
+/// Lamports per native XNT - 9 decimals
+pub const LAMPORTS_PER_XNT: u64 = 1_000_000_000;
+
+/// Compute units assumed for a chat message or burn when estimating its
+/// priority fee (the real limit comes from simulating the transaction)
+pub const FEE_ESTIMATE_COMPUTE_UNITS: u64 = 100_000;
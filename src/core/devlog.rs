@@ -0,0 +1,230 @@
+//! Devlog posts: project burns whose message is a JSON object
+//!
+//! A devlog message looks like
+//! `{"type":"devlog","title":"...","content":"...","image":"...","edited_of":"..."}`.
+//! Any field order is accepted and unknown fields are ignored; only objects
+//! with `"type":"devlog"` are devlogs. Older posts were written without
+//! escaping line breaks, so raw control characters inside strings are
+//! escaped before giving up on a message.
+//!
+//! On-chain devlogs can't change, so an edit is a new devlog naming the
+//! original's signature in `edited_of`; [`merge_devlog_edits`] shows it in
+//! the original's place.
+
+use serde::Deserialize;
+
+use super::rpc_project::ProjectBurnMessage;
+
+/// Parsed devlog data from a burn message
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ParsedDevlog {
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub image: String,
+    /// Signature of the devlog this one replaces, for an edit
+    #[serde(default, deserialize_with = "non_empty")]
+    pub edited_of: Option<String>,
+}
+
+/// The message as parsed, with its `type` checked separately
+#[derive(Deserialize)]
+struct DevlogMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(flatten)]
+    devlog: ParsedDevlog,
+}
+
+fn non_empty<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    Ok(value.filter(|value| !value.is_empty()))
+}
+
+impl ParsedDevlog {
+    /// Parse a burn message, None unless it is a devlog
+    pub fn from_message(message: &str) -> Option<Self> {
+        let parsed: DevlogMessage = serde_json::from_str(message)
+            .or_else(|_| serde_json::from_str(&escape_control_chars(message)))
+            .ok()?;
+        (parsed.kind == "devlog").then_some(parsed.devlog)
+    }
+}
+
+/// A devlog burn, with the edits that replace its content folded in
+#[derive(Debug, Clone, PartialEq)]
+pub struct DevlogPost {
+    pub message: ProjectBurnMessage,
+    pub parsed: ParsedDevlog,
+    /// Time of the latest edit shown in place of the original, if any
+    pub edited_at: Option<i64>,
+}
+
+impl DevlogPost {
+    /// The burn as a devlog post, None unless its message is a devlog
+    pub fn from_message(message: ProjectBurnMessage) -> Option<Self> {
+        let parsed = ParsedDevlog::from_message(&message.message)?;
+        Some(Self { message, parsed, edited_at: None })
+    }
+
+    /// Show `edit` in place of this devlog, which keeps its position and
+    /// signature (edits reference the original) and adds the edit's burn
+    pub fn apply_edit(&mut self, edit: &DevlogPost) {
+        self.parsed = edit.parsed.clone();
+        self.edited_at = Some(edit.message.timestamp);
+        self.message.burn_amount = self.message.burn_amount.saturating_add(edit.message.burn_amount);
+    }
+}
+
+/// Fold edits into the devlogs they replace. Only edits by the original's
+/// burner count, applied oldest first so the newest wins. An edit whose
+/// original isn't loaded is shown on its own.
+pub fn merge_devlog_edits(posts: Vec<DevlogPost>) -> Vec<DevlogPost> {
+    let (mut edits, mut merged): (Vec<_>, Vec<_>) = posts.into_iter().partition(|post| post.parsed.edited_of.is_some());
+    edits.sort_by_key(|edit| edit.message.timestamp);
+
+    for edit in edits {
+        let original_signature = edit.parsed.edited_of.as_deref().unwrap_or_default();
+        match merged.iter_mut().find(|post| {
+            post.message.signature == original_signature && post.message.burner == edit.message.burner
+        }) {
+            Some(original) => original.apply_edit(&edit),
+            None => merged.push(edit),
+        }
+    }
+    merged
+}
+
+/// Escape raw control characters (such as line breaks) inside JSON strings,
+/// which strict JSON rejects but older devlogs contain
+fn escape_control_chars(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in json.chars() {
+        if in_string && !escaped && c.is_control() {
+            match c {
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                _ => out.push_str(&format!("\\u{:04x}", c as u32)),
+            }
+            continue;
+        }
+        out.push(c);
+        if escaped {
+            escaped = false;
+        } else if in_string && c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            in_string = !in_string;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_devlog() {
+        let devlog = ParsedDevlog::from_message(
+            r#"{"type":"devlog","title":"v1.2","content":"Fixed the \"send\" button \\ finally","image":"c:abc"}"#,
+        )
+        .unwrap();
+        assert_eq!(devlog.title, "v1.2");
+        assert_eq!(devlog.content, r#"Fixed the "send" button \ finally"#);
+        assert_eq!(devlog.image, "c:abc");
+        assert_eq!(devlog.edited_of, None);
+    }
+
+    #[test]
+    fn test_parse_any_field_order_and_unknown_fields() {
+        let devlog = ParsedDevlog::from_message(
+            r#"{"image":"","meta":{"tags":["a","b"]},"content":"{braces} inside","title":"🚀 Launch","type":"devlog","edited_of":"5xSig"}"#,
+        )
+        .unwrap();
+        assert_eq!(devlog.title, "🚀 Launch");
+        assert_eq!(devlog.content, "{braces} inside");
+        assert_eq!(devlog.edited_of.as_deref(), Some("5xSig"));
+    }
+
+    #[test]
+    fn test_parse_unicode_and_raw_line_breaks() {
+        let devlog = ParsedDevlog::from_message("{\"type\":\"devlog\",\"title\":\"燃烧\",\"content\":\"line one\nline two \\u00e9\"}")
+            .unwrap();
+        assert_eq!(devlog.title, "燃烧");
+        assert_eq!(devlog.content, "line one\nline two é");
+        assert_eq!(devlog.image, "");
+    }
+
+    #[test]
+    fn test_non_devlogs_are_rejected() {
+        assert_eq!(ParsedDevlog::from_message(r#"{"type":"burn","title":"x"}"#), None);
+        assert_eq!(ParsedDevlog::from_message(r#"{"title":"no type"}"#), None);
+        assert_eq!(ParsedDevlog::from_message(r#"gm "type":"devlog""#), None);
+        assert_eq!(ParsedDevlog::from_message(""), None);
+    }
+
+    fn post(signature: &str, burner: &str, timestamp: i64, title: &str, edited_of: Option<&str>) -> DevlogPost {
+        let message = serde_json::json!({
+            "type": "devlog",
+            "title": title,
+            "content": "",
+            "image": "",
+            "edited_of": edited_of.unwrap_or_default(),
+        });
+        DevlogPost::from_message(ProjectBurnMessage {
+            signature: signature.to_string(),
+            burner: burner.to_string(),
+            message: message.to_string(),
+            timestamp,
+            slot: 0,
+            burn_amount: 100,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_edit_by_another_wallet_is_not_applied() {
+        let merged = merge_devlog_edits(vec![
+            post("orig", "alice", 10, "Original", None),
+            post("edit", "mallory", 20, "Hijacked", Some("orig")),
+        ]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].parsed.title, "Original");
+        assert_eq!(merged[0].edited_at, None);
+        assert_eq!(merged[0].message.burn_amount, 100);
+        assert_eq!(merged[1].message.signature, "edit");
+    }
+
+    #[test]
+    fn test_edits_apply_in_timestamp_order() {
+        // newest first, as loaded from chain
+        let merged = merge_devlog_edits(vec![
+            post("edit2", "alice", 30, "Second edit", Some("orig")),
+            post("edit1", "alice", 20, "First edit", Some("orig")),
+            post("orig", "alice", 10, "Original", None),
+        ]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].message.signature, "orig");
+        assert_eq!(merged[0].parsed.title, "Second edit");
+        assert_eq!(merged[0].edited_at, Some(30));
+        assert_eq!(merged[0].message.burn_amount, 300);
+    }
+
+    #[test]
+    fn test_edit_without_original_is_kept() {
+        let merged = merge_devlog_edits(vec![
+            post("other", "alice", 10, "Other", None),
+            post("edit", "alice", 20, "Orphan edit", Some("missing")),
+        ]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].parsed.title, "Other");
+        assert_eq!(merged[1].parsed.title, "Orphan edit");
+        assert_eq!(merged[1].edited_at, None);
+    }
+}
@@ -0,0 +1,274 @@
+//! Number and time formatting shared by all pages
+//!
+//! These run in render paths for every card and table row, so each builds
+//! its result in a single pre-sized `String`.
+
+use super::constants::{LAMPORTS_PER_TOKEN, LAMPORTS_PER_XNT};
+
+/// Push the decimal digits of `value` into `out` with comma thousands separators
+fn push_with_commas(out: &mut String, mut value: u128) {
+    // least significant digit first; u128::MAX has 39 digits
+    let mut digits = [0u8; 39];
+    let mut len = 0;
+    loop {
+        digits[len] = b'0' + (value % 10) as u8;
+        len += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        out.push(digits[i] as char);
+        if i > 0 && i.is_multiple_of(3) {
+            out.push(',');
+        }
+    }
+}
+
+/// Format an integer (`u64`, `i64`, `u32`, ...) with comma thousands separators,
+/// e.g. 1234567 -> "1,234,567" and -1000 -> "-1,000"
+pub fn format_number_with_commas(num: impl Into<i128>) -> String {
+    let num = num.into();
+    let mut formatted = String::with_capacity(28);
+    if num < 0 {
+        formatted.push('-');
+    }
+    push_with_commas(&mut formatted, num.unsigned_abs());
+    formatted
+}
+
+/// Format a lamport amount as MEMO with thousands separators and up to 6 decimals,
+/// e.g. 1_500_000 -> "1.5" and 1_234_000_000 -> "1,234"
+pub fn format_memo(lamports: u64) -> String {
+    let mut formatted = String::with_capacity(32);
+    push_with_commas(&mut formatted, (lamports / LAMPORTS_PER_TOKEN) as u128);
+
+    push_fraction(&mut formatted, lamports % LAMPORTS_PER_TOKEN, LAMPORTS_PER_TOKEN);
+    formatted
+}
+
+/// Format a native lamport amount as XNT with up to 9 decimals,
+/// e.g. 5_000 -> "0.000005" and 1_500_000_000 -> "1.5"
+pub fn format_xnt(lamports: u64) -> String {
+    let mut formatted = String::with_capacity(32);
+    push_with_commas(&mut formatted, (lamports / LAMPORTS_PER_XNT) as u128);
+    push_fraction(&mut formatted, lamports % LAMPORTS_PER_XNT, LAMPORTS_PER_XNT);
+    formatted
+}
+
+/// Push `fraction` of `unit` as decimals without trailing zeros (nothing if 0)
+fn push_fraction(out: &mut String, mut fraction: u64, unit: u64) {
+    if fraction == 0 {
+        return;
+    }
+    out.push('.');
+    let mut divisor = unit / 10;
+    while fraction > 0 {
+        out.push((b'0' + (fraction / divisor) as u8) as char);
+        fraction %= divisor;
+        divisor /= 10;
+    }
+}
+
+/// Format a duration in seconds compactly with its two largest units,
+/// e.g. 45 -> "45s", 90 -> "1m 30s" and 86400 -> "24h"
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (hours, minutes, secs) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    match (hours, minutes, secs) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, 0) => format!("{}m", m),
+        (0, m, s) => format!("{}m {}s", m, s),
+        (h, 0, _) => format!("{}h", h),
+        (h, m, _) => format!("{}h {}m", h, m),
+    }
+}
+
+/// A local date and time as "2024-05-01 14:05", or "2024-05-01 2:05 PM"
+/// with `twelve_hour`. `month` is 1-based.
+pub fn format_date_time(year: u32, month: u32, day: u32, hours: u32, minutes: u32, twelve_hour: bool) -> String {
+    if !twelve_hour {
+        return format!("{}-{:02}-{:02} {:02}:{:02}", year, month, day, hours, minutes);
+    }
+    let suffix = if hours < 12 { "AM" } else { "PM" };
+    let hours = match hours % 12 {
+        0 => 12,
+        h => h,
+    };
+    format!("{}-{:02}-{:02} {}:{:02} {}", year, month, day, hours, minutes, suffix)
+}
+
+/// How long before `now` the unix time `timestamp` was, compactly: "just now",
+/// "5m ago", "3h ago", "yesterday" or "4d ago". `None` from a week on, and
+/// for times more than a minute ahead (clock skew), so callers show the date.
+pub fn format_relative_time(timestamp: i64, now: i64) -> Option<String> {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let elapsed = now.saturating_sub(timestamp);
+    let relative = match elapsed {
+        e if e < -MINUTE => return None,
+        e if e < MINUTE => "just now".to_string(),
+        e if e < HOUR => format!("{}m ago", e / MINUTE),
+        e if e < DAY => format!("{}h ago", e / HOUR),
+        e if e < 2 * DAY => "yesterday".to_string(),
+        e if e < 7 * DAY => format!("{}d ago", e / DAY),
+        _ => return None,
+    };
+    Some(relative)
+}
+
+/// Classic hex dump, 16 bytes per line: offset, hex bytes and printable ASCII,
+/// e.g. "00000000  7b 7d                                             |{}|"
+pub fn hex_dump(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    // 8 offset + 2 + 16 * 3 + 1 + up to 16 ascii + 2 + newline
+    let mut out = String::with_capacity(bytes.len().div_ceil(16) * 78);
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        if line > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("{:08x} ", line * 16));
+        for i in 0..16 {
+            out.push(' ');
+            match chunk.get(i) {
+                Some(byte) => {
+                    out.push(HEX[(byte >> 4) as usize] as char);
+                    out.push(HEX[(byte & 0x0f) as usize] as char);
+                }
+                None => out.push_str("  "),
+            }
+        }
+        out.push_str("  |");
+        for byte in chunk {
+            out.push(if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' });
+        }
+        out.push('|');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number_with_commas_boundaries() {
+        assert_eq!(format_number_with_commas(0u64), "0");
+        assert_eq!(format_number_with_commas(999u64), "999");
+        assert_eq!(format_number_with_commas(1_000u64), "1,000");
+        assert_eq!(format_number_with_commas(123_456u64), "123,456");
+        assert_eq!(format_number_with_commas(1_234_567u64), "1,234,567");
+        assert_eq!(format_number_with_commas(u64::MAX), "18,446,744,073,709,551,615");
+    }
+
+    #[test]
+    fn test_format_number_with_commas_signed() {
+        assert_eq!(format_number_with_commas(-1i64), "-1");
+        assert_eq!(format_number_with_commas(-1_000i64), "-1,000");
+        assert_eq!(format_number_with_commas(i64::MIN), "-9,223,372,036,854,775,808");
+        assert_eq!(format_number_with_commas(i64::MAX), "9,223,372,036,854,775,807");
+    }
+
+    #[test]
+    fn test_format_memo_sub_token_amounts() {
+        assert_eq!(format_memo(0), "0");
+        assert_eq!(format_memo(1), "0.000001");
+        assert_eq!(format_memo(500_000), "0.5");
+        assert_eq!(format_memo(1_500_000), "1.5");
+        assert_eq!(format_memo(1_000_000), "1");
+        assert_eq!(format_memo(42_000_120), "42.00012");
+    }
+
+    #[test]
+    fn test_format_memo_large_amounts() {
+        assert_eq!(format_memo(999_000_000), "999");
+        assert_eq!(format_memo(1_000_000_000), "1,000");
+        assert_eq!(format_memo(42_069_000_000), "42,069");
+        assert_eq!(format_memo(1_234_567_890_123), "1,234,567.890123");
+        assert_eq!(format_memo(u64::MAX), "18,446,744,073,709.551615");
+    }
+
+    #[test]
+    fn test_format_xnt() {
+        assert_eq!(format_xnt(0), "0");
+        assert_eq!(format_xnt(5_000), "0.000005");
+        assert_eq!(format_xnt(1), "0.000000001");
+        assert_eq!(format_xnt(1_500_000_000), "1.5");
+        assert_eq!(format_xnt(2_000_000_000_000), "2,000");
+    }
+
+    #[test]
+    fn test_format_duration_units() {
+        assert_eq!(format_duration(-5), "0s");
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(60), "1m");
+        assert_eq!(format_duration(90), "1m 30s");
+        assert_eq!(format_duration(3_600), "1h");
+        assert_eq!(format_duration(5_430), "1h 30m");
+        assert_eq!(format_duration(86_400), "24h");
+    }
+
+    #[test]
+    fn test_format_date_time_24_hour() {
+        assert_eq!(format_date_time(2024, 5, 1, 14, 5, false), "2024-05-01 14:05");
+        assert_eq!(format_date_time(2024, 12, 31, 0, 0, false), "2024-12-31 00:00");
+    }
+
+    #[test]
+    fn test_format_date_time_12_hour() {
+        assert_eq!(format_date_time(2024, 5, 1, 14, 5, true), "2024-05-01 2:05 PM");
+        assert_eq!(format_date_time(2024, 5, 1, 0, 30, true), "2024-05-01 12:30 AM");
+        assert_eq!(format_date_time(2024, 5, 1, 12, 0, true), "2024-05-01 12:00 PM");
+        assert_eq!(format_date_time(2024, 5, 1, 11, 59, true), "2024-05-01 11:59 AM");
+    }
+
+    #[test]
+    fn test_format_relative_time_buckets() {
+        let now = 1_700_000_000;
+        assert_eq!(format_relative_time(now, now).as_deref(), Some("just now"));
+        assert_eq!(format_relative_time(now - 59, now).as_deref(), Some("just now"));
+        assert_eq!(format_relative_time(now - 60, now).as_deref(), Some("1m ago"));
+        assert_eq!(format_relative_time(now - 3_599, now).as_deref(), Some("59m ago"));
+        assert_eq!(format_relative_time(now - 3_600, now).as_deref(), Some("1h ago"));
+        assert_eq!(format_relative_time(now - 86_399, now).as_deref(), Some("23h ago"));
+        assert_eq!(format_relative_time(now - 86_400, now).as_deref(), Some("yesterday"));
+        assert_eq!(format_relative_time(now - 2 * 86_400, now).as_deref(), Some("2d ago"));
+        assert_eq!(format_relative_time(now - 7 * 86_400 + 1, now).as_deref(), Some("6d ago"));
+    }
+
+    #[test]
+    fn test_format_relative_time_out_of_range() {
+        let now = 1_700_000_000;
+        // a week or older shows the absolute date instead
+        assert_eq!(format_relative_time(now - 7 * 86_400, now), None);
+        assert_eq!(format_relative_time(0, now), None);
+        // slightly ahead (clock skew) is still "just now", further ahead is not
+        assert_eq!(format_relative_time(now + 30, now).as_deref(), Some("just now"));
+        assert_eq!(format_relative_time(now + 3_600, now), None);
+        assert_eq!(format_relative_time(i64::MIN, i64::MAX), None);
+    }
+
+    #[test]
+    fn test_hex_dump_layout() {
+        assert_eq!(hex_dump(&[]), "");
+        assert_eq!(
+            hex_dump(b"{}"),
+            "00000000  7b 7d                                            |{}|"
+        );
+
+        let dump = hex_dump(b"0123456789abcdef\n\xff");
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "00000000  30 31 32 33 34 35 36 37 38 39 61 62 63 64 65 66  |0123456789abcdef|"
+        );
+        assert_eq!(
+            lines[1],
+            "00000010  0a ff                                            |..|"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Paging through a chat group's full message history
+//!
+//! Long operations (like exporting a group's history) page through many
+//! `getSignaturesForAddress` calls. The loop reports a `LoadProgress` after
+//! every page so the page can show how far it got, and stops as soon as the
+//! caller's cancel channel fires, abandoning the request in flight.
+
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use serde::Serialize;
+use std::fmt;
+use std::pin::pin;
+
+use super::rpc_base::{RpcConnection, RpcError};
+use super::rpc_chat::ChatMessage;
+
+/// Signatures requested per page
+pub const HISTORY_PAGE_SIZE: usize = 200;
+
+/// How much of a long paged operation is done
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoadProgress {
+    pub loaded: usize,
+    /// Expected total, when known (e.g. the group's memo count); only an estimate
+    pub estimated_total: Option<u64>,
+}
+
+impl LoadProgress {
+    /// e.g. "Loaded 300 of ~1200"
+    pub fn label(&self) -> String {
+        match self.estimated_total {
+            Some(total) => format!("Loaded {} of ~{}", self.loaded, total),
+            None => format!("Loaded {}", self.loaded),
+        }
+    }
+
+    /// Completed share in 0.0..=1.0, when the total is known
+    pub fn fraction(&self) -> Option<f64> {
+        self.estimated_total
+            .filter(|total| *total > 0)
+            .map(|total| (self.loaded as f64 / total as f64).min(1.0))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryError {
+    /// The user stopped the operation
+    Cancelled,
+    Rpc(String),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Cancelled => write!(f, "Cancelled by user"),
+            HistoryError::Rpc(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<RpcError> for HistoryError {
+    fn from(error: RpcError) -> Self {
+        HistoryError::Rpc(error.to_string())
+    }
+}
+
+/// Load every message of a group, oldest first.
+///
+/// `on_progress` is called after each page. Sending on (or dropping the
+/// sender of) `cancel` stops the loop with `HistoryError::Cancelled`.
+pub async fn fetch_chat_history(
+    rpc: &RpcConnection,
+    group_id: u64,
+    estimated_total: Option<u64>,
+    mut cancel: oneshot::Receiver<()>,
+    on_progress: impl Fn(LoadProgress),
+) -> Result<Vec<ChatMessage>, HistoryError> {
+    let mut messages: Vec<ChatMessage> = Vec::new();
+    let mut before: Option<String> = None;
+    on_progress(LoadProgress { loaded: 0, estimated_total });
+
+    loop {
+        let page = pin!(rpc.get_chat_messages(group_id, Some(HISTORY_PAGE_SIZE), before.clone()));
+        let page = match future::select(page, &mut cancel).await {
+            Either::Left((page, _)) => page?,
+            Either::Right(_) => return Err(HistoryError::Cancelled),
+        };
+
+        messages.extend(page.messages);
+        on_progress(LoadProgress { loaded: messages.len(), estimated_total });
+
+        match page.next_before {
+            Some(next) if page.has_more => before = Some(next),
+            _ => break,
+        }
+    }
+
+    messages.sort_by_key(|message| message.timestamp);
+    Ok(messages)
+}
+
+/// Chat history export file
+#[derive(Serialize)]
+struct ChatHistoryExport<'a> {
+    group_id: u64,
+    /// Unix timestamp (seconds) of the export
+    exported_at: i64,
+    messages: &'a [ChatMessage],
+}
+
+/// Export file contents for a group's messages
+pub fn chat_history_json(group_id: u64, messages: &[ChatMessage], exported_at: i64) -> Result<String, String> {
+    serde_json::to_string_pretty(&ChatHistoryExport { group_id, exported_at, messages })
+        .map_err(|e| format!("Failed to serialize chat history: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_label_and_fraction() {
+        let progress = LoadProgress { loaded: 300, estimated_total: Some(1200) };
+        assert_eq!(progress.label(), "Loaded 300 of ~1200");
+        assert_eq!(progress.fraction(), Some(0.25));
+
+        // the total is an estimate, so more can be loaded than expected
+        assert_eq!(LoadProgress { loaded: 5, estimated_total: Some(4) }.fraction(), Some(1.0));
+        assert_eq!(LoadProgress { loaded: 5, estimated_total: Some(0) }.fraction(), None);
+        assert_eq!(LoadProgress { loaded: 5, estimated_total: None }.label(), "Loaded 5");
+    }
+}
@@ -0,0 +1,264 @@
+//! UI string lookup
+//!
+//! UI text lives in per-locale tables keyed by dotted ids such as
+//! `"chat.room.refresh"`, so strings are kept in one place and can be
+//! translated. Only English ships for now. `t` returns the text for the
+//! current locale, falling back to English and then to the key itself so a
+//! missing entry is visible instead of blank. Templates use `{}` for values
+//! filled in by `tf`, in order.
+//!
+//! The native gas token is called XNT throughout, never SOL.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// Supported UI languages
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+}
+
+/// English strings: chat room
+const EN_CHAT_ROOM: &[(&str, &str)] = &[
+    ("chat.room.back", "Back to Groups"),
+    ("chat.room.loading_group", "Loading Group..."),
+    ("chat.room.creator_badge", "Creator"),
+    ("chat.room.creator_badge_title", "You created this group"),
+    ("chat.room.refresh", "Refresh"),
+    ("chat.room.live", "Live"),
+    ("chat.room.live_title", "New messages load every {} seconds"),
+    ("chat.room.export", "Export"),
+    ("chat.room.export_title", "Download this group's full message history as JSON"),
+    ("chat.room.exporting", "Exporting message history"),
+    ("chat.room.loading_messages", "Loading messages..."),
+    ("chat.room.empty", "No messages in this group yet"),
+    ("chat.room.empty_hint", "Be the first to start the conversation!"),
+    ("chat.room.load_older", "Load older messages"),
+    ("chat.room.loading_older", "Loading older messages..."),
+    ("chat.room.search_placeholder", "Search loaded messages"),
+    ("chat.room.search_count", "{} of {}"),
+    ("chat.room.search_clear", "Clear search"),
+    ("chat.room.no_matches", "No matches"),
+    ("chat.room.no_matches_hint", "Only loaded messages are searched"),
+    ("chat.room.new_messages", "New messages"),
+    ("chat.room.hidden_count", "{} hidden in this browser - manage in Settings"),
+    ("chat.room.min_interval", "Min interval between messages in this group: {}"),
+    ("chat.room.next_message_in", "Next message possible in {}"),
+    ("chat.input.placeholder", "Type your message..."),
+    ("chat.input.sending", "Sending, please wait..."),
+    ("chat.input.low_balance_send", "Insufficient balance, sending message requires at least {} XNT (current: {} XNT)"),
+    ("chat.input.burn_placeholder", "Type your burn message..."),
+    ("chat.input.burning", "Burning tokens, please wait..."),
+    ("chat.input.low_balance_burn", "Insufficient balance, burning requires at least {} XNT (current: {} XNT)"),
+    ("chat.input.mode_message", "Send Message"),
+    ("chat.input.mode_burn", "Burn Tokens"),
+    ("chat.input.send", "Send"),
+    ("chat.input.wait", "Wait {}"),
+    ("chat.input.sending_short", "Sending..."),
+    ("chat.input.burn", "Burn"),
+    ("chat.input.burning_short", "Burning..."),
+    ("chat.input.max", "Max"),
+    ("chat.input.show_options", "Show burn presets and hints"),
+    ("chat.input.hide_options", "Hide burn presets and hints"),
+    ("chat.input.exceeds_balance", "Exceeds balance ({} MEMO)"),
+    ("chat.input.burn_bytes", "{} / {} bytes"),
+    ("chat.input.burn_bytes_title", "Burn message size; a burn-reply's reference to the original message uses part of the limit"),
+    ("chat.input.burn_reply_to", "Burn-reply to {}"),
+    ("chat.input.burn_reply_cancel", "Burn without replying"),
+    ("chat.input.reply_to", "Reply to {}"),
+    ("chat.input.reply_cancel", "Send without replying"),
+    ("chat.input.attach_pixel", "Attach pixel art to this message"),
+    ("chat.input.pixel_hint", "Click cells to draw"),
+    ("chat.input.pixel_remove", "Remove"),
+    ("chat.input.pixel_remove_title", "Send the message without an image"),
+    ("chat.input.pixel_done", "Done"),
+    ("chat.input.earn_prefix", "Earn "),
+    ("chat.input.earn_suffix", " per message"),
+    ("chat.input.burn_hint", "Burn tokens to boost your leaderboard ranking"),
+    ("chat.input.fee_estimate", "Network fee: ~{} XNT"),
+    ("chat.input.fee_minimum", "Sending requires at least {} XNT for fees"),
+    ("chat.input.top_up", "Your balance is {} XNT, below the {} XNT needed for transaction fees. Top up to continue."),
+    ("chat.input.check_balance", "Check again"),
+    ("chat.input.check_balance_title", "Reload your balance"),
+    ("chat.input.dismiss_error", "Dismiss"),
+    ("chat.error.low_fee_balance", "Insufficient XNT balance for transaction fee! Current: {} XNT, Required: at least {} XNT"),
+    ("chat.message.reply", "Reply"),
+    ("chat.message.reply_burn", "Reply with a burn"),
+    ("chat.message.copy_markdown", "Copy this and the following messages as Markdown"),
+    ("chat.message.copied_markdown", "Copied to clipboard"),
+    ("chat.message.copy", "Copy message text"),
+    ("chat.message.explorer", "View transaction in the explorer"),
+    ("chat.message.copied", "Copied!"),
+    ("chat.message.copy_burn_text", "[Burned {} MEMO] {}"),
+    ("chat.message.hide", "Hide this message (only in this browser)"),
+    ("chat.message.reported", "Reported (saved in this browser for your records)"),
+    ("chat.message.report", "Report this message (saved in this browser for your records, not sent anywhere)"),
+    ("chat.message.reply_to", "Reply to {}"),
+    ("chat.message.reply_author_unknown", "message {}"),
+    ("chat.message.reply_not_loaded", "Original message is not loaded"),
+    ("chat.message.pixel_image", "[pixel art]"),
+    ("chat.message.verify", "Verify this message against its transaction on chain"),
+    ("chat.message.verifying", "Checking the transaction..."),
+    ("chat.message.verified", "Verified on chain"),
+    ("chat.message.verified_title", "The on-chain memo matches this message"),
+    ("chat.message.mismatch", "Mismatch"),
+    ("chat.message.not_found", "Not on chain yet"),
+    ("chat.message.not_found_title", "No confirmed transaction with this signature yet; try again shortly"),
+    ("chat.message.verify_failed", "Could not verify: {}"),
+    ("chat.status.sending", "Sending..."),
+    ("chat.status.cancel", "Cancel"),
+    ("chat.status.cancel_title", "Stop waiting. The transaction may still land on-chain, so check before sending again."),
+    ("chat.status.sent", "Sent"),
+    ("chat.status.sent_title", "Submitted, waiting for the transaction to be finalized"),
+    ("chat.status.confirmed", "Confirmed"),
+    ("chat.status.confirmed_title", "Finalized on-chain, this can no longer be rolled back"),
+    ("chat.status.failed", "Failed"),
+    ("chat.status.timeout", "Timeout"),
+    ("chat.status.retry", "Retry"),
+    ("chat.status.retry_title", "Retry this operation"),
+    ("chat.status.unconfirmed", "Unknown, verify on explorer"),
+    ("chat.status.unconfirmed_title", "This send was interrupted and has not been seen on-chain. It may still have landed; verify on the explorer before sending it again."),
+    ("chat.status.explorer", "Explorer"),
+    ("chat.status.explorer_title", "Open your account's transactions in the explorer"),
+    ("chat.status.dismiss", "Dismiss"),
+    ("chat.status.dismiss_title", "Stop tracking this send"),
+    ("chat.notify.title", "New message in {}"),
+    ("chat.notify.body", "{}: {}"),
+    ("chat.notify.body_more", "{}: {} (+{} more)"),
+];
+
+/// English strings: chat groups list
+const EN_CHAT_GROUPS: &[(&str, &str)] = &[
+    ("chat.groups.title", "Chat Groups"),
+    ("chat.groups.subtitle", "Connect and communicate on X1 Blockchain"),
+    ("chat.groups.create", "Create Group"),
+    ("chat.groups.create_title", "Create new chat group"),
+    ("chat.groups.profile_required", "Please create your profile first"),
+    ("chat.groups.refresh", "Refresh"),
+    ("chat.groups.refresh_title", "Refresh chat groups"),
+    ("chat.groups.loading_leaderboard", "Loading burn leaderboard..."),
+    ("chat.groups.featured", "Featured Activity"),
+    ("chat.groups.display_mode", "Display Mode:"),
+    ("chat.groups.jump_placeholder", "Group ID"),
+    ("chat.groups.ranking", "Chat Groups Ranking"),
+    ("chat.groups.latest", "Latest Chat Groups"),
+    ("chat.groups.oldest", "Oldest Chat Groups"),
+    ("chat.groups.favorites", "Favorite Chat Groups"),
+    ("chat.groups.loading_latest", "Loading latest groups..."),
+    ("chat.groups.loading_oldest", "Loading oldest groups..."),
+    ("chat.groups.loading_favorites", "Loading favorite groups..."),
+    ("chat.groups.empty", "No groups found"),
+    ("chat.groups.empty_leaderboard", "No groups in burn leaderboard yet"),
+    ("chat.groups.empty_favorites", "No favorite groups yet. Click the star on a group to add it."),
+    ("chat.groups.none_yet", "No chat groups yet"),
+    ("chat.groups.none_yet_hint", "This network doesn't have any chat groups. Start the first one!"),
+    ("chat.groups.create_first", "Create the first group"),
+    ("chat.groups.previous", "Previous"),
+    ("chat.groups.next", "Next"),
+    ("chat.groups.retry", "Retry"),
+    ("chat.groups.showing_ranks", "Showing rank {} - {} of {} groups"),
+    ("chat.groups.page_of", " (Page {} of {})"),
+    ("chat.groups.page", "Page {}"),
+    ("chat.groups.page_latest", "Page {} - Latest groups"),
+    ("chat.groups.page_oldest", "Page {} - Oldest groups"),
+    ("chat.groups.favorites_count", "{} favorite groups (saved in this browser)"),
+    ("chat.groups.failed_one", "{} group failed to load"),
+    ("chat.groups.failed_many", "{} groups failed to load"),
+    ("chat.group.number", "Group #{}"),
+    ("chat.group.number_unavailable", "Group #{} (info not available)"),
+    ("chat.group.info_unavailable", "Group info not available"),
+    ("chat.group.loading_info", "Loading group info..."),
+    ("chat.group.creator", "Creator:"),
+    ("chat.group.created", "Created:"),
+    ("chat.group.last_message", "Last message:"),
+    ("chat.group.min_interval", "Min interval:"),
+    ("chat.group.messages", "{} messages"),
+    ("chat.group.burned", "{} MEMO"),
+    ("chat.group.participants", "{} participants"),
+    ("chat.group.participants_recent", "{} participants among last {} messages"),
+    ("chat.group.enter_hint", "Click to enter chat group"),
+    ("chat.group.enter", "Enter"),
+    ("chat.group.enter_title", "Enter chat group"),
+    ("chat.group.unread", "New messages since your last visit"),
+];
+
+/// English strings: local address nicknames
+const EN_NICKNAMES: &[(&str, &str)] = &[
+    ("nickname.add", "Set a local nickname"),
+    ("nickname.edit", "Edit local nickname"),
+    ("nickname.placeholder", "Nickname (only in this browser)"),
+    ("nickname.save", "Save nickname"),
+    ("nickname.clear", "Clear nickname"),
+    ("nickname.local_title", "Your local nickname, not verified on chain. Real identity: {}"),
+];
+
+const EN_TABLES: &[&[(&str, &str)]] = &[EN_CHAT_ROOM, EN_CHAT_GROUPS, EN_NICKNAMES];
+
+static EN: Lazy<HashMap<&'static str, &'static str>> =
+    Lazy::new(|| EN_TABLES.iter().flat_map(|table| table.iter().copied()).collect());
+
+/// Locale the UI is shown in
+pub fn current_locale() -> Locale {
+    Locale::default()
+}
+
+fn lookup(locale: Locale, key: &str) -> Option<&'static str> {
+    match locale {
+        Locale::En => EN.get(key).copied(),
+    }
+}
+
+/// Text for `key` in the current locale
+pub fn t(key: &'static str) -> &'static str {
+    lookup(current_locale(), key)
+        .or_else(|| lookup(Locale::En, key))
+        .unwrap_or_else(|| {
+            log::warn!("Missing UI string: {}", key);
+            key
+        })
+}
+
+/// Text for `key` with each `{}` replaced by the next of `args`
+pub fn tf(key: &'static str, args: &[&str]) -> String {
+    let template = t(key);
+    let mut out = String::with_capacity(template.len() + args.iter().map(|a| a.len()).sum::<usize>());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        out.push_str(args.next().copied().unwrap_or("{}"));
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_english_keys_are_unique() {
+        let mut seen = HashSet::new();
+        for (key, _) in EN_TABLES.iter().flat_map(|table| table.iter()) {
+            assert!(seen.insert(*key), "duplicate UI string key: {}", key);
+        }
+    }
+
+    #[test]
+    fn test_t_and_tf() {
+        assert_eq!(t("chat.room.refresh"), "Refresh");
+        assert_eq!(t("no.such.key"), "no.such.key");
+        assert_eq!(tf("chat.group.number", &["42"]), "Group #42");
+        assert_eq!(tf("chat.group.messages", &[]), "{} messages");
+    }
+
+    #[test]
+    fn test_native_token_is_called_xnt() {
+        for (key, text) in EN_TABLES.iter().flat_map(|table| table.iter()) {
+            assert!(!text.contains("SOL"), "{} still says SOL: {}", key, text);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! The newest message seen in each chat group, remembered locally
+//!
+//! When a room is opened again, messages after the remembered one are new
+//! since the last visit, and the groups list marks groups whose last memo is
+//! newer than anything read there as unread. Records are kept per network in localStorage via
+//! `storage_base`, most recently updated last, and capped at
+//! `MAX_LAST_SEEN_GROUPS`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::rpc_chat::ChatMessage;
+use super::storage_base;
+
+const LAST_SEEN_KEY: &str = "last_seen_chat_groups";
+
+/// Maximum groups remembered; the least recently updated are dropped first
+pub const MAX_LAST_SEEN_GROUPS: usize = 500;
+
+/// Newest on-chain message the user has had on screen in a group
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LastSeen {
+    pub group_id: u64,
+    /// Empty until a message was on screen
+    pub signature: String,
+    /// Block time (seconds) of that message
+    pub timestamp: i64,
+    /// The group's `last_memo_time` when the room was last opened
+    #[serde(default)]
+    pub group_memo_time: i64,
+}
+
+impl LastSeen {
+    fn empty(group_id: u64) -> Self {
+        Self { group_id, signature: String::new(), timestamp: 0, group_memo_time: 0 }
+    }
+
+    /// Block time up to which everything in the group has been read
+    pub fn read_until(&self) -> i64 {
+        self.timestamp.max(self.group_memo_time)
+    }
+
+    /// Index of the first message in `messages` (oldest first) that is newer
+    /// than this one, None if there are no new messages
+    pub fn first_unseen_index(&self, messages: &[ChatMessage]) -> Option<usize> {
+        let first = match messages.iter().rposition(|m| m.signature == self.signature) {
+            Some(seen) => seen + 1,
+            // the seen message is older than the loaded page
+            None => messages.iter().position(|m| m.timestamp > self.read_until())?,
+        };
+        (first < messages.len()).then_some(first)
+    }
+}
+
+fn load_all() -> Vec<LastSeen> {
+    storage_base::load_network_list(LAST_SEEN_KEY)
+}
+
+/// Replace the group's record and move it to the end, dropping the oldest beyond the cap
+fn push_last_seen(records: &mut Vec<LastSeen>, record: LastSeen) {
+    storage_base::push_bounded(records, record, MAX_LAST_SEEN_GROUPS, |a, b| a.group_id == b.group_id);
+}
+
+/// The newest message seen in a group on a previous visit
+pub fn load_last_seen(group_id: u64) -> Option<LastSeen> {
+    load_all().into_iter().find(|r| r.group_id == group_id)
+}
+
+/// Read-until time of every remembered group, for the groups list.
+/// Empty if storage is unavailable, so nothing shows as unread.
+pub fn load_read_until() -> HashMap<u64, i64> {
+    load_all().into_iter().map(|r| (r.group_id, r.read_until())).collect()
+}
+
+/// Whether a group whose newest memo is at `last_memo_time` has anything
+/// newer than what was read. Groups never opened are not unread.
+pub fn has_unread(read_until: &HashMap<u64, i64>, group_id: u64, last_memo_time: i64) -> bool {
+    read_until.get(&group_id).is_some_and(|&read| last_memo_time > read)
+}
+
+/// Change the group's record in place and move it to the end
+fn update_record(group_id: u64, f: impl FnOnce(&mut LastSeen)) -> Result<(), String> {
+    let key = storage_base::network_key(LAST_SEEN_KEY).ok_or_else(|| "Network is not initialized".to_string())?;
+    let mut records = load_all();
+    let mut record = records
+        .iter()
+        .find(|r| r.group_id == group_id)
+        .cloned()
+        .unwrap_or_else(|| LastSeen::empty(group_id));
+    f(&mut record);
+    push_last_seen(&mut records, record);
+    storage_base::save_json(&key, &records)
+}
+
+/// Remember `message` as the newest one seen in the group (no-op if already recorded)
+pub fn mark_seen(group_id: u64, message: &ChatMessage) -> Result<(), String> {
+    if load_last_seen(group_id).is_some_and(|r| r.signature == message.signature) {
+        return Ok(());
+    }
+    update_record(group_id, |record| {
+        record.signature = message.signature.clone();
+        record.timestamp = message.timestamp;
+    })
+}
+
+/// Remember the group's `last_memo_time` on opening its room, so the group
+/// counts as read even if its newest memo never shows as a message
+pub fn mark_opened(group_id: u64, last_memo_time: i64) -> Result<(), String> {
+    update_record(group_id, |record| {
+        record.group_memo_time = record.group_memo_time.max(last_memo_time);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(signature: &str, timestamp: i64) -> ChatMessage {
+        ChatMessage {
+            signature: signature.to_string(),
+            sender: "alice".to_string(),
+            message: "gm".to_string(),
+            timestamp,
+            slot: 1,
+            memo_amount: 0,
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            reply_to_sig: None,
+        }
+    }
+
+    fn seen(signature: &str, timestamp: i64) -> LastSeen {
+        LastSeen { group_id: 1, signature: signature.to_string(), timestamp, group_memo_time: 0 }
+    }
+
+    #[test]
+    fn test_first_unseen_index_after_seen_signature() {
+        // same block time as the seen message, so the signature decides
+        let messages = vec![message("a", 10), message("b", 20), message("c", 20), message("d", 30)];
+        assert_eq!(seen("b", 20).first_unseen_index(&messages), Some(2));
+        assert_eq!(seen("d", 30).first_unseen_index(&messages), None);
+    }
+
+    #[test]
+    fn test_first_unseen_index_falls_back_to_time() {
+        let messages = vec![message("x", 100), message("y", 200)];
+        assert_eq!(seen("old", 150).first_unseen_index(&messages), Some(1));
+        assert_eq!(seen("old", 50).first_unseen_index(&messages), Some(0));
+        assert_eq!(seen("old", 300).first_unseen_index(&messages), None);
+        assert_eq!(seen("old", 50).first_unseen_index(&[]), None);
+        // opened before any message was on screen
+        let opened = LastSeen { group_memo_time: 150, ..LastSeen::empty(1) };
+        assert_eq!(opened.first_unseen_index(&messages), Some(1));
+    }
+
+    #[test]
+    fn test_has_unread() {
+        let read_until: HashMap<u64, i64> = [
+            (1, seen("a", 100).read_until()),
+            (2, LastSeen { group_memo_time: 300, ..seen("b", 200) }.read_until()),
+        ].into_iter().collect();
+        assert!(has_unread(&read_until, 1, 101));
+        assert!(!has_unread(&read_until, 1, 100));
+        assert!(!has_unread(&read_until, 2, 250));
+        assert!(has_unread(&read_until, 2, 301));
+        // never opened
+        assert!(!has_unread(&read_until, 3, 1_000));
+    }
+
+    #[test]
+    fn test_push_last_seen_replaces_and_is_bounded() {
+        let mut records = vec![seen("a", 1), LastSeen { group_id: 2, ..seen("b", 2) }];
+        push_last_seen(&mut records, seen("c", 3));
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.last().map(|r| r.signature.as_str()), Some("c"));
+
+        let mut records: Vec<LastSeen> = (0..MAX_LAST_SEEN_GROUPS as u64)
+            .map(|group_id| LastSeen { group_id, ..seen("s", 0) })
+            .collect();
+        push_last_seen(&mut records, LastSeen { group_id: 10_000, ..seen("s", 0) });
+        assert_eq!(records.len(), MAX_LAST_SEEN_GROUPS);
+        assert_eq!(records.first().map(|r| r.group_id), Some(1));
+    }
+}
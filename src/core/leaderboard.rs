@@ -0,0 +1,130 @@
+//! Client-side burn leaderboard estimates
+//!
+//! The chat and project leaderboards are both ranked by total burned amount
+//! and hold at most `LEADERBOARD_CAPACITY` entries. The helpers here only
+//! look at data the page has already loaded, so their results are estimates:
+//! other burns may land before the user's does.
+
+/// Number of entries kept by the on-chain burn leaderboards
+pub const LEADERBOARD_CAPACITY: usize = 100;
+
+/// How a group's or project's rank would change after an additional burn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankProjection {
+    /// Rank (1-based) in the loaded leaderboard, None if not listed
+    pub current_rank: Option<usize>,
+    /// Estimated rank once the burn lands, None if still outside the leaderboard
+    pub projected_rank: Option<usize>,
+    /// Extra lamports on top of the entered burn needed to pass the next entry above
+    pub to_next_rank: Option<u64>,
+}
+
+impl RankProjection {
+    /// Whether the burn is expected to improve the rank
+    pub fn improves(&self) -> bool {
+        match (self.current_rank, self.projected_rank) {
+            (Some(current), Some(projected)) => projected < current,
+            (None, Some(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Estimate the rank of `target_id` after burning `added` more lamports.
+///
+/// `entries` are `(id, burned_amount)` pairs in leaderboard order (highest
+/// burn first). `current_burned` is the target's latest known total, which
+/// may be fresher than its leaderboard entry. Ties are not counted as
+/// overtaking, so the estimate errs on the conservative side.
+pub fn project_rank(entries: &[(u64, u64)], target_id: u64, current_burned: u64, added: u64) -> RankProjection {
+    let current_rank = entries
+        .iter()
+        .position(|(id, _)| *id == target_id)
+        .map(|index| index + 1);
+
+    let new_total = current_burned.saturating_add(added);
+    let others = entries.iter().filter(|(id, _)| *id != target_id);
+
+    let ahead = others.clone().filter(|(_, burned)| *burned >= new_total).count();
+    let projected_rank = Some(ahead + 1).filter(|rank| *rank <= LEADERBOARD_CAPACITY);
+
+    // the entry directly above is the smallest burn that is still not passed
+    let to_next_rank = others
+        .map(|(_, burned)| *burned)
+        .filter(|burned| *burned >= new_total)
+        .min()
+        .map(|next| next - new_total + 1);
+
+    RankProjection {
+        current_rank,
+        projected_rank,
+        to_next_rank,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(amounts: &[u64]) -> Vec<(u64, u64)> {
+        amounts.iter().enumerate().map(|(i, amount)| (i as u64 + 1, *amount)).collect()
+    }
+
+    #[test]
+    fn test_burn_overtakes_entries_above() {
+        let entries = board(&[500, 400, 300, 200]);
+        let projection = project_rank(&entries, 4, 200, 250);
+        assert_eq!(projection.current_rank, Some(4));
+        assert_eq!(projection.projected_rank, Some(2));
+        assert_eq!(projection.to_next_rank, Some(51));
+        assert!(projection.improves());
+    }
+
+    #[test]
+    fn test_tie_does_not_overtake() {
+        let entries = board(&[500, 400, 300]);
+        let projection = project_rank(&entries, 3, 300, 100);
+        assert_eq!(projection.projected_rank, Some(3));
+        assert_eq!(projection.to_next_rank, Some(1));
+        assert!(!projection.improves());
+    }
+
+    #[test]
+    fn test_top_rank_has_nothing_to_pass() {
+        let entries = board(&[500, 400]);
+        let projection = project_rank(&entries, 1, 500, 10);
+        assert_eq!(projection.projected_rank, Some(1));
+        assert_eq!(projection.to_next_rank, None);
+    }
+
+    #[test]
+    fn test_unlisted_target_enters_board() {
+        let entries = board(&[500, 400]);
+        let projection = project_rank(&entries, 99, 0, 450);
+        assert_eq!(projection.current_rank, None);
+        assert_eq!(projection.projected_rank, Some(2));
+        assert!(projection.improves());
+    }
+
+    #[test]
+    fn test_full_board_requires_beating_last_entry() {
+        let amounts: Vec<u64> = (0..LEADERBOARD_CAPACITY as u64).map(|i| 1_000 - i).collect();
+        let entries = board(&amounts);
+        let last = *amounts.last().unwrap();
+
+        let projection = project_rank(&entries, 999, 0, last);
+        assert_eq!(projection.projected_rank, None);
+        assert_eq!(projection.to_next_rank, Some(1));
+
+        let projection = project_rank(&entries, 999, 0, last + 1);
+        assert_eq!(projection.projected_rank, Some(LEADERBOARD_CAPACITY));
+    }
+
+    #[test]
+    fn test_fresher_burned_amount_is_used() {
+        // the leaderboard still shows 100 but the group has since reached 350
+        let entries = board(&[500, 400, 100]);
+        let projection = project_rank(&entries, 3, 350, 100);
+        assert_eq!(projection.projected_rank, Some(2));
+    }
+}
@@ -0,0 +1,224 @@
+//! Minimal inline markdown for user text (chat messages, devlogs)
+//!
+//! Supports `**bold**`, `*italic*`, `` `code` `` and bare http(s) links
+//! (detected by `text::split_links`). Parsing only splits the text into
+//! styled pieces; the UI emits every piece as a text node inside the
+//! matching element, so markup in the input is never interpreted as HTML.
+//! Delimiters without a partner are kept as typed.
+
+use super::text::{split_links, TextSegment};
+
+/// A styled piece of user text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    /// Plain text, possibly bold and/or italic
+    Text { text: String, bold: bool, italic: bool },
+    /// Inline code, shown as typed (no links or emphasis inside)
+    Code(String),
+    /// A detected http(s) URL
+    Link(String),
+}
+
+/// Split `text` into styled pieces. Code spans are found first, then links,
+/// then emphasis in the text between them (so emphasis never spans a link).
+pub fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    let mut plain = String::new();
+
+    while let Some(open) = rest.find('`') {
+        let after = &rest[open + 1..];
+        // an empty pair (``) is not a code span
+        let Some(close) = after.find('`').filter(|close| *close > 0) else {
+            break;
+        };
+        plain.push_str(&rest[..open]);
+        push_styled(&mut pieces, &plain);
+        plain.clear();
+        pieces.push(Inline::Code(after[..close].to_string()));
+        rest = &after[close + 1..];
+    }
+    plain.push_str(rest);
+    push_styled(&mut pieces, &plain);
+    pieces
+}
+
+/// Push links and emphasized text found in `text`
+fn push_styled(pieces: &mut Vec<Inline>, text: &str) {
+    for segment in split_links(text) {
+        match segment {
+            TextSegment::Link(url) => pieces.push(Inline::Link(url)),
+            TextSegment::Text(text) => push_emphasis(pieces, &text, false, false),
+        }
+    }
+}
+
+/// Push `text` with `**` and `*` pairs turned into bold and italic pieces
+fn push_emphasis(pieces: &mut Vec<Inline>, text: &str, bold: bool, italic: bool) {
+    let mut plain_start = 0;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let delimiter = if rest.starts_with("**") {
+            "**"
+        } else if rest.starts_with('*') {
+            "*"
+        } else {
+            pos += rest.chars().next().map_or(1, char::len_utf8);
+            continue;
+        };
+
+        let inner = &rest[delimiter.len()..];
+        match find_closing(inner, delimiter) {
+            Some(close) => {
+                push_text(pieces, &text[plain_start..pos], bold, italic);
+                let (inner_bold, inner_italic) = if delimiter == "**" { (true, italic) } else { (bold, true) };
+                push_emphasis(pieces, &inner[..close], inner_bold, inner_italic);
+                pos += delimiter.len() * 2 + close;
+                plain_start = pos;
+            }
+            // unpaired: stays part of the plain text
+            None => pos += delimiter.len(),
+        }
+    }
+
+    push_text(pieces, &text[plain_start..], bold, italic);
+}
+
+/// Byte offset in `inner` of the delimiter closing one that was just opened.
+/// Emphasis can't start or end with whitespace, and a `*` that is half of a
+/// `**` never closes an italic.
+fn find_closing(inner: &str, delimiter: &str) -> Option<usize> {
+    if inner.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let mut search = 0;
+    while let Some(found) = inner[search..].find(delimiter) {
+        let at = search + found;
+        if delimiter == "*" && inner[at + 1..].starts_with('*') {
+            search = at + 2;
+            continue;
+        }
+        if at > 0 && !inner[..at].ends_with(char::is_whitespace) {
+            return Some(at);
+        }
+        search = at + delimiter.len();
+    }
+    None
+}
+
+fn push_text(pieces: &mut Vec<Inline>, text: &str, bold: bool, italic: bool) {
+    if !text.is_empty() {
+        pieces.push(Inline::Text { text: text.to_string(), bold, italic });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(s: &str) -> Inline {
+        Inline::Text { text: s.to_string(), bold: false, italic: false }
+    }
+
+    fn bold(s: &str) -> Inline {
+        Inline::Text { text: s.to_string(), bold: true, italic: false }
+    }
+
+    fn italic(s: &str) -> Inline {
+        Inline::Text { text: s.to_string(), bold: false, italic: true }
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(parse_inline("gm everyone"), vec![plain("gm everyone")]);
+        assert!(parse_inline("").is_empty());
+    }
+
+    #[test]
+    fn test_bold_italic_and_code() {
+        assert_eq!(
+            parse_inline("a **big** and *small* `x = 1`"),
+            vec![
+                plain("a "),
+                bold("big"),
+                plain(" and "),
+                italic("small"),
+                plain(" "),
+                Inline::Code("x = 1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_emphasis() {
+        assert_eq!(
+            parse_inline("*very **important** note*"),
+            vec![
+                italic("very "),
+                Inline::Text { text: "important".to_string(), bold: true, italic: true },
+                italic(" note"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(
+            parse_inline("**see** https://x1.xyz now"),
+            vec![bold("see"), plain(" "), Inline::Link("https://x1.xyz".to_string()), plain(" now")]
+        );
+        // asterisks inside a URL belong to it
+        assert_eq!(
+            parse_inline("https://example.com/*a*"),
+            vec![Inline::Link("https://example.com/*a*".to_string())]
+        );
+        // code spans are not linked
+        assert_eq!(
+            parse_inline("`https://x1.xyz`"),
+            vec![Inline::Code("https://x1.xyz".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_html_stays_text() {
+        assert_eq!(
+            parse_inline("<script>alert('x')</script>"),
+            vec![plain("<script>alert('x')</script>")]
+        );
+        assert_eq!(parse_inline("**<img src=x onerror=alert(1)>**"), vec![bold("<img src=x onerror=alert(1)>")]);
+        assert_eq!(parse_inline("`<b>`"), vec![Inline::Code("<b>".to_string())]);
+        assert_eq!(parse_inline("javascript:alert(1)"), vec![plain("javascript:alert(1)")]);
+    }
+
+    #[test]
+    fn test_unbalanced_delimiters_are_literal() {
+        for input in ["**bold", "*a", "a * b * c", "2 ** 3", "****", "*", "**", "`unclosed", "``", "a ` b"] {
+            let rendered: String = parse_inline(input)
+                .into_iter()
+                .map(|piece| match piece {
+                    Inline::Text { text, bold, italic } => {
+                        assert!(!bold && !italic, "{input:?} should have no emphasis");
+                        text
+                    }
+                    other => panic!("{input:?} parsed as {other:?}"),
+                })
+                .collect();
+            assert_eq!(rendered, input);
+        }
+    }
+
+    #[test]
+    fn test_code_keeps_asterisks() {
+        assert_eq!(
+            parse_inline("`*not italic*` but *this*"),
+            vec![Inline::Code("*not italic*".to_string()), plain(" but "), italic("this")]
+        );
+    }
+
+    #[test]
+    fn test_multibyte_text() {
+        assert_eq!(parse_inline("🔥 **燃烧** 🔥"), vec![plain("🔥 "), bold("燃烧"), plain(" 🔥")]);
+    }
+}
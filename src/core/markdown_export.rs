@@ -0,0 +1,142 @@
+//! Markdown copies of devlogs and chat threads, for sharing off-platform
+//!
+//! The output pastes cleanly into Discord or forum posts. Chat messages are
+//! block-quoted so their text can't break the surrounding layout. Pixel-art
+//! images are encoded strings stored on chain rather than files, so they are
+//! kept verbatim in a labelled code block; plain URLs become image links.
+
+/// A devlog with everything already formatted for display
+pub struct DevlogMarkdown<'a> {
+    pub project_name: &'a str,
+    pub title: &'a str,
+    pub content: &'a str,
+    pub image: &'a str,
+    pub author: &'a str,
+    pub date: &'a str,
+    /// Burn amount already formatted, e.g. "420"
+    pub burned_memo: &'a str,
+}
+
+/// One chat message with its sender's resolved display name
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChatMarkdownLine {
+    pub author: String,
+    pub time: String,
+    pub text: String,
+    /// Burn amount already formatted, for burn messages
+    pub burned_memo: Option<String>,
+}
+
+/// Markdown for an image field, None when there is no image
+pub fn image_markdown(image: &str) -> Option<String> {
+    let image = image.trim();
+    if image.is_empty() {
+        return None;
+    }
+    if image.starts_with("https://") || image.starts_with("http://") {
+        return Some(format!("![image]({})", image));
+    }
+    let label = if image.starts_with("c:") || image.starts_with("n:") {
+        "Image (encoded pixel art string, view it in MEMO App):"
+    } else {
+        "Image (encoded string):"
+    };
+    Some(format!("{}\n```text\n{}\n```", label, image))
+}
+
+/// `text` as a Markdown block quote, keeping blank lines inside the quote
+fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| if line.trim().is_empty() { ">".to_string() } else { format!("> {}", line) })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn devlog_markdown(devlog: &DevlogMarkdown) -> String {
+    let mut out = format!(
+        "## {}\n*{} devlog by {} on {}, burned {} MEMO*\n",
+        devlog.title, devlog.project_name, devlog.author, devlog.date, devlog.burned_memo
+    );
+    if !devlog.content.trim().is_empty() {
+        out.push('\n');
+        out.push_str(devlog.content.trim_end());
+        out.push('\n');
+    }
+    if let Some(image) = image_markdown(devlog.image) {
+        out.push('\n');
+        out.push_str(&image);
+        out.push('\n');
+    }
+    out
+}
+
+/// A run of chat messages under the group's name, oldest first
+pub fn chat_thread_markdown(group_name: &str, lines: &[ChatMarkdownLine]) -> String {
+    let mut out = format!("### {}\n", group_name);
+    for line in lines {
+        out.push_str(&format!("\n**{}** ({})", line.author, line.time));
+        if let Some(burned) = &line.burned_memo {
+            out.push_str(&format!(", burned {} MEMO", burned));
+        }
+        out.push('\n');
+        if !line.text.trim().is_empty() {
+            out.push_str(&quote(&line.text));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_markdown_labels_encoded_strings() {
+        assert_eq!(image_markdown("  "), None);
+        assert_eq!(image_markdown("https://x1.xyz/a.png"), Some("![image](https://x1.xyz/a.png)".to_string()));
+
+        let pixel = image_markdown("n:ABC").unwrap();
+        assert!(pixel.starts_with("Image (encoded pixel art string"));
+        assert!(pixel.contains("```text\nn:ABC\n```"));
+    }
+
+    #[test]
+    fn test_devlog_markdown() {
+        let markdown = devlog_markdown(&DevlogMarkdown {
+            project_name: "Memo",
+            title: "v2 is out",
+            content: "New editor\nand fixes\n",
+            image: "",
+            author: "abcd...wxyz",
+            date: "2026-10-14 12:00",
+            burned_memo: "420",
+        });
+        assert_eq!(
+            markdown,
+            "## v2 is out\n*Memo devlog by abcd...wxyz on 2026-10-14 12:00, burned 420 MEMO*\n\nNew editor\nand fixes\n"
+        );
+    }
+
+    #[test]
+    fn test_chat_thread_markdown_quotes_messages() {
+        let lines = vec![
+            ChatMarkdownLine {
+                author: "alice (abcd...wxyz)".to_string(),
+                time: "2026-10-14 12:00".to_string(),
+                text: "gm\n\n# not a heading".to_string(),
+                burned_memo: None,
+            },
+            ChatMarkdownLine {
+                author: "efgh...ijkl".to_string(),
+                time: "2026-10-14 12:01".to_string(),
+                text: "to the moon".to_string(),
+                burned_memo: Some("1,000".to_string()),
+            },
+        ];
+        assert_eq!(
+            chat_thread_markdown("Builders", &lines),
+            "### Builders\n\n**alice (abcd...wxyz)** (2026-10-14 12:00)\n> gm\n>\n> # not a heading\n\n**efgh...ijkl** (2026-10-14 12:01), burned 1,000 MEMO\n> to the moon\n"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! One typed entry point over the RPC modules
+//!
+//! The read side of the chain API is spread over `rpc_chat`, `rpc_project`,
+//! `rpc_profile`, `rpc_burn`, `rpc_mint` and `rpc_domain`, each as methods
+//! on `RpcConnection` or free functions. `MemoClient` groups them by topic
+//! (groups, projects, profiles, domains, balances) behind one value, so pages
+//! and code embedding this app don't need to know which module holds what.
+//! The modules stay the implementation; every method here delegates to them.
+//! Transactions are still built and signed through `Session`.
+//!
+//! ```
+//! let client = MemoClient::new();
+//! let balances = client.balances("DEQWNRhQmNg7T6UQxV8d2oJAanFHBu9YkNyXDb7GvzvA").await?;
+//! let top_groups = client.group_leaderboard().await?;
+//! println!("{} MEMO, {} ranked groups", balances.memo, top_groups.entries.len());
+//! ```
+
+// API for embedders: the UI doesn't call every method
+#![allow(dead_code)]
+
+use crate::core::cache;
+use crate::core::rpc_base::{get_token_mint, RpcConnection, RpcError};
+use crate::core::rpc_burn::UserGlobalBurnStats;
+use crate::core::rpc_chat::{BurnLeaderboardResponse, ChatGroupInfo, ChatMessagesResponse, GlobalStatistics};
+use crate::core::rpc_domain::{self, DomainError};
+use crate::core::rpc_profile::{UserDisplayInfo, UserProfile};
+use crate::core::rpc_project::{ProjectBurnLeaderboardResponse, ProjectInfo};
+
+/// Lamports per XNT
+const LAMPORTS_PER_XNT: f64 = 1_000_000_000.0;
+
+/// Wallet balances in whole tokens
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Balances {
+    /// Native gas token
+    pub xnt: f64,
+    pub memo: f64,
+}
+
+/// XNT balance from a `getBalance` result
+pub fn parse_xnt_balance(result_json: &str) -> Option<f64> {
+    let json: serde_json::Value = serde_json::from_str(result_json).ok()?;
+    json.get("value")?.as_u64().map(|lamports| lamports as f64 / LAMPORTS_PER_XNT)
+}
+
+/// Token balance from a `getTokenAccountsByOwner` (jsonParsed) result; None
+/// when the wallet has no token account yet
+pub fn parse_token_balance(result_json: &str) -> Option<f64> {
+    let json: serde_json::Value = serde_json::from_str(result_json).ok()?;
+    json.get("value")?
+        .as_array()?
+        .first()?
+        .pointer("/account/data/parsed/info/tokenAmount/uiAmount")?
+        .as_f64()
+}
+
+/// Typed client for reading MEMO data from the chain
+pub struct MemoClient {
+    rpc: RpcConnection,
+}
+
+impl Default for MemoClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoClient {
+    /// Client on an endpoint picked for the current network
+    pub fn new() -> Self {
+        Self::with_connection(RpcConnection::new())
+    }
+
+    /// Client on an existing connection, e.g. one made with `RpcConnection::with_endpoint`
+    pub fn with_connection(rpc: RpcConnection) -> Self {
+        Self { rpc }
+    }
+
+    /// The underlying connection, for calls the facade doesn't cover
+    pub fn rpc(&self) -> &RpcConnection {
+        &self.rpc
+    }
+
+    // ============ Balances ============
+
+    /// XNT and MEMO balances of `owner`. A wallet without a MEMO token
+    /// account has a MEMO balance of 0.
+    ///
+    /// ```
+    /// let balances = MemoClient::new().balances(&address).await?;
+    /// if balances.xnt < MIN_FEE_BALANCE_XNT { /* ask for a top-up */ }
+    /// ```
+    pub async fn balances(&self, owner: &str) -> Result<Balances, RpcError> {
+        let xnt = self.rpc.get_balance(owner).await?;
+        let xnt = parse_xnt_balance(&xnt)
+            .ok_or_else(|| RpcError::Other("Failed to parse XNT balance".to_string()))?;
+        let mint = get_token_mint()?;
+        let memo = self.rpc.get_token_balance(owner, &mint.to_string()).await?;
+        Ok(Balances { xnt, memo: parse_token_balance(&memo).unwrap_or(0.0) })
+    }
+
+    /// Total MEMO supply, in lamports (cached for a short while)
+    pub async fn token_supply(&self) -> Result<u64, RpcError> {
+        cache::get_token_supply(&self.rpc).await
+    }
+
+    /// Largest MEMO holders as `(address, balance)`, biggest first
+    pub async fn top_holders(&self, limit: usize) -> Result<Vec<(String, f64)>, RpcError> {
+        self.rpc.get_token_holders(limit).await
+    }
+
+    /// Largest burners as `(address, burned tokens, burn count)`, biggest first
+    pub async fn top_burners(&self, limit: usize) -> Result<Vec<(String, f64, u64)>, RpcError> {
+        self.rpc.get_top_burners(limit).await
+    }
+
+    /// Burn totals of a user, None until they initialized burn stats
+    pub async fn burn_stats(&self, user: &str) -> Result<Option<UserGlobalBurnStats>, RpcError> {
+        self.rpc.get_user_global_burn_stats(user).await
+    }
+
+    // ============ Chat groups ============
+
+    /// Number of chat groups
+    pub async fn group_statistics(&self) -> Result<GlobalStatistics, RpcError> {
+        self.rpc.get_chat_global_statistics().await
+    }
+
+    /// A group's info, served from the short-lived group cache
+    pub async fn group(&self, group_id: u64) -> Result<ChatGroupInfo, RpcError> {
+        cache::get_chat_group_info(&self.rpc, group_id).await
+    }
+
+    /// Groups with ids in `start_id..end_id` that exist
+    pub async fn groups(&self, start_id: u64, end_id: u64) -> Result<Vec<ChatGroupInfo>, RpcError> {
+        self.rpc.get_chat_groups_range(start_id, end_id).await
+    }
+
+    /// Groups ranked by tokens burned
+    pub async fn group_leaderboard(&self) -> Result<BurnLeaderboardResponse, RpcError> {
+        self.rpc.get_burn_leaderboard().await
+    }
+
+    /// A page of a group's messages. Pass the previous page's `next_before`
+    /// as `before` to read further back.
+    ///
+    /// ```
+    /// let client = MemoClient::new();
+    /// let page = client.group_messages(7, Some(20), None).await?;
+    /// let older = client.group_messages(7, Some(20), page.next_before.clone()).await?;
+    /// ```
+    pub async fn group_messages(
+        &self,
+        group_id: u64,
+        limit: Option<usize>,
+        before: Option<String>,
+    ) -> Result<ChatMessagesResponse, RpcError> {
+        self.rpc.get_chat_messages(group_id, limit, before).await
+    }
+
+    // ============ Projects ============
+
+    pub async fn project(&self, project_id: u64) -> Result<ProjectInfo, RpcError> {
+        self.rpc.get_project_info(project_id).await
+    }
+
+    /// Projects with ids in `start_id..end_id` that exist
+    pub async fn projects(&self, start_id: u64, end_id: u64) -> Result<Vec<ProjectInfo>, RpcError> {
+        self.rpc.get_projects_range(start_id, end_id).await
+    }
+
+    /// Projects ranked by tokens burned
+    pub async fn project_leaderboard(&self) -> Result<ProjectBurnLeaderboardResponse, RpcError> {
+        self.rpc.get_project_burn_leaderboard().await
+    }
+
+    // ============ Profiles and domains ============
+
+    /// A user's profile, None if they haven't created one
+    pub async fn profile(&self, user: &str) -> Result<Option<UserProfile>, RpcError> {
+        self.rpc.get_profile(user).await
+    }
+
+    /// Usernames and avatars for many users at once, in the order given
+    pub async fn display_infos(&self, users: &[&str]) -> Result<Vec<UserDisplayInfo>, RpcError> {
+        self.rpc.get_user_display_info_batch(users).await
+    }
+
+    /// The wallet's primary X1NS domain, if it set one. Uses the X1NS API
+    /// rather than the RPC endpoint, hence its own error type. Lookups are
+    /// cached, see `rpc_domain::get_primary_domain_cached`.
+    pub async fn primary_domain(&self, address: &str) -> Result<Option<String>, DomainError> {
+        rpc_domain::get_primary_domain_cached(address).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xnt_balance() {
+        assert_eq!(parse_xnt_balance(r#"{"context":{"slot":1},"value":2500000000}"#), Some(2.5));
+        assert_eq!(parse_xnt_balance(r#"{"value":null}"#), None);
+        assert_eq!(parse_xnt_balance("not json"), None);
+    }
+
+    #[test]
+    fn test_parse_token_balance() {
+        let json = r#"{"value":[{"account":{"data":{"parsed":{"info":{"tokenAmount":{"uiAmount":42.5}}}}}}]}"#;
+        assert_eq!(parse_token_balance(json), Some(42.5));
+        assert_eq!(parse_token_balance(r#"{"value":[]}"#), None);
+    }
+}
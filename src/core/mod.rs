@@ -16,7 +16,32 @@ pub mod rpc_burn;
 pub mod rpc_transfer;
 pub mod rpc_domain;
 pub mod rpc_forum;
+pub mod memo_client;
 pub mod settings;
+pub mod text;
+pub mod markdown;
+pub mod storage_base;
+pub mod moderation;
+pub mod nicknames;
+pub mod preferences;
+pub mod cache;
+pub mod leaderboard;
+pub mod visited;
+pub mod format;
+pub mod tx_error;
+pub mod pending_sends;
+pub mod settings_backup;
+pub mod theme;
+pub mod i18n;
+pub mod history;
+pub mod last_seen;
+pub mod chat_drafts;
+pub mod rpc_inspector;
+pub mod markdown_export;
+pub mod devlog;
+pub mod burn_stats;
+pub mod wallet_history;
+pub mod pagination;
 
 // Re-export commonly used network types
 pub use network_config::{NetworkType, initialize_network};
\ No newline at end of file
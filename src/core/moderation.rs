@@ -0,0 +1,182 @@
+//! Client-local message moderation
+//!
+//! Hiding or reporting a message only affects this browser: hidden
+//! signatures are stored in localStorage and filtered out of the rendered
+//! list, and reports are kept as a private record for the user. No data is
+//! sent to any server and on-chain content is never changed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::storage_base;
+
+const HIDDEN_MESSAGES_KEY: &str = "hidden_messages";
+const REPORTED_MESSAGES_KEY: &str = "reported_messages";
+
+/// Maximum characters of message text kept as a preview
+const PREVIEW_CHARS: usize = 80;
+
+/// A message entry kept in the local hidden/reported lists
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModeratedMessage {
+    pub signature: String,
+    pub group_id: u64,
+    pub sender: String,
+    /// Short preview so the user can recognise the message later
+    pub preview: String,
+    /// Unix timestamp (seconds) when the entry was added
+    pub added_at: i64,
+}
+
+impl ModeratedMessage {
+    pub fn new(signature: &str, group_id: u64, sender: &str, message: &str, added_at: i64) -> Self {
+        let preview = if message.chars().count() > PREVIEW_CHARS {
+            let truncated: String = message.chars().take(PREVIEW_CHARS).collect();
+            format!("{}...", truncated)
+        } else {
+            message.to_string()
+        };
+
+        Self {
+            signature: signature.to_string(),
+            group_id,
+            sender: sender.to_string(),
+            preview,
+            added_at,
+        }
+    }
+}
+
+/// An ordered list of moderated messages, unique by signature
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModeratedMessageList {
+    pub entries: Vec<ModeratedMessage>,
+}
+
+impl ModeratedMessageList {
+    pub fn contains(&self, signature: &str) -> bool {
+        self.entries.iter().any(|e| e.signature == signature)
+    }
+
+    /// Add an entry, returning false if the signature was already listed
+    pub fn add(&mut self, entry: ModeratedMessage) -> bool {
+        if self.contains(&entry.signature) {
+            return false;
+        }
+        self.entries.push(entry);
+        true
+    }
+
+    /// Remove an entry by signature, returning true if it was listed
+    pub fn remove(&mut self, signature: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.signature != signature);
+        self.entries.len() != before
+    }
+
+    pub fn signatures(&self) -> HashSet<String> {
+        self.entries.iter().map(|e| e.signature.clone()).collect()
+    }
+}
+
+fn now_secs() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+/// Load the locally hidden messages
+pub fn load_hidden_messages() -> ModeratedMessageList {
+    storage_base::load_json(HIDDEN_MESSAGES_KEY).unwrap_or_default()
+}
+
+/// Signatures of all locally hidden messages
+pub fn hidden_signatures() -> HashSet<String> {
+    load_hidden_messages().signatures()
+}
+
+/// Hide a message in this browser
+pub fn hide_message(signature: &str, group_id: u64, sender: &str, message: &str) -> Result<(), String> {
+    let mut hidden = load_hidden_messages();
+    if hidden.add(ModeratedMessage::new(signature, group_id, sender, message, now_secs())) {
+        storage_base::save_json(HIDDEN_MESSAGES_KEY, &hidden)?;
+    }
+    Ok(())
+}
+
+/// Show a previously hidden message again
+pub fn unhide_message(signature: &str) -> Result<(), String> {
+    let mut hidden = load_hidden_messages();
+    if hidden.remove(signature) {
+        storage_base::save_json(HIDDEN_MESSAGES_KEY, &hidden)?;
+    }
+    Ok(())
+}
+
+/// Load the user's own record of reported messages
+pub fn load_reported_messages() -> ModeratedMessageList {
+    storage_base::load_json(REPORTED_MESSAGES_KEY).unwrap_or_default()
+}
+
+/// Record a report for the user's own records (kept locally, not sent anywhere)
+pub fn report_message(signature: &str, group_id: u64, sender: &str, message: &str) -> Result<(), String> {
+    let mut reported = load_reported_messages();
+    if reported.add(ModeratedMessage::new(signature, group_id, sender, message, now_secs())) {
+        log::info!("Reported message locally: group {} signature {}", group_id, signature);
+        storage_base::save_json(REPORTED_MESSAGES_KEY, &reported)?;
+    }
+    Ok(())
+}
+
+/// Clear the local report record
+pub fn clear_reported_messages() -> Result<(), String> {
+    storage_base::remove(REPORTED_MESSAGES_KEY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(signature: &str) -> ModeratedMessage {
+        ModeratedMessage::new(signature, 1, "sender", "hello", 0)
+    }
+
+    #[test]
+    fn test_add_is_unique_by_signature() {
+        let mut list = ModeratedMessageList::default();
+        assert!(list.add(entry("sig1")));
+        assert!(!list.add(entry("sig1")));
+        assert!(list.add(entry("sig2")));
+        assert_eq!(list.entries.len(), 2);
+        assert!(list.contains("sig1"));
+        assert!(!list.contains("sig3"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list = ModeratedMessageList::default();
+        list.add(entry("sig1"));
+        list.add(entry("sig2"));
+        assert!(list.remove("sig1"));
+        assert!(!list.remove("sig1"));
+        assert_eq!(list.signatures(), HashSet::from(["sig2".to_string()]));
+    }
+
+    #[test]
+    fn test_preview_is_truncated_on_char_boundary() {
+        let long = "消息".repeat(100);
+        let e = ModeratedMessage::new("sig", 1, "sender", &long, 0);
+        assert_eq!(e.preview.chars().count(), PREVIEW_CHARS + 3);
+        assert!(e.preview.ends_with("..."));
+
+        let short = ModeratedMessage::new("sig", 1, "sender", "short", 0);
+        assert_eq!(short.preview, "short");
+    }
+
+    #[test]
+    fn test_list_roundtrip_json() {
+        let mut list = ModeratedMessageList::default();
+        list.add(entry("sig1"));
+        let json = serde_json::to_string(&list).unwrap();
+        let parsed: ModeratedMessageList = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, list);
+    }
+}
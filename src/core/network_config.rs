@@ -3,7 +3,7 @@ use std::sync::RwLock;
 use serde::{Serialize, Deserialize};
 
 /// Network environment enumeration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NetworkType {
     /// Testnet environment with testnet program IDs
     Testnet,
@@ -18,6 +18,8 @@ pub enum NetworkType {
 pub struct NetworkConfig {
     pub network_type: NetworkType,
     pub rpc_endpoints: &'static [&'static str],
+    /// Block explorer for transactions and accounts on this network
+    pub explorer_url: &'static str,
     pub program_ids: ProgramIds,
 }
 
@@ -42,6 +44,7 @@ impl NetworkConfig {
         rpc_endpoints: &[
             "https://rpc.testnet.x1.xyz",
         ],
+        explorer_url: "https://explorer.testnet.x1.xyz",
         program_ids: ProgramIds {
             mint_program_id: "A31a17bhgQyRQygeZa1SybytjbCdjMpu6oPr9M3iQWzy",
             burn_program_id: "FEjJ9KKJETocmaStfsFteFrktPchDLAVNTMeTvndoxaP",
@@ -62,6 +65,7 @@ impl NetworkConfig {
         rpc_endpoints: &[
             "https://rpc.testnet.x1.xyz",
         ],
+        explorer_url: "https://explorer.testnet.x1.xyz",
         program_ids: ProgramIds {
             mint_program_id: "8iq6zqaEVcfaym2u8t939PAN5jmfPVc6Z333RuxKTTZX",
             burn_program_id: "2sb3gz5Cmr2g1ia5si2rmCZqPACxgaZXEmiS5k6Htcvh",
@@ -81,6 +85,7 @@ impl NetworkConfig {
         rpc_endpoints: &[
             "https://rpc.mainnet.x1.xyz",
         ],
+        explorer_url: "https://explorer.x1.xyz",
         program_ids: ProgramIds {
             mint_program_id: "8iq6zqaEVcfaym2u8t939PAN5jmfPVc6Z333RuxKTTZX",
             burn_program_id: "2sb3gz5Cmr2g1ia5si2rmCZqPACxgaZXEmiS5k6Htcvh",
@@ -224,3 +229,43 @@ pub fn get_program_ids() -> &'static ProgramIds {
 pub fn try_get_network_config() -> Option<&'static NetworkConfig> {
     get_network().map(NetworkConfig::for_network)
 }
+
+/// Whether `signature` is a placeholder for a send that isn't on chain yet
+/// (e.g. `local_...`, `local_burn_...`, `local_devlog_...`)
+pub fn is_local_signature(signature: &str) -> bool {
+    signature.is_empty() || signature.starts_with("local_")
+}
+
+/// Explorer base URL for the current network, honouring a custom one from settings
+fn current_explorer_url() -> Option<String> {
+    let config = try_get_network_config()?;
+    let custom = crate::core::settings::load_current_network_settings().and_then(|s| s.custom_explorer());
+    Some(custom.unwrap_or_else(|| config.explorer_url.to_string()))
+}
+
+/// Explorer page of a transaction on the current network
+pub fn explorer_tx_url(signature: &str) -> Option<String> {
+    if is_local_signature(signature) {
+        return None;
+    }
+    Some(format!("{}/tx/{}", current_explorer_url()?, signature))
+}
+
+/// Explorer page of an account on the current network
+pub fn explorer_address_url(address: &str) -> Option<String> {
+    Some(format!("{}/address/{}", current_explorer_url()?, address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_signatures_have_no_explorer_page() {
+        assert!(is_local_signature("local_devlog_1700000000000"));
+        assert!(is_local_signature("local_burn_1"));
+        assert!(is_local_signature(""));
+        assert!(!is_local_signature("5abc"));
+        assert_eq!(explorer_tx_url("local_burn_1"), None);
+    }
+}
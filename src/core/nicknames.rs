@@ -0,0 +1,90 @@
+//! Client-local nicknames for addresses
+//!
+//! A nickname is a private label ("Alice (trusted)") the user gives an
+//! address in this browser. It takes the place of the shortened address in
+//! chat and project views but is never published, so it must always be shown
+//! apart from verified on-chain names (profile usernames, X1NS domains).
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::storage_base;
+use super::text::sanitize_display_text;
+
+const NICKNAMES_KEY: &str = "nicknames";
+
+/// Longest nickname kept, in characters
+pub const MAX_NICKNAME_CHARS: usize = 32;
+
+/// Nicknames keyed by base58 address
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NicknameMap {
+    pub entries: BTreeMap<String, String>,
+}
+
+impl NicknameMap {
+    pub fn get(&self, address: &str) -> Option<&str> {
+        self.entries.get(address).map(String::as_str)
+    }
+
+    /// Set the nickname for `address`; a blank nickname clears it
+    pub fn set(&mut self, address: &str, nickname: &str) {
+        match normalize_nickname(nickname) {
+            Some(nickname) => {
+                self.entries.insert(address.to_string(), nickname);
+            }
+            None => {
+                self.entries.remove(address);
+            }
+        }
+    }
+}
+
+/// Clean up user input: invisible characters and runs of whitespace are
+/// dropped like in other displayed text, and the result is capped at
+/// `MAX_NICKNAME_CHARS`. None if nothing visible is left.
+pub fn normalize_nickname(input: &str) -> Option<String> {
+    let cleaned = sanitize_display_text(input);
+    let nickname: String = cleaned.chars().take(MAX_NICKNAME_CHARS).collect();
+    let nickname = nickname.trim_end();
+    (!nickname.is_empty()).then(|| nickname.to_string())
+}
+
+/// Load the nicknames set in this browser
+pub fn load_nicknames() -> NicknameMap {
+    storage_base::load_json(NICKNAMES_KEY).unwrap_or_default()
+}
+
+/// Set (or with a blank nickname, clear) the nickname of `address`,
+/// returning the updated map
+pub fn set_nickname(address: &str, nickname: &str) -> Result<NicknameMap, String> {
+    let mut nicknames = load_nicknames();
+    nicknames.set(address, nickname);
+    storage_base::save_json(NICKNAMES_KEY, &nicknames)?;
+    Ok(nicknames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_nickname() {
+        assert_eq!(normalize_nickname("  Alice   (trusted) "), Some("Alice (trusted)".to_string()));
+        assert_eq!(normalize_nickname(" \u{200B} "), None);
+        let long = "n".repeat(MAX_NICKNAME_CHARS + 10);
+        assert_eq!(normalize_nickname(&long).map(|n| n.chars().count()), Some(MAX_NICKNAME_CHARS));
+    }
+
+    #[test]
+    fn test_set_and_clear() {
+        let mut nicknames = NicknameMap::default();
+        nicknames.set("addr1", "Alice");
+        assert_eq!(nicknames.get("addr1"), Some("Alice"));
+        nicknames.set("addr1", "Bob");
+        assert_eq!(nicknames.get("addr1"), Some("Bob"));
+        nicknames.set("addr1", "   ");
+        assert_eq!(nicknames.get("addr1"), None);
+        assert!(nicknames.entries.is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! Page arithmetic shared by the paged listings (chat groups, projects, ...)
+
+/// Nearest existing page to `page` for a listing of `total_items`, so a page
+/// kept across a refresh stays valid when the listing shrank; 1 when empty
+pub fn clamp_page(page: usize, total_items: usize, per_page: usize) -> usize {
+    if per_page == 0 {
+        return 1;
+    }
+    page.clamp(1, total_items.div_ceil(per_page).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_page() {
+        assert_eq!(clamp_page(5, 100, 10), 5);
+        // the listing shrank to 3 pages
+        assert_eq!(clamp_page(5, 25, 10), 3);
+        assert_eq!(clamp_page(5, 0, 10), 1);
+        assert_eq!(clamp_page(0, 100, 10), 1);
+        assert_eq!(clamp_page(5, 100, 0), 1);
+    }
+}
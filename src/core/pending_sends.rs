@@ -0,0 +1,205 @@
+//! Locally recorded chat sends whose outcome is not known yet
+//!
+//! A record is written when a message or burn is submitted and removed as
+//! soon as the page learns the result. Records left behind (the app was
+//! closed mid-send, or the user stopped waiting) are reconciled against the
+//! group's messages the next time it is opened. Records are kept per network
+//! in localStorage via `storage_base`.
+
+use serde::{Deserialize, Serialize};
+
+use super::rpc_chat::{ChatMessage, LocalChatMessage, MessageStatus};
+use super::storage_base;
+
+const PENDING_SENDS_KEY: &str = "pending_chat_sends";
+
+/// Maximum records kept; the oldest are dropped first
+pub const MAX_PENDING_SENDS: usize = 50;
+
+/// Allowed difference between the local clock and the chain block time
+const CLOCK_SKEW_SECS: i64 = 60;
+
+/// A chat message or burn that was submitted but not seen on chain yet
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PendingSend {
+    pub group_id: u64,
+    /// Client nonce: the placeholder signature of the optimistic local message
+    pub local_signature: String,
+    pub sender: String,
+    pub message: String,
+    /// "chat" or "burn", as in `ChatMessage::message_type`
+    pub message_type: String,
+    /// Burn amount in lamports, for burns
+    pub burn_amount: Option<u64>,
+    /// Unix timestamp (seconds) when the send started
+    pub created_at: i64,
+    /// Signature of the message being replied to, for replies and burn-replies
+    #[serde(default)]
+    pub reply_to_sig: Option<String>,
+}
+
+impl PendingSend {
+    pub fn from_local(group_id: u64, local: &LocalChatMessage) -> Self {
+        Self {
+            group_id,
+            local_signature: local.message.signature.clone(),
+            sender: local.message.sender.clone(),
+            message: local.message.message.clone(),
+            message_type: local.message.message_type.clone(),
+            burn_amount: local.message.burn_amount,
+            created_at: local.message.timestamp,
+            reply_to_sig: local.message.reply_to_sig.clone(),
+        }
+    }
+
+    /// Rebuild the optimistic message, shown as unconfirmed
+    pub fn to_local_message(&self) -> LocalChatMessage {
+        LocalChatMessage {
+            message: ChatMessage {
+                signature: self.local_signature.clone(),
+                sender: self.sender.clone(),
+                message: self.message.clone(),
+                timestamp: self.created_at,
+                slot: 0,
+                memo_amount: 0,
+                message_type: self.message_type.clone(),
+                burn_amount: self.burn_amount,
+                reply_to_sig: self.reply_to_sig.clone(),
+            },
+            status: MessageStatus::Unconfirmed,
+            is_local: true,
+        }
+    }
+
+    /// Find the on-chain message this send produced, if it is among `chain_messages`
+    pub fn find_landed<'a>(&self, chain_messages: &'a [ChatMessage]) -> Option<&'a ChatMessage> {
+        chain_messages.iter().find(|m| {
+            m.sender == self.sender
+                && m.message == self.message
+                && m.message_type == self.message_type
+                && m.timestamp >= self.created_at - CLOCK_SKEW_SECS
+        })
+    }
+}
+
+fn load_all() -> Vec<PendingSend> {
+    storage_base::load_network_list(PENDING_SENDS_KEY)
+}
+
+fn save_all(sends: &[PendingSend]) -> Result<(), String> {
+    let key = storage_base::network_key(PENDING_SENDS_KEY).ok_or_else(|| "Network is not initialized".to_string())?;
+    if sends.is_empty() {
+        storage_base::remove(&key)
+    } else {
+        storage_base::save_json(&key, sends)
+    }
+}
+
+/// Add or replace (by local signature) a pending record, dropping the oldest beyond the cap
+fn push_pending(sends: &mut Vec<PendingSend>, send: PendingSend) {
+    storage_base::push_bounded(sends, send, MAX_PENDING_SENDS, |a, b| a.local_signature == b.local_signature);
+}
+
+/// Remember a send before submitting it
+pub fn record_pending_send(send: PendingSend) -> Result<(), String> {
+    let mut sends = load_all();
+    push_pending(&mut sends, send);
+    save_all(&sends)
+}
+
+/// Forget a send whose outcome is known
+pub fn remove_pending_send(local_signature: &str) -> Result<(), String> {
+    let mut sends = load_all();
+    let before = sends.len();
+    sends.retain(|s| s.local_signature != local_signature);
+    if sends.len() == before {
+        return Ok(());
+    }
+    save_all(&sends)
+}
+
+/// Drop the group's records that appear in `chain_messages` and return the
+/// ones still unresolved, oldest first
+pub fn reconcile_group(group_id: u64, chain_messages: &[ChatMessage]) -> Vec<PendingSend> {
+    let mut sends = load_all();
+    let before = sends.len();
+    sends.retain(|s| s.group_id != group_id || s.find_landed(chain_messages).is_none());
+    if sends.len() != before {
+        log::info!("Reconciled {} pending sends for group {}", before - sends.len(), group_id);
+        if let Err(e) = save_all(&sends) {
+            log::warn!("Failed to save pending sends: {}", e);
+        }
+    }
+    sends.retain(|s| s.group_id == group_id);
+    sends
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(local_signature: &str, message: &str, created_at: i64) -> PendingSend {
+        PendingSend {
+            group_id: 1,
+            local_signature: local_signature.to_string(),
+            sender: "alice".to_string(),
+            message: message.to_string(),
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            created_at,
+            reply_to_sig: None,
+        }
+    }
+
+    fn chain(signature: &str, sender: &str, message: &str, timestamp: i64) -> ChatMessage {
+        ChatMessage {
+            signature: signature.to_string(),
+            sender: sender.to_string(),
+            message: message.to_string(),
+            timestamp,
+            slot: 1,
+            memo_amount: 0,
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            reply_to_sig: None,
+        }
+    }
+
+    #[test]
+    fn test_find_landed_matches_sender_text_and_time() {
+        let send = pending("local_1", "gm", 1_000);
+        let messages = vec![
+            chain("old", "alice", "gm", 500),
+            chain("other", "bob", "gm", 1_010),
+            chain("sig", "alice", "gm", 1_005),
+        ];
+        assert_eq!(send.find_landed(&messages).map(|m| m.signature.as_str()), Some("sig"));
+
+        // block time slightly behind the local clock still matches
+        assert!(send.find_landed(&[chain("skewed", "alice", "gm", 960)]).is_some());
+        assert!(send.find_landed(&[chain("x", "alice", "gn", 1_005)]).is_none());
+    }
+
+    #[test]
+    fn test_push_pending_replaces_and_is_bounded() {
+        let mut sends = vec![pending("local_1", "a", 1), pending("local_2", "b", 2)];
+        push_pending(&mut sends, pending("local_1", "a", 3));
+        assert_eq!(sends.len(), 2);
+        assert_eq!(sends.last().unwrap().created_at, 3);
+
+        let mut sends: Vec<PendingSend> = (0..MAX_PENDING_SENDS)
+            .map(|i| pending(&format!("local_{}", i), "m", i as i64))
+            .collect();
+        push_pending(&mut sends, pending("local_new", "m", 999));
+        assert_eq!(sends.len(), MAX_PENDING_SENDS);
+        assert_eq!(sends.first().unwrap().local_signature, "local_1");
+    }
+
+    #[test]
+    fn test_to_local_message_is_unconfirmed() {
+        let local = pending("local_1", "gm", 1_000).to_local_message();
+        assert_eq!(local.status, MessageStatus::Unconfirmed);
+        assert!(local.is_local);
+        assert_eq!(local.message.signature, "local_1");
+    }
+}
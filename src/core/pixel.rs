@@ -3,12 +3,32 @@ use flate2::write::DeflateEncoder;
 use flate2::read::DeflateDecoder;
 use base64::{encode, decode};
 use std::io::prelude::*;
+use std::ops::RangeInclusive;
+
+/// Grid sizes offered by the chat group image editors. A dense 64x64 image
+/// doesn't fit in a group memo, but sparse ones compress well enough.
+pub const GROUP_IMAGE_SIZES: [usize; 4] = [8, 16, 32, 64];
+
+/// Colors of palette images, by index. Index 0 is an empty cell (the canvas
+/// background) and 1 is the black of black/white art.
+pub const PALETTE: [&str; PALETTE_SIZE] = [
+    "transparent", "#000000", "#ffffff", "#9ca3af",
+    "#ef4444", "#f97316", "#facc15", "#22c55e",
+    "#14b8a6", "#3b82f6", "#6366f1", "#a855f7",
+    "#ec4899", "#92400e", "#1e3a8a", "#166534",
+];
+
+/// Number of palette colors; an index fits in 4 bits
+pub const PALETTE_SIZE: usize = 16;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Pixel {
     width: usize,
     height: usize,
     data: Vec<bool>,
+    /// Palette index of each cell, for palette images only. `data` stays in
+    /// sync (a cell is set when its index isn't 0).
+    colors: Option<Vec<u8>>,
 }
 
 impl Pixel {
@@ -28,12 +48,21 @@ impl Pixel {
             width,
             height,
             data: vec![false; width * height],
+            colors: None,
+        }
+    }
+
+    /// Set the cell at `index`, keeping the palette (if any) in sync
+    fn write(&mut self, index: usize, value: bool) {
+        self.data[index] = value;
+        if let Some(colors) = self.colors.as_mut() {
+            colors[index] = value as u8;
         }
     }
 
     pub fn set(&mut self, x: usize, y: usize, value: bool) {
         if x < self.width && y < self.height {
-            self.data[y * self.width + x] = value;
+            self.write(y * self.width + x, value);
         }
     }
 
@@ -52,12 +81,83 @@ impl Pixel {
 
     // Set pixel state
     pub fn set_pixel(&mut self, row: usize, col: usize, value: bool) {
-        self.data[row * self.width + col] = value;
+        self.write(row * self.width + col, value);
     }
 
     // Toggle pixel state
     pub fn toggle_pixel(&mut self, row: usize, col: usize) {
-        self.data[row * self.width + col] = !self.data[row * self.width + col];
+        let index = row * self.width + col;
+        self.write(index, !self.data[index]);
+    }
+
+    /// Palette index of a cell: 0 when empty, 1 (black) for set cells of
+    /// black/white art
+    pub fn get_color(&self, row: usize, col: usize) -> u8 {
+        match &self.colors {
+            Some(colors) => colors[row * self.width + col],
+            None => self.get_pixel(row, col) as u8,
+        }
+    }
+
+    /// Paint a cell with a palette index (0 clears it). Any color besides
+    /// black turns black/white art into a palette image.
+    pub fn set_color(&mut self, row: usize, col: usize, color: u8) {
+        let color = color.min(PALETTE_SIZE as u8 - 1);
+        let index = row * self.width + col;
+        if self.colors.is_none() && color > 1 {
+            self.colors = Some(self.data.iter().map(|&set| set as u8).collect());
+        }
+        self.data[index] = color != 0;
+        if let Some(colors) = self.colors.as_mut() {
+            colors[index] = color;
+        }
+    }
+
+    /// Paint a cell with `color`, or clear it if it already has that color
+    pub fn toggle_color(&mut self, row: usize, col: usize, color: u8) {
+        let color = if self.get_color(row, col) == color { 0 } else { color };
+        self.set_color(row, col, color);
+    }
+
+    /// Fill the area around (`x`, `y`) with a palette index (0 clears it):
+    /// every cell reachable from it through 4-connected cells of the start
+    /// cell's color. Returns whether any cell changed.
+    pub fn flood_fill_color(&mut self, x: usize, y: usize, color: u8) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let color = color.min(PALETTE_SIZE as u8 - 1);
+        let start = self.get_color(y, x);
+        if start == color {
+            return false;
+        }
+
+        // iterative, so a 1024x1024 fill can't overflow the stack
+        let mut stack = vec![(x, y)];
+        while let Some((x, y)) = stack.pop() {
+            if self.get_color(y, x) != start {
+                continue;
+            }
+            self.set_color(y, x, color);
+            if x > 0 {
+                stack.push((x - 1, y));
+            }
+            if x + 1 < self.width {
+                stack.push((x + 1, y));
+            }
+            if y > 0 {
+                stack.push((x, y - 1));
+            }
+            if y + 1 < self.height {
+                stack.push((x, y + 1));
+            }
+        }
+        true
+    }
+
+    /// Whether any cell uses a color besides black
+    fn has_colors(&self) -> bool {
+        self.colors.as_ref().is_some_and(|colors| colors.iter().any(|&c| c > 1))
     }
 
     // Check if all pixels are false (blank image)
@@ -228,14 +328,21 @@ impl Pixel {
         for pixel in self.data.iter_mut() {
             *pixel = false;
         }
+        self.colors = None;
     }
 
     pub fn set_pixels_from_image(&mut self, x: usize, y: usize, is_black: bool) {
-        self.data[y * self.width + x] = is_black;
+        self.write(y * self.width + x, is_black);
     }
 
     // convert to optimal string
     pub fn to_optimal_string(&self) -> String {
+        // art that only uses black keeps the black/white encoding
+        if self.has_colors() {
+            if let Ok(encoded) = self.to_palette_string() {
+                return encoded;
+            }
+        }
         let normal_string = self.to_safe_string();
         
         match self.compress_with_deflate(&normal_string) {
@@ -258,6 +365,11 @@ impl Pixel {
             return None;
         }
 
+        // still images of animated art show its first frame
+        if s.starts_with(ANIMATION_PREFIX) {
+            return PixelAnimation::first_frame(s);
+        }
+
         // Try new format first: type:widthxheight:data
         let parts: Vec<&str> = s.splitn(3, ':').collect();
         
@@ -291,6 +403,7 @@ impl Pixel {
                     }
                 },
                 "n" => Self::from_safe_string_with_size(data, width, height),
+                "p" => Self::from_palette_data(data, width, height),
                 _ => None
             }
         } else if parts.len() == 2 {
@@ -320,6 +433,30 @@ impl Pixel {
         }
     }
 
+    /// Decode a shared `c:`/`n:` string for an editor that offers the square
+    /// grid `sizes`, explaining what is wrong when it can't be loaded
+    pub fn from_shared_string(s: &str, sizes: &[usize]) -> Result<Self, String> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("Paste an encoded image string first".to_string());
+        }
+        let pixel = Self::from_optimal_string(s)
+            .ok_or_else(|| "Invalid image string: expected a c: or n: encoded image".to_string())?;
+        let (width, height) = pixel.dimensions();
+        if width != height || !sizes.contains(&width) {
+            let supported = sizes
+                .iter()
+                .map(|size| format!("{}x{}", size, size))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(format!(
+                "Unsupported grid size {}x{} (supported: {})",
+                width, height, supported
+            ));
+        }
+        Ok(pixel)
+    }
+
     // New helper function: restore from safe string with specified dimensions
     pub fn from_safe_string_with_size(s: &str, width: usize, height: usize) -> Option<Self> {
         let expected_pixels = width * height;
@@ -365,6 +502,51 @@ impl Pixel {
         Some(pixel)
     }
 
+    /// `p:WxH:` followed by the Base64 of the deflated palette indices,
+    /// two cells per byte (high nibble first)
+    fn to_palette_string(&self) -> Result<String, String> {
+        let colors = self.colors.as_ref().ok_or("Not a palette image")?;
+        let packed: Vec<u8> = colors
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0))
+            .collect();
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&packed)
+            .map_err(|e| format!("Compression error: {}", e))?;
+        let compressed = encoder.finish()
+            .map_err(|e| format!("Compression finish error: {}", e))?;
+
+        Ok(format!("p:{}x{}:{}", self.width, self.height, encode(compressed)))
+    }
+
+    /// Decode the data part of a `p:` string
+    fn from_palette_data(data: &str, width: usize, height: usize) -> Option<Self> {
+        let cells = width.checked_mul(height)?;
+        let bytes = decode(data).ok()?;
+        let mut packed = Vec::new();
+        // read one byte past the expected length to reject oversized data
+        DeflateDecoder::new(&bytes[..])
+            .take((cells as u64).div_ceil(2) + 1)
+            .read_to_end(&mut packed)
+            .ok()?;
+        if packed.len() != cells.div_ceil(2) {
+            return None;
+        }
+
+        let colors: Vec<u8> = packed
+            .iter()
+            .flat_map(|byte| [byte >> 4, byte & 0x0f])
+            .take(cells)
+            .collect();
+        Some(Self {
+            width,
+            height,
+            data: colors.iter().map(|&c| c != 0).collect(),
+            colors: Some(colors),
+        })
+    }
+
     // compress string
     fn compress_with_deflate(&self, input: &str) -> Result<String, String> {
         // convert string to raw bytes
@@ -413,11 +595,380 @@ impl Default for Pixel {
     }
 }
 
+/// Prefix of animated pixel art: `a:<frame ms>:<frame>|<frame>|...`, each
+/// frame encoded by `to_optimal_string` (whose alphabets never contain `|`)
+const ANIMATION_PREFIX: &str = "a:";
+
+/// Most frames of an animation
+pub const MAX_ANIMATION_FRAMES: usize = 8;
+
+/// How long each frame of an animation may show, in milliseconds
+pub const FRAME_MS_RANGE: RangeInclusive<u32> = 100..=2000;
+
+pub const DEFAULT_FRAME_MS: u32 = 500;
+
+/// A short pixel animation, e.g. for a profile avatar. All frames have the
+/// same size and show for `frame_ms` each, looping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelAnimation {
+    pub frames: Vec<Pixel>,
+    pub frame_ms: u32,
+}
+
+impl PixelAnimation {
+    /// `a:` encoding of the frames; a single frame is encoded as a still image
+    pub fn to_encoded_string(&self) -> String {
+        match self.frames.as_slice() {
+            [frame] => frame.to_optimal_string(),
+            frames => format!(
+                "{}{}:{}",
+                ANIMATION_PREFIX,
+                self.frame_ms,
+                frames.iter().map(Pixel::to_optimal_string).collect::<Vec<_>>().join("|")
+            ),
+        }
+    }
+
+    /// Decode animated art, or a still image as a single frame. `None` if any
+    /// frame is damaged, the frames differ in size, or the frame count or
+    /// duration is out of range.
+    pub fn from_encoded_string(s: &str) -> Option<Self> {
+        let Some(rest) = s.strip_prefix(ANIMATION_PREFIX) else {
+            return Pixel::from_optimal_string(s).map(|frame| Self {
+                frames: vec![frame],
+                frame_ms: DEFAULT_FRAME_MS,
+            });
+        };
+        let (frame_ms, frames) = rest.split_once(':')?;
+        let frame_ms = frame_ms.parse::<u32>().ok().filter(|ms| FRAME_MS_RANGE.contains(ms))?;
+        let frames = frames
+            .split('|')
+            .map(Pixel::from_optimal_string)
+            .collect::<Option<Vec<_>>>()?;
+        let size = frames.first()?.dimensions();
+        if frames.len() > MAX_ANIMATION_FRAMES || frames.iter().any(|frame| frame.dimensions() != size) {
+            return None;
+        }
+        Some(Self { frames, frame_ms })
+    }
+
+    /// The first frame of `a:` art, even if later frames don't decode
+    fn first_frame(s: &str) -> Option<Pixel> {
+        let (_, frames) = s.strip_prefix(ANIMATION_PREFIX)?.split_once(':')?;
+        frames.split('|').next().and_then(Pixel::from_optimal_string)
+    }
+}
+
+/// Most earlier images kept by `PixelHistory`
+pub const MAX_PIXEL_HISTORY: usize = 50;
+
+/// Undo/redo history of a pixel editor. Editors record the image as it was
+/// before each edit (a toggled pixel, an import, a new grid size); undo and
+/// redo trade the image on screen for the one they return.
+#[derive(Debug, Clone, Default)]
+pub struct PixelHistory {
+    undo: Vec<Pixel>,
+    redo: Vec<Pixel>,
+}
+
+impl PixelHistory {
+    /// Remember `before` as the image to go back to, dropping the redo
+    /// history and the oldest image beyond `MAX_PIXEL_HISTORY`
+    pub fn record(&mut self, before: Pixel) {
+        self.redo.clear();
+        self.undo.push(before);
+        if self.undo.len() > MAX_PIXEL_HISTORY {
+            self.undo.remove(0);
+        }
+    }
+
+    /// The image before the last edit, with `current` kept for redo
+    pub fn undo(&mut self, current: Pixel) -> Option<Pixel> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// The image the last undo went back from, with `current` kept for undo
+    pub fn redo(&mut self, current: Pixel) -> Option<Pixel> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forget everything, e.g. once the image was submitted
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::Rng;
 
+    fn toggled(pixel: &Pixel, row: usize, col: usize) -> Pixel {
+        let mut pixel = pixel.clone();
+        pixel.toggle_pixel(row, col);
+        pixel
+    }
+
+    #[test]
+    fn test_small_and_large_grids_round_trip() {
+        for size in GROUP_IMAGE_SIZES {
+            let mut art = Pixel::new_with_size(size);
+            art.toggle_pixel(0, 0);
+            art.toggle_pixel(size - 1, size / 2);
+            let encoded = art.to_optimal_string();
+            assert!(encoded.contains(&format!(":{}x{}:", size, size)));
+            assert_eq!(Pixel::from_optimal_string(&encoded), Some(art));
+        }
+
+        // a sparse 64x64 image compresses far below its 683 raw characters
+        let sparse = Pixel::new_with_size(64).to_optimal_string();
+        assert!(sparse.starts_with("c:64x64:"));
+        assert!(sparse.len() < 100);
+    }
+
+    #[test]
+    fn test_palette_round_trip() {
+        let mut art = Pixel::new_with_size(16);
+        art.toggle_pixel(0, 0);
+        art.set_color(1, 2, 4);
+        art.set_color(15, 15, 15);
+        assert!(art.colors.is_some());
+        assert_eq!(art.get_color(0, 0), 1);
+        assert!(art.get_pixel(1, 2));
+
+        let encoded = art.to_optimal_string();
+        assert!(encoded.starts_with("p:16x16:"));
+        let decoded = Pixel::from_optimal_string(&encoded).unwrap();
+        assert_eq!(decoded, art);
+        assert_eq!(decoded.get_color(1, 2), 4);
+        assert_eq!(decoded.get_color(15, 15), 15);
+        assert_eq!(decoded.get_color(5, 5), 0);
+    }
+
+    #[test]
+    fn test_palette_of_black_only_uses_black_white_encoding() {
+        let mut art = Pixel::new_with_size(16);
+        art.set_color(3, 3, 5);
+        art.toggle_pixel(4, 4);
+        // repainting the only color cell black leaves black/white art
+        art.set_color(3, 3, 1);
+        let encoded = art.to_optimal_string();
+        assert!(!encoded.starts_with("p:"));
+
+        let decoded = Pixel::from_optimal_string(&encoded).unwrap();
+        assert!(decoded.colors.is_none());
+        assert!(decoded.get_pixel(3, 3) && decoded.get_pixel(4, 4));
+    }
+
+    #[test]
+    fn test_toggle_color() {
+        let mut art = Pixel::new_with_size(8);
+        art.toggle_color(2, 2, 7);
+        assert_eq!(art.get_color(2, 2), 7);
+        art.toggle_color(2, 2, 3);
+        assert_eq!(art.get_color(2, 2), 3);
+        art.toggle_color(2, 2, 3);
+        assert_eq!(art.get_color(2, 2), 0);
+        assert!(art.is_blank());
+    }
+
+    #[test]
+    fn test_invalid_palette_data() {
+        let mut art = Pixel::new_with_size(8);
+        art.set_color(0, 0, 9);
+        let encoded = art.to_optimal_string();
+        let data = encoded.trim_start_matches("p:8x8:");
+        // the same data doesn't fill a bigger grid, nor fit a smaller one
+        assert!(Pixel::from_optimal_string(&format!("p:16x16:{}", data)).is_none());
+        assert!(Pixel::from_optimal_string(&format!("p:4x4:{}", data)).is_none());
+        assert!(Pixel::from_optimal_string("p:8x8:not base64!").is_none());
+    }
+
+    #[test]
+    fn test_flood_fill_whole_grid() {
+        let mut art = Pixel::new_with_size(32);
+        assert!(art.flood_fill_color(5, 7, 1));
+        assert!(art.data.iter().all(|&set| set));
+        // filling a full grid with its own state changes nothing
+        assert!(!art.flood_fill_color(0, 0, 1));
+        assert!(art.flood_fill_color(31, 31, 0));
+        assert!(art.is_blank());
+    }
+
+    #[test]
+    fn test_flood_fill_checkerboard_is_single_cell() {
+        let mut art = Pixel::new_with_size(8);
+        for row in 0..8 {
+            for col in 0..8 {
+                art.set_pixel(row, col, (row + col) % 2 == 0);
+            }
+        }
+        let before = art.clone();
+        // no same-state neighbours are 4-connected on a checkerboard
+        assert!(art.flood_fill_color(1, 0, 1));
+        assert!(art.get_pixel(0, 1));
+        let changed = (0..64).filter(|&i| art.data[i] != before.data[i]).count();
+        assert_eq!(changed, 1);
+    }
+
+    #[test]
+    fn test_flood_fill_stops_at_walls() {
+        let mut art = Pixel::new_with_size(8);
+        // a vertical wall at column 3
+        for row in 0..8 {
+            art.set_pixel(row, 3, true);
+        }
+        assert!(art.flood_fill_color(0, 0, 1));
+        for row in 0..8 {
+            assert!((0..4).all(|col| art.get_pixel(row, col)));
+            assert!((4..8).all(|col| !art.get_pixel(row, col)));
+        }
+        assert!(!art.flood_fill_color(8, 0, 1));
+    }
+
+    #[test]
+    fn test_flood_fill_color() {
+        let mut art = Pixel::new_with_size(8);
+        art.set_color(0, 0, 4);
+        art.set_color(0, 1, 4);
+        art.set_color(1, 1, 6);
+        assert!(art.flood_fill_color(0, 0, 9));
+        assert_eq!(art.get_color(0, 0), 9);
+        assert_eq!(art.get_color(0, 1), 9);
+        assert_eq!(art.get_color(1, 1), 6);
+        assert_eq!(art.get_color(1, 0), 0);
+    }
+
+    #[test]
+    fn test_animation_round_trip() {
+        let blank = Pixel::new_with_size(32);
+        let one = toggled(&blank, 4, 4);
+        let animation = PixelAnimation { frames: vec![blank.clone(), one.clone()], frame_ms: 250 };
+        let encoded = animation.to_encoded_string();
+        assert!(encoded.starts_with("a:250:c:32x32:"));
+        assert_eq!(PixelAnimation::from_encoded_string(&encoded), Some(animation));
+        // still viewers show the first frame
+        assert_eq!(Pixel::from_optimal_string(&encoded), Some(blank.clone()));
+
+        // a single frame stays a plain image
+        let still = PixelAnimation { frames: vec![one.clone()], frame_ms: 250 };
+        assert_eq!(still.to_encoded_string(), one.to_optimal_string());
+        let decoded = PixelAnimation::from_encoded_string(&one.to_optimal_string()).unwrap();
+        assert_eq!(decoded.frames, vec![one]);
+        assert_eq!(decoded.frame_ms, DEFAULT_FRAME_MS);
+    }
+
+    #[test]
+    fn test_frame_strings_never_contain_separator() {
+        let mut rng = rand::thread_rng();
+        let mut art = Pixel::new_with_size(32);
+        for i in 0..32 * 32 {
+            art.data[i] = rng.gen_bool(0.5);
+        }
+        assert!(!art.to_safe_string().contains('|'));
+        assert!(!art.to_optimal_string().contains('|'));
+    }
+
+    #[test]
+    fn test_damaged_animation_falls_back_to_first_frame() {
+        let first = toggled(&Pixel::new_with_size(16), 1, 1);
+        let first_string = first.to_optimal_string();
+
+        let damaged = format!("a:300:{}|c:16x16:!!!", first_string);
+        assert_eq!(PixelAnimation::from_encoded_string(&damaged), None);
+        assert_eq!(Pixel::from_optimal_string(&damaged), Some(first.clone()));
+
+        let mixed_sizes = format!("a:300:{}|{}", first_string, Pixel::new_with_size(32).to_optimal_string());
+        assert_eq!(PixelAnimation::from_encoded_string(&mixed_sizes), None);
+
+        let too_fast = format!("a:10:{}|{}", first_string, first_string);
+        assert_eq!(PixelAnimation::from_encoded_string(&too_fast), None);
+
+        let too_long = format!("a:300:{}", [first_string.as_str(); MAX_ANIMATION_FRAMES + 1].join("|"));
+        assert_eq!(PixelAnimation::from_encoded_string(&too_long), None);
+        assert_eq!(Pixel::from_optimal_string("a:300:"), None);
+    }
+
+    #[test]
+    fn test_from_shared_string() {
+        let mut art = Pixel::new_with_size(16);
+        art.toggle_pixel(3, 5);
+        let encoded = art.to_optimal_string();
+
+        assert_eq!(Pixel::from_shared_string(&format!(" {}\n", encoded), &[16, 32]), Ok(art));
+        assert!(Pixel::from_shared_string("", &[16, 32]).is_err());
+        assert!(Pixel::from_shared_string("not an image", &[16, 32]).is_err());
+        assert!(Pixel::from_shared_string("n:16x16:!!!", &[16, 32]).is_err());
+
+        let err = Pixel::from_shared_string(&Pixel::new_with_size(8).to_optimal_string(), &[16, 32])
+            .unwrap_err();
+        assert_eq!(err, "Unsupported grid size 8x8 (supported: 16x16, 32x32)");
+        let wide = Pixel::with_size(32, 16).to_optimal_string();
+        assert!(Pixel::from_shared_string(&wide, &[16, 32]).is_err());
+    }
+
+    #[test]
+    fn test_history_undo_redo() {
+        let mut history = PixelHistory::default();
+        let blank = Pixel::new_with_size(16);
+        let one = toggled(&blank, 0, 0);
+        let two = toggled(&one, 1, 1);
+        history.record(blank.clone());
+        history.record(one.clone());
+
+        assert_eq!(history.undo(two.clone()), Some(one.clone()));
+        assert_eq!(history.undo(one.clone()), Some(blank.clone()));
+        assert_eq!(history.undo(blank.clone()), None);
+        assert_eq!(history.redo(blank.clone()), Some(one.clone()));
+        assert_eq!(history.redo(one.clone()), Some(two.clone()));
+        assert!(!history.can_redo());
+        assert!(history.can_undo());
+    }
+
+    #[test]
+    fn test_history_new_edit_clears_redo() {
+        let mut history = PixelHistory::default();
+        let small = Pixel::new_with_size(16);
+        let large = Pixel::new_with_size(32);
+        history.record(small.clone());
+        assert_eq!(history.undo(large.clone()), Some(small.clone()));
+        assert!(history.can_redo());
+
+        // e.g. switching the grid size again
+        history.record(small);
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(large), None);
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut history = PixelHistory::default();
+        let mut pixel = Pixel::new_with_size(16);
+        for i in 0..MAX_PIXEL_HISTORY + 5 {
+            history.record(pixel.clone());
+            pixel = toggled(&pixel, i % 16, i / 16);
+        }
+        let mut undone = 0;
+        while let Some(previous) = history.undo(pixel.clone()) {
+            pixel = previous;
+            undone += 1;
+        }
+        assert_eq!(undone, MAX_PIXEL_HISTORY);
+    }
+
     #[test]
     fn test_safe_string_conversion() {
         let mut pixel = Pixel::new();
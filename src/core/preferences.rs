@@ -0,0 +1,351 @@
+//! Client-side UI preferences
+//!
+//! Preferences are not tied to a network and are kept in localStorage via
+//! `storage_base`. Every field has a serde default so older stored values
+//! keep loading as new preferences are added.
+
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+use super::storage_base;
+
+const PREFERENCES_KEY: &str = "preferences";
+const FAVORITE_GROUPS_KEY: &str = "favorite_chat_groups";
+
+/// Allowed chat groups per page
+pub const GROUPS_PER_PAGE_RANGE: RangeInclusive<usize> = 5..=50;
+/// Allowed chat messages fetched per page
+pub const MESSAGES_PER_PAGE_RANGE: RangeInclusive<usize> = 10..=100;
+/// Allowed seconds between automatic chat room refreshes
+pub const AUTO_REFRESH_SECS_RANGE: RangeInclusive<usize> = 5..=300;
+
+/// How the chat groups list is displayed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GroupsDisplayMode {
+    #[default]
+    BurnLeaderboard,
+    Latest,
+    Oldest,
+    Favorites,
+}
+
+impl GroupsDisplayMode {
+    pub const ALL: [GroupsDisplayMode; 4] = [
+        GroupsDisplayMode::BurnLeaderboard,
+        GroupsDisplayMode::Latest,
+        GroupsDisplayMode::Oldest,
+        GroupsDisplayMode::Favorites,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupsDisplayMode::BurnLeaderboard => "Burn Leaderboard",
+            GroupsDisplayMode::Latest => "Latest",
+            GroupsDisplayMode::Oldest => "Oldest",
+            GroupsDisplayMode::Favorites => "Favorites",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.label() == label)
+            .unwrap_or_default()
+    }
+}
+
+/// Whether a list of groups/projects is shown as cards or compact rows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListViewMode {
+    #[default]
+    Grid,
+    List,
+}
+
+impl ListViewMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ListViewMode::Grid => "Grid view",
+            ListViewMode::List => "List view",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ListViewMode::Grid => "fas fa-th-large",
+            ListViewMode::List => "fas fa-list",
+        }
+    }
+}
+
+/// Column the project leaderboard is sorted by
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectSortKey {
+    /// Burn rank, i.e. burned amount
+    #[default]
+    Rank,
+    Name,
+    Burned,
+    LastMemo,
+}
+
+impl ProjectSortKey {
+    /// Direction a column sorts in when first chosen: biggest and newest
+    /// first, ranks and names from the top
+    pub fn default_descending(&self) -> bool {
+        matches!(self, ProjectSortKey::Burned | ProjectSortKey::LastMemo)
+    }
+}
+
+/// Color theme choice; `System` follows the browser's `prefers-color-scheme`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThemePreference {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemePreference {
+    pub const ALL: [ThemePreference; 3] = [
+        ThemePreference::System,
+        ThemePreference::Light,
+        ThemePreference::Dark,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemePreference::System => "System",
+            ThemePreference::Light => "Light",
+            ThemePreference::Dark => "Dark",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|theme| theme.label() == label)
+            .unwrap_or_default()
+    }
+
+    /// Whether the dark theme applies, given the system preference
+    pub fn is_dark(&self, system_prefers_dark: bool) -> bool {
+        match self {
+            ThemePreference::System => system_prefers_dark,
+            ThemePreference::Light => false,
+            ThemePreference::Dark => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiPreferences {
+    /// Display mode the chat groups list opens with
+    pub default_groups_display_mode: GroupsDisplayMode,
+    /// Card grid or compact list for the chat groups list
+    pub groups_view_mode: ListViewMode,
+    /// Card grid or compact list (table) for the project leaderboard
+    pub projects_view_mode: ListViewMode,
+    /// Debug feature: show raw payload bytes and decoded JSON under messages and devlogs
+    pub developer_mode: bool,
+    /// Light, dark or system color theme
+    pub theme: ThemePreference,
+    /// Show burn leaderboards in the rank order stored on chain instead of
+    /// re-sorting them by burned amount
+    pub keep_leaderboard_order: bool,
+    /// Show burn presets and hints under the chat composer; collapsed by default
+    pub composer_options_expanded: bool,
+    /// Debug feature: record RPC requests and show them in a panel
+    pub rpc_inspector: bool,
+    /// Chat groups shown per page of the groups list
+    pub groups_per_page: usize,
+    /// Chat messages loaded when a room opens and per "Load older messages"
+    pub messages_per_page: usize,
+    /// Show times as "2:05 PM" instead of "14:05"
+    pub use_12_hour_clock: bool,
+    /// System notification for new messages in the open chat room while
+    /// the tab is in the background
+    pub message_notifications: bool,
+    /// Chime for those messages too
+    pub message_sound: bool,
+    /// Refresh the open chat room's messages on an interval
+    pub auto_refresh_messages: bool,
+    /// Seconds between those refreshes
+    pub auto_refresh_secs: usize,
+    /// Column the project leaderboard table is sorted by
+    pub project_sort: ProjectSortKey,
+    /// Sort that column from the largest value down
+    pub project_sort_descending: bool,
+}
+
+impl Default for UiPreferences {
+    fn default() -> Self {
+        Self {
+            default_groups_display_mode: GroupsDisplayMode::default(),
+            groups_view_mode: ListViewMode::Grid,
+            // the project leaderboard has always been a table
+            projects_view_mode: ListViewMode::List,
+            developer_mode: false,
+            theme: ThemePreference::System,
+            keep_leaderboard_order: false,
+            composer_options_expanded: false,
+            rpc_inspector: false,
+            groups_per_page: 10,
+            messages_per_page: 20,
+            use_12_hour_clock: false,
+            message_notifications: false,
+            message_sound: false,
+            auto_refresh_messages: false,
+            auto_refresh_secs: 15,
+            project_sort: ProjectSortKey::Rank,
+            project_sort_descending: false,
+        }
+    }
+}
+
+impl UiPreferences {
+    pub fn load() -> Self {
+        storage_base::load_json(PREFERENCES_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        storage_base::save_json(PREFERENCES_KEY, self)
+    }
+
+    /// Groups per page, kept within `GROUPS_PER_PAGE_RANGE`
+    pub fn groups_page_size(&self) -> usize {
+        clamp_to(self.groups_per_page, &GROUPS_PER_PAGE_RANGE)
+    }
+
+    /// Messages per page, kept within `MESSAGES_PER_PAGE_RANGE`
+    pub fn messages_page_size(&self) -> usize {
+        clamp_to(self.messages_per_page, &MESSAGES_PER_PAGE_RANGE)
+    }
+
+    /// Seconds between chat room refreshes, kept within `AUTO_REFRESH_SECS_RANGE`
+    pub fn auto_refresh_interval(&self) -> usize {
+        clamp_to(self.auto_refresh_secs, &AUTO_REFRESH_SECS_RANGE)
+    }
+
+    /// Load, modify and save preferences in one step
+    pub fn update(f: impl FnOnce(&mut UiPreferences)) -> Result<UiPreferences, String> {
+        let mut preferences = Self::load();
+        f(&mut preferences);
+        preferences.save()?;
+        Ok(preferences)
+    }
+}
+
+/// `value` moved into `range` (stored values may predate the bounds)
+pub fn clamp_to(value: usize, range: &RangeInclusive<usize>) -> usize {
+    value.clamp(*range.start(), *range.end())
+}
+
+/// Load favourite chat group ids, in the order they were added
+pub fn load_favorite_groups() -> Vec<u64> {
+    storage_base::load_json(FAVORITE_GROUPS_KEY).unwrap_or_default()
+}
+
+/// Add or remove a favourite group, returning the updated list
+pub fn toggle_favorite_group(group_id: u64) -> Result<Vec<u64>, String> {
+    let mut favorites = load_favorite_groups();
+    if let Some(pos) = favorites.iter().position(|id| *id == group_id) {
+        favorites.remove(pos);
+    } else {
+        favorites.push(group_id);
+    }
+    storage_base::save_json(FAVORITE_GROUPS_KEY, &favorites)?;
+    Ok(favorites)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_mode_label_roundtrip() {
+        for mode in GroupsDisplayMode::ALL {
+            assert_eq!(GroupsDisplayMode::from_label(mode.label()), mode);
+        }
+        assert_eq!(GroupsDisplayMode::from_label("unknown"), GroupsDisplayMode::BurnLeaderboard);
+    }
+
+    #[test]
+    fn test_preferences_missing_fields_use_defaults() {
+        let preferences: UiPreferences = serde_json::from_str("{}").unwrap();
+        assert_eq!(preferences, UiPreferences::default());
+
+        let preferences: UiPreferences =
+            serde_json::from_str(r#"{"default_groups_display_mode":"Favorites"}"#).unwrap();
+        assert_eq!(preferences.default_groups_display_mode, GroupsDisplayMode::Favorites);
+        assert_eq!(preferences.groups_view_mode, ListViewMode::Grid);
+        assert_eq!(preferences.projects_view_mode, ListViewMode::List);
+        assert!(!preferences.developer_mode);
+        assert_eq!(preferences.theme, ThemePreference::System);
+        assert!(!preferences.keep_leaderboard_order);
+        assert!(!preferences.composer_options_expanded);
+        assert!(!preferences.rpc_inspector);
+        assert_eq!(preferences.groups_per_page, 10);
+        assert_eq!(preferences.messages_per_page, 20);
+        assert!(!preferences.use_12_hour_clock);
+        assert!(!preferences.message_notifications);
+        assert!(!preferences.message_sound);
+        assert!(!preferences.auto_refresh_messages);
+        assert_eq!(preferences.auto_refresh_secs, 15);
+        assert_eq!(preferences.project_sort, ProjectSortKey::Rank);
+        assert!(!preferences.project_sort_descending);
+    }
+
+    #[test]
+    fn test_page_sizes_are_clamped() {
+        let preferences = UiPreferences {
+            groups_per_page: 1,
+            messages_per_page: 5000,
+            ..UiPreferences::default()
+        };
+        assert_eq!(preferences.groups_page_size(), 5);
+        assert_eq!(preferences.messages_page_size(), 100);
+        assert_eq!(UiPreferences::default().groups_page_size(), 10);
+        assert_eq!(UiPreferences::default().messages_page_size(), 20);
+        assert_eq!(UiPreferences { auto_refresh_secs: 0, ..UiPreferences::default() }.auto_refresh_interval(), 5);
+        assert_eq!(UiPreferences::default().auto_refresh_interval(), 15);
+    }
+
+    #[test]
+    fn test_theme_follows_system_only_when_unset() {
+        assert!(ThemePreference::System.is_dark(true));
+        assert!(!ThemePreference::System.is_dark(false));
+        assert!(ThemePreference::Dark.is_dark(false));
+        assert!(!ThemePreference::Light.is_dark(true));
+        for theme in ThemePreference::ALL {
+            assert_eq!(ThemePreference::from_label(theme.label()), theme);
+        }
+    }
+
+    #[test]
+    fn test_project_sort_roundtrip_json() {
+        let preferences = UiPreferences {
+            project_sort: ProjectSortKey::LastMemo,
+            project_sort_descending: true,
+            ..UiPreferences::default()
+        };
+        let json = serde_json::to_string(&preferences).unwrap();
+        let parsed: UiPreferences = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, preferences);
+        assert!(ProjectSortKey::Burned.default_descending());
+        assert!(!ProjectSortKey::Name.default_descending());
+    }
+
+    #[test]
+    fn test_view_modes_roundtrip_json() {
+        let preferences = UiPreferences {
+            groups_view_mode: ListViewMode::List,
+            projects_view_mode: ListViewMode::Grid,
+            ..UiPreferences::default()
+        };
+        let json = serde_json::to_string(&preferences).unwrap();
+        let parsed: UiPreferences = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, preferences);
+    }
+}
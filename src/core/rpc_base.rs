@@ -3,28 +3,73 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{Request, RequestInit, RequestMode, Response};
 use std::fmt;
+use std::future::Future;
 use std::str::FromStr;
 use gloo_utils::format::JsValueSerdeExt;
 use js_sys::{Date, Math};
 use solana_sdk::transaction::Transaction;
+use solana_sdk::message::Message;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 use solana_sdk::pubkey::Pubkey;
 use base64;
 use bincode;
-use super::network_config::{try_get_network_config, get_program_ids};
+use super::network_config::{try_get_network_config, get_program_ids, NetworkConfig, NetworkType};
 use super::settings::load_current_network_settings;
+use super::rpc_inspector;
 use super::constants::*;
 
 // error type
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub enum RpcError {
     ConnectionFailed(String),
     InvalidAddress(String),
     TransactionFailed(String),
     Other(String),
     InvalidParameter(String),
+    /// Any other error answered by the node, with the contract's
+    /// "Error Message:" appended after " - " when the logs have one
     SolanaRpcError(String),
+    /// The contract's minimum memo interval has not passed yet (memo-chat custom error 6009)
+    MemoTooFrequent,
+    /// Not enough XNT for fees or tokens for the burn
+    InsufficientBalance,
+    /// No answer in time
+    Timeout,
+    /// The node refused the transaction before running it (bad signature, expired blockhash)
+    Rejected(String),
+    /// A custom program error without a message in the logs
+    Program(u32),
+}
+
+/// memo-chat's custom error code for MemoTooFrequent
+pub const MEMO_TOO_FREQUENT_CODE: u32 = 6009;
+
+/// JSON-RPC code for a transaction whose signatures failed to verify
+const SIGNATURE_VERIFICATION_FAILURE_CODE: i64 = -32003;
+
+/// JSON-RPC code for a transaction that failed preflight simulation
+const SIMULATION_FAILED_CODE: i64 = -32002;
+
+/// Commitment levels a transaction passes through, weakest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    /// Rooted by a supermajority, can no longer be rolled back
+    Finalized,
+}
+
+impl Commitment {
+    /// Parse a `confirmationStatus` value from `getSignatureStatuses`
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "processed" => Some(Commitment::Processed),
+            "confirmed" => Some(Commitment::Confirmed),
+            "finalized" => Some(Commitment::Finalized),
+            _ => None,
+        }
+    }
 }
 
 // implement the display for the rpc error
@@ -37,8 +82,156 @@ impl fmt::Display for RpcError {
             RpcError::Other(msg) => write!(f, "Error: {}", msg),
             RpcError::InvalidParameter(msg) => write!(f, "Invalid parameter: {}", msg),
             RpcError::SolanaRpcError(msg) => write!(f, "Solana RPC error: {}", msg),
+            RpcError::MemoTooFrequent => write!(f, "Message sent too frequently. Please wait before sending another message."),
+            RpcError::InsufficientBalance => write!(f, "Insufficient balance"),
+            RpcError::Timeout => write!(f, "Request timed out. Please try again."),
+            RpcError::Rejected(msg) => write!(f, "Transaction rejected: {}", msg),
+            RpcError::Program(code) => write!(f, "Program error {}", code),
+        }
+    }
+}
+
+/// Round trips slower than this mark the RPC as slow
+pub const SLOW_RPC_MS: f64 = 2000.0;
+
+/// Result of a lightweight ping of the current RPC endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpcHealth {
+    Healthy,
+    /// Answered, but slower than `SLOW_RPC_MS`
+    Slow,
+    /// No healthy answer; holds the error shown to the user
+    Unreachable(String),
+}
+
+impl RpcHealth {
+    fn from_ping(result: Result<(), RpcError>, elapsed_ms: f64) -> Self {
+        match result {
+            Err(e) => RpcHealth::Unreachable(e.to_string()),
+            Ok(()) if elapsed_ms > SLOW_RPC_MS => RpcHealth::Slow,
+            Ok(()) => RpcHealth::Healthy,
+        }
+    }
+}
+
+impl RpcError {
+    /// Whether the request may succeed if simply sent again (network
+    /// hiccups, timeouts), as opposed to errors the node or program answered
+    pub fn is_transient(&self) -> bool {
+        matches!(self, RpcError::ConnectionFailed(_) | RpcError::Timeout)
+    }
+}
+
+/// Turn a JSON-RPC error object into a typed `RpcError`. Custom program
+/// errors are read from `data.err`; the contract's "Error Message:" log
+/// line, when present, is kept in the `SolanaRpcError` text.
+fn classify_rpc_error(code: i64, message: &str, data: Option<&serde_json::Value>) -> RpcError {
+    let custom_code = custom_error_code(data);
+    let logs: Vec<&str> = data
+        .and_then(|data| data.get("logs"))
+        .and_then(|logs| logs.as_array())
+        .map(|logs| logs.iter().filter_map(|log| log.as_str()).collect())
+        .unwrap_or_default();
+
+    if let Some(custom) = custom_code {
+        log::error!("Contract error code: {}", custom);
+    }
+    // custom codes are per program: 6009 is only MemoTooFrequent in memo-chat
+    if custom_code == Some(MEMO_TOO_FREQUENT_CODE) && failed_program(&logs).is_some_and(is_chat_program) {
+        return RpcError::MemoTooFrequent;
+    }
+    let insufficient = |text: &str| {
+        let text = text.to_lowercase();
+        text.contains("insufficient") || text.contains("no record of a prior credit")
+    };
+    if insufficient(message) || logs.iter().any(|log| insufficient(log)) {
+        return RpcError::InsufficientBalance;
+    }
+
+    // Extract the specific error message from the logs of a contract error
+    let specific_error = custom_code.and(logs.iter().find_map(|log| {
+        log.find("Error Message:").map(|start| log[start + 14..].trim().to_string())
+    }));
+    match (custom_code, specific_error) {
+        (_, Some(specific)) => {
+            log::error!("Extracted error message: {}", specific);
+            RpcError::SolanaRpcError(format!("Code {}: {} - {}", code, message, specific))
+        }
+        (Some(custom), None) => RpcError::Program(custom),
+        (None, None) if code == SIGNATURE_VERIFICATION_FAILURE_CODE || message.contains("Blockhash not found") => {
+            RpcError::Rejected(message.to_string())
+        }
+        (None, None) => RpcError::SolanaRpcError(format!("Code {}: {}", code, message)),
+    }
+}
+
+/// The program whose failure ended the transaction, from its
+/// "Program <id> failed: ..." log line
+fn failed_program<'a>(logs: &[&'a str]) -> Option<&'a str> {
+    logs.iter()
+        .rev()
+        .find_map(|log| log.strip_prefix("Program ")?.split_once(" failed"))
+        .map(|(program_id, _)| program_id)
+}
+
+/// Whether `program_id` is memo-chat on any network
+fn is_chat_program(program_id: &str) -> bool {
+    [NetworkType::Testnet, NetworkType::ProdStaging, NetworkType::Mainnet]
+        .into_iter()
+        .any(|network| NetworkConfig::for_network(network).program_ids.chat_program_id == program_id)
+}
+
+/// Custom program error code in the `err` of an RPC error's data or a simulation result
+fn custom_error_code(data: Option<&serde_json::Value>) -> Option<u32> {
+    data.and_then(|data| data.get("err"))
+        .and_then(|err| err.get("InstructionError"))
+        .and_then(|err| err.as_array())
+        .and_then(|err| err.get(1))
+        .and_then(|err| err.get("Custom"))
+        .and_then(|custom| custom.as_u64())
+        .and_then(|custom| u32::try_from(custom).ok())
+}
+
+/// What a transaction would do, from simulating it without sending
+#[derive(Debug, Clone)]
+pub struct SimulationReport {
+    /// Program log lines, in order
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    /// Why the transaction would fail, None if it would succeed. Classified
+    /// the same way as a failed send, so callers can match the variants.
+    pub error: Option<RpcError>,
+    /// Custom program error code of the failure, if the program raised one
+    pub error_code: Option<u32>,
+}
+
+impl SimulationReport {
+    /// Read the `value` of a `simulateTransaction` result
+    pub fn from_simulation(value: &serde_json::Value) -> Self {
+        let logs = value["logs"]
+            .as_array()
+            .map(|logs| logs.iter().filter_map(|log| log.as_str().map(str::to_string)).collect())
+            .unwrap_or_default();
+        let error_code = custom_error_code(Some(value));
+        let error = value.get("err").filter(|err| !err.is_null()).map(|err| {
+            match classify_rpc_error(SIMULATION_FAILED_CODE, "Transaction simulation failed", Some(value)) {
+                // not a program error: the `err` value itself says the most
+                RpcError::SolanaRpcError(_) if error_code.is_none() => RpcError::TransactionFailed(err.to_string()),
+                error => error,
+            }
+        });
+        Self {
+            logs,
+            units_consumed: value["unitsConsumed"].as_u64(),
+            error,
+            error_code,
         }
     }
+
+    /// Whether the transaction would succeed
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
 }
 
 // define the rpc response error structure
@@ -51,6 +244,7 @@ struct RpcResponseError {
     message: String,
 }
 
+#[derive(Clone)]
 pub struct RpcConnection {
     endpoint: String,
 }
@@ -172,6 +366,30 @@ impl RpcConnection {
     }
 
     pub async fn send_request<T, R>(&self, method: &str, params: T) -> Result<R, RpcError>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        if !rpc_inspector::is_enabled() {
+            return self.dispatch_request(method, params).await;
+        }
+
+        let params_summary = serde_json::to_string(&params)
+            .map(|json| rpc_inspector::summarize_params(&json))
+            .unwrap_or_default();
+        let started_at = Date::now();
+        let result = self.dispatch_request(method, params).await;
+        rpc_inspector::record(
+            method,
+            params_summary,
+            started_at,
+            Date::now() - started_at,
+            result.as_ref().err().map(|e| e.to_string()),
+        );
+        result
+    }
+
+    async fn dispatch_request<T, R>(&self, method: &str, params: T) -> Result<R, RpcError>
     where
         T: Serialize,
         R: for<'de> Deserialize<'de>,
@@ -264,48 +482,7 @@ impl RpcConnection {
             if let Some(error_obj) = error.as_object() {
                 let code = error_obj.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
                 let message = error_obj.get("message").and_then(|m| m.as_str()).unwrap_or("Unknown error");
-                
-                // Extract specific error details from transaction logs
-                let mut specific_error = None;
-                if let Some(data) = error_obj.get("data") {
-                    // Check for specific Solana contract errors
-                    if let Some(err_info) = data.get("err") {
-                        if let Some(custom) = err_info.get("InstructionError").and_then(|e| e.as_array()) {
-                            if custom.len() >= 2 {
-                                if let Some(custom_error) = custom[1].get("Custom") {
-                                    let error_code = custom_error.as_i64().unwrap_or(0);
-                                    log::error!("Contract error code: {}", error_code);
-                                    
-                                    // Extract specific error message from logs
-                                    if let Some(logs) = data.get("logs").and_then(|l| l.as_array()) {
-                                        for log_entry in logs {
-                                            if let Some(log_str) = log_entry.as_str() {
-                                                if log_str.contains("Error Message:") {
-                                                    // Extract the error message after "Error Message:"
-                                                    if let Some(msg_start) = log_str.find("Error Message:") {
-                                                        let error_msg = &log_str[msg_start + 14..].trim();
-                                                        specific_error = Some(error_msg.to_string());
-                                                        log::error!("Extracted error message: {}", error_msg);
-                                                        break;
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                
-                // Create error message with specific details if available
-                let error_message = if let Some(specific_msg) = specific_error {
-                    format!("Code {}: {} - {}", code, message, specific_msg)
-                } else {
-                    format!("Code {}: {}", code, message)
-                };
-                
-                return Err(RpcError::SolanaRpcError(error_message));
+                return Err(classify_rpc_error(code, message, error_obj.get("data")));
             } else {
                 return Err(RpcError::Other(error.to_string()));
             }
@@ -331,6 +508,22 @@ impl RpcConnection {
         Ok(result.to_string())
     }
 
+    /// Ping the node; Ok when it reports itself healthy
+    pub async fn get_health(&self) -> Result<(), RpcError> {
+        let result: serde_json::Value = self.send_request("getHealth", Vec::<String>::new()).await?;
+        match result.as_str() {
+            Some("ok") => Ok(()),
+            _ => Err(RpcError::Other(format!("Node reported unhealthy: {}", result))),
+        }
+    }
+
+    /// Time a `getHealth` ping to tell healthy, slow and unreachable apart
+    pub async fn check_health(&self) -> RpcHealth {
+        let started = Date::now();
+        let result = self.get_health().await;
+        RpcHealth::from_ping(result, Date::now() - started)
+    }
+
     pub async fn get_version(&self) -> Result<String, RpcError> {
         let result: serde_json::Value = self.send_request("getVersion", Vec::<String>::new()).await?;
         Ok(result.to_string())
@@ -373,6 +566,24 @@ impl RpcConnection {
         Ok(result.to_string())
     }
 
+    /// Simulate an unsigned transaction (no signature check, fresh blockhash)
+    /// to see whether it would succeed, without paying any fee
+    pub async fn simulate_unsigned_transaction(&self, transaction: &Transaction) -> Result<SimulationReport, RpcError> {
+        let serialized_tx = base64::encode(bincode::serialize(transaction)
+            .map_err(|e| RpcError::Other(format!("Failed to serialize transaction: {}", e)))?);
+        let options = serde_json::json!({
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "replaceRecentBlockhash": true,
+            "sigVerify": false
+        });
+
+        let result = self.simulate_transaction(&serialized_tx, Some(options)).await?;
+        let result: serde_json::Value = serde_json::from_str(&result)
+            .map_err(|e| RpcError::Other(format!("Failed to parse simulation result: {}", e)))?;
+        Ok(SimulationReport::from_simulation(&result["value"]))
+    }
+
     // ============ Common Transaction Utilities ============
 
     /// Get the latest blockhash from the network
@@ -398,6 +609,35 @@ impl RpcConnection {
             .map_err(|e| RpcError::Other(format!("Invalid blockhash: {}", e)))
     }
 
+    /// Fee (lamports) the network would charge for `message`
+    pub async fn get_fee_for_message(&self, message: &Message) -> Result<u64, RpcError> {
+        let serialized = base64::encode(bincode::serialize(message)
+            .map_err(|e| RpcError::Other(format!("Failed to serialize message: {}", e)))?);
+        let result: serde_json::Value = self.send_request(
+            "getFeeForMessage",
+            serde_json::json!([serialized, {"commitment": "confirmed"}])
+        ).await?;
+
+        // null when the blockhash has already expired
+        result["value"]
+            .as_u64()
+            .ok_or_else(|| RpcError::Other("Fee not available for message".to_string()))
+    }
+
+    /// Estimated fee (lamports) of a memo transaction paid by `payer`: the
+    /// base fee plus the priority fee from settings for
+    /// `FEE_ESTIMATE_COMPUTE_UNITS`
+    pub async fn estimate_memo_fee(&self, payer: &Pubkey) -> Result<u64, RpcError> {
+        let blockhash = self.get_latest_blockhash().await?;
+        let mut instructions = vec![spl_memo::build_memo(b"fee estimate", &[])];
+        instructions.extend(Self::build_compute_budget_instructions(
+            FEE_ESTIMATE_COMPUTE_UNITS,
+            COMPUTE_UNIT_BUFFER,
+        ));
+        let message = Message::new_with_blockhash(&instructions, Some(payer), &blockhash);
+        self.get_fee_for_message(&message).await
+    }
+
     /// Send a signed transaction to the network
     /// 
     /// This is a common utility method used by all modules after signing transactions.
@@ -432,6 +672,47 @@ impl RpcConnection {
         Ok(result)
     }
 
+    /// How far a sent transaction has progressed
+    /// 
+    /// # Returns
+    /// None while the cluster hasn't seen the signature yet; an error if the
+    /// transaction landed but failed
+    pub async fn get_signature_commitment(&self, signature: &str) -> Result<Option<Commitment>, RpcError> {
+        let result: serde_json::Value = self.send_request(
+            "getSignatureStatuses",
+            serde_json::json!([[signature], { "searchTransactionHistory": false }])
+        ).await?;
+        
+        let status = &result["value"][0];
+        if status.is_null() {
+            return Ok(None);
+        }
+        if !status["err"].is_null() {
+            return Err(RpcError::TransactionFailed(format!("Transaction failed: {}", status["err"])));
+        }
+        Ok(status["confirmationStatus"].as_str().and_then(Commitment::parse))
+    }
+
+    /// Poll a sent transaction once a second until it reaches `target`
+    /// 
+    /// # Returns
+    /// Whether `target` was reached within `timeout_ms`
+    pub async fn wait_for_commitment(
+        &self,
+        signature: &str,
+        target: Commitment,
+        timeout_ms: u32,
+    ) -> Result<bool, RpcError> {
+        const POLL_INTERVAL_MS: u32 = 1_000;
+        for _ in 0..timeout_ms.div_ceil(POLL_INTERVAL_MS) {
+            if self.get_signature_commitment(signature).await?.is_some_and(|reached| reached >= target) {
+                return Ok(true);
+            }
+            gloo_timers::future::TimeoutFuture::new(POLL_INTERVAL_MS).await;
+        }
+        Ok(false)
+    }
+
     // ============ End Transaction Utilities ============
 
     /// Apply compute budget instructions based on user settings
@@ -498,6 +779,22 @@ impl RpcConnection {
         Ok(result.to_string())
     }
 
+    /// interface: get a confirmed transaction by signature, instructions parsed
+    /// returns "null" while the transaction isn't known (yet) at confirmed commitment
+    pub async fn get_transaction(&self, signature: &str) -> Result<String, RpcError> {
+        let params = serde_json::json!([
+            signature,
+            {
+                "encoding": "jsonParsed",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0
+            }
+        ]);
+        
+        let result: serde_json::Value = self.send_request("getTransaction", params).await?;
+        Ok(result.to_string())
+    }
+
     /// Helper function to read a String from account data
     pub fn read_string_from_data(&self, data: &[u8], offset: usize) -> Result<(String, usize), RpcError> {
         if data.len() < offset + 4 {
@@ -582,6 +879,61 @@ pub fn validate_memo_length_str(memo: &str) -> Result<(), RpcError> {
     Ok(())
 }
 
+/// Convert a whole-token burn amount to lamports, rejecting amounts above `MAX_BURN_TOKENS`
+pub fn tokens_to_lamports(tokens: u64) -> Result<u64, RpcError> {
+    if tokens > MAX_BURN_TOKENS {
+        return Err(RpcError::InvalidParameter(format!(
+            "Burn amount {} MEMO exceeds the maximum of {} MEMO", tokens, MAX_BURN_TOKENS
+        )));
+    }
+    tokens
+        .checked_mul(LAMPORTS_PER_TOKEN)
+        .ok_or_else(|| RpcError::InvalidParameter(format!("Burn amount {} MEMO is too large", tokens)))
+}
+
+/// Validate a burn amount against the maximum and the wallet's token balance, returning lamports
+pub fn validate_burn_tokens(tokens: u64, token_balance: f64) -> Result<u64, RpcError> {
+    let lamports = tokens_to_lamports(tokens)?;
+    if token_balance < tokens as f64 {
+        return Err(RpcError::InvalidParameter(format!(
+            "Insufficient balance. Required: {} MEMO, Available: {:.2} MEMO", tokens, token_balance
+        )));
+    }
+    Ok(lamports)
+}
+
+/// Attempts made by `with_retry` for read-only requests
+pub const READ_RETRY_ATTEMPTS: u32 = 3;
+/// First backoff delay of `with_retry` for read-only requests, doubled per retry
+pub const READ_RETRY_BASE_DELAY_MS: u32 = 500;
+
+/// Delay before retry number `retry` (1-based): `base_delay_ms` doubled per retry
+fn backoff_delay_ms(base_delay_ms: u32, retry: u32) -> u32 {
+    base_delay_ms.saturating_mul(1 << retry.saturating_sub(1).min(16))
+}
+
+/// Run `f` up to `attempts` times, retrying transient failures (see
+/// `RpcError::is_transient`) with exponential backoff. Only for read-only
+/// requests: a retried transaction could land twice.
+pub async fn with_retry<F, Fut, T>(attempts: u32, base_delay_ms: u32, mut f: F) -> Result<T, RpcError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RpcError>>,
+{
+    let mut retry = 0;
+    loop {
+        match f().await {
+            Err(e) if e.is_transient() && retry + 1 < attempts => {
+                retry += 1;
+                let delay = backoff_delay_ms(base_delay_ms, retry);
+                log::warn!("RPC request failed ({}), retry {} in {} ms", e, retry, delay);
+                gloo_timers::future::TimeoutFuture::new(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
 /// Validate memo data length (for &[u8] input)
 pub fn validate_memo_length_bytes(memo_data: &[u8]) -> Result<(), RpcError> {
     let len = memo_data.len();
@@ -593,4 +945,149 @@ pub fn validate_memo_length_bytes(memo_data: &[u8]) -> Result<(), RpcError> {
     }
     Ok(())
 }
- 
\ No newline at end of file
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_to_lamports_boundary() {
+        assert_eq!(tokens_to_lamports(0).unwrap(), 0);
+        assert_eq!(tokens_to_lamports(42_069).unwrap(), 42_069_000_000);
+        assert_eq!(tokens_to_lamports(MAX_BURN_TOKENS).unwrap(), MAX_BURN_TOKENS * LAMPORTS_PER_TOKEN);
+        assert!(tokens_to_lamports(MAX_BURN_TOKENS + 1).is_err());
+        // would wrap without the checks
+        assert!(tokens_to_lamports(u64::MAX / LAMPORTS_PER_TOKEN + 1).is_err());
+        assert!(tokens_to_lamports(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles() {
+        assert_eq!(backoff_delay_ms(500, 1), 500);
+        assert_eq!(backoff_delay_ms(500, 2), 1000);
+        assert_eq!(backoff_delay_ms(500, 3), 2000);
+        // capped instead of overflowing
+        assert_eq!(backoff_delay_ms(500, 100), 500 << 16);
+        assert_eq!(backoff_delay_ms(u32::MAX, 2), u32::MAX);
+    }
+
+    #[test]
+    fn test_only_network_errors_are_transient() {
+        assert!(RpcError::ConnectionFailed("HTTP 503".to_string()).is_transient());
+        assert!(RpcError::Timeout.is_transient());
+        assert!(!RpcError::Program(6001).is_transient());
+        assert!(!RpcError::MemoTooFrequent.is_transient());
+        assert!(!RpcError::InvalidParameter("bad".to_string()).is_transient());
+        assert!(!RpcError::Other("Chat group 7 not found".to_string()).is_transient());
+    }
+
+    #[test]
+    fn test_classify_rpc_error() {
+        let custom = |code: u64, logs: &[&str]| serde_json::json!({
+            "err": { "InstructionError": [2, { "Custom": code }] },
+            "logs": logs,
+        });
+
+        let too_frequent = custom(6009, &[
+            "Program log: Error Message: Memo too frequent.",
+            "Program 54ky4LNnRsbYioDSBKNrc5hG8HoDyZ6yhf8TuncxTBRF failed: custom program error: 0x1779",
+        ]);
+        assert!(matches!(
+            classify_rpc_error(-32002, "Transaction simulation failed", Some(&too_frequent)),
+            RpcError::MemoTooFrequent
+        ));
+        // the same code from another program is that program's own error
+        let other_program = custom(6009, &["Program 11111111111111111111111111111111 failed: custom program error: 0x1779"]);
+        assert!(matches!(
+            classify_rpc_error(-32002, "Transaction simulation failed", Some(&other_program)),
+            RpcError::Program(6009)
+        ));
+
+        let with_message = custom(6001, &["Program log: AnchorError occurred. Error Message: Invalid group."]);
+        assert!(matches!(
+            classify_rpc_error(-32002, "Transaction simulation failed", Some(&with_message)),
+            RpcError::SolanaRpcError(msg) if msg == "Code -32002: Transaction simulation failed - Invalid group."
+        ));
+
+        let without_message = custom(6001, &["Program failed"]);
+        assert!(matches!(
+            classify_rpc_error(-32002, "Transaction simulation failed", Some(&without_message)),
+            RpcError::Program(6001)
+        ));
+
+        let token_error = custom(1, &["Program log: Error: insufficient funds"]);
+        assert!(matches!(
+            classify_rpc_error(-32002, "Transaction simulation failed", Some(&token_error)),
+            RpcError::InsufficientBalance
+        ));
+        assert!(matches!(
+            classify_rpc_error(-32002, "Attempt to debit an account but found no record of a prior credit.", None),
+            RpcError::InsufficientBalance
+        ));
+
+        assert!(matches!(
+            classify_rpc_error(-32003, "Transaction signature verification failure", None),
+            RpcError::Rejected(_)
+        ));
+        assert!(matches!(
+            classify_rpc_error(-32601, "Method not found", None),
+            RpcError::SolanaRpcError(msg) if msg == "Code -32601: Method not found"
+        ));
+    }
+
+    #[test]
+    fn test_simulation_report() {
+        let ok = SimulationReport::from_simulation(&serde_json::json!({
+            "err": null,
+            "logs": ["Program log: Instruction: BurnForGroup", "Program success"],
+            "unitsConsumed": 41_000,
+        }));
+        assert!(ok.is_ok());
+        assert_eq!(ok.logs.len(), 2);
+        assert_eq!(ok.units_consumed, Some(41_000));
+        assert_eq!(ok.error_code, None);
+
+        let too_frequent = SimulationReport::from_simulation(&serde_json::json!({
+            "err": { "InstructionError": [2, { "Custom": 6009 }] },
+            "logs": [
+                "Program log: Error Message: Memo too frequent.",
+                "Program 54ky4LNnRsbYioDSBKNrc5hG8HoDyZ6yhf8TuncxTBRF failed: custom program error: 0x1779",
+            ],
+        }));
+        assert!(matches!(too_frequent.error, Some(RpcError::MemoTooFrequent)));
+        assert_eq!(too_frequent.error_code, Some(6009));
+
+        let no_account = SimulationReport::from_simulation(&serde_json::json!({
+            "err": "AccountNotFound",
+            "logs": [],
+        }));
+        assert!(matches!(no_account.error, Some(RpcError::TransactionFailed(msg)) if msg == "\"AccountNotFound\""));
+        assert_eq!(no_account.error_code, None);
+    }
+
+    #[test]
+    fn test_commitment_parse_and_order() {
+        assert_eq!(Commitment::parse("confirmed"), Some(Commitment::Confirmed));
+        assert_eq!(Commitment::parse("finalized"), Some(Commitment::Finalized));
+        assert_eq!(Commitment::parse("rooted"), None);
+        assert!(Commitment::Processed < Commitment::Confirmed);
+        assert!(Commitment::Finalized >= Commitment::Confirmed);
+    }
+
+    #[test]
+    fn test_validate_burn_tokens_checks_balance() {
+        assert_eq!(validate_burn_tokens(100, 100.0).unwrap(), 100_000_000);
+        assert!(validate_burn_tokens(101, 100.5).is_err());
+        assert!(validate_burn_tokens(u64::MAX, f64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_health_from_ping() {
+        assert_eq!(RpcHealth::from_ping(Ok(()), 150.0), RpcHealth::Healthy);
+        assert_eq!(RpcHealth::from_ping(Ok(()), 2500.0), RpcHealth::Slow);
+        assert_eq!(
+            RpcHealth::from_ping(Err(RpcError::ConnectionFailed("refused".to_string())), 10.0),
+            RpcHealth::Unreachable("Connection failed: refused".to_string())
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use super::rpc_base::{
     RpcConnection, RpcError,
-    get_token_2022_program_id, validate_memo_length_bytes
+    get_token_2022_program_id, validate_memo_length_bytes,
+    with_retry, READ_RETRY_ATTEMPTS, READ_RETRY_BASE_DELAY_MS,
 };
 use super::network_config::get_program_ids;
+use super::pixel::Pixel;
 use super::constants::*;
 use serde::{Serialize, Deserialize};
 use borsh::{BorshSerialize, BorshDeserialize};
@@ -352,10 +354,168 @@ impl ChatGroupBurnData {
         
         Ok(())
     }
+    
+    /// Calculate the final memo size (Borsh + Base64) for this burn
+    pub fn calculate_final_memo_size(&self, burn_amount: u64) -> Result<usize, String> {
+        let payload_bytes = self.try_to_vec()
+            .map_err(|e| format!("Failed to serialize ChatGroupBurnData: {}", e))?;
+        
+        let burn_memo = BurnMemo {
+            version: BURN_MEMO_VERSION,
+            burn_amount,
+            payload: payload_bytes,
+        };
+        
+        let memo_data_bytes = burn_memo.try_to_vec()
+            .map_err(|e| format!("Failed to serialize BurnMemo: {}", e))?;
+        
+        Ok(base64::encode(&memo_data_bytes).len())
+    }
+
+    /// Length of the Borsh-serialized burn memo before Base64 encoding
+    fn memo_data_len(&self, burn_amount: u64) -> Result<usize, String> {
+        let payload_bytes = self.try_to_vec()
+            .map_err(|e| format!("Failed to serialize ChatGroupBurnData: {}", e))?;
+        
+        let burn_memo = BurnMemo {
+            version: BURN_MEMO_VERSION,
+            burn_amount,
+            payload: payload_bytes,
+        };
+        
+        burn_memo.try_to_vec()
+            .map(|bytes| bytes.len())
+            .map_err(|e| format!("Failed to serialize BurnMemo: {}", e))
+    }
+}
+
+/// Burn data has no reply field on chain, so a burn-reply carries the
+/// replied-to signature at the start of the burn message:
+/// "re:<signature>\n<message>"
+const BURN_REPLY_PREFIX: &str = "re:";
+
+/// Prefix `message` with a reply reference for a burn-reply
+pub fn encode_burn_reply(reply_to_sig: Option<&str>, message: &str) -> String {
+    match reply_to_sig {
+        Some(sig) => format!("{}{}\n{}", BURN_REPLY_PREFIX, sig, message),
+        None => message.to_string(),
+    }
+}
+
+/// Split a burn message into its reply reference (if any) and the text shown to users
+pub fn split_burn_reply(message: &str) -> (Option<String>, String) {
+    let parsed = message.strip_prefix(BURN_REPLY_PREFIX).and_then(|rest| {
+        let (sig, text) = rest.split_once('\n')?;
+        let is_signature = (64..=88).contains(&sig.len()) && bs58::decode(sig).into_vec().is_ok();
+        is_signature.then(|| (Some(sig.to_string()), text.to_string()))
+    });
+    parsed.unwrap_or_else(|| (None, message.to_string()))
+}
+
+/// Check that a group burn, including any reply reference, fits both the
+/// 512-byte burn message limit and the final memo length limit
+pub fn validate_group_burn_size(
+    group_id: u64,
+    burner: &str,
+    reply_to_sig: Option<&str>,
+    message: &str,
+    burn_amount: u64,
+) -> Result<(), String> {
+    let message = encode_burn_reply(reply_to_sig, message);
+    if message.len() > 512 {
+        return Err(format!("Burn message too long: {} bytes (max: 512)", message.len()));
+    }
+    let memo_size = ChatGroupBurnData::new(group_id, burner.to_string(), message)
+        .calculate_final_memo_size(burn_amount)?;
+    if memo_size > MAX_MEMO_LENGTH {
+        return Err(format!("Burn memo too long: {} bytes (max: {})", memo_size, MAX_MEMO_LENGTH));
+    }
+    Ok(())
+}
+
+/// Bytes the text of a group burn uses and the most it may use, as
+/// `(used, max)`. The maximum is whichever limit is tighter: the 512-byte
+/// burn message or the final memo length, both after the reply reference
+/// and the rest of the burn framing. `used > max` exactly when
+/// `validate_group_burn_size` fails on size.
+pub fn group_burn_message_budget(
+    group_id: u64,
+    burner: &str,
+    reply_to_sig: Option<&str>,
+    message: &str,
+    burn_amount: u64,
+) -> Result<(usize, usize), String> {
+    let encoded = encode_burn_reply(reply_to_sig, message);
+    let framing = encoded.len() - message.len();
+    let memo_data_len = ChatGroupBurnData::new(group_id, burner.to_string(), encoded)
+        .memo_data_len(burn_amount)?;
+    // Base64 turns every 3 bytes into 4 characters
+    let max_memo_data_len = MAX_MEMO_LENGTH / 4 * 3;
+    let memo_max = (message.len() + max_memo_data_len).saturating_sub(memo_data_len);
+    let message_max = 512usize.saturating_sub(framing);
+    Ok((message.len(), memo_max.min(message_max)))
+}
+
+/// A chat message can carry a small pixel image on its last line:
+/// "<text>\npx:<pixel string>", or just "px:<pixel string>" for an image
+/// alone. The chat program only sees text, so clients without image
+/// support show the line as it is.
+const MESSAGE_PIXEL_PREFIX: &str = "px:";
+
+/// Size of the pixel grid drawn in the chat composer
+pub const MESSAGE_PIXEL_SIZE: usize = 16;
+
+/// Append an optional pixel image (in `Pixel::to_optimal_string` form) to
+/// the text of a chat message
+pub fn encode_message_pixel(text: &str, pixel: Option<&str>) -> String {
+    match pixel {
+        Some(pixel) if text.is_empty() => format!("{}{}", MESSAGE_PIXEL_PREFIX, pixel),
+        Some(pixel) => format!("{}\n{}{}", text, MESSAGE_PIXEL_PREFIX, pixel),
+        None => text.to_string(),
+    }
+}
+
+/// Split a chat message into the text shown to users and its pixel image,
+/// if any. A last line that merely starts with "px:" stays part of the text.
+pub fn split_message_pixel(message: &str) -> (String, Option<String>) {
+    let (text, last_line) = message.rsplit_once('\n').unwrap_or(("", message));
+    match last_line.strip_prefix(MESSAGE_PIXEL_PREFIX) {
+        Some(pixel) if Pixel::from_optimal_string(pixel).is_some() => {
+            (text.to_string(), Some(pixel.to_string()))
+        }
+        _ => (message.to_string(), None),
+    }
+}
+
+/// Check that a chat message, including any pixel image, fits both the
+/// 512-byte message limit and the final memo length limit
+pub fn validate_chat_message_size(
+    group_id: u64,
+    sender: &str,
+    reply_to_sig: Option<&str>,
+    message: &str,
+) -> Result<(), String> {
+    if message.len() > 512 {
+        return Err(format!("Message too long: {} bytes (max: 512)", message.len()));
+    }
+    let data = ChatMessageData::new(
+        group_id,
+        sender.to_string(),
+        message.to_string(),
+        None,
+        reply_to_sig.map(str::to_string),
+    );
+    let bytes = data.try_to_vec()
+        .map_err(|e| format!("Failed to serialize chat message data: {}", e))?;
+    let memo_size = base64::encode(&bytes).len();
+    if memo_size > MAX_MEMO_LENGTH {
+        return Err(format!("Message memo too long: {} bytes (max: {})", memo_size, MAX_MEMO_LENGTH));
+    }
+    Ok(())
 }
 
 /// Parse Base64+Borsh-formatted memo data to extract chat message
-fn parse_borsh_chat_message(memo_data: &[u8]) -> Option<(String, String)> {
+pub(crate) fn parse_borsh_chat_message(memo_data: &[u8]) -> Option<(String, String, Option<String>)> {
     // Convert bytes to UTF-8 string (should be Base64)
     let memo_str = std::str::from_utf8(memo_data).ok()?;
     
@@ -367,7 +527,7 @@ fn parse_borsh_chat_message(memo_data: &[u8]) -> Option<(String, String)> {
         Ok(chat_data) => {
             // Validate category and operation
             if chat_data.category == "chat" && chat_data.operation == "send_message" {
-                Some((chat_data.sender, chat_data.message))
+                Some((chat_data.sender, chat_data.message, chat_data.reply_to_sig))
             } else {
                 None
             }
@@ -404,21 +564,84 @@ fn parse_borsh_burn_message(memo_data: &[u8]) -> Option<(String, String, u64)> {
     }
 }
 
+/// A chat or burn memo decoded from a group's transaction
+struct ParsedChatMemo {
+    sender: String,
+    message: String,
+    message_type: String,
+    burn_amount: Option<u64>,
+    reply_to_sig: Option<String>,
+}
+
 /// Parse memo data and determine message type
-fn parse_memo_data(memo_data: &[u8]) -> Option<(String, String, String, Option<u64>)> {
+fn parse_memo_data(memo_data: &[u8]) -> Option<ParsedChatMemo> {
     // Try parsing as chat message first
-    if let Some((sender, message)) = parse_borsh_chat_message(memo_data) {
-        return Some((sender, message, "chat".to_string(), None));
+    if let Some((sender, message, reply_to_sig)) = parse_borsh_chat_message(memo_data) {
+        return Some(ParsedChatMemo { sender, message, message_type: "chat".to_string(), burn_amount: None, reply_to_sig });
     }
     
     // Try parsing as burn message
     if let Some((burner, message, burn_amount)) = parse_borsh_burn_message(memo_data) {
-        return Some((burner, message, "burn".to_string(), Some(burn_amount)));
+        let (reply_to_sig, message) = split_burn_reply(&message);
+        return Some(ParsedChatMemo { sender: burner, message, message_type: "burn".to_string(), burn_amount: Some(burn_amount), reply_to_sig });
     }
     
     None
 }
 
+/// Outcome of checking a displayed message against its transaction on chain
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageVerification {
+    /// The transaction's memo decodes to exactly the displayed message
+    Verified,
+    /// The transaction exists but doesn't carry the displayed message; says what differs
+    Mismatch(String),
+    /// No confirmed transaction with this signature yet
+    NotFound,
+}
+
+/// Memo texts of the spl-memo instructions in a `getTransaction` (jsonParsed) result
+fn transaction_memos(transaction: &serde_json::Value) -> Vec<&str> {
+    transaction["transaction"]["message"]["instructions"]
+        .as_array()
+        .map(|instructions| {
+            instructions.iter()
+                .filter(|ix| ix["program"].as_str() == Some("spl-memo"))
+                .filter_map(|ix| ix["parsed"].as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Compare the chat memos among `memos` with `message`, field by field
+fn verify_memos(memos: &[&str], message: &ChatMessage) -> MessageVerification {
+    let mut first_difference = None;
+    for parsed in memos.iter().filter_map(|memo| parse_memo_data(memo.as_bytes())) {
+        let difference = if parsed.sender != message.sender {
+            Some("sender")
+        } else if parsed.message_type != message.message_type {
+            Some("message type")
+        } else if parsed.message != message.message {
+            Some("content")
+        } else if parsed.burn_amount != message.burn_amount {
+            Some("burn amount")
+        } else if parsed.reply_to_sig != message.reply_to_sig {
+            Some("reply target")
+        } else {
+            None
+        };
+        match difference {
+            None => return MessageVerification::Verified,
+            Some(field) => {
+                first_difference.get_or_insert_with(|| format!("On-chain {} differs", field));
+            }
+        }
+    }
+    MessageVerification::Mismatch(
+        first_difference.unwrap_or_else(|| "Transaction has no chat memo".to_string())
+    )
+}
+
 /// Represents global statistics from the memo-chat contract
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobalStatistics {
@@ -442,6 +665,17 @@ pub struct ChatGroupInfo {
     pub bump: u8,
 }
 
+impl ChatGroupInfo {
+    /// Seconds until the group's `min_memo_interval` has passed since its last memo
+    /// (0 once a new memo is allowed). `now` is a unix timestamp in seconds.
+    pub fn cooldown_remaining(&self, now: i64) -> i64 {
+        if self.min_memo_interval <= 0 || self.last_memo_time <= 0 {
+            return 0;
+        }
+        (self.last_memo_time.saturating_add(self.min_memo_interval) - now).max(0)
+    }
+}
+
 /// Summary statistics for all chat groups
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatStatistics {
@@ -463,6 +697,8 @@ pub struct ChatMessage {
     pub memo_amount: u64,      // Amount of MEMO tokens burned for this message
     pub message_type: String,  // "chat" or "burn"
     pub burn_amount: Option<u64>, // For burn messages, the amount burned (in lamports)
+    #[serde(default)]
+    pub reply_to_sig: Option<String>, // Signature of the message this one replies to
 }
 
 /// Response containing chat messages for a group
@@ -472,15 +708,40 @@ pub struct ChatMessagesResponse {
     pub messages: Vec<ChatMessage>,
     pub total_found: usize,
     pub has_more: bool,        // Indicates if there are more messages available
+    /// Oldest signature scanned for this page, to pass as `before` for the next one
+    #[serde(default)]
+    pub next_before: Option<String>,
 }
 
+/// Distinct senders among a group's most recent messages
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupParticipants {
+    /// Sender pubkeys, most recent poster first
+    pub senders: Vec<String>,
+    /// Messages scanned to find them
+    pub scanned: usize,
+    /// Whether the scan reached the group's first message, so `senders`
+    /// is everyone who ever posted rather than only recent posters
+    pub complete: bool,
+}
+
+/// Messages scanned for a group's participants. Only the group's account
+/// history is on chain, so counting everyone means reading every message;
+/// the count is bounded to the most recent ones instead.
+pub const PARTICIPANTS_SCAN_LIMIT: usize = 200;
+
 /// Local message status for UI display
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MessageStatus {
     Sending,
+    /// Submitted; the signature was returned but the transaction may not be final yet
     Sent,
+    /// The transaction reached `Commitment::Finalized` and can't be rolled back
+    Confirmed,
     Failed,
     Timeout,
+    /// The user stopped waiting for the send; it may or may not have landed
+    Unconfirmed,
 }
 
 /// Custom error type that includes timeout
@@ -524,6 +785,7 @@ impl LocalChatMessage {
                 memo_amount: 0,
                 message_type: "chat".to_string(),
                 burn_amount: None,
+                reply_to_sig: None,
             },
             status: MessageStatus::Sending,
             is_local: true,
@@ -541,7 +803,8 @@ impl LocalChatMessage {
                 slot: 0,
                 memo_amount: 0,
                 message_type: "burn".to_string(),
-                burn_amount: Some(burn_amount * 1_000_000), // Convert to lamports for display
+                burn_amount: Some(burn_amount.saturating_mul(LAMPORTS_PER_TOKEN)), // Convert to lamports for display
+                reply_to_sig: None,
             },
             status: MessageStatus::Sending,
             is_local: true,
@@ -558,6 +821,97 @@ impl LocalChatMessage {
     }
 }
 
+/// Put a page of older chain messages in front of the loaded ones, skipping
+/// any already loaded (pages can overlap while new messages arrive).
+/// Returns how many were added.
+pub fn prepend_older_messages(messages: &mut Vec<LocalChatMessage>, older: Vec<ChatMessage>) -> usize {
+    let loaded: std::collections::HashSet<String> = messages
+        .iter()
+        .map(|m| m.message.signature.clone())
+        .collect();
+    let mut merged: Vec<LocalChatMessage> = older
+        .into_iter()
+        .filter(|m| !loaded.contains(&m.signature))
+        .map(LocalChatMessage::from_chain_message)
+        .collect();
+    let added = merged.len();
+    merged.append(messages);
+    *messages = merged;
+    added
+}
+
+/// Messages of a refreshed page that are newer than the loaded ones and
+/// were sent by someone other than `own_pubkey`. Empty while nothing from
+/// the chain is loaded yet, so opening a room brings nothing "new".
+pub fn incoming_messages<'a>(
+    loaded: &[LocalChatMessage],
+    refreshed: &'a [ChatMessage],
+    own_pubkey: &str,
+) -> Vec<&'a ChatMessage> {
+    let chain = || loaded.iter().filter(|m| !m.is_local).map(|m| &m.message);
+    let Some(newest) = chain().map(|m| m.timestamp).max() else {
+        return vec![];
+    };
+    let known: std::collections::HashSet<&str> = chain().map(|m| m.signature.as_str()).collect();
+    refreshed
+        .iter()
+        .filter(|m| m.timestamp >= newest && m.sender != own_pubkey && !known.contains(m.signature.as_str()))
+        .collect()
+}
+
+/// Distinct senders of `messages` (oldest to newest), most recent poster first
+pub fn unique_senders<'a>(messages: impl DoubleEndedIterator<Item = &'a ChatMessage>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    messages
+        .rev()
+        .filter(|m| seen.insert(m.sender.as_str()))
+        .map(|m| m.sender.clone())
+        .collect()
+}
+
+/// Group ids shown on a page of the "latest first" groups listing.
+/// Group ids run from 0 to `total_groups - 1`; page numbers start at 1.
+pub fn latest_group_ids_for_page(total_groups: u64, page: usize, per_page: usize) -> Vec<u64> {
+    if page == 0 || per_page == 0 {
+        return vec![];
+    }
+
+    let skipped = ((page - 1) * per_page) as u64;
+    if skipped >= total_groups {
+        return vec![];
+    }
+
+    let newest_id = total_groups - 1 - skipped;
+    let count = (per_page as u64).min(newest_id + 1);
+    (0..count).map(|offset| newest_id - offset).collect()
+}
+
+/// Group ids shown on a page of the "oldest first" groups listing; empty
+/// past the last group. Page numbers start at 1.
+pub fn oldest_group_ids_for_page(total_groups: u64, page: usize, per_page: usize) -> std::ops::Range<u64> {
+    if page == 0 {
+        return 0..0;
+    }
+    let start = ((page - 1) as u64).saturating_mul(per_page as u64).min(total_groups);
+    let end = start.saturating_add(per_page as u64).min(total_groups);
+    start..end
+}
+
+/// First and last rank (1-based) of the `shown` leaderboard entries on
+/// `page`, None when the page is empty
+pub fn leaderboard_rank_range(page: usize, per_page: usize, shown: usize) -> Option<(usize, usize)> {
+    if page == 0 || shown == 0 {
+        return None;
+    }
+    let first = (page - 1) * per_page + 1;
+    Some((first, first + shown - 1))
+}
+
+/// Whether a groups listing has another page after `page`
+pub fn has_next_groups_page(total_groups: u64, page: usize, per_page: usize) -> bool {
+    (page as u64).saturating_mul(per_page as u64) < total_groups
+}
+
 impl RpcConnection {
     /// Build an unsigned transaction to send a chat message
     pub async fn build_send_chat_message_transaction(
@@ -1032,7 +1386,10 @@ impl RpcConnection {
         
         log::info!("Fetching global chat statistics from PDA: {}", global_counter_pda);
         
-        let account_info = self.get_account_info(&global_counter_pda.to_string(), Some("base64")).await?;
+        let address = global_counter_pda.to_string();
+        let account_info = with_retry(READ_RETRY_ATTEMPTS, READ_RETRY_BASE_DELAY_MS, || {
+            self.get_account_info(&address, Some("base64"))
+        }).await?;
         let account_info: serde_json::Value = serde_json::from_str(&account_info)
             .map_err(|e| RpcError::Other(format!("Failed to parse account info: {}", e)))?;
         
@@ -1090,7 +1447,10 @@ impl RpcConnection {
         
         log::info!("Fetching chat group {} info from PDA: {}", group_id, chat_group_pda);
         
-        let account_info = self.get_account_info(&chat_group_pda.to_string(), Some("base64")).await?;
+        let address = chat_group_pda.to_string();
+        let account_info = with_retry(READ_RETRY_ATTEMPTS, READ_RETRY_BASE_DELAY_MS, || {
+            self.get_account_info(&address, Some("base64"))
+        }).await?;
         let account_info: serde_json::Value = serde_json::from_str(&account_info)
             .map_err(|e| RpcError::Other(format!("Failed to parse account info: {}", e)))?;
         
@@ -1424,18 +1784,19 @@ impl RpcConnection {
                 let memo_bytes = memo_data.as_bytes();
                 
                 // Parse memo data (both chat and burn messages)
-                if let Some((sender, message, msg_type, burn_amount)) = parse_memo_data(memo_bytes) {
+                if let Some(parsed) = parse_memo_data(memo_bytes) {
                     // Skip empty messages
-                    if !message.trim().is_empty() {
+                    if !parsed.message.trim().is_empty() {
                         messages.push(ChatMessage {
                             signature: signature.clone(),
-                            sender,
-                            message,
+                            sender: parsed.sender,
+                            message: parsed.message,
                             timestamp: block_time,
                             slot,
                             memo_amount: 0,
-                            message_type: msg_type,
-                            burn_amount,
+                            message_type: parsed.message_type,
+                            burn_amount: parsed.burn_amount,
+                            reply_to_sig: parsed.reply_to_sig,
                         });
                     }
                 }
@@ -1447,6 +1808,10 @@ impl RpcConnection {
         
         let has_more = signatures.len() == limit;
         let total_found = messages.len();
+        let next_before = signatures
+            .last()
+            .and_then(|sig_info| sig_info["signature"].as_str())
+            .map(str::to_string);
         
         log::info!("Found {} chat messages for group {}", total_found, group_id);
         
@@ -1455,9 +1820,57 @@ impl RpcConnection {
             messages,
             total_found,
             has_more,
+            next_before,
+        })
+    }
+
+    /// Get the distinct senders among a group's most recent messages
+    ///
+    /// # Parameters
+    /// * `group_id` - The ID of the chat group
+    /// * `limit` - Messages to scan (default: `PARTICIPANTS_SCAN_LIMIT`, max 1000)
+    ///
+    /// # Returns
+    /// The senders, and whether every message of the group was scanned
+    pub async fn get_chat_group_participants(
+        &self,
+        group_id: u64,
+        limit: Option<usize>,
+    ) -> Result<GroupParticipants, RpcError> {
+        let response = self
+            .get_chat_messages(group_id, Some(limit.unwrap_or(PARTICIPANTS_SCAN_LIMIT)), None)
+            .await?;
+        Ok(GroupParticipants {
+            senders: unique_senders(response.messages.iter()),
+            scanned: response.messages.len(),
+            complete: !response.has_more,
         })
     }
 
+    /// Re-fetch a message's transaction and check that its memo matches what
+    /// is displayed, guarding against stale or tampered client-side data
+    /// 
+    /// # Parameters
+    /// * `message` - The message as rendered, with the signature to look up
+    /// 
+    /// # Returns
+    /// `NotFound` while the transaction isn't confirmed yet, `Mismatch` if it
+    /// failed or its memo differs
+    pub async fn verify_chat_message(&self, message: &ChatMessage) -> Result<MessageVerification, RpcError> {
+        let transaction = self.get_transaction(&message.signature).await?;
+        let transaction: serde_json::Value = serde_json::from_str(&transaction)
+            .map_err(|e| RpcError::Other(format!("Failed to parse transaction: {}", e)))?;
+        
+        if transaction.is_null() {
+            return Ok(MessageVerification::NotFound);
+        }
+        if !transaction["meta"]["err"].is_null() {
+            return Ok(MessageVerification::Mismatch("Transaction failed on chain".to_string()));
+        }
+        
+        Ok(verify_memos(&transaction_memos(&transaction), message))
+    }
+
     /// Send a chat message to a group with timeout handling
     /// 
     /// # Parameters
@@ -1502,7 +1915,7 @@ impl RpcConnection {
                         let elapsed = js_sys::Date::now() - start_time;
                         if elapsed >= timeout_duration as f64 {
                             log::warn!("Chat message send timeout after {}ms", elapsed);
-                            Err(RpcError::Other(format!("Request timeout after {}ms", elapsed)))
+                            Err(RpcError::Timeout)
                         } else {
                             Err(e)
                         }
@@ -1532,7 +1945,7 @@ impl RpcConnection {
             let elapsed = js_sys::Date::now() - start_time;
             if elapsed >= timeout_ms as f64 {
                 log::warn!("Timeout detected before operation: {}ms elapsed", elapsed);
-                return Err(RpcError::Other(format!("Request timeout after {}ms", elapsed)));
+                return Err(RpcError::Timeout);
             }
             Ok(())
         };
@@ -1798,7 +2211,10 @@ impl RpcConnection {
         
         log::info!("Fetching burn leaderboard from PDA: {}", burn_leaderboard_pda);
         
-        let account_info = self.get_account_info(&burn_leaderboard_pda.to_string(), Some("base64")).await?;
+        let address = burn_leaderboard_pda.to_string();
+        let account_info = with_retry(READ_RETRY_ATTEMPTS, READ_RETRY_BASE_DELAY_MS, || {
+            self.get_account_info(&address, Some("base64"))
+        }).await?;
         let account_info: serde_json::Value = serde_json::from_str(&account_info)
             .map_err(|e| RpcError::Other(format!("Failed to parse leaderboard account info: {}", e)))?;
         
@@ -2096,6 +2512,32 @@ pub struct BurnLeaderboardResponse {
     pub total_burned_tokens: u64, // total burned amount of all leaderboard entries
 }
 
+impl BurnLeaderboardResponse {
+    /// Sort entries by burned amount (descending) and reassign ranks from 1.
+    /// Ties are ordered by group id ascending, so equal burns keep the same
+    /// ranks across refreshes whatever order the account stores them in.
+    pub fn sort_by_burned_amount(&mut self) {
+        self.entries.sort_by(|a, b| {
+            b.burned_amount
+                .cmp(&a.burned_amount)
+                .then(a.group_id.cmp(&b.group_id))
+        });
+        for (index, entry) in self.entries.iter_mut().enumerate() {
+            entry.rank = (index + 1) as u8;
+        }
+    }
+
+    /// Put entries in display order: the ranks as read from the leaderboard
+    /// account when `keep_stored_order` is set, otherwise re-sorted by burned amount
+    pub fn order_entries(&mut self, keep_stored_order: bool) {
+        if keep_stored_order {
+            self.entries.sort_by_key(|entry| entry.rank);
+        } else {
+            self.sort_by_burned_amount();
+        }
+    }
+}
+
 /// Chat burn operation types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ChatOperationType {
@@ -2145,9 +2587,20 @@ pub struct ChatContractTransactionsResponse {
     pub total_found: usize,
 }
 
+/// Transactions that burned tokens, biggest burn first, limited to `count`
+pub fn top_burn_transactions(transactions: &[ChatContractTransaction], count: usize) -> Vec<ChatContractTransaction> {
+    let mut burns: Vec<ChatContractTransaction> = transactions.iter()
+        .filter(|tx| tx.burn_amount > 0)
+        .cloned()
+        .collect();
+    burns.sort_by_key(|tx| std::cmp::Reverse(tx.burn_amount));
+    burns.truncate(count);
+    burns
+}
+
 /// Parse memo data for all chat operations (create_group, burn_for_group, send_memo)
 /// Returns (user, operation_type, details, burn_amount)
-fn parse_chat_operation_memo(memo_data: &[u8]) -> Option<(String, ChatOperationType, ChatOperationDetails, u64)> {
+pub(crate) fn parse_chat_operation_memo(memo_data: &[u8]) -> Option<(String, ChatOperationType, ChatOperationDetails, u64)> {
     // Convert bytes to UTF-8 string (should be Base64)
     let memo_str = std::str::from_utf8(memo_data).ok()?;
     
@@ -2185,7 +2638,7 @@ fn parse_chat_operation_memo(memo_data: &[u8]) -> Option<(String, ChatOperationT
                 ChatOperationDetails::BurnForGroup {
                     burner: burn_data.burner,
                     group_id: burn_data.group_id,
-                    message: burn_data.message,
+                    message: split_burn_reply(&burn_data.message).1,
                 },
                 burn_amount,
             ));
@@ -2209,4 +2662,392 @@ fn parse_chat_operation_memo(memo_data: &[u8]) -> Option<(String, ChatOperationT
     }
     
     None
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIG: &str = "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+
+    fn entry(group_id: u64, burned_amount: u64) -> LeaderboardEntry {
+        LeaderboardEntry { group_id, burned_amount, rank: 0 }
+    }
+
+    fn burn_tx(signature: &str, burn_amount: u64) -> ChatContractTransaction {
+        ChatContractTransaction {
+            signature: signature.to_string(),
+            user: "user".to_string(),
+            timestamp: 0,
+            slot: 0,
+            burn_amount,
+            operation_type: ChatOperationType::BurnForGroup,
+            details: ChatOperationDetails::BurnForGroup {
+                burner: "user".to_string(),
+                group_id: 0,
+                message: String::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_sort_leaderboard_by_burned_amount() {
+        let mut leaderboard = BurnLeaderboardResponse {
+            entries: vec![entry(1, 10), entry(2, 30), entry(3, 20), entry(4, 30)],
+            total_burned_tokens: 90,
+        };
+        leaderboard.sort_by_burned_amount();
+
+        let order: Vec<(u64, u8)> = leaderboard.entries.iter().map(|e| (e.group_id, e.rank)).collect();
+        // ties are ordered by group id
+        assert_eq!(order, vec![(2, 1), (4, 2), (3, 3), (1, 4)]);
+    }
+
+    #[test]
+    fn test_leaderboard_ties_rank_the_same_across_refreshes() {
+        let first = vec![entry(7, 30), entry(3, 30), entry(5, 10), entry(9, 30)];
+        let mut second = first.clone();
+        second.reverse();
+
+        let ranks = |entries: Vec<LeaderboardEntry>| {
+            let mut leaderboard = BurnLeaderboardResponse { entries, total_burned_tokens: 100 };
+            leaderboard.order_entries(false);
+            leaderboard.entries.iter().map(|e| (e.group_id, e.rank)).collect::<Vec<_>>()
+        };
+        assert_eq!(ranks(first.clone()), vec![(3, 1), (7, 2), (9, 3), (5, 4)]);
+        assert_eq!(ranks(first), ranks(second));
+    }
+
+    #[test]
+    fn test_leaderboard_keeps_stored_ranks() {
+        let mut stored = vec![entry(1, 10), entry(2, 30), entry(3, 20)];
+        for (index, entry) in stored.iter_mut().enumerate() {
+            entry.rank = (index + 1) as u8;
+        }
+        stored.swap(0, 2);
+        let mut leaderboard = BurnLeaderboardResponse { entries: stored, total_burned_tokens: 60 };
+        leaderboard.order_entries(true);
+
+        let order: Vec<(u64, u8)> = leaderboard.entries.iter().map(|e| (e.group_id, e.rank)).collect();
+        assert_eq!(order, vec![(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn test_top_burn_transactions() {
+        let transactions = vec![burn_tx("a", 5), burn_tx("b", 0), burn_tx("c", 50), burn_tx("d", 20), burn_tx("e", 1)];
+        let top: Vec<String> = top_burn_transactions(&transactions, 3).into_iter().map(|tx| tx.signature).collect();
+        assert_eq!(top, vec!["c", "d", "a"]);
+        assert!(top_burn_transactions(&[burn_tx("b", 0)], 3).is_empty());
+    }
+
+    #[test]
+    fn test_latest_group_ids_single_group() {
+        assert_eq!(latest_group_ids_for_page(1, 1, 10), vec![0]);
+        assert!(latest_group_ids_for_page(1, 2, 10).is_empty());
+        assert!(!has_next_groups_page(1, 1, 10));
+    }
+
+    #[test]
+    fn test_latest_group_ids_partial_first_page() {
+        assert_eq!(latest_group_ids_for_page(9, 1, 10), (0..9).rev().collect::<Vec<_>>());
+        assert!(!has_next_groups_page(9, 1, 10));
+    }
+
+    #[test]
+    fn test_latest_group_ids_exact_page() {
+        assert_eq!(latest_group_ids_for_page(10, 1, 10), (0..10).rev().collect::<Vec<_>>());
+        assert!(latest_group_ids_for_page(10, 2, 10).is_empty());
+        assert!(!has_next_groups_page(10, 1, 10));
+    }
+
+    #[test]
+    fn test_latest_group_ids_last_page_keeps_group_zero() {
+        assert_eq!(latest_group_ids_for_page(11, 1, 10), (1..11).rev().collect::<Vec<_>>());
+        assert_eq!(latest_group_ids_for_page(11, 2, 10), vec![0]);
+        assert!(has_next_groups_page(11, 1, 10));
+        assert!(!has_next_groups_page(11, 2, 10));
+    }
+
+    #[test]
+    fn test_latest_group_ids_many_pages() {
+        assert_eq!(latest_group_ids_for_page(100, 1, 10), (90..100).rev().collect::<Vec<_>>());
+        assert_eq!(latest_group_ids_for_page(100, 10, 10), (0..10).rev().collect::<Vec<_>>());
+        assert!(latest_group_ids_for_page(100, 11, 10).is_empty());
+        assert!(has_next_groups_page(100, 9, 10));
+        assert!(!has_next_groups_page(100, 10, 10));
+
+        // every id appears exactly once across all pages
+        let all: Vec<u64> = (1..=10).flat_map(|page| latest_group_ids_for_page(100, page, 10)).collect();
+        assert_eq!(all, (0..100).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_zero_and_one_group_boundaries() {
+        // fresh network
+        assert!(oldest_group_ids_for_page(0, 1, 10).is_empty());
+        assert!(!has_next_groups_page(0, 1, 10));
+        assert_eq!(crate::core::pagination::clamp_page(3, 0, 10), 1);
+        assert_eq!(leaderboard_rank_range(1, 10, 0), None);
+
+        assert_eq!(oldest_group_ids_for_page(1, 1, 10), 0..1);
+        assert!(oldest_group_ids_for_page(1, 2, 10).is_empty());
+        assert_eq!(crate::core::pagination::clamp_page(3, 1, 10), 1);
+        assert_eq!(leaderboard_rank_range(1, 10, 1), Some((1, 1)));
+
+        assert!(oldest_group_ids_for_page(5, 0, 10).is_empty());
+        assert_eq!(leaderboard_rank_range(0, 10, 1), None);
+    }
+
+    #[test]
+    fn test_oldest_group_ids_and_rank_range() {
+        assert_eq!(oldest_group_ids_for_page(25, 1, 10), 0..10);
+        assert_eq!(oldest_group_ids_for_page(25, 3, 10), 20..25);
+        assert!(oldest_group_ids_for_page(25, 4, 10).is_empty());
+        assert_eq!(leaderboard_rank_range(3, 10, 5), Some((21, 25)));
+    }
+
+    #[test]
+    fn test_latest_group_ids_empty_and_invalid() {
+        assert!(latest_group_ids_for_page(0, 1, 10).is_empty());
+        assert!(latest_group_ids_for_page(10, 0, 10).is_empty());
+        assert!(latest_group_ids_for_page(10, 1, 0).is_empty());
+        assert!(!has_next_groups_page(0, 1, 10));
+    }
+
+    fn group_with_interval(min_memo_interval: i64, last_memo_time: i64) -> ChatGroupInfo {
+        ChatGroupInfo {
+            group_id: 1,
+            creator: String::new(),
+            created_at: 0,
+            name: String::new(),
+            description: String::new(),
+            image: String::new(),
+            tags: vec![],
+            memo_count: 0,
+            burned_amount: 0,
+            min_memo_interval,
+            last_memo_time,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_cooldown_remaining() {
+        let group = group_with_interval(86_400, 1_000);
+        assert_eq!(group.cooldown_remaining(1_000), 86_400);
+        assert_eq!(group.cooldown_remaining(80_000), 7_400);
+        assert_eq!(group.cooldown_remaining(87_400), 0);
+        assert_eq!(group.cooldown_remaining(100_000), 0);
+
+        // no interval, or no memo yet
+        assert_eq!(group_with_interval(0, 1_000).cooldown_remaining(1_000), 0);
+        assert_eq!(group_with_interval(60, 0).cooldown_remaining(10), 0);
+    }
+
+    #[test]
+    fn test_prepend_older_messages_dedupes() {
+        let chain = |signature: &str, timestamp: i64| ChatMessage {
+            signature: signature.to_string(),
+            sender: "sender".to_string(),
+            message: signature.to_string(),
+            timestamp,
+            slot: 0,
+            memo_amount: 0,
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            reply_to_sig: None,
+        };
+        let mut messages = vec![
+            LocalChatMessage::from_chain_message(chain("c", 3)),
+            LocalChatMessage::from_chain_message(chain("d", 4)),
+        ];
+        let added = prepend_older_messages(&mut messages, vec![chain("a", 1), chain("b", 2), chain("c", 3)]);
+        assert_eq!(added, 2);
+        let order: Vec<&str> = messages.iter().map(|m| m.message.signature.as_str()).collect();
+        assert_eq!(order, ["a", "b", "c", "d"]);
+        assert_eq!(prepend_older_messages(&mut messages, vec![chain("a", 1)]), 0);
+        assert_eq!(messages.len(), 4);
+    }
+
+    #[test]
+    fn test_incoming_messages() {
+        let chain = |signature: &str, sender: &str, timestamp: i64| ChatMessage {
+            signature: signature.to_string(),
+            sender: sender.to_string(),
+            message: signature.to_string(),
+            timestamp,
+            slot: 0,
+            memo_amount: 0,
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            reply_to_sig: None,
+        };
+        let loaded = vec![
+            LocalChatMessage::from_chain_message(chain("a", "bob", 10)),
+            LocalChatMessage::from_chain_message(chain("b", "me", 20)),
+        ];
+        let refreshed = vec![
+            chain("a", "bob", 10),
+            chain("b", "me", 20),
+            chain("c", "bob", 20),
+            chain("d", "me", 30),
+            chain("e", "carol", 40),
+        ];
+        let incoming: Vec<&str> = incoming_messages(&loaded, &refreshed, "me")
+            .into_iter()
+            .map(|m| m.signature.as_str())
+            .collect();
+        assert_eq!(incoming, ["c", "e"]);
+
+        // pending local messages don't count as loaded
+        let pending = vec![LocalChatMessage { is_local: true, ..loaded[0].clone() }];
+        assert!(incoming_messages(&pending, &refreshed, "me").is_empty());
+        assert!(incoming_messages(&[], &refreshed, "me").is_empty());
+    }
+
+    #[test]
+    fn test_unique_senders() {
+        let message = |sender: &str| ChatMessage {
+            signature: String::new(),
+            sender: sender.to_string(),
+            message: "gm".to_string(),
+            timestamp: 0,
+            slot: 0,
+            memo_amount: 0,
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            reply_to_sig: None,
+        };
+        let messages = [message("alice"), message("bob"), message("alice"), message("carol")];
+        assert_eq!(unique_senders(messages.iter()), ["carol", "alice", "bob"]);
+        assert!(unique_senders(std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_burn_reply_round_trip() {
+        let encoded = encode_burn_reply(Some(SIG), "well said");
+        assert_eq!(encoded, format!("re:{}\nwell said", SIG));
+        assert_eq!(split_burn_reply(&encoded), (Some(SIG.to_string()), "well said".to_string()));
+        assert_eq!(encode_burn_reply(None, "gm"), "gm");
+    }
+
+    #[test]
+    fn test_split_burn_reply_ignores_plain_messages() {
+        assert_eq!(split_burn_reply("gm"), (None, "gm".to_string()));
+        // "re:" without a valid signature is just text
+        assert_eq!(split_burn_reply("re: your idea\nnice"), (None, "re: your idea\nnice".to_string()));
+        assert_eq!(split_burn_reply(&format!("re:{}", SIG)), (None, format!("re:{}", SIG)));
+    }
+
+    #[test]
+    fn test_message_pixel_round_trip() {
+        let mut pixel = Pixel::new_with_size(MESSAGE_PIXEL_SIZE);
+        pixel.set_pixel(3, 5, true);
+        let art = pixel.to_optimal_string();
+
+        let encoded = encode_message_pixel("look", Some(&art));
+        assert_eq!(split_message_pixel(&encoded), ("look".to_string(), Some(art.clone())));
+        // image only
+        let encoded = encode_message_pixel("", Some(&art));
+        assert_eq!(split_message_pixel(&encoded), (String::new(), Some(art)));
+        assert_eq!(encode_message_pixel("gm", None), "gm");
+    }
+
+    #[test]
+    fn test_split_message_pixel_ignores_plain_messages() {
+        assert_eq!(split_message_pixel("gm\nfrens"), ("gm\nfrens".to_string(), None));
+        // "px:" without valid pixel data is just text
+        assert_eq!(split_message_pixel("rate\npx: 16x16"), ("rate\npx: 16x16".to_string(), None));
+    }
+
+    #[test]
+    fn test_validate_chat_message_size_counts_pixel() {
+        let sender = &SIG[..44];
+        let art = Pixel::new_with_size(MESSAGE_PIXEL_SIZE).to_optimal_string();
+        assert!(validate_chat_message_size(1, sender, None, &encode_message_pixel("hi", Some(&art))).is_ok());
+        // a blank image encodes to 16 bytes, plus 4 for "\npx:": 500 + 20 > 512
+        let text = "a".repeat(500);
+        assert!(validate_chat_message_size(1, sender, None, &text).is_ok());
+        assert!(validate_chat_message_size(1, sender, None, &encode_message_pixel(&text, Some(&art))).is_err());
+        assert!(validate_chat_message_size(1, sender, Some(SIG), &"a".repeat(500)).is_err());
+    }
+
+    #[test]
+    fn test_validate_group_burn_size_counts_reply() {
+        let burner = &SIG[..44];
+        let message = "a".repeat(420);
+        assert!(validate_group_burn_size(1, burner, None, &message, 1_000_000).is_ok());
+        assert!(validate_group_burn_size(1, burner, Some(SIG), &message, 1_000_000).is_err());
+        assert!(validate_group_burn_size(1, burner, None, &"a".repeat(513), 1_000_000).is_err());
+    }
+
+    #[test]
+    fn test_group_burn_message_budget_matches_validation() {
+        let burner = &SIG[..44];
+        for reply in [None, Some(SIG)] {
+            let (_, max) = group_burn_message_budget(1, burner, reply, "", 1_000_000).unwrap();
+            assert!(max > 0 && max <= 512);
+
+            let fits = "a".repeat(max);
+            assert_eq!(group_burn_message_budget(1, burner, reply, &fits, 1_000_000).unwrap(), (max, max));
+            assert!(validate_group_burn_size(1, burner, reply, &fits, 1_000_000).is_ok());
+
+            let over = "a".repeat(max + 1);
+            assert!(validate_group_burn_size(1, burner, reply, &over, 1_000_000).is_err());
+        }
+        // The reply reference takes part of the budget
+        let (_, plain) = group_burn_message_budget(1, burner, None, "", 1_000_000).unwrap();
+        let (_, reply) = group_burn_message_budget(1, burner, Some(SIG), "", 1_000_000).unwrap();
+        assert!(reply < plain);
+    }
+
+    fn chat_memo(sender: &str, message: &str) -> String {
+        let data = ChatMessageData::new(1, sender.to_string(), message.to_string(), None, None);
+        base64::encode(data.try_to_vec().unwrap())
+    }
+
+    fn displayed(sender: &str, message: &str) -> ChatMessage {
+        ChatMessage {
+            signature: SIG.to_string(),
+            sender: sender.to_string(),
+            message: message.to_string(),
+            timestamp: 0,
+            slot: 0,
+            memo_amount: 0,
+            message_type: "chat".to_string(),
+            burn_amount: None,
+            reply_to_sig: None,
+        }
+    }
+
+    #[test]
+    fn test_verify_memos() {
+        let memo = chat_memo("alice", "gm");
+        assert_eq!(verify_memos(&[&memo], &displayed("alice", "gm")), MessageVerification::Verified);
+        assert_eq!(
+            verify_memos(&[&memo], &displayed("alice", "gn")),
+            MessageVerification::Mismatch("On-chain content differs".to_string())
+        );
+        assert_eq!(
+            verify_memos(&[&memo], &displayed("mallory", "gm")),
+            MessageVerification::Mismatch("On-chain sender differs".to_string())
+        );
+        assert_eq!(
+            verify_memos(&["not a chat memo"], &displayed("alice", "gm")),
+            MessageVerification::Mismatch("Transaction has no chat memo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_transaction_memos() {
+        let transaction = serde_json::json!({
+            "meta": { "err": null },
+            "transaction": { "message": { "instructions": [
+                { "programId": "ComputeBudget111111111111111111111111111111", "data": "abc" },
+                { "program": "spl-memo", "parsed": "memo text" },
+            ]}}
+        });
+        assert_eq!(transaction_memos(&transaction), vec!["memo text"]);
+        assert!(transaction_memos(&serde_json::Value::Null).is_empty());
+    }
+}
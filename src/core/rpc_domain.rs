@@ -3,14 +3,37 @@
 //! This module provides integration with the X1NS domain name service.
 //! API Documentation: https://api.x1ns.xyz
 
+use futures::future::{self, Either};
+use gloo_timers::future::TimeoutFuture;
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::str::FromStr;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{Request, RequestInit, RequestMode, Response};
+use web_sys::{AbortController, AbortSignal, Request, RequestInit, RequestMode, Response};
+
+use super::cache::TtlMap;
 
 /// X1NS API base URL
 const X1NS_API_BASE: &str = "https://api.x1ns.xyz";
 
+/// How long a lookup may take before giving up. Domains only decorate
+/// addresses, so this is kept short.
+pub const DEFAULT_DOMAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Primary domains are rarely changed, so lookups are kept for 10 minutes,
+/// including those that found no domain
+const PRIMARY_DOMAIN_TTL_MS: f64 = 10.0 * 60.0 * 1000.0;
+
+/// Primary domain lookups keyed by address; `None` means the address has no domain
+static PRIMARY_DOMAIN_CACHE: Lazy<TtlMap<String, Option<String>>> =
+    Lazy::new(|| TtlMap::new(PRIMARY_DOMAIN_TTL_MS));
+
 /// Response from the X1NS primary domain API
 #[derive(Debug, Clone, Deserialize)]
 pub struct PrimaryDomainResponse {
@@ -32,6 +55,23 @@ pub struct PrimaryDomainResponse {
     pub timestamp: Option<String>,
 }
 
+/// Response from the X1NS domain resolution API
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainResolveResponse {
+    /// The domain name queried
+    #[allow(dead_code)]
+    pub domain: String,
+    /// Wallet address owning the domain (absent or null if unregistered)
+    pub owner: Option<String>,
+    /// The domain's address (optional)
+    #[serde(rename = "domainAddress")]
+    #[allow(dead_code)]
+    pub domain_address: Option<String>,
+    /// Timestamp (optional)
+    #[allow(dead_code)]
+    pub timestamp: Option<String>,
+}
+
 /// Error type for domain service operations
 #[derive(Debug)]
 pub enum DomainError {
@@ -61,7 +101,8 @@ impl std::fmt::Display for DomainError {
 /// # Returns
 /// * `Ok(Some(domain))` - If the wallet has a primary domain set
 /// * `Ok(None)` - If the wallet does not have a primary domain
-/// * `Err(DomainError)` - If there was an error querying the API
+/// * `Err(DomainError)` - If there was an error querying the API, or
+///   `NetworkError("timeout")` after `DEFAULT_DOMAIN_TIMEOUT`
 /// 
 /// # Example
 /// ```
@@ -73,17 +114,215 @@ impl std::fmt::Display for DomainError {
 /// }
 /// ```
 pub async fn get_primary_domain(address: &str) -> Result<Option<String>, DomainError> {
-    let url = format!("{}/api/primary/{}", X1NS_API_BASE, address);
-    
+    get_primary_domain_with_timeout(address, DEFAULT_DOMAIN_TIMEOUT).await
+}
+
+/// `get_primary_domain`, served from an in-memory cache while the last
+/// lookup for `address` is fresh. Addresses without a domain are cached too,
+/// so rendering their messages again doesn't re-query X1NS; failed lookups
+/// are not cached.
+pub async fn get_primary_domain_cached(address: &str) -> Result<Option<String>, DomainError> {
+    let key = address.to_string();
+    if let Some(domain) = PRIMARY_DOMAIN_CACHE.get_at(&key, js_sys::Date::now()) {
+        return Ok(domain);
+    }
+    let domain = get_primary_domain(address).await?;
+    PRIMARY_DOMAIN_CACHE.insert_at(key, domain.clone(), js_sys::Date::now());
+    Ok(domain)
+}
+
+/// Primary domains of many addresses at once, e.g. the senders of a page of
+/// chat messages. The lookups run concurrently and go through the cache
+/// (`get_primary_domain_cached`). An address whose lookup fails is logged
+/// and mapped to `None`, so one slow or failing lookup doesn't cost the
+/// others their names; only when every lookup fails is the error returned.
+pub async fn get_primary_domains_batch(addresses: &[&str]) -> Result<HashMap<String, Option<String>>, DomainError> {
+    let lookups = addresses.iter().map(|address| async move {
+        (address.to_string(), get_primary_domain_cached(address).await)
+    });
+    let results = future::join_all(lookups).await;
+
+    let total = results.len();
+    let mut domains = HashMap::with_capacity(total);
+    let mut failures = 0;
+    let mut last_error = None;
+    for (address, result) in results {
+        let domain = match result {
+            Ok(domain) => domain,
+            Err(e) => {
+                log::warn!("X1NS lookup for {} failed: {}", address, e);
+                failures += 1;
+                last_error = Some(e);
+                None
+            }
+        };
+        domains.insert(address, domain);
+    }
+    match last_error {
+        Some(e) if failures == total => Err(e),
+        _ => Ok(domains),
+    }
+}
+
+/// Forget all cached primary domain lookups
+pub fn clear_domain_cache() {
+    PRIMARY_DOMAIN_CACHE.clear();
+}
+
+/// `get_primary_domain` with a custom timeout. On expiry the request is
+/// aborted and `NetworkError("timeout")` is returned.
+pub async fn get_primary_domain_with_timeout(address: &str, timeout: Duration) -> Result<Option<String>, DomainError> {
+    with_timeout(address, timeout, |signal| async move {
+        fetch_primary_domain(address, &signal).await
+    }).await
+}
+
+/// Resolve an X1NS domain name to the wallet address that owns it
+///
+/// `name` may be given as typed in chat: a leading `@` is dropped, case is
+/// ignored and `.x1` is assumed when no TLD is given (see `normalize_domain_name`).
+///
+/// # Returns
+/// * `Ok(Some(address))` - If the domain is registered
+/// * `Ok(None)` - If the domain is not registered (including an API 404)
+/// * `Err(DomainError)` - If there was an error querying the API, or
+///   `NetworkError("timeout")` after `DEFAULT_DOMAIN_TIMEOUT`
+///
+/// # Example
+/// ```
+/// match resolve_domain("@xen_artist.x1").await {
+///     Ok(Some(address)) => println!("Owner: {}", address),
+///     Ok(None) => println!("Domain not registered"),
+///     Err(e) => println!("Error: {}", e),
+/// }
+/// ```
+pub async fn resolve_domain(name: &str) -> Result<Option<String>, DomainError> {
+    resolve_domain_with_timeout(name, DEFAULT_DOMAIN_TIMEOUT).await
+}
+
+/// `resolve_domain` with a custom timeout. On expiry the request is
+/// aborted and `NetworkError("timeout")` is returned.
+pub async fn resolve_domain_with_timeout(name: &str, timeout: Duration) -> Result<Option<String>, DomainError> {
+    let domain = normalize_domain_name(name)
+        .ok_or_else(|| DomainError::ApiError(format!("Invalid domain name: {}", name)))?;
+    let domain = domain.as_str();
+    with_timeout(domain, timeout, |signal| async move {
+        fetch_domain_owner(domain, &signal).await
+    }).await
+}
+
+/// Turn user input like `@Xen_Artist.x1` or `xen_artist` into the domain
+/// name the API expects (`xen_artist.x1`). None if nothing usable is left.
+pub fn normalize_domain_name(name: &str) -> Option<String> {
+    let name = name.trim().trim_start_matches('@').to_lowercase();
+    let valid = !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !name.starts_with('.')
+        && !name.ends_with('.');
+    if !valid {
+        return None;
+    }
+    Some(if name.contains('.') { name } else { format!("{}.x1", name) })
+}
+
+/// A transfer recipient as typed by the user
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recipient {
+    /// A wallet address
+    Address(String),
+    /// An X1NS name, normalized (e.g. `xen_artist.x1`)
+    Domain(String),
+}
+
+/// Read user input as a wallet address, or else as an X1NS name. None if it
+/// is neither. Addresses are checked first, since a base58 address would
+/// also pass as a bare name.
+pub fn parse_recipient(input: &str) -> Option<Recipient> {
+    let input = input.trim();
+    if solana_sdk::pubkey::Pubkey::from_str(input).is_ok() {
+        return Some(Recipient::Address(input.to_string()));
+    }
+    normalize_domain_name(input).map(Recipient::Domain)
+}
+
+/// Resolve a recipient to its wallet address, with the name it was looked
+/// up from for a domain. Unregistered names are an `ApiError`.
+pub async fn resolve_recipient(input: &str) -> Result<(String, Option<String>), DomainError> {
+    match parse_recipient(input) {
+        Some(Recipient::Address(address)) => Ok((address, None)),
+        Some(Recipient::Domain(domain)) => match resolve_domain(&domain).await? {
+            Some(address) if solana_sdk::pubkey::Pubkey::from_str(&address).is_ok() => Ok((address, Some(domain))),
+            Some(address) => Err(DomainError::ApiError(format!("{} resolved to an invalid address: {}", domain, address))),
+            None => Err(DomainError::ApiError(format!("{} is not registered", domain))),
+        },
+        None => Err(DomainError::ApiError(format!("Invalid address or name: {}", input.trim()))),
+    }
+}
+
+/// Run an X1NS lookup for `what`, aborting it after `timeout`
+async fn with_timeout<T, F, Fut>(what: &str, timeout: Duration, lookup: F) -> Result<T, DomainError>
+where
+    F: FnOnce(AbortSignal) -> Fut,
+    Fut: Future<Output = Result<T, DomainError>>,
+{
+    let controller = AbortController::new()
+        .map_err(|e| DomainError::NetworkError(format!("Failed to create abort controller: {:?}", e)))?;
+    let lookup = pin!(lookup(controller.signal()));
+    let delay = TimeoutFuture::new(u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX));
+
+    match future::select(lookup, delay).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => {
+            controller.abort();
+            log::debug!("X1NS lookup for {} timed out after {:?}", what, timeout);
+            Err(DomainError::NetworkError("timeout".to_string()))
+        }
+    }
+}
+
+async fn fetch_primary_domain(address: &str, signal: &AbortSignal) -> Result<Option<String>, DomainError> {
     log::debug!("Querying X1NS primary domain for address: {}", address);
+
+    // 404 might mean no domain found, treat as no primary domain
+    let url = format!("{}/api/primary/{}", X1NS_API_BASE, address);
+    let Some(response) = fetch_json::<PrimaryDomainResponse>(&url, signal).await? else {
+        return Ok(None);
+    };
+
+    log::debug!("X1NS response for {}: has_primary={}, domain={:?}", 
+        address, response.has_primary, response.domain);
     
+    if response.has_primary {
+        Ok(response.domain)
+    } else {
+        Ok(None)
+    }
+}
+
+async fn fetch_domain_owner(domain: &str, signal: &AbortSignal) -> Result<Option<String>, DomainError> {
+    log::debug!("Resolving X1NS domain: {}", domain);
+
+    // 404 means the domain is not registered
+    let url = format!("{}/api/resolve/{}", X1NS_API_BASE, domain);
+    let Some(response) = fetch_json::<DomainResolveResponse>(&url, signal).await? else {
+        return Ok(None);
+    };
+
+    log::debug!("X1NS resolution for {}: owner={:?}", domain, response.owner);
+    Ok(response.owner.filter(|owner| !owner.is_empty()))
+}
+
+/// GET `url` from the X1NS API and deserialize the JSON body. An HTTP 404
+/// is returned as `Ok(None)`.
+async fn fetch_json<T: DeserializeOwned>(url: &str, signal: &AbortSignal) -> Result<Option<T>, DomainError> {
     // Create request options
     let opts = RequestInit::new();
     opts.set_method("GET");
     opts.set_mode(RequestMode::Cors);
+    opts.set_signal(Some(signal));
     
     // Create request
-    let request = Request::new_with_str_and_init(&url, &opts)
+    let request = Request::new_with_str_and_init(url, &opts)
         .map_err(|e| DomainError::NetworkError(format!("Failed to create request: {:?}", e)))?;
     
     // Execute fetch
@@ -99,9 +338,8 @@ pub async fn get_primary_domain(address: &str) -> Result<Option<String>, DomainE
     
     // Check HTTP status
     if !resp.ok() {
-        // 404 might mean no domain found, treat as no primary domain
         if resp.status() == 404 {
-            log::debug!("X1NS returned 404 for address {}, treating as no primary domain", address);
+            log::debug!("X1NS returned 404 for {}", url);
             return Ok(None);
         }
         return Err(DomainError::ApiError(format!("HTTP {} {}", resp.status(), resp.status_text())));
@@ -115,17 +353,9 @@ pub async fn get_primary_domain(address: &str) -> Result<Option<String>, DomainE
     .map_err(|e| DomainError::ParseError(format!("Failed to parse JSON: {:?}", e)))?;
     
     // Deserialize response
-    let response: PrimaryDomainResponse = serde_wasm_bindgen::from_value(json)
-        .map_err(|e| DomainError::ParseError(format!("Failed to deserialize response: {:?}", e)))?;
-    
-    log::debug!("X1NS response for {}: has_primary={}, domain={:?}", 
-        address, response.has_primary, response.domain);
-    
-    if response.has_primary {
-        Ok(response.domain)
-    } else {
-        Ok(None)
-    }
+    serde_wasm_bindgen::from_value(json)
+        .map(Some)
+        .map_err(|e| DomainError::ParseError(format!("Failed to deserialize response: {:?}", e)))
 }
 
 #[cfg(test)]
@@ -164,5 +394,62 @@ mod tests {
         assert!(!response.has_primary);
         assert_eq!(response.domain, None);
     }
-}
+    
+    #[test]
+    fn test_domain_resolve_response_deserialize() {
+        let json = r#"{
+            "domain": "xen_artist.x1",
+            "owner": "3NvVAGuTQr9DFQhNGjMyLFAAC22L1k2AEL3V1LE25XfP",
+            "domainAddress": "4NwU8rHu9kDVKprNTg9DT7aU858UCGZy8yHAdGE8stYN",
+            "timestamp": "1765370933"
+        }"#;
+        
+        let response: DomainResolveResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.domain, "xen_artist.x1");
+        assert_eq!(response.owner, Some("3NvVAGuTQr9DFQhNGjMyLFAAC22L1k2AEL3V1LE25XfP".to_string()));
+    }
+    
+    #[test]
+    fn test_unregistered_domain_response_deserialize() {
+        let json = r#"{
+            "domain": "nobody_here.x1",
+            "owner": null
+        }"#;
+        
+        let response: DomainResolveResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.domain, "nobody_here.x1");
+        assert_eq!(response.owner, None);
+
+        let response: DomainResolveResponse = serde_json::from_str(r#"{"domain": "nobody_here.x1"}"#).unwrap();
+        assert_eq!(response.owner, None);
+    }
+
+    #[test]
+    fn test_domain_cache_keeps_missing_domains() {
+        let cache: TtlMap<String, Option<String>> = TtlMap::new(PRIMARY_DOMAIN_TTL_MS);
+        cache.insert_at("with".to_string(), Some("xen_artist.x1".to_string()), 0.0);
+        cache.insert_at("without".to_string(), None, 0.0);
+        assert_eq!(cache.get_at(&"with".to_string(), 1_000.0), Some(Some("xen_artist.x1".to_string())));
+        // a cached "no domain" is a hit, unlike an address never looked up
+        assert_eq!(cache.get_at(&"without".to_string(), 1_000.0), Some(None));
+        assert_eq!(cache.get_at(&"unknown".to_string(), 1_000.0), None);
+        assert_eq!(cache.get_at(&"without".to_string(), PRIMARY_DOMAIN_TTL_MS), None);
+    }
 
+    #[test]
+    fn test_normalize_domain_name() {
+        assert_eq!(normalize_domain_name("@Xen_Artist.x1"), Some("xen_artist.x1".to_string()));
+        assert_eq!(normalize_domain_name(" xen_artist "), Some("xen_artist.x1".to_string()));
+        assert_eq!(normalize_domain_name("@"), None);
+        assert_eq!(normalize_domain_name("bad/name.x1"), None);
+        assert_eq!(normalize_domain_name("name."), None);
+    }
+
+    #[test]
+    fn test_parse_recipient() {
+        let address = "DEQWNRhQmNg7T6UQxV8d2oJAanFHBu9YkNyXDb7GvzvA";
+        assert_eq!(parse_recipient(&format!(" {} ", address)), Some(Recipient::Address(address.to_string())));
+        assert_eq!(parse_recipient("@Xen_Artist"), Some(Recipient::Domain("xen_artist.x1".to_string())));
+        assert_eq!(parse_recipient("not an address"), None);
+    }
+}
@@ -0,0 +1,140 @@
+//! Developer log of RPC requests, like a browser's network tab
+//!
+//! While enabled (Settings > Developer), `RpcConnection::send_request`
+//! records every call: method, a shortened params summary, duration and
+//! outcome. Only the newest `MAX_RPC_CALLS` are kept. While disabled nothing
+//! is serialized or stored; the only cost per request is one atomic load.
+
+use once_cell::sync::Lazy;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::RwLock;
+
+/// Calls kept; older ones are dropped first
+pub const MAX_RPC_CALLS: usize = 200;
+
+/// Longest params summary, in characters
+pub const PARAMS_SUMMARY_CHARS: usize = 160;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_CALL_ID: AtomicU64 = AtomicU64::new(1);
+static CALLS: Lazy<RwLock<VecDeque<RpcCall>>> = Lazy::new(|| RwLock::new(VecDeque::new()));
+
+/// One recorded RPC request
+#[derive(Clone, Debug, PartialEq)]
+pub struct RpcCall {
+    /// Increases with every call, for keying the list
+    pub id: u64,
+    pub method: String,
+    /// Serialized params, shortened to `PARAMS_SUMMARY_CHARS`
+    pub params: String,
+    /// Unix time (milliseconds) the request started
+    pub started_at: f64,
+    pub duration_ms: f64,
+    /// None when the call succeeded
+    pub error: Option<String>,
+}
+
+impl RpcCall {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Turn recording on or off; turning it off drops the recorded calls
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        clear();
+    }
+}
+
+/// Params JSON cut to `PARAMS_SUMMARY_CHARS` characters
+pub fn summarize_params(params_json: &str) -> String {
+    match params_json.char_indices().nth(PARAMS_SUMMARY_CHARS) {
+        Some((end, _)) => format!("{}…", &params_json[..end]),
+        None => params_json.to_string(),
+    }
+}
+
+fn push_bounded(calls: &mut VecDeque<RpcCall>, call: RpcCall) {
+    calls.push_back(call);
+    while calls.len() > MAX_RPC_CALLS {
+        calls.pop_front();
+    }
+}
+
+/// Record a finished call (ignored while disabled)
+pub fn record(method: &str, params: String, started_at: f64, duration_ms: f64, error: Option<String>) {
+    if !is_enabled() {
+        return;
+    }
+    let call = RpcCall {
+        id: NEXT_CALL_ID.fetch_add(1, Ordering::Relaxed),
+        method: method.to_string(),
+        params,
+        started_at,
+        duration_ms,
+        error,
+    };
+    if let Ok(mut calls) = CALLS.write() {
+        push_bounded(&mut calls, call);
+    }
+}
+
+/// Recorded calls, newest first
+pub fn calls() -> Vec<RpcCall> {
+    CALLS.read()
+        .map(|calls| calls.iter().rev().cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn clear() {
+    if let Ok(mut calls) = CALLS.write() {
+        calls.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(id: u64) -> RpcCall {
+        RpcCall {
+            id,
+            method: "getBalance".to_string(),
+            params: "[]".to_string(),
+            started_at: 0.0,
+            duration_ms: 1.0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_summarize_params_truncates_on_char_boundary() {
+        assert_eq!(summarize_params(r#"["abc"]"#), r#"["abc"]"#);
+
+        let long = "é".repeat(PARAMS_SUMMARY_CHARS + 10);
+        let summary = summarize_params(&long);
+        assert_eq!(summary.chars().count(), PARAMS_SUMMARY_CHARS + 1);
+        assert!(summary.ends_with('…'));
+
+        let exact = "a".repeat(PARAMS_SUMMARY_CHARS);
+        assert_eq!(summarize_params(&exact), exact);
+    }
+
+    #[test]
+    fn test_push_bounded_drops_oldest() {
+        let mut calls = VecDeque::new();
+        for id in 0..(MAX_RPC_CALLS as u64 + 5) {
+            push_bounded(&mut calls, call(id));
+        }
+        assert_eq!(calls.len(), MAX_RPC_CALLS);
+        assert_eq!(calls.front().map(|c| c.id), Some(5));
+        assert_eq!(calls.back().map(|c| c.id), Some(MAX_RPC_CALLS as u64 + 4));
+    }
+}
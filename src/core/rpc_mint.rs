@@ -4,6 +4,7 @@ use super::rpc_base::{
 };
 use super::network_config::get_program_ids;
 use super::constants::*;
+use super::format::format_number_with_commas;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 use solana_sdk::{
@@ -99,11 +100,13 @@ impl MintConfig {
     pub fn format_mint_reward(amount: f64) -> String {
         // If it's a whole number, don't show decimals
         if amount.fract() == 0.0 {
-            format!("+{} MEMO", amount as u64)
+            format!("+{} MEMO", format_number_with_commas(amount as u64))
         } else if amount >= 1.0 {
             // For values >= 1, show minimal decimals (remove trailing zeros)
             let formatted = format!("{}", amount);
-            format!("+{} MEMO", formatted)
+            let (whole, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+            let whole = whole.parse::<u64>().map(format_number_with_commas).unwrap_or_else(|_| whole.to_string());
+            format!("+{}.{} MEMO", whole, fraction)
         } else {
             // For values < 1, show appropriate precision (remove trailing zeros)
             let formatted = if amount >= 0.1 {
@@ -356,26 +359,6 @@ impl RpcConnection {
         }
     }
 
-    /// Get current mint reward amount for the current supply
-    /// 
-    /// # Returns
-    /// The formatted mint reward string (e.g., "+1.000000 MEMO")
-    pub async fn get_current_mint_reward_formatted(&self) -> Result<String, RpcError> {
-        let supply = self.get_token_supply().await?;
-        let reward_amount = MintConfig::calculate_mint_reward(supply);
-        Ok(MintConfig::format_mint_reward(reward_amount))
-    }
-
-    /// Get current supply tier information
-    /// 
-    /// # Returns
-    /// Tuple of (current_supply, current_tier)
-    pub async fn get_current_supply_tier_info(&self) -> Result<(u64, SupplyTier), RpcError> {
-        let supply = self.get_token_supply().await?;
-        let tier = MintConfig::get_current_supply_tier(supply);
-        Ok((supply, tier))
-    }
-
     /// Get token holders using getProgramAccounts
     /// Returns token accounts sorted by balance (descending)
     /// Note: For Token-2022 with extensions, account size varies
@@ -447,4 +430,84 @@ impl RpcConnection {
         
         Ok(holders)
     }
-}
\ No newline at end of file
+
+    /// Count the distinct owners holding a non-zero MEMO balance.
+    ///
+    /// There is no RPC method for this, so it scans every token account of
+    /// the mint like `get_token_holders`, but asks only for the owner and
+    /// amount bytes of each account to keep the response small. Still a
+    /// heavy call: cache the result.
+    pub async fn get_token_holder_count(&self) -> Result<u64, RpcError> {
+        let token_mint = get_token_mint()?.to_string();
+        let token_program_id = get_token_2022_program_id()?.to_string();
+
+        let params = serde_json::json!([
+            token_program_id,
+            {
+                "encoding": "base64",
+                "dataSlice": {
+                    "offset": TOKEN_ACCOUNT_OWNER_OFFSET,
+                    "length": 40  // owner pubkey + u64 amount
+                },
+                "filters": [
+                    {
+                        "memcmp": {
+                            "offset": 0,  // mint pubkey is at offset 0
+                            "bytes": token_mint
+                        }
+                    }
+                ]
+            }
+        ]);
+
+        let result: serde_json::Value = self.send_request("getProgramAccounts", params).await?;
+        let accounts = result.as_array()
+            .ok_or_else(|| RpcError::Other("Unexpected getProgramAccounts response".to_string()))?;
+
+        let slices: Vec<Vec<u8>> = accounts.iter()
+            .filter_map(|account| account["account"]["data"][0].as_str())
+            .filter_map(|data| base64::decode(data).ok())
+            .collect();
+        let count = count_holders(&slices);
+
+        log::info!("Found {} MEMO holders among {} token accounts", count, accounts.len());
+        Ok(count)
+    }
+}
+
+/// Offset of the owner pubkey in a token account, followed by the u64 amount
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// Distinct owners with a non-zero amount, from `owner (32) + amount (8)`
+/// slices of token accounts; malformed slices are skipped
+fn count_holders(slices: &[Vec<u8>]) -> u64 {
+    let owners: std::collections::HashSet<&[u8]> = slices.iter()
+        .filter(|slice| slice.len() >= 40)
+        .filter(|slice| slice[32..40].iter().any(|byte| *byte != 0))
+        .map(|slice| &slice[..32])
+        .collect();
+    owners.len() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_holders() {
+        let slice = |owner: u8, amount: u64| {
+            let mut data = vec![owner; 32];
+            data.extend_from_slice(&amount.to_le_bytes());
+            data
+        };
+        let slices = vec![
+            slice(1, 5),
+            slice(1, 7), // second account of the same owner
+            slice(2, 0), // emptied account
+            slice(3, 1),
+            vec![4; 20], // malformed
+        ];
+        assert_eq!(count_holders(&slices), 2);
+        assert_eq!(count_holders(&[]), 0);
+    }
+}
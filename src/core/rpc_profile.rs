@@ -1,6 +1,6 @@
 use super::rpc_base::{
     RpcConnection, RpcError,
-    get_token_2022_program_id
+    get_token_2022_program_id, tokens_to_lamports
 };
 use super::network_config::get_program_ids;
 use super::constants::*;
@@ -278,7 +278,7 @@ impl RpcConnection {
         
         profile_creation_data.validate(*user_pubkey)?;
         
-        let burn_amount_units = burn_amount * 1_000_000;
+        let burn_amount_units = tokens_to_lamports(burn_amount)?;
         let burn_memo = BurnMemo {
             version: 1,
             burn_amount: burn_amount_units,
@@ -415,7 +415,7 @@ impl RpcConnection {
             about_me.clone(),
         );
         
-        let burn_amount_units = burn_amount * 1_000_000;
+        let burn_amount_units = tokens_to_lamports(burn_amount)?;
         let burn_memo = BurnMemo {
             version: 1,
             burn_amount: burn_amount_units,
@@ -692,7 +692,7 @@ fn parse_borsh_project_burn_message(memo_data: &[u8]) -> Option<(String, String,
 
 /// Parse memo data for all project operations (create, update, burn)
 /// Returns (burner, operation_type, details, burn_amount)
-fn parse_project_operation_memo(memo_data: &[u8]) -> Option<(String, ProjectOperationType, ProjectOperationDetails, u64)> {
+pub(crate) fn parse_project_operation_memo(memo_data: &[u8]) -> Option<(String, ProjectOperationType, ProjectOperationDetails, u64)> {
     // Convert bytes to UTF-8 string (should be Base64)
     let memo_str = std::str::from_utf8(memo_data).ok()?;
     
@@ -1,22 +1,30 @@
 use serde::{Serialize, Deserialize};
 use crate::core::encrypt;
-use crate::core::rpc_base::RpcConnection;
+use crate::core::rpc_base::{RpcConnection, RpcError, SimulationReport, tokens_to_lamports};
 use crate::core::rpc_profile::UserProfile;
 use crate::core::rpc_project::{ProjectInfo, ProjectStatistics, ProjectBurnLeaderboardResponse};
 use crate::core::rpc_blog::BlogInfo;
 use crate::core::rpc_burn::{UserGlobalBurnStats};
+use crate::core::rpc_chat::encode_burn_reply;
+use crate::core::constants::MIN_FEE_BALANCE_XNT;
+use crate::core::cache;
+use crate::core::memo_client::{parse_token_balance, parse_xnt_balance};
 use crate::core::network_config::{NetworkType, clear_network};
 use crate::core::backpack::{BackpackWallet, BackpackError};
 use crate::core::x1::{X1Wallet, X1Error};
+use crate::core::wallet::WalletAccounts;
 use web_sys::js_sys::Date;
 use secrecy::{Secret, ExposeSecret};
 use zeroize::{Zeroize, Zeroizing};
 use hex;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::Transaction;
-use serde_json;
 use std::fmt;
 use std::str::FromStr;
+use std::future::Future;
+use std::pin::pin;
+use futures::channel::oneshot;
+use futures::future::{self, Either};
 use log;
 use base64;
 
@@ -41,6 +49,10 @@ pub enum SessionError {
     ProfileError(String),
     BackpackError(String),
     X1Error(String),
+    /// The user stopped waiting; a transaction already submitted may still land
+    Cancelled,
+    /// Building or sending the transaction failed at the RPC node or in the contract
+    Rpc(RpcError),
 }
 
 impl fmt::Display for SessionError {
@@ -54,6 +66,8 @@ impl fmt::Display for SessionError {
             SessionError::ProfileError(msg) => write!(f, "Profile error: {}", msg),
             SessionError::BackpackError(msg) => write!(f, "Backpack wallet error: {}", msg),
             SessionError::X1Error(msg) => write!(f, "X1 wallet error: {}", msg),
+            SessionError::Cancelled => write!(f, "Cancelled by user"),
+            SessionError::Rpc(e) => write!(f, "{}", e),
         }
     }
 }
@@ -70,6 +84,23 @@ impl From<X1Error> for SessionError {
     }
 }
 
+/// Await `operation` unless `cancel` fires first, in which case it is dropped
+/// and `SessionError::Cancelled` is returned. This only abandons the wait:
+/// if the signed transaction was already handed to the RPC node it may still
+/// land, so callers must not treat a cancelled send as failed.
+/// A dropped sender is not a cancellation.
+pub async fn cancellable<T>(
+    operation: impl Future<Output = Result<T, SessionError>>,
+    cancel: oneshot::Receiver<()>,
+) -> Result<T, SessionError> {
+    let operation = pin!(operation);
+    match future::select(operation, cancel).await {
+        Either::Left((result, _)) => result,
+        Either::Right((Ok(()), _)) => Err(SessionError::Cancelled),
+        Either::Right((Err(oneshot::Canceled), operation)) => operation.await,
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SessionConfig {
     // session timeout in minutes, None means never expire
@@ -112,12 +143,16 @@ pub struct Session {
     // balance information
     sol_balance: f64,
     token_balance: f64,
+    // whether the token balance has been fetched since login
+    balances_loaded: bool,
     // balance update trigger
     balance_update_needed: bool,
     // user global burn stats
     user_burn_stats: Option<UserGlobalBurnStats>,
     // network type for this session (set during login, immutable after that)
     network: Option<NetworkType>,
+    // BIP44 account index in use (only for Internal wallet)
+    account_index: u32,
 }
 
 impl Session {
@@ -135,9 +170,11 @@ impl Session {
             cached_pubkey: None,
             sol_balance: 0.0,
             token_balance: 0.0,
+            balances_loaded: false,
             balance_update_needed: false,
             user_burn_stats: None,
             network: None,
+            account_index: 0,
         }
     }
     
@@ -188,9 +225,11 @@ impl Session {
         self.cached_pubkey = None;
         self.sol_balance = 0.0;
         self.token_balance = 0.0;
+        self.balances_loaded = false;
         self.balance_update_needed = false;
         self.user_burn_stats = None;
         self.network = None;
+        self.account_index = 0;
         
         // If Backpack wallet, disconnect
         if is_backpack {
@@ -238,10 +277,9 @@ impl Session {
         let seed: [u8; 64] = seed_bytes.try_into()
             .map_err(|_| SessionError::Encryption("Invalid seed length".to_string()))?;
 
-        let (_, pubkey) = crate::core::wallet::derive_keypair_from_seed(
-            &seed,
-            crate::core::wallet::get_default_derivation_path()
-        ).map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))?;
+        let account_index = WalletAccounts::load().active;
+        let (_, pubkey) = crate::core::wallet::derive_account(&seed, account_index)
+            .map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))?;
 
         // save session info (Internal wallet)
         self.wallet_type = WalletType::Internal;
@@ -250,6 +288,7 @@ impl Session {
         self.backpack_pubkey = None;
         self.start_time = Date::now();
         self.cached_pubkey = Some(pubkey.clone());
+        self.account_index = account_index;
 
         log::info!("Session initialized with internal wallet: {}", pubkey);
         Ok(())
@@ -373,6 +412,7 @@ impl Session {
         self.user_profile = None;
         self.sol_balance = 0.0;
         self.token_balance = 0.0;
+        self.balances_loaded = false;
         self.balance_update_needed = false;
         self.ui_locked = false;
         self.user_burn_stats = None;
@@ -406,6 +446,65 @@ impl Session {
         }
     }
 
+    // get BIP44 account index in use
+    pub fn get_account_index(&self) -> u32 {
+        self.account_index
+    }
+
+    /// Addresses of the given account indices, derived from the session seed
+    pub fn derive_account_addresses(&self, indices: &[u32]) -> Result<Vec<(u32, String)>, SessionError> {
+        if !self.is_internal_wallet() {
+            return Err(SessionError::InvalidData("Accounts are only available for the internal wallet".to_string()));
+        }
+        let seed = Zeroizing::new(self.get_seed()?);
+        let seed_bytes = Zeroizing::new(
+            hex::decode(seed.as_str())
+                .map_err(|e| SessionError::Encryption(format!("Failed to decode seed: {}", e)))?
+        );
+        let mut seed_array = [0u8; 64];
+        seed_array.copy_from_slice(&seed_bytes);
+
+        let addresses = indices
+            .iter()
+            .map(|&index| {
+                crate::core::wallet::derive_account(&seed_array, index)
+                    .map(|(_, address)| (index, address))
+                    .map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))
+            })
+            .collect();
+        seed_array.zeroize();
+        addresses
+    }
+
+    /// Make account `index` the active one: sign with it from now on and drop
+    /// the previous account's profile, burn stats and balances so they reload
+    pub fn switch_account(&mut self, index: u32) -> Result<String, SessionError> {
+        let (_, address) = self.derive_account_addresses(&[index])?
+            .pop()
+            .ok_or(SessionError::NotInitialized)?;
+
+        let mut accounts = WalletAccounts::load();
+        if !accounts.indices.contains(&index) {
+            accounts.indices.push(index);
+        }
+        accounts.active = index;
+        if let Err(e) = accounts.save() {
+            log::warn!("Failed to save active account: {}", e);
+        }
+
+        self.account_index = index;
+        self.cached_pubkey = Some(address.clone());
+        self.user_profile = None;
+        self.user_burn_stats = None;
+        self.sol_balance = 0.0;
+        self.token_balance = 0.0;
+        self.balances_loaded = false;
+        self.balance_update_needed = true;
+
+        log::info!("Switched to account {}: {}", index, address);
+        Ok(address)
+    }
+
     // get user profile
     pub fn get_user_profile(&self) -> Option<UserProfile> {
         self.user_profile.clone()
@@ -432,16 +531,16 @@ impl Session {
         let seed: [u8; 64] = seed_bytes.try_into()
             .map_err(|_| SessionError::Encryption("Invalid seed length".to_string()))?;
 
-        let (_, pubkey) = crate::core::wallet::derive_keypair_from_seed(
-            &seed,
-            crate::core::wallet::get_default_derivation_path()
-        ).map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))?;
+        let account_index = WalletAccounts::load().active;
+        let (_, pubkey) = crate::core::wallet::derive_account(&seed, account_index)
+            .map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))?;
 
         // save session info
         self.session_key = Some(session_key);
         self.encrypted_seed = Some(session_encrypted_seed.to_string());
         self.start_time = Date::now();
         self.cached_pubkey = Some(pubkey);
+        self.account_index = account_index;
 
         Ok(())
     }
@@ -609,10 +708,8 @@ impl Session {
         let seed_array: [u8; 64] = seed_bytes.try_into()
             .map_err(|_| SessionError::Encryption("Invalid seed length".to_string()))?;
 
-        let (keypair, _) = crate::core::wallet::derive_keypair_from_seed(
-            &seed_array,
-            crate::core::wallet::get_default_derivation_path()
-        ).map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))?;
+        let (keypair, _) = crate::core::wallet::derive_account(&seed_array, self.account_index)
+            .map_err(|_| SessionError::Encryption("Failed to derive keypair".to_string()))?;
 
         Ok(keypair.to_bytes().to_vec())
     }
@@ -662,10 +759,8 @@ impl Session {
         seed_array.copy_from_slice(&seed_bytes);
         
         // Derive keypair from seed
-        let (keypair, _) = crate::core::wallet::derive_keypair_from_seed(
-            &seed_array,
-            crate::core::wallet::get_default_derivation_path()
-        ).map_err(|e| SessionError::Encryption(format!("Failed to derive keypair: {:?}", e)))?;
+        let (keypair, _) = crate::core::wallet::derive_account(&seed_array, self.account_index)
+            .map_err(|e| SessionError::Encryption(format!("Failed to derive keypair: {:?}", e)))?;
         
         // Sign the transaction
         transaction.sign(&[&keypair], transaction.message.recent_blockhash);
@@ -791,9 +886,20 @@ impl Session {
         self.token_balance
     }
 
+    /// Whether the token balance has been fetched yet; until then it reads as 0
+    pub fn balances_loaded(&self) -> bool {
+        self.balances_loaded
+    }
+
+    /// Whether the XNT balance may not cover a transaction fee
+    pub fn has_low_fee_balance(&self) -> bool {
+        self.sol_balance < MIN_FEE_BALANCE_XNT
+    }
+
     pub fn set_balances(&mut self, sol_balance: f64, token_balance: f64) {
         self.sol_balance = sol_balance;
         self.token_balance = token_balance;
+        self.balances_loaded = true;
         self.balance_update_needed = false;
     }
 
@@ -819,22 +925,9 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Failed to get token mint: {}", e)))?;
         match rpc.get_token_balance(&pubkey, &token_mint.to_string()).await {
             Ok(token_result) => {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&token_result) {
-                    if let Some(accounts) = json.get("value").and_then(|v| v.as_array()) {
-                        if let Some(first_account) = accounts.first() {
-                            if let Some(amount) = first_account
-                                .get("account")
-                                .and_then(|a| a.get("data"))
-                                .and_then(|d| d.get("parsed"))
-                                .and_then(|p| p.get("info"))
-                                .and_then(|i| i.get("tokenAmount"))
-                                .and_then(|t| t.get("uiAmount"))
-                                .and_then(|a| a.as_f64())
-                            {
-                                self.token_balance = amount;
-                            }
-                        }
-                    }
+                if let Some(amount) = parse_token_balance(&token_result) {
+                    self.token_balance = amount;
+                    self.balances_loaded = true;
                 }
             }
             Err(e) => {
@@ -845,11 +938,8 @@ impl Session {
         // get SOL balance
         match rpc.get_balance(&pubkey).await {
             Ok(balance_result) => {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&balance_result) {
-                    if let Some(lamports) = json.get("value").and_then(|v| v.as_u64()) {
-                        let sol = lamports as f64 / 1_000_000_000.0;
-                        self.sol_balance = sol;
-                    }
+                if let Some(sol) = parse_xnt_balance(&balance_result) {
+                    self.sol_balance = sol;
                 }
             }
             Err(e) => {
@@ -880,36 +970,45 @@ impl Session {
         
         log::info!("Building send chat message transaction...");
         let mut transaction = rpc.build_send_chat_message_transaction(&pubkey, group_id, message, receiver, reply_to_sig).await
-            .map_err(|e| SessionError::InvalidData(format!("Failed to build transaction: {}", e)))?;
+            .map_err(SessionError::Rpc)?;
         
         log::info!("Signing transaction in Session...");
         self.sign_transaction(&mut transaction).await?;
         
         log::info!("Sending signed transaction...");
         let tx_hash = rpc.send_signed_transaction(&transaction).await
-            .map_err(|e| SessionError::InvalidData(format!("Failed to send transaction: {}", e)))?;
+            .map_err(SessionError::Rpc)?;
         
         log::info!("Chat message sent successfully: {}", tx_hash);
         self.balance_update_needed = true;
+        cache::invalidate_chat_group_info(group_id);
         
         Ok(tx_hash)
     }
 
     /// Send a chat message to a group with timeout
     /// Note: Timeout handling is currently simplified in the new architecture
+    ///
+    /// Firing `cancel` stops waiting and returns `SessionError::Cancelled`;
+    /// see `cancellable` for what that means for the transaction.
     pub async fn send_chat_message_with_timeout(
         &mut self, 
         group_id: u64, 
         message: &str,
         receiver: Option<String>,
         reply_to_sig: Option<String>,
-        timeout_ms: Option<u32>
+        timeout_ms: Option<u32>,
+        cancel: Option<oneshot::Receiver<()>>,
     ) -> Result<String, SessionError> {
         if timeout_ms.is_some() {
             log::warn!("Timeout parameter is currently not supported in the new architecture");
         }
         // Use the standard send_chat_message method
-        self.send_chat_message(group_id, message, receiver, reply_to_sig).await
+        let send = self.send_chat_message(group_id, message, receiver, reply_to_sig);
+        match cancel {
+            Some(cancel) => cancellable(send, cancel).await,
+            None => send.await,
+        }
     }
 
     /// Create a new chat group - internal handle all key operations
@@ -952,12 +1051,37 @@ impl Session {
         Ok((tx_hash, group_id))
     }
 
+    /// Dry-run `create_chat_group`: simulate the transaction without signing
+    /// or sending it, so no fee is spent. Takes the same parameters.
+    pub async fn simulate_create_chat_group(
+        &self,
+        name: &str,
+        description: &str,
+        image: &str,
+        tags: Vec<String>,
+        min_memo_interval: Option<i64>,
+        burn_amount: u64,
+    ) -> Result<SimulationReport, SessionError> {
+        let rpc = RpcConnection::new();
+        let pubkey_str = self.get_public_key()?;
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
+
+        let (transaction, _) = rpc.build_create_chat_group_transaction(
+            &pubkey, name, description, image, tags, min_memo_interval, burn_amount
+        ).await
+            .map_err(|e| SessionError::InvalidData(format!("Failed to build transaction: {}", e)))?;
+
+        rpc.simulate_unsigned_transaction(&transaction).await.map_err(SessionError::Rpc)
+    }
+
     /// Burn tokens for a chat group
     /// 
     /// # Parameters
     /// * `group_id` - The ID of the chat group to burn tokens for
     /// * `amount` - Amount of MEMO tokens to burn (in token units, not lamports)
-    /// * `message` - Optional burn message (max 512 characters)
+    /// * `message` - Optional burn message (max 512 characters, including any reply reference)
+    /// * `reply_to_sig` - Signature of the message this burn replies to, if any
     /// 
     /// # Returns
     /// Result containing transaction signature
@@ -966,6 +1090,7 @@ impl Session {
         group_id: u64,
         amount: u64,
         message: &str,
+        reply_to_sig: Option<&str>,
     ) -> Result<String, SessionError> {
         if self.is_expired() {
             return Err(SessionError::Expired);
@@ -977,20 +1102,25 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
         
         // Convert amount from tokens to lamports
-        let amount_lamports = amount * 1_000_000;
+        let amount_lamports = tokens_to_lamports(amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
+        
+        // A burn-reply carries the replied-to signature inside the burn message
+        let message = encode_burn_reply(reply_to_sig, message);
         
         log::info!("Building burn tokens for group transaction...");
-        let mut transaction = rpc.build_burn_tokens_for_group_transaction(&pubkey, group_id, amount_lamports, message).await
-            .map_err(|e| SessionError::InvalidData(format!("Failed to build transaction: {}", e)))?;
+        let mut transaction = rpc.build_burn_tokens_for_group_transaction(&pubkey, group_id, amount_lamports, &message).await
+            .map_err(SessionError::Rpc)?;
         
         log::info!("Signing transaction in Session...");
         self.sign_transaction(&mut transaction).await?;
         
         log::info!("Sending signed transaction...");
         let signature = rpc.send_signed_transaction(&transaction).await
-            .map_err(|e| SessionError::InvalidData(format!("Failed to send transaction: {}", e)))?;
+            .map_err(SessionError::Rpc)?;
         
         log::info!("Tokens burned successfully for group {}", group_id);
+        cache::invalidate_chat_group_info(group_id);
         
         // Update balances after successful burn
         match self.fetch_and_update_balances().await {
@@ -1039,7 +1169,8 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
         
         // Convert amount from tokens to lamports
-        let burn_amount_lamports = burn_amount * 1_000_000;
+        let burn_amount_lamports = tokens_to_lamports(burn_amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
         
         log::info!("Building create project transaction...");
         let (mut transaction, project_id) = rpc.build_create_project_transaction(
@@ -1095,7 +1226,8 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
         
         // Convert amount from tokens to lamports
-        let burn_amount_lamports = burn_amount * 1_000_000;
+        let burn_amount_lamports = tokens_to_lamports(burn_amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
         
         log::info!("Building update project transaction...");
         let mut transaction = rpc.build_update_project_transaction(
@@ -1141,7 +1273,8 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
         
         // Convert amount from tokens to lamports
-        let amount_lamports = amount * 1_000_000;
+        let amount_lamports = tokens_to_lamports(amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
         
         log::info!("Building burn tokens for project transaction...");
         let mut transaction = rpc.build_burn_tokens_for_project_transaction(&pubkey, project_id, amount_lamports, message).await
@@ -1170,6 +1303,27 @@ impl Session {
         Ok(signature)
     }
 
+    /// Dry-run `burn_tokens_for_project`: simulate the transaction without
+    /// signing or sending it, so no fee is spent. Takes the same parameters.
+    pub async fn simulate_burn_tokens_for_project(
+        &self,
+        project_id: u64,
+        amount: u64,
+        message: &str,
+    ) -> Result<SimulationReport, SessionError> {
+        let rpc = RpcConnection::new();
+        let pubkey_str = self.get_public_key()?;
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
+        let amount_lamports = tokens_to_lamports(amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
+
+        let transaction = rpc.build_burn_tokens_for_project_transaction(&pubkey, project_id, amount_lamports, message).await
+            .map_err(|e| SessionError::InvalidData(format!("Failed to build transaction: {}", e)))?;
+
+        rpc.simulate_unsigned_transaction(&transaction).await.map_err(SessionError::Rpc)
+    }
+
     /// Get information for a specific project (doesn't require authentication)
     /// 
     /// # Parameters
@@ -1179,7 +1333,7 @@ impl Session {
     /// Project information if it exists
     pub async fn get_project_info(&self, project_id: u64) -> Result<ProjectInfo, SessionError> {
         let rpc = crate::core::rpc_base::RpcConnection::new();
-        rpc.get_project_info(project_id).await
+        cache::get_project_info(&rpc, project_id).await
             .map_err(|e| SessionError::InvalidData(format!("Get project info failed: {}", e)))
     }
 
@@ -1316,7 +1470,8 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
 
         // Convert tokens to lamports
-        let burn_amount_lamports = burn_amount * 1_000_000;
+        let burn_amount_lamports = tokens_to_lamports(burn_amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
 
         let rpc = RpcConnection::new();
         let mut transaction = rpc.build_create_blog_transaction(
@@ -1375,7 +1530,8 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
 
         // Convert tokens to lamports
-        let burn_amount_lamports = burn_amount * 1_000_000;
+        let burn_amount_lamports = tokens_to_lamports(burn_amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
 
         let rpc = RpcConnection::new();
         let mut transaction = rpc.build_update_blog_transaction(
@@ -1430,7 +1586,8 @@ impl Session {
             .map_err(|e| SessionError::InvalidData(format!("Invalid pubkey: {}", e)))?;
 
         // Convert tokens to lamports
-        let amount_lamports = amount * 1_000_000;
+        let amount_lamports = tokens_to_lamports(amount)
+            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
 
         let rpc = RpcConnection::new();
         let mut transaction = rpc.build_burn_tokens_for_blog_transaction(
@@ -1826,3 +1983,25 @@ impl Drop for Session {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_cancellable_returns_cancelled_when_fired() {
+        let (tx, rx) = oneshot::channel();
+        tx.send(()).unwrap();
+        let result = block_on(cancellable(future::pending::<Result<u8, SessionError>>(), rx));
+        assert!(matches!(result, Err(SessionError::Cancelled)));
+    }
+
+    #[test]
+    fn test_cancellable_ignores_dropped_sender() {
+        let (tx, rx) = oneshot::channel::<()>();
+        drop(tx);
+        let result = block_on(cancellable(async { Ok::<u8, SessionError>(7) }, rx));
+        assert!(matches!(result, Ok(7)));
+    }
+}
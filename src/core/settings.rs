@@ -20,6 +20,9 @@ pub struct UserSettings {
     pub compute_unit_buffer_percentage: u32,
     /// Compute unit price in micro-lamports (0 = no priority fee)
     pub compute_unit_price_micro_lamports: u64,
+    /// Block explorer to link to instead of the network's default (empty = default)
+    #[serde(default)]
+    pub custom_explorer_url: String,
 }
 
 impl Default for UserSettings {
@@ -29,6 +32,7 @@ impl Default for UserSettings {
             custom_rpc_url: String::new(),
             compute_unit_buffer_percentage: 1,
             compute_unit_price_micro_lamports: 0,
+            custom_explorer_url: String::new(),
         }
     }
 }
@@ -63,20 +67,37 @@ impl UserSettings {
             .map_err(|_| "Failed to write settings to local storage".to_string())
     }
 
+    /// Forget the stored settings, so the network's defaults apply again
+    pub fn clear(network_type: NetworkType) -> Result<(), String> {
+        let storage = Self::local_storage().ok_or_else(|| "Local storage not available".to_string())?;
+        storage
+            .remove_item(&Self::storage_key(network_type))
+            .map_err(|_| "Failed to remove settings from local storage".to_string())
+    }
+
+    /// Custom RPC URL when selected and valid; stored values are checked
+    /// again here since they may come from an imported settings file
     pub fn custom_rpc_endpoint(&self) -> Option<String> {
         match self.rpc_selection {
-            RpcSelection::Custom => {
-                let trimmed = self.custom_rpc_url.trim();
-                if trimmed.is_empty() {
-                    None
-                } else {
-                    Some(trimmed.to_string())
-                }
-            }
+            RpcSelection::Custom => validate_endpoint_url(&self.custom_rpc_url)
+                .map_err(|e| log::warn!("Ignoring custom RPC URL: {e}"))
+                .ok(),
             RpcSelection::Default => None,
         }
     }
 
+    /// Custom explorer base URL without a trailing slash, None to use the
+    /// default (also for anything but an http(s) URL, which ends up in links)
+    pub fn custom_explorer(&self) -> Option<String> {
+        if self.custom_explorer_url.trim().is_empty() {
+            return None;
+        }
+        validate_endpoint_url(&self.custom_explorer_url)
+            .map(|url| url.trim_end_matches('/').to_string())
+            .map_err(|e| log::warn!("Ignoring custom explorer URL: {e}"))
+            .ok()
+    }
+
     /// Get the compute unit buffer multiplier (1.0 + buffer_percentage / 100)
     /// Returns 1.0 if buffer is 0 (no buffer)
     pub fn get_cu_buffer_multiplier(&self) -> f64 {
@@ -112,3 +133,68 @@ pub fn load_current_network_settings() -> Option<UserSettings> {
         .and_then(|config| UserSettings::load(config.network_type))
 }
 
+pub fn clear_settings_for_network(network_type: NetworkType) -> Result<(), String> {
+    UserSettings::clear(network_type)
+}
+
+/// Check that `url` is an absolute http(s) URL with a host, returning it trimmed
+pub fn validate_endpoint_url(url: &str) -> Result<String, String> {
+    let trimmed = url.trim();
+    if trimmed.is_empty() {
+        return Err("URL is empty".to_string());
+    }
+    let rest = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .ok_or_else(|| "URL must start with http:// or https://".to_string())?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_name = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    if host_name.is_empty() || host_name.starts_with(':') {
+        return Err("URL has no host".to_string());
+    }
+    if trimmed.chars().any(char::is_whitespace) {
+        return Err("URL must not contain spaces".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_endpoint_url() {
+        assert_eq!(validate_endpoint_url(" https://rpc.x1.xyz/ ").as_deref(), Ok("https://rpc.x1.xyz/"));
+        assert!(validate_endpoint_url("http://localhost:8899").is_ok());
+        assert!(validate_endpoint_url("").is_err());
+        assert!(validate_endpoint_url("rpc.x1.xyz").is_err());
+        assert!(validate_endpoint_url("wss://rpc.x1.xyz").is_err());
+        assert!(validate_endpoint_url("https://").is_err());
+        assert!(validate_endpoint_url("https://:8899").is_err());
+        assert!(validate_endpoint_url("https://rpc x1.xyz").is_err());
+    }
+
+    #[test]
+    fn test_old_settings_without_explorer_still_load() {
+        let stored = r#"{"rpc_selection":"Custom","custom_rpc_url":"https://rpc.example","compute_unit_buffer_percentage":5,"compute_unit_price_micro_lamports":0}"#;
+        let settings: UserSettings = serde_json::from_str(stored).unwrap();
+        assert_eq!(settings.custom_rpc_endpoint().as_deref(), Some("https://rpc.example"));
+        assert_eq!(settings.custom_explorer(), None);
+
+        let with_explorer = UserSettings { custom_explorer_url: "https://explorer.example/ ".to_string(), ..settings };
+        assert_eq!(with_explorer.custom_explorer().as_deref(), Some("https://explorer.example"));
+    }
+
+    #[test]
+    fn test_stored_urls_are_validated_on_read() {
+        let settings = UserSettings {
+            rpc_selection: RpcSelection::Custom,
+            custom_rpc_url: "javascript:alert(1)".to_string(),
+            custom_explorer_url: "javascript:alert(1)//".to_string(),
+            ..UserSettings::default()
+        };
+        assert_eq!(settings.custom_rpc_endpoint(), None);
+        assert_eq!(settings.custom_explorer(), None);
+    }
+}
+
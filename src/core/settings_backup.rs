@@ -0,0 +1,255 @@
+//! Export and import of the browser-local settings
+//!
+//! Everything `storage_base` keeps under the app prefix (preferences,
+//! favorites, hidden messages, visited history, per-network RPC settings,
+//! ...) is written to one versioned JSON document. Per-network values keep
+//! their network suffix in the key, so importing restores each network's
+//! values separately. The wallet keystore is stored outside the app prefix
+//! and is never part of an export.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::settings::{validate_endpoint_url, RpcSelection, UserSettings};
+use super::storage_base;
+
+/// Version of the export document format
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Keys (or key prefixes) that are transient state or must never leave the browser
+const EXCLUDED_KEYS: &[&str] = &["pending_chat_sends", "wallet"];
+
+/// Key prefix of the per-network `UserSettings` (RPC, explorer, fees)
+const NETWORK_SETTINGS_PREFIX: &str = "settings.";
+
+/// Exported settings: storage keys without the app prefix and their JSON values
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SettingsExport {
+    pub version: u32,
+    /// Unix timestamp (seconds) of the export
+    pub exported_at: i64,
+    pub entries: BTreeMap<String, Value>,
+}
+
+/// Result of an import, for showing to the user
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportSummary {
+    pub imported: Vec<String>,
+    /// Excluded or invalid keys that were not written
+    pub skipped: Vec<String>,
+    /// Imported RPC / explorer endpoints that differ from the current ones
+    pub endpoint_changes: Vec<String>,
+}
+
+fn is_exportable(key: &str) -> bool {
+    !key.is_empty()
+        && !EXCLUDED_KEYS
+            .iter()
+            .any(|excluded| key == *excluded || key.starts_with(&format!("{}.", excluded)))
+}
+
+/// Collect all exportable settings from local storage
+pub fn export_settings() -> SettingsExport {
+    let entries = storage_base::keys()
+        .into_iter()
+        .filter(|key| is_exportable(key))
+        .filter_map(|key| storage_base::load_json::<Value>(&key).map(|value| (key, value)))
+        .collect();
+    SettingsExport {
+        version: SETTINGS_EXPORT_VERSION,
+        exported_at: (js_sys::Date::now() / 1000.0) as i64,
+        entries,
+    }
+}
+
+/// Export file contents
+pub fn export_settings_json() -> Result<String, String> {
+    serde_json::to_string_pretty(&export_settings())
+        .map_err(|e| format!("Failed to serialize settings: {}", e))
+}
+
+/// Parse and validate an export document
+pub fn parse_settings_export(json: &str) -> Result<SettingsExport, String> {
+    let export: SettingsExport = serde_json::from_str(json)
+        .map_err(|e| format!("Not a settings export file: {}", e))?;
+    if export.version != SETTINGS_EXPORT_VERSION {
+        return Err(format!(
+            "Unsupported settings export version: {} (expected: {})",
+            export.version, SETTINGS_EXPORT_VERSION
+        ));
+    }
+    Ok(export)
+}
+
+/// Merge an imported value into the existing one: objects are merged key by
+/// key, lists keep existing items and add new ones, anything else is replaced
+fn merge_value(existing: Option<Value>, imported: Value) -> Value {
+    match (existing, imported) {
+        (Some(Value::Object(mut existing)), Value::Object(imported)) => {
+            for (key, value) in imported {
+                let merged = merge_value(existing.remove(&key), value);
+                existing.insert(key, merged);
+            }
+            Value::Object(existing)
+        }
+        (Some(Value::Array(mut existing)), Value::Array(imported)) => {
+            for item in imported {
+                if !existing.contains(&item) {
+                    existing.push(item);
+                }
+            }
+            Value::Array(existing)
+        }
+        (_, imported) => imported,
+    }
+}
+
+/// Parse imported per-network settings and check the URLs they point to
+fn check_network_settings(value: &Value) -> Result<UserSettings, String> {
+    let settings: UserSettings = serde_json::from_value(value.clone())
+        .map_err(|e| format!("Invalid network settings: {}", e))?;
+    if settings.rpc_selection == RpcSelection::Custom || !settings.custom_rpc_url.trim().is_empty() {
+        validate_endpoint_url(&settings.custom_rpc_url)
+            .map_err(|e| format!("Invalid custom RPC URL: {}", e))?;
+    }
+    if !settings.custom_explorer_url.trim().is_empty() {
+        validate_endpoint_url(&settings.custom_explorer_url)
+            .map_err(|e| format!("Invalid custom explorer URL: {}", e))?;
+    }
+    Ok(settings)
+}
+
+/// Describe the endpoints `imported` switches `network` to, compared to `current`
+fn describe_endpoint_changes(network: &str, current: Option<&UserSettings>, imported: &UserSettings) -> Vec<String> {
+    let current_rpc = current.and_then(UserSettings::custom_rpc_endpoint);
+    let current_explorer = current.and_then(UserSettings::custom_explorer);
+    let imported_rpc = imported.custom_rpc_endpoint();
+    let imported_explorer = imported.custom_explorer();
+
+    let mut changes = Vec::new();
+    if imported_rpc != current_rpc {
+        changes.push(format!("{} RPC: {}", network, imported_rpc.as_deref().unwrap_or("default")));
+    }
+    if imported_explorer != current_explorer {
+        changes.push(format!("{} explorer: {}", network, imported_explorer.as_deref().unwrap_or("default")));
+    }
+    changes
+}
+
+/// Merge an export document's entries into local storage
+pub fn import_settings(export: SettingsExport) -> Result<ImportSummary, String> {
+    let mut summary = ImportSummary::default();
+    for (key, value) in export.entries {
+        if !is_exportable(&key) {
+            summary.skipped.push(key);
+            continue;
+        }
+        let existing: Option<Value> = storage_base::load_json(&key);
+        let merged = merge_value(existing.clone(), value);
+        if let Some(network) = key.strip_prefix(NETWORK_SETTINGS_PREFIX) {
+            let imported = match check_network_settings(&merged) {
+                Ok(imported) => imported,
+                Err(e) => {
+                    log::warn!("Not importing {}: {}", key, e);
+                    summary.skipped.push(key);
+                    continue;
+                }
+            };
+            let current = existing.and_then(|value| serde_json::from_value::<UserSettings>(value).ok());
+            summary
+                .endpoint_changes
+                .extend(describe_endpoint_changes(network, current.as_ref(), &imported));
+            storage_base::save_json(&key, &imported)?;
+        } else {
+            storage_base::save_json(&key, &merged)?;
+        }
+        summary.imported.push(key);
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_settings_export_checks_shape_and_version() {
+        let export = parse_settings_export(r#"{"version":1,"exported_at":0,"entries":{"preferences":{"developer_mode":true}}}"#)
+            .unwrap();
+        assert_eq!(export.entries["preferences"], json!({"developer_mode": true}));
+
+        assert!(parse_settings_export(r#"{"version":2,"exported_at":0,"entries":{}}"#).is_err());
+        assert!(parse_settings_export(r#"{"entries":[]}"#).is_err());
+        assert!(parse_settings_export("not json").is_err());
+    }
+
+    #[test]
+    fn test_merge_value_keeps_existing_entries() {
+        let merged = merge_value(
+            Some(json!({"developer_mode": false, "projects_view_mode": "Table"})),
+            json!({"developer_mode": true}),
+        );
+        assert_eq!(merged, json!({"developer_mode": true, "projects_view_mode": "Table"}));
+
+        assert_eq!(merge_value(Some(json!([1, 2])), json!([2, 3])), json!([1, 2, 3]));
+        assert_eq!(merge_value(None, json!([3])), json!([3]));
+        assert_eq!(merge_value(Some(json!("old")), json!("new")), json!("new"));
+    }
+
+    #[test]
+    fn test_transient_keys_are_not_exportable() {
+        assert!(is_exportable("preferences"));
+        assert!(is_exportable("settings.testnet"));
+        assert!(!is_exportable("pending_chat_sends.testnet"));
+        assert!(!is_exportable("wallet"));
+        assert!(!is_exportable(""));
+    }
+
+    #[test]
+    fn test_check_network_settings_rejects_bad_urls() {
+        let valid = json!({
+            "rpc_selection": "Custom",
+            "custom_rpc_url": "https://rpc.example.com",
+            "compute_unit_buffer_percentage": 1,
+            "compute_unit_price_micro_lamports": 0,
+            "custom_explorer_url": ""
+        });
+        assert!(check_network_settings(&valid).is_ok());
+
+        let mut bad_rpc = valid.clone();
+        bad_rpc["custom_rpc_url"] = json!("javascript:alert(1)");
+        assert!(check_network_settings(&bad_rpc).is_err());
+
+        let mut bad_explorer = valid.clone();
+        bad_explorer["custom_explorer_url"] = json!("javascript:alert(1)");
+        assert!(check_network_settings(&bad_explorer).is_err());
+
+        assert!(check_network_settings(&json!({"rpc_selection": "Custom"})).is_err());
+    }
+
+    #[test]
+    fn test_describe_endpoint_changes() {
+        let current = UserSettings::default();
+        assert!(describe_endpoint_changes("testnet", Some(&current), &current).is_empty());
+
+        let imported = UserSettings {
+            rpc_selection: RpcSelection::Custom,
+            custom_rpc_url: "https://rpc.example.com".to_string(),
+            custom_explorer_url: "https://explorer.example.com/".to_string(),
+            ..UserSettings::default()
+        };
+        assert_eq!(
+            describe_endpoint_changes("testnet", None, &imported),
+            vec![
+                "testnet RPC: https://rpc.example.com".to_string(),
+                "testnet explorer: https://explorer.example.com".to_string(),
+            ]
+        );
+        assert_eq!(
+            describe_endpoint_changes("mainnet", Some(&imported), &current),
+            vec!["mainnet RPC: default".to_string(), "mainnet explorer: default".to_string()]
+        );
+    }
+}
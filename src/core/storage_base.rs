@@ -0,0 +1,87 @@
+//! Shared browser localStorage helpers
+//!
+//! Small client-side preferences and lists (hidden messages, display
+//! preferences, ...) are stored as JSON under a common `memo-app.` prefix.
+//! Nothing stored here ever leaves the browser.
+
+use serde::{de::DeserializeOwned, Serialize};
+use web_sys::Storage;
+
+use super::network_config::get_network;
+
+const STORAGE_PREFIX: &str = "memo-app.";
+
+pub fn local_storage() -> Option<Storage> {
+    web_sys::window()
+        .and_then(|win| win.local_storage().ok().flatten())
+}
+
+fn storage_key(key: &str) -> String {
+    format!("{}{}", STORAGE_PREFIX, key)
+}
+
+/// Load and deserialize a JSON value, returning None if missing or unreadable
+pub fn load_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let storage = local_storage()?;
+    let value = storage
+        .get_item(&storage_key(key))
+        .ok()
+        .flatten()?;
+
+    serde_json::from_str(&value).ok()
+}
+
+/// Serialize and store a JSON value
+pub fn save_json<T: Serialize + ?Sized>(key: &str, value: &T) -> Result<(), String> {
+    let storage = local_storage().ok_or_else(|| "Local storage not available".to_string())?;
+    let serialized = serde_json::to_string(value)
+        .map_err(|e| format!("Failed to serialize {key}: {e}"))?;
+
+    storage
+        .set_item(&storage_key(key), &serialized)
+        .map_err(|_| format!("Failed to write {key} to local storage"))
+}
+
+/// `key` for the current network ("<key>.<network>"), for values kept per
+/// network; None until the network is initialized
+pub fn network_key(key: &str) -> Option<String> {
+    get_network().map(|network| format!("{}.{}", key, network.as_str()))
+}
+
+/// Load a list kept per network, empty if missing, unreadable or before the
+/// network is initialized
+pub fn load_network_list<T: DeserializeOwned>(key: &str) -> Vec<T> {
+    network_key(key)
+        .and_then(|key| load_json(&key))
+        .unwrap_or_default()
+}
+
+/// Add `item` at the end of a list kept most recent last, replacing any
+/// entry `same` as it, and drop the oldest entries beyond `cap`
+pub fn push_bounded<T>(list: &mut Vec<T>, item: T, cap: usize, same: impl Fn(&T, &T) -> bool) {
+    list.retain(|existing| !same(existing, &item));
+    list.push(item);
+    if list.len() > cap {
+        list.drain(..list.len() - cap);
+    }
+}
+
+/// Remove a stored value
+pub fn remove(key: &str) -> Result<(), String> {
+    let storage = local_storage().ok_or_else(|| "Local storage not available".to_string())?;
+    storage
+        .remove_item(&storage_key(key))
+        .map_err(|_| format!("Failed to remove {key} from local storage"))
+}
+
+/// Keys of all values stored under the app prefix, without the prefix
+pub fn keys() -> Vec<String> {
+    let Some(storage) = local_storage() else {
+        return Vec::new();
+    };
+    let len = storage.length().unwrap_or(0);
+    (0..len)
+        .filter_map(|i| storage.key(i).ok().flatten())
+        .filter_map(|key| key.strip_prefix(STORAGE_PREFIX).map(str::to_string))
+        .collect()
+}
@@ -0,0 +1,398 @@
+//! Text helpers for user-authored content (chat messages, devlogs, ...)
+//! and for shortening strings for display
+//!
+//! Everything here works on plain strings; rendering is left to the UI
+//! layer, which emits text nodes so no raw HTML ever reaches the DOM.
+
+/// A piece of user text, either plain text or a detected link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextSegment {
+    Text(String),
+    Link(String),
+}
+
+/// Supported link schemes - anything else (javascript:, data:, ...) stays plain text
+const LINK_SCHEMES: [&str; 2] = ["https://", "http://"];
+
+/// Characters that usually end a sentence rather than a URL
+const TRAILING_PUNCTUATION: [char; 9] = ['.', ',', '!', '?', ';', ':', '\'', '"', '>'];
+
+/// Split text into plain and link segments, detecting http(s) URLs
+pub fn split_links(text: &str) -> Vec<TextSegment> {
+    let mut segments = Vec::new();
+    let mut plain_start = 0;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let at_boundary = text[..pos]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+
+        if at_boundary {
+            if let Some(url_len) = match_url(rest) {
+                if plain_start < pos {
+                    segments.push(TextSegment::Text(text[plain_start..pos].to_string()));
+                }
+                segments.push(TextSegment::Link(rest[..url_len].to_string()));
+                pos += url_len;
+                plain_start = pos;
+                continue;
+            }
+        }
+
+        pos += rest.chars().next().map_or(1, |c| c.len_utf8());
+    }
+
+    if plain_start < text.len() {
+        segments.push(TextSegment::Text(text[plain_start..].to_string()));
+    }
+
+    segments
+}
+
+/// Return the byte length of the URL at the start of `text`, if any
+fn match_url(text: &str) -> Option<usize> {
+    let scheme = LINK_SCHEMES
+        .iter()
+        .find(|scheme| text.get(..scheme.len()).is_some_and(|p| p.eq_ignore_ascii_case(scheme)))?;
+
+    // a URL runs until whitespace or a character that can't appear unescaped in it
+    let mut end = text
+        .find(|c: char| c.is_whitespace() || c == '<' || c == '`')
+        .unwrap_or(text.len());
+
+    // strip trailing punctuation, keeping ')' only while it balances a '(' in the URL
+    loop {
+        let candidate = &text[..end];
+        let Some(last) = candidate.chars().next_back() else { break };
+        let strip = if TRAILING_PUNCTUATION.contains(&last) {
+            true
+        } else if last == ')' {
+            candidate.matches('(').count() < candidate.matches(')').count()
+        } else {
+            false
+        };
+        if !strip {
+            break;
+        }
+        end -= last.len_utf8();
+    }
+
+    // need at least one host character after the scheme
+    let host = &text[scheme.len()..end];
+    if host.is_empty() || !host.starts_with(|c: char| c.is_alphanumeric() || c == '[') {
+        return None;
+    }
+
+    Some(end)
+}
+
+/// Invisible formatting characters that `char::is_control` does not cover
+const INVISIBLE_CHARS: [char; 5] = ['\u{200B}', '\u{200C}', '\u{2060}', '\u{FEFF}', '\u{00AD}'];
+
+/// Zero-width joiner, kept only inside emoji sequences such as 👨‍👩‍👧
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Normalize a short single-line display string (group/project name, tag):
+/// whitespace runs (tabs, newlines, ...) become one space, control and
+/// zero-width characters are removed and the ends are trimmed
+pub fn sanitize_display_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut pending_space = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_whitespace() {
+            pending_space = !out.is_empty();
+            continue;
+        }
+        if c.is_control() || INVISIBLE_CHARS.contains(&c) {
+            continue;
+        }
+        if c == ZERO_WIDTH_JOINER {
+            let joins_emoji = out.chars().next_back().is_some_and(is_symbol_like)
+                && chars.get(i + 1).copied().is_some_and(is_symbol_like);
+            if !joins_emoji {
+                continue;
+            }
+        }
+        if pending_space {
+            out.push(' ');
+            pending_space = false;
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+/// Emoji and pictographic symbols (arrows, dingbats, emoji blocks)
+fn is_symbol_like(c: char) -> bool {
+    matches!(c as u32, 0x2190..=0x2BFF | 0x1F000..=0x1FAFF)
+}
+
+/// Whether the text has anything besides punctuation and whitespace:
+/// a letter, digit or emoji
+pub fn has_visible_text(text: &str) -> bool {
+    text.chars().any(|c| c.is_alphanumeric() || is_symbol_like(c))
+}
+
+/// Length in bytes of the match of `query` at the start of `text`,
+/// comparing characters case-insensitively
+fn match_len_at(text: &str, query: &[char]) -> Option<usize> {
+    let mut chars = text.char_indices();
+    for q in query {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(q.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(i, _)| i))
+}
+
+/// Split text into pieces that do (`true`) and don't match `query`,
+/// ignoring case. Matches don't overlap; an empty query matches nothing.
+pub fn split_matches<'a>(text: &'a str, query: &str) -> Vec<(&'a str, bool)> {
+    let query: Vec<char> = query.chars().collect();
+    let mut pieces = Vec::new();
+    let mut plain_start = 0;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        match match_len_at(&text[pos..], &query).filter(|_| !query.is_empty()) {
+            Some(len) => {
+                if plain_start < pos {
+                    pieces.push((&text[plain_start..pos], false));
+                }
+                pieces.push((&text[pos..pos + len], true));
+                pos += len;
+                plain_start = pos;
+            }
+            None => pos += text[pos..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if plain_start < text.len() {
+        pieces.push((&text[plain_start..], false));
+    }
+    pieces
+}
+
+/// Whether `text` contains `query`, ignoring case
+pub fn contains_ignore_case(text: &str, query: &str) -> bool {
+    split_matches(text, query).iter().any(|(_, matched)| *matched)
+}
+
+/// Marker appended to shortened strings
+const ELLIPSIS: &str = "...";
+
+/// Cut `s` to at most `max_chars` characters, ending it with "..." when
+/// anything was dropped. Counts characters, so multi-byte text (emoji,
+/// CJK) is never split inside a character.
+pub fn safe_truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+    let keep = max_chars.saturating_sub(ELLIPSIS.len());
+    let mut truncated: String = s.chars().take(keep).collect();
+    truncated.push_str(ELLIPSIS);
+    truncated
+}
+
+/// Keep the first `head` and last `tail` characters of `s` around "...",
+/// or `s` unchanged if it is not longer than that
+pub fn shorten_middle(s: &str, head: usize, tail: usize) -> String {
+    let count = s.chars().count();
+    if count <= head + tail {
+        return s.to_string();
+    }
+    let start: String = s.chars().take(head).collect();
+    let end: String = s.chars().skip(count - tail).collect();
+    format!("{}{}{}", start, ELLIPSIS, end)
+}
+
+/// Short form of an address for display, e.g. "7xKX...AsU1"
+pub fn shorten_pubkey(s: &str) -> String {
+    shorten_middle(s, 4, 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> TextSegment {
+        TextSegment::Text(s.to_string())
+    }
+
+    fn link(s: &str) -> TextSegment {
+        TextSegment::Link(s.to_string())
+    }
+
+    #[test]
+    fn test_plain_text_has_no_links() {
+        assert_eq!(split_links("hello world"), vec![text("hello world")]);
+        assert!(split_links("").is_empty());
+    }
+
+    #[test]
+    fn test_detects_http_and_https() {
+        assert_eq!(
+            split_links("see https://x1.xyz and http://example.com/a?b=1#c"),
+            vec![
+                text("see "),
+                link("https://x1.xyz"),
+                text(" and "),
+                link("http://example.com/a?b=1#c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trailing_punctuation_is_not_part_of_link() {
+        assert_eq!(
+            split_links("Visit https://example.com."),
+            vec![text("Visit "), link("https://example.com"), text(".")]
+        );
+        assert_eq!(
+            split_links("really? https://example.com/path!?"),
+            vec![text("really? "), link("https://example.com/path"), text("!?")]
+        );
+        assert_eq!(
+            split_links("\"https://example.com\""),
+            vec![text("\""), link("https://example.com"), text("\"")]
+        );
+    }
+
+    #[test]
+    fn test_parentheses() {
+        // wrapping parens are left outside the link
+        assert_eq!(
+            split_links("(https://example.com)"),
+            vec![text("("), link("https://example.com"), text(")")]
+        );
+        // balanced parens inside the URL are kept
+        assert_eq!(
+            split_links("https://en.wikipedia.org/wiki/Rust_(programming_language)"),
+            vec![link("https://en.wikipedia.org/wiki/Rust_(programming_language)")]
+        );
+        assert_eq!(
+            split_links("(see https://en.wikipedia.org/wiki/Rust_(language))."),
+            vec![
+                text("(see "),
+                link("https://en.wikipedia.org/wiki/Rust_(language)"),
+                text(")."),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_only_http_schemes_are_linked() {
+        assert_eq!(split_links("javascript:alert(1)"), vec![text("javascript:alert(1)")]);
+        assert_eq!(split_links("ftp://example.com"), vec![text("ftp://example.com")]);
+        assert_eq!(split_links("data:text/html,hi"), vec![text("data:text/html,hi")]);
+    }
+
+    #[test]
+    fn test_requires_host_and_word_boundary() {
+        assert_eq!(split_links("https:// nothing"), vec![text("https:// nothing")]);
+        assert_eq!(split_links("xhttps://example.com"), vec![text("xhttps://example.com")]);
+    }
+
+    #[test]
+    fn test_multiline_and_unicode() {
+        assert_eq!(
+            split_links("链接:\nhttps://example.com/路径\n完"),
+            vec![text("链接:\n"), link("https://example.com/路径"), text("\n完")]
+        );
+    }
+
+    #[test]
+    fn test_html_is_not_interpreted() {
+        assert_eq!(
+            split_links("<b>https://example.com</b>"),
+            vec![text("<b>"), link("https://example.com"), text("</b>")]
+        );
+    }
+
+    #[test]
+    fn test_sanitize_strips_zero_width_and_collapses_whitespace() {
+        assert_eq!(sanitize_display_text("  memo\u{200B}\u{FEFF} group  "), "memo group");
+        assert_eq!(sanitize_display_text("memo\t\tgroup\n2"), "memo group 2");
+        assert_eq!(sanitize_display_text("a\u{0007}b\u{200D}c"), "abc");
+        assert_eq!(sanitize_display_text("\u{200B}\t "), "");
+    }
+
+    #[test]
+    fn test_sanitize_keeps_emoji() {
+        assert_eq!(sanitize_display_text("🔥  burn club 🚀"), "🔥 burn club 🚀");
+        // the joiner inside an emoji sequence survives
+        assert_eq!(sanitize_display_text("👨\u{200D}👩\u{200D}👧"), "👨\u{200D}👩\u{200D}👧");
+        assert_eq!(sanitize_display_text("❤\u{FE0F} memo"), "❤\u{FE0F} memo");
+    }
+
+    #[test]
+    fn test_has_visible_text() {
+        assert!(has_visible_text("memo"));
+        assert!(has_visible_text("42"));
+        assert!(has_visible_text("🔥"));
+        assert!(has_visible_text("日本"));
+        assert!(!has_visible_text("..."));
+        assert!(!has_visible_text(" -_- "));
+        assert!(!has_visible_text(""));
+    }
+
+    #[test]
+    fn test_split_matches_ignores_case() {
+        assert_eq!(
+            split_matches("GM frens, gm!", "gm"),
+            vec![("GM", true), (" frens, ", false), ("gm", true), ("!", false)]
+        );
+        assert_eq!(split_matches("Straße", "SSE"), vec![("Straße", false)]);
+        assert_eq!(split_matches("Ünïcode ünï", "ÜNÏ"), vec![("Ünï", true), ("code ", false), ("ünï", true)]);
+        assert_eq!(split_matches("hello", ""), vec![("hello", false)]);
+        assert!(split_matches("", "x").is_empty());
+    }
+
+    #[test]
+    fn test_contains_ignore_case() {
+        assert!(contains_ignore_case("Burned 100 MEMO", "memo"));
+        assert!(!contains_ignore_case("Burned 100 MEMO", "memos"));
+        assert!(!contains_ignore_case("anything", ""));
+    }
+
+    #[test]
+    fn test_safe_truncate() {
+        assert_eq!(safe_truncate("short", 10), "short");
+        assert_eq!(safe_truncate("", 10), "");
+        assert_eq!(safe_truncate("exactly10!", 10), "exactly10!");
+        assert_eq!(safe_truncate("a longer description", 10), "a longe...");
+        assert_eq!(safe_truncate("abcdef", 2), "...");
+    }
+
+    #[test]
+    fn test_safe_truncate_emoji() {
+        let description = "🚀🚀🚀 launching soon 🎉";
+        let truncated = safe_truncate(description, 8);
+        assert_eq!(truncated, "🚀🚀🚀 l...");
+        assert_eq!(truncated.chars().count(), 8);
+        assert_eq!(safe_truncate("日本語のテキスト", 5), "日本...");
+    }
+
+    #[test]
+    fn test_shorten_pubkey() {
+        assert_eq!(
+            shorten_pubkey("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU"),
+            "7xKX...gAsU"
+        );
+        assert_eq!(shorten_pubkey("abcdefgh"), "abcdefgh");
+        assert_eq!(shorten_pubkey("abc"), "abc");
+        assert_eq!(shorten_pubkey("🔑🔑🔑🔑🔑🔑🔑🔑🔑"), "🔑🔑🔑🔑...🔑🔑🔑🔑");
+    }
+
+    #[test]
+    fn test_shorten_middle() {
+        assert_eq!(shorten_middle("0123456789abcdef", 6, 4), "012345...cdef");
+        assert_eq!(shorten_middle("0123456789", 6, 4), "0123456789");
+    }
+}
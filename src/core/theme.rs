@@ -0,0 +1,66 @@
+//! Applying the color theme to the document
+//!
+//! The theme is a `data-theme="light|dark"` attribute on the root element
+//! that switches the CSS variables in `global.css`. The choice is stored in
+//! `UiPreferences::theme` and applied before the app mounts, so the first
+//! paint already uses it.
+
+use super::preferences::{ThemePreference, UiPreferences};
+
+/// Key the theme used to be stored under, as a raw "dark"/"light" string
+const LEGACY_THEME_KEY: &str = "theme";
+
+fn system_prefers_dark() -> bool {
+    web_sys::window()
+        .and_then(|win| win.match_media("(prefers-color-scheme: dark)").ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+/// Set the root `data-theme` attribute
+pub fn apply_theme(is_dark: bool) {
+    if let Some(root) = web_sys::window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.document_element())
+    {
+        let _ = root.set_attribute("data-theme", if is_dark { "dark" } else { "light" });
+    }
+}
+
+/// Whether the dark theme is currently applied
+pub fn is_dark_applied() -> bool {
+    web_sys::window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.document_element())
+        .and_then(|root| root.get_attribute("data-theme"))
+        .is_some_and(|theme| theme == "dark")
+}
+
+/// Apply a theme preference, resolving `System` against the browser setting
+pub fn apply_theme_preference(theme: ThemePreference) -> bool {
+    let is_dark = theme.is_dark(system_prefers_dark());
+    apply_theme(is_dark);
+    is_dark
+}
+
+/// Move a theme saved by older versions into the preferences
+fn migrate_legacy_theme() {
+    let Some(storage) = super::storage_base::local_storage() else {
+        return;
+    };
+    let Ok(Some(legacy)) = storage.get_item(LEGACY_THEME_KEY) else {
+        return;
+    };
+    let theme = if legacy == "dark" { ThemePreference::Dark } else { ThemePreference::Light };
+    match UiPreferences::update(|p| p.theme = theme) {
+        Ok(_) => {
+            let _ = storage.remove_item(LEGACY_THEME_KEY);
+        }
+        Err(e) => log::warn!("Failed to migrate saved theme: {}", e),
+    }
+}
+
+/// Apply the saved theme; called once before the app mounts
+pub fn apply_saved_theme() {
+    migrate_legacy_theme();
+    apply_theme_preference(UiPreferences::load().theme);
+}
@@ -0,0 +1,104 @@
+//! User-facing classification of failed memo transactions
+//!
+//! Chat sends and burns keep the typed `RpcError` (`SessionError::Rpc`), so
+//! the chat page matches on its variants. Other failures reach the pages as
+//! strings (`RpcError` display output including any "Error Message:"
+//! extracted from the program logs, wallet errors). `classify_tx_error` maps
+//! them to the few cases worth explaining to the user; each page picks the
+//! wording for its own action.
+
+/// Reason a chat message, burn or devlog transaction failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxErrorKind {
+    /// The contract's minimum memo interval has not passed yet
+    TooFrequent,
+    /// Not enough XNT for fees or MEMO for the burn
+    InsufficientBalance,
+    /// The request did not complete in time
+    Timeout,
+    Other,
+}
+
+/// Whether `code` appears as a whole number (so "6009" doesn't match "36009ms")
+fn contains_code(error: &str, code: &str) -> bool {
+    error.match_indices(code).any(|(start, _)| {
+        let before = error[..start].chars().next_back();
+        let after = error[start + code.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_digit()) && !after.is_some_and(|c| c.is_ascii_digit())
+    })
+}
+
+/// Classify an error string by the markers the RPC layer and contracts produce
+pub fn classify_tx_error(error: &str) -> TxErrorKind {
+    let error = error.to_lowercase();
+    // 6009 is the memo-chat MemoTooFrequent code
+    if error.contains("memotoofrequent") || error.contains("too frequent") || contains_code(&error, "6009") {
+        TxErrorKind::TooFrequent
+    } else if error.contains("insufficient") {
+        TxErrorKind::InsufficientBalance
+    } else if error.contains("timeout") || error.contains("timed out") {
+        TxErrorKind::Timeout
+    } else {
+        TxErrorKind::Other
+    }
+}
+
+impl TxErrorKind {
+    /// Wording for a failed chat message
+    pub fn chat_message(self) -> &'static str {
+        match self {
+            TxErrorKind::TooFrequent => "Message sent too frequently. Please wait before sending another message.",
+            TxErrorKind::InsufficientBalance => "Insufficient balance",
+            TxErrorKind::Timeout => "Message send timeout. Please try again.",
+            TxErrorKind::Other => "Failed to send message. Please try again.",
+        }
+    }
+
+    /// Wording for a failed devlog post (a project burn with a devlog memo)
+    pub fn devlog_message(self) -> &'static str {
+        match self {
+            TxErrorKind::TooFrequent => "Devlog posted too frequently. Please wait before posting another one.",
+            TxErrorKind::InsufficientBalance => "Insufficient balance for the devlog burn",
+            TxErrorKind::Timeout => "Devlog post timed out. Please try again.",
+            TxErrorKind::Other => "Failed to post devlog. Please try again.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_contract_and_rpc_errors() {
+        assert_eq!(
+            classify_tx_error("Solana RPC error: Code -32002: Transaction simulation failed - MemoTooFrequent"),
+            TxErrorKind::TooFrequent
+        );
+        assert_eq!(classify_tx_error("custom program error: 6009"), TxErrorKind::TooFrequent);
+        assert_eq!(
+            classify_tx_error("Error: Insufficient funds for fee"),
+            TxErrorKind::InsufficientBalance
+        );
+        assert_eq!(classify_tx_error("Error: Request timeout after 30000ms"), TxErrorKind::Timeout);
+        assert_eq!(classify_tx_error("Error: Request timeout after 36009ms"), TxErrorKind::Timeout);
+        assert_eq!(classify_tx_error("Connection failed: network down"), TxErrorKind::Other);
+    }
+
+    #[test]
+    fn test_devlog_messages() {
+        assert_eq!(
+            classify_tx_error("Transaction failed: insufficient balance").devlog_message(),
+            "Insufficient balance for the devlog burn"
+        );
+        assert_eq!(
+            classify_tx_error("Error Message: Memo too frequent").devlog_message(),
+            "Devlog posted too frequently. Please wait before posting another one."
+        );
+        assert_eq!(
+            classify_tx_error("Error: Request timeout after 30001ms").devlog_message(),
+            "Devlog post timed out. Please try again."
+        );
+        assert_eq!(classify_tx_error("boom").devlog_message(), "Failed to post devlog. Please try again.");
+    }
+}
@@ -0,0 +1,78 @@
+//! Locally remembered chat groups and projects the user has opened
+//!
+//! Used only to style already-visited leaderboard entries. Ids are kept in
+//! localStorage via `storage_base`, most recent last, and each list is
+//! capped at `MAX_VISITED_IDS` so it cannot grow without bound.
+
+use super::storage_base;
+
+const VISITED_GROUPS_KEY: &str = "visited_chat_groups";
+const VISITED_PROJECTS_KEY: &str = "visited_projects";
+
+/// Maximum ids remembered per list; the least recently visited are dropped first
+pub const MAX_VISITED_IDS: usize = 500;
+
+/// Move `id` to the most recent end of `ids`, dropping the oldest beyond the cap
+fn push_visited(ids: &mut Vec<u64>, id: u64) {
+    ids.retain(|existing| *existing != id);
+    ids.push(id);
+    if ids.len() > MAX_VISITED_IDS {
+        ids.drain(..ids.len() - MAX_VISITED_IDS);
+    }
+}
+
+fn mark_visited(key: &str, id: u64) -> Result<Vec<u64>, String> {
+    let mut ids: Vec<u64> = storage_base::load_json(key).unwrap_or_default();
+    push_visited(&mut ids, id);
+    storage_base::save_json(key, &ids)?;
+    Ok(ids)
+}
+
+/// Load visited chat group ids
+pub fn load_visited_groups() -> Vec<u64> {
+    storage_base::load_json(VISITED_GROUPS_KEY).unwrap_or_default()
+}
+
+/// Remember a chat group as visited, returning the updated list
+pub fn mark_group_visited(group_id: u64) -> Result<Vec<u64>, String> {
+    mark_visited(VISITED_GROUPS_KEY, group_id)
+}
+
+/// Load visited project ids
+pub fn load_visited_projects() -> Vec<u64> {
+    storage_base::load_json(VISITED_PROJECTS_KEY).unwrap_or_default()
+}
+
+/// Remember a project as visited, returning the updated list
+pub fn mark_project_visited(project_id: u64) -> Result<Vec<u64>, String> {
+    mark_visited(VISITED_PROJECTS_KEY, project_id)
+}
+
+/// Forget all visited groups and projects
+pub fn clear_visited_history() -> Result<(), String> {
+    storage_base::remove(VISITED_GROUPS_KEY)?;
+    storage_base::remove(VISITED_PROJECTS_KEY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_visited_moves_existing_to_end() {
+        let mut ids = vec![1, 2, 3];
+        push_visited(&mut ids, 2);
+        assert_eq!(ids, vec![1, 3, 2]);
+        push_visited(&mut ids, 4);
+        assert_eq!(ids, vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn test_push_visited_is_bounded() {
+        let mut ids: Vec<u64> = (0..MAX_VISITED_IDS as u64).collect();
+        push_visited(&mut ids, 10_000);
+        assert_eq!(ids.len(), MAX_VISITED_IDS);
+        assert_eq!(ids.first(), Some(&1));
+        assert_eq!(ids.last(), Some(&10_000));
+    }
+}
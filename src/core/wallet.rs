@@ -1,6 +1,7 @@
 use bip39::{Mnemonic, Language};
 use serde::{Serialize, Deserialize};
 use web_sys::{window, Storage};
+use super::storage_base;
 use hmac::Hmac;
 use pbkdf2::pbkdf2;
 use sha2::Sha512;
@@ -9,6 +10,13 @@ use solana_sdk::{
     signature::{Keypair, keypair_from_seed_and_derivation_path, Signer},
 };
 
+/// Derived accounts list, kept under the `storage_base` prefix; "wallet."
+/// keys are never part of a settings export
+const WALLET_ACCOUNTS_KEY: &str = "wallet.accounts";
+
+/// Most accounts that can be derived from one wallet
+pub const MAX_WALLET_ACCOUNTS: usize = 20;
+
 #[derive(Serialize, Deserialize)]
 pub struct Wallet {
     encrypted_seed: String,
@@ -85,7 +93,13 @@ pub fn derive_keypair_from_seed(
     Ok((keypair, pubkey))
 }
 
-// store encrypted seed
+// derive the keypair of account `index` (BIP44 account level) from seed
+pub fn derive_account(seed: &[u8; 64], index: u32) -> Result<(Keypair, String), WalletError> {
+    derive_keypair_from_seed(seed, &derivation_path_for(index))
+}
+
+// store encrypted seed; only the seed derived from the mnemonic is kept,
+// and that derivation is one-way, so the phrase itself can't be recovered
 pub async fn store_encrypted_seed(
     seed: &[u8; 64], 
     password: &str,
@@ -110,6 +124,9 @@ pub async fn store_encrypted_seed(
             .map_err(|_| WalletError::Storage)?;
     }
 
+    // accounts derived from a previous wallet don't belong to this seed
+    WalletAccounts::clear();
+
     Ok(())
 }
 
@@ -118,6 +135,65 @@ pub fn get_default_derivation_path() -> &'static str {
     "m/44'/501'/0'/0'"
 }
 
+// get solana derivation path of account `index`; index 0 is the default path
+pub fn derivation_path_for(index: u32) -> String {
+    format!("m/44'/501'/{}'/0'", index)
+}
+
+/// Account indices derived from the wallet's seed and the one in use.
+/// Only indices are stored; addresses are derived again from the seed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WalletAccounts {
+    pub indices: Vec<u32>,
+    pub active: u32,
+}
+
+impl Default for WalletAccounts {
+    fn default() -> Self {
+        Self { indices: vec![0], active: 0 }
+    }
+}
+
+impl WalletAccounts {
+    /// Stored accounts, always including account 0 and the active one
+    pub fn load() -> Self {
+        storage_base::load_json::<Self>(WALLET_ACCOUNTS_KEY)
+            .unwrap_or_default()
+            .normalized()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        storage_base::save_json(WALLET_ACCOUNTS_KEY, self)
+    }
+
+    pub fn clear() {
+        if let Err(e) = storage_base::remove(WALLET_ACCOUNTS_KEY) {
+            log::warn!("Failed to clear wallet accounts: {}", e);
+        }
+    }
+
+    fn normalized(mut self) -> Self {
+        self.indices.push(0);
+        self.indices.sort_unstable();
+        self.indices.dedup();
+        self.indices.truncate(MAX_WALLET_ACCOUNTS);
+        if !self.indices.contains(&self.active) {
+            self.active = 0;
+        }
+        self
+    }
+
+    /// Add the account after the highest index, None once the limit is reached
+    pub fn add_next(&mut self) -> Option<u32> {
+        if self.indices.len() >= MAX_WALLET_ACCOUNTS {
+            return None;
+        }
+        let next = self.indices.iter().max().map_or(0, |max| max + 1);
+        self.indices.push(next);
+        Some(next)
+    }
+}
+
 impl Wallet {
     // get the encrypted seed
     pub fn get_encrypted_seed(&self) -> &str {
@@ -250,6 +326,26 @@ mod tests {
         assert_eq!(path, "m/44'/501'/0'/0'");
     }
 
+    #[test]
+    fn test_derive_account() {
+        let seed = generate_seed_from_mnemonic(&create_test_mnemonic(), None).unwrap();
+        let (_, default_address) = derive_keypair_from_seed(&seed, get_default_derivation_path()).unwrap();
+        let (_, first) = derive_account(&seed, 0).unwrap();
+        let (_, second) = derive_account(&seed, 1).unwrap();
+        assert_eq!(first, default_address);
+        assert_ne!(first, second);
+        assert_eq!(derivation_path_for(3), "m/44'/501'/3'/0'");
+    }
+
+    #[test]
+    fn test_wallet_accounts_normalized() {
+        let mut accounts = WalletAccounts { indices: vec![2, 2], active: 5 }.normalized();
+        assert_eq!(accounts, WalletAccounts { indices: vec![0, 2], active: 0 });
+        assert_eq!(accounts.add_next(), Some(3));
+        let mut full = WalletAccounts { indices: (0..MAX_WALLET_ACCOUNTS as u32).collect(), active: 1 };
+        assert_eq!(full.add_next(), None);
+    }
+
     #[test]
     fn test_wallet_struct() {
         let encrypted_seed = "test_encrypted_seed".to_string();
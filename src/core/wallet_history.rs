@@ -0,0 +1,233 @@
+//! The connected wallet's own MEMO activity
+//!
+//! Built from `getSignaturesForAddress` on the wallet, which already carries
+//! each transaction's memo, so no transaction is fetched. Memos written by
+//! the chat and project contracts are decoded with their parsers; anything
+//! else (including memos that fail to decode) is kept as raw text, so one
+//! odd transaction never hides the rest of the list.
+
+use super::rpc_base::{RpcConnection, RpcError};
+use super::rpc_chat::{parse_borsh_chat_message, parse_chat_operation_memo, ChatOperationDetails};
+use super::rpc_project::{parse_project_operation_memo, ProjectOperationDetails};
+
+/// Signatures requested per page
+pub const ACTIVITY_PAGE_SIZE: usize = 25;
+
+/// What a transaction did, as far as its memo tells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    Message,
+    Burn,
+    Mint,
+    Create,
+    Update,
+    /// No memo, or one in an unknown format
+    Other,
+}
+
+impl ActivityKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityKind::Message => "Message",
+            ActivityKind::Burn => "Burn",
+            ActivityKind::Mint => "Mint",
+            ActivityKind::Create => "Create",
+            ActivityKind::Update => "Update",
+            ActivityKind::Other => "Other",
+        }
+    }
+}
+
+/// The chat group or project a transaction went to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityTarget {
+    Group(u64),
+    Project(u64),
+}
+
+/// One transaction of the wallet
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletActivity {
+    pub signature: String,
+    pub timestamp: i64,
+    pub slot: u64,
+    /// Whether the transaction failed on chain
+    pub failed: bool,
+    pub kind: ActivityKind,
+    /// Burned lamports, for contract operations
+    pub amount: Option<u64>,
+    pub target: Option<ActivityTarget>,
+    /// Message or name for decoded memos, the raw memo otherwise
+    pub text: String,
+}
+
+/// A page of activity, newest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WalletActivityPage {
+    pub items: Vec<WalletActivity>,
+    /// Pass as `before` to load the next (older) page; None when done
+    pub next_before: Option<String>,
+}
+
+/// Decoded meaning of a memo: (kind, amount, target, text)
+type DecodedMemo = (ActivityKind, Option<u64>, Option<ActivityTarget>, String);
+
+/// The memo text without the RPC's `[length] ` prefix
+fn strip_length_prefix(memo: &str) -> &str {
+    match memo.strip_prefix('[').and_then(|rest| rest.split_once("] ")) {
+        Some((length, data)) if length.chars().all(|c| c.is_ascii_digit()) => data,
+        _ => memo,
+    }
+}
+
+/// Classify a memo (as reported by `getSignaturesForAddress`)
+pub fn decode_memo(memo: Option<&str>) -> DecodedMemo {
+    let Some(memo) = memo else {
+        return (ActivityKind::Other, None, None, String::new());
+    };
+    let data = strip_length_prefix(memo);
+    let bytes = data.as_bytes();
+
+    if let Some((_, message, _)) = parse_borsh_chat_message(bytes) {
+        return (ActivityKind::Message, None, None, message);
+    }
+    if let Some((_, _, details, amount)) = parse_chat_operation_memo(bytes) {
+        return match details {
+            ChatOperationDetails::CreateGroup { group_id, name, .. } => {
+                (ActivityKind::Create, Some(amount), Some(ActivityTarget::Group(group_id)), name)
+            }
+            ChatOperationDetails::BurnForGroup { group_id, message, .. } => {
+                (ActivityKind::Burn, Some(amount), Some(ActivityTarget::Group(group_id)), message)
+            }
+            ChatOperationDetails::SendMemo { group_id, message, .. } => {
+                (ActivityKind::Message, Some(amount), Some(ActivityTarget::Group(group_id)), message)
+            }
+        };
+    }
+    if let Some((_, _, details, amount)) = parse_project_operation_memo(bytes) {
+        return match details {
+            ProjectOperationDetails::Create { project_id, name, .. } => {
+                (ActivityKind::Create, Some(amount), Some(ActivityTarget::Project(project_id)), name)
+            }
+            ProjectOperationDetails::Update { project_id, name, .. } => {
+                (ActivityKind::Update, Some(amount), Some(ActivityTarget::Project(project_id)), name.unwrap_or_default())
+            }
+            ProjectOperationDetails::Burn { project_id, message } => {
+                (ActivityKind::Burn, Some(amount), Some(ActivityTarget::Project(project_id)), message)
+            }
+        };
+    }
+    // the mint page's memo is a small JSON object with "action":"mint"
+    let is_mint = serde_json::from_str::<serde_json::Value>(data)
+        .map(|json| json["action"] == "mint")
+        .unwrap_or(false);
+    if is_mint {
+        return (ActivityKind::Mint, None, None, String::new());
+    }
+    (ActivityKind::Other, None, None, data.to_string())
+}
+
+/// Turn one `getSignaturesForAddress` entry into an activity row
+fn activity_from_signature(info: &serde_json::Value) -> Option<WalletActivity> {
+    let signature = info["signature"].as_str().filter(|sig| !sig.is_empty())?;
+    let (kind, amount, target, text) = decode_memo(info["memo"].as_str());
+    Some(WalletActivity {
+        signature: signature.to_string(),
+        timestamp: info["blockTime"].as_i64().unwrap_or(0),
+        slot: info["slot"].as_u64().unwrap_or(0),
+        failed: !info["err"].is_null(),
+        kind,
+        amount,
+        target,
+        text,
+    })
+}
+
+impl RpcConnection {
+    /// Get a page of the wallet's transactions, newest first
+    ///
+    /// # Parameters
+    /// * `address` - The wallet's pubkey
+    /// * `before` - Signature to continue from (the previous page's `next_before`)
+    pub async fn get_wallet_activity(&self, address: &str, before: Option<String>) -> Result<WalletActivityPage, RpcError> {
+        let mut options = serde_json::json!({
+            "commitment": "confirmed",
+            "limit": ACTIVITY_PAGE_SIZE
+        });
+        if let Some(before) = before {
+            options["before"] = serde_json::Value::String(before);
+        }
+
+        let response = self.get_signatures_for_address(address, Some(options)).await?;
+        let response: serde_json::Value = serde_json::from_str(&response)
+            .map_err(|e| RpcError::Other(format!("Failed to parse signatures: {}", e)))?;
+        let signatures = response.as_array()
+            .ok_or_else(|| RpcError::Other("Invalid signatures response format".to_string()))?;
+
+        let items: Vec<WalletActivity> = signatures.iter().filter_map(activity_from_signature).collect();
+        let next_before = if signatures.len() == ACTIVITY_PAGE_SIZE {
+            items.last().map(|item| item.signature.clone())
+        } else {
+            None
+        };
+        Ok(WalletActivityPage { items, next_before })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+    use super::super::rpc_chat::{BurnMemo, ChatGroupBurnData};
+
+    #[test]
+    fn test_decode_chat_burn_memo() {
+        let payload = ChatGroupBurnData {
+            version: 1,
+            category: "chat".to_string(),
+            operation: "burn_for_group".to_string(),
+            group_id: 7,
+            burner: "burner".to_string(),
+            message: "gm".to_string(),
+        };
+        let memo = BurnMemo { version: 1, burn_amount: 5_000_000, payload: payload.try_to_vec().unwrap() };
+        let encoded = base64::encode(memo.try_to_vec().unwrap());
+        let field = format!("[{}] {}", encoded.len(), encoded);
+
+        assert_eq!(
+            decode_memo(Some(&field)),
+            (ActivityKind::Burn, Some(5_000_000), Some(ActivityTarget::Group(7)), "gm".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_mint_and_unknown_memos() {
+        let mint = r#"[70] {"action":"mint","platform":"memo-app","version":"1.0.0","chain":"X1"}"#;
+        assert_eq!(decode_memo(Some(mint)).0, ActivityKind::Mint);
+
+        assert_eq!(
+            decode_memo(Some("[5] hello")),
+            (ActivityKind::Other, None, None, "hello".to_string())
+        );
+        // not valid base64/borsh: kept as the raw text
+        assert_eq!(decode_memo(Some("[3] QUJD")).3, "QUJD");
+        assert_eq!(decode_memo(None).0, ActivityKind::Other);
+    }
+
+    #[test]
+    fn test_activity_from_signature() {
+        let info = serde_json::json!({
+            "signature": "sig1",
+            "blockTime": 1_700_000_000,
+            "slot": 42,
+            "err": {"InstructionError": [0, "Custom"]},
+            "memo": null
+        });
+        let activity = activity_from_signature(&info).unwrap();
+        assert!(activity.failed);
+        assert_eq!(activity.kind, ActivityKind::Other);
+        assert_eq!(activity.timestamp, 1_700_000_000);
+
+        assert_eq!(activity_from_signature(&serde_json::json!({"signature": ""})), None);
+    }
+}
@@ -89,7 +89,7 @@ pub fn ShowMnemonicStep(
 
             <div class="warning-message" style="margin: 1.5rem auto;">
                 <i class="fas fa-exclamation-triangle"></i>
-                <span>"Write down these words in order and keep them safe. Never share them with anyone!"</span>
+                <span>"Write down these words in order and keep them safe. Never share them with anyone! They are shown only now: the app cannot display them again later."</span>
             </div>
 
             <div class="mnemonic-display">
@@ -14,6 +14,10 @@ fn main() {
 
     log::info!("Starting MEMO Engraves Memories Onchain");
 
+    // before mounting, so the first paint uses the saved theme
+    core::theme::apply_saved_theme();
+    core::rpc_inspector::set_enabled(core::preferences::UiPreferences::load().rpc_inspector);
+
     mount_to_body(|| {
         view! {
             <App/>
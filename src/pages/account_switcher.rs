@@ -0,0 +1,71 @@
+use leptos::*;
+use crate::core::session::Session;
+use crate::core::text::shorten_pubkey;
+use crate::core::wallet::WalletAccounts;
+use crate::pages::log_view::add_log_entry;
+
+/// Value of the "add account" entry in the account list
+const NEW_ACCOUNT_VALUE: &str = "new";
+
+/// Header list of the accounts derived from the internal wallet's seed.
+/// Picking one (or adding the next) makes it the active account; `on_switch`
+/// runs afterwards so the header and pages reload for the new address.
+#[component]
+pub fn AccountSwitcher(session: RwSignal<Session>, on_switch: Callback<()>) -> impl IntoView {
+    let (accounts, set_accounts) = create_signal(WalletAccounts::load());
+
+    let addresses = create_memo(move |_| {
+        let indices = accounts.with(|a| a.indices.clone());
+        session
+            .with_untracked(|s| s.derive_account_addresses(&indices))
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to derive account addresses: {}", e);
+                Vec::new()
+            })
+    });
+
+    let switch_to = move |index: u32| {
+        match session.try_update(|s| s.switch_account(index)) {
+            Some(Ok(address)) => {
+                set_accounts.set(WalletAccounts::load());
+                add_log_entry("INFO", &format!("Switched to account {} ({})", index + 1, shorten_pubkey(&address)));
+                on_switch.call(());
+            }
+            Some(Err(e)) => add_log_entry("ERROR", &format!("Failed to switch account: {}", e)),
+            None => {}
+        }
+    };
+
+    let on_change = move |ev| {
+        let value = event_target_value(&ev);
+        if value == NEW_ACCOUNT_VALUE {
+            let mut stored = WalletAccounts::load();
+            match stored.add_next() {
+                Some(index) => switch_to(index),
+                None => {
+                    add_log_entry("WARN", "Maximum number of accounts reached");
+                    // put the list back on the active account
+                    set_accounts.update(|_| {});
+                }
+            }
+        } else if let Ok(index) = value.parse::<u32>() {
+            if index != session.with_untracked(|s| s.get_account_index()) {
+                switch_to(index);
+            }
+        }
+    };
+
+    view! {
+        <select class="account-switcher" title="Switch account" on:change=on_change>
+            {move || {
+                let active = session.with(|s| s.get_account_index());
+                addresses.get().into_iter().map(|(index, address)| view! {
+                    <option value=index.to_string() prop:selected=index == active>
+                        {format!("Account {} · {}", index + 1, shorten_pubkey(&address))}
+                    </option>
+                }).collect_view()
+            }}
+            <option value=NEW_ACCOUNT_VALUE>"+ Add account"</option>
+        </select>
+    }
+}
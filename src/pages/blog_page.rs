@@ -6,10 +6,14 @@ use crate::core::rpc_blog::{
     BlogCreationData,
 };
 use crate::core::rpc_base::RpcConnection;
+use crate::core::constants::LAMPORTS_PER_TOKEN;
 use crate::core::rpc_mint::MintConfig;
+use crate::core::cache;
 use crate::core::pixel::Pixel;
 use wasm_bindgen_futures::spawn_local;
 use crate::pages::pixel_view::{LazyPixelView, PixelView};
+use crate::pages::burn_network::{is_mainnet, BurnNetworkBadge, MainnetBurnGuard};
+use crate::pages::global_refresh::GlobalRefresh;
 use gloo_timers::future::TimeoutFuture;
 use leptos::web_sys::window;
 use web_sys::{HtmlInputElement, FileReader, Event, ProgressEvent};
@@ -392,7 +396,7 @@ fn render_transaction_card(
                         
                         // Fetch current supply and calculate mint reward
                         let rpc = RpcConnection::new();
-                        if let Ok((supply, _tier)) = rpc.get_current_supply_tier_info().await {
+                        if let Ok((supply, _tier)) = cache::get_current_supply_tier_info(&rpc).await {
                             let reward = MintConfig::calculate_mint_reward(supply);
                             set_mint_reward.set(Some(reward));
                         }
@@ -810,6 +814,7 @@ pub fn BlogPage(
     create_effect(move |_| {
         load_transactions.dispatch(());
     });
+    GlobalRefresh::from_context().register(Some("blog"), move || load_transactions.dispatch(()), loading.into());
     
     // Auto-rotate featured cards every 30 seconds
     {
@@ -1108,6 +1113,7 @@ fn NewPostForm(
     on_success: Rc<dyn Fn()>,
     on_create_blog: Rc<dyn Fn()>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     let on_create_blog_signal = create_rw_signal(Some(on_create_blog));
@@ -1397,7 +1403,14 @@ fn NewPostForm(
                         }
                     }
                 >
-                    <form class="blog-form" on:submit=handle_submit>
+                    <form class="blog-form" on:submit=move |ev: leptos::ev::SubmitEvent| {
+                        ev.prevent_default();
+                        if post_type.get_untracked() == PostType::Burn {
+                            burn_guard.run(move || handle_submit(ev.clone()));
+                        } else {
+                            handle_submit(ev);
+                        }
+                    }>
                         <div class="form-layout">
                             // Left side: Title and Content
                             <div class="form-left">
@@ -1640,10 +1653,14 @@ fn NewPostForm(
                                     <i class="fas fa-fire"></i>
                                 </button>
                             </div>
+                            <Show when=move || post_type.get() == PostType::Burn>
+                                <BurnNetworkBadge/>
+                            </Show>
                             <button
                                 type="submit"
                                 class="submit-btn"
                                 class:burn-submit=move || post_type.get() == PostType::Burn
+                                class:mainnet-burn=move || post_type.get() == PostType::Burn && is_mainnet()
                                 class:mint-submit=move || post_type.get() == PostType::Mint
                                 prop:disabled=move || is_posting.get()
                             >
@@ -1994,6 +2011,7 @@ fn CreateBlogForm(
     on_close: Rc<dyn Fn()>,
     on_success: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     
@@ -2016,7 +2034,7 @@ fn CreateBlogForm(
         let name = blog_name.get().trim().to_string();
         let description = blog_description.get().trim().to_string();
         let image_data = get_image_data();
-        let amount = burn_amount.get() * 1_000_000; // lamports
+        let amount = burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN); // lamports
         
         // Use dummy creator pubkey for calculation
         let blog_data = BlogCreationData::new("11111111111111111111111111111111".to_string(), name, description, image_data);
@@ -2219,7 +2237,10 @@ fn CreateBlogForm(
                 </button>
             </div>
             
-            <form class="blog-form" on:submit=handle_submit>
+            <form class="blog-form" on:submit=move |ev: leptos::ev::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                 <div class="form-layout">
                     // Left side: Basic Information
                     <div class="form-left">
@@ -2461,11 +2482,14 @@ fn CreateBlogForm(
                     </div>
                 </Show>
                 
+                <BurnNetworkBadge/>
+
                 // Submit button
                 <div class="form-actions">
                     <button
                         type="submit"
                         class="submit-btn"
+                        class:mainnet-burn=is_mainnet()
                         prop:disabled=move || is_creating.get()
                     >
                         {move || if is_creating.get() {
@@ -2487,6 +2511,7 @@ fn UpdateBlogForm(
     on_close: Rc<dyn Fn()>,
     on_success: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     
@@ -2672,7 +2697,10 @@ fn UpdateBlogForm(
                         </div>
                     }
                 >
-                    <form class="blog-form" on:submit=handle_submit>
+                    <form class="blog-form" on:submit=move |ev: leptos::ev::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                         <div class="form-layout">
                             // Left side: Basic Information
                             <div class="form-left">
@@ -2832,11 +2860,14 @@ fn UpdateBlogForm(
                             </div>
                         </Show>
                         
+                        <BurnNetworkBadge/>
+
                         // Submit button
                         <div class="form-actions">
                             <button
                                 type="submit"
                                 class="submit-btn"
+                        class:mainnet-burn=is_mainnet()
                                 prop:disabled=move || is_updating.get()
                             >
                                 {move || if is_updating.get() {
@@ -0,0 +1,107 @@
+use leptos::*;
+use crate::core::network_config::{get_network, NetworkType};
+
+/// Whether burns on the current network spend real tokens
+pub fn is_mainnet() -> bool {
+    get_network() == Some(NetworkType::Mainnet)
+}
+
+/// CSS class for a network, matching the sidebar network indicator
+fn network_class(network: Option<NetworkType>) -> &'static str {
+    match network {
+        Some(NetworkType::Testnet) => "network-testnet",
+        Some(NetworkType::ProdStaging) => "network-staging",
+        Some(NetworkType::Mainnet) => "network-mainnet",
+        None => "network-unknown",
+    }
+}
+
+/// The network a burn form will burn on, shown next to its confirm button
+#[component]
+pub fn BurnNetworkBadge() -> impl IntoView {
+    let network = get_network();
+    let name = network.map(|n| n.display_name()).unwrap_or("Unknown network");
+    let mainnet = network == Some(NetworkType::Mainnet);
+
+    view! {
+        <div class=format!("burn-network-badge {}", network_class(network)) title=network.map(|n| n.description())>
+            <i class=if mainnet { "fas fa-exclamation-triangle" } else { "fas fa-circle" }></i>
+            {format!(" Burning on {}", name)}
+            {mainnet.then_some(" - real MEMO tokens")}
+        </div>
+    }
+}
+
+/// Asks once per session (while the main page is open) before the first
+/// burn on mainnet. Provided by the main page and shared by all pages.
+#[derive(Clone, Copy)]
+pub struct MainnetBurnGuard {
+    acknowledged: RwSignal<bool>,
+    pending: RwSignal<Option<Callback<()>>>,
+}
+
+impl MainnetBurnGuard {
+    /// Create the guard and make it available to child pages
+    pub fn provide() -> Self {
+        let guard = Self {
+            acknowledged: create_rw_signal(false),
+            pending: create_rw_signal(None),
+        };
+        provide_context(guard);
+        guard
+    }
+
+    /// The guard provided by the main page
+    pub fn from_context() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Run `burn` now, or once the user has confirmed they are on mainnet
+    /// if this is the first mainnet burn of the session
+    pub fn run(self, burn: impl Fn() + 'static) {
+        if is_mainnet() && !self.acknowledged.get_untracked() {
+            self.pending.set(Some(Callback::new(move |_| burn())));
+        } else {
+            burn();
+        }
+    }
+}
+
+/// Acknowledgment dialog for `MainnetBurnGuard`
+#[component]
+pub fn MainnetBurnAck(guard: MainnetBurnGuard) -> impl IntoView {
+    let confirm = move |_| {
+        guard.acknowledged.set(true);
+        if let Some(burn) = guard.pending.get_untracked() {
+            guard.pending.set(None);
+            burn.call(());
+        }
+    };
+    let cancel = move |_| guard.pending.set(None);
+
+    view! {
+        <Show when=move || guard.pending.with(|pending| pending.is_some())>
+            <div class="modal-overlay" on:click=cancel>
+                <div class="modal-content mainnet-burn-ack" on:click=|e| e.stop_propagation()>
+                    <div class="modal-header">
+                        <h3>
+                            <i class="fas fa-exclamation-triangle"></i>
+                            " You are on MAINNET"
+                        </h3>
+                    </div>
+                    <div class="modal-body">
+                        <p>"This burn destroys real MEMO tokens and cannot be undone."</p>
+                        <p>"If you meant to try this out, switch to Testnet at login. You will only be asked once per session."</p>
+                    </div>
+                    <div class="modal-footer">
+                        <button class="mainnet-burn-ack-cancel" on:click=cancel>"Cancel"</button>
+                        <button class="mainnet-burn-ack-confirm" on:click=confirm>
+                            <i class="fas fa-fire"></i>
+                            " Burn on Mainnet"
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -1,20 +1,57 @@
 use leptos::*;
 use leptos::html::Div;
 use wasm_bindgen::JsCast;
-use crate::core::session::Session;
-use crate::core::rpc_base::RpcConnection;
-use crate::core::rpc_chat::{ChatStatistics, ChatGroupInfo, LocalChatMessage, MessageStatus, BurnLeaderboardResponse, LeaderboardEntry, ChatContractTransaction};
+use crate::core::session::{cancellable, Session, SessionError};
+use crate::core::network_config::{explorer_address_url, explorer_tx_url};
+use crate::core::rpc_base::{Commitment, RpcConnection, RpcError, SimulationReport, tokens_to_lamports, validate_burn_tokens};
+use crate::core::format::{format_duration, format_memo, format_number_with_commas, format_xnt};
+use crate::core::constants::{LAMPORTS_PER_TOKEN, MIN_FEE_BALANCE_XNT};
+use crate::core::rpc_chat::{latest_group_ids_for_page, oldest_group_ids_for_page, has_next_groups_page, leaderboard_rank_range, top_burn_transactions, ChatStatistics, ChatGroupInfo, ChatMessage, LocalChatMessage, MessageStatus, GroupParticipants, incoming_messages, unique_senders, BurnLeaderboardResponse, LeaderboardEntry, ChatContractTransaction, MessageVerification, prepend_older_messages, validate_group_burn_size, group_burn_message_budget, encode_message_pixel, split_message_pixel, validate_chat_message_size, MESSAGE_PIXEL_SIZE};
+use crate::core::moderation;
+use crate::core::pagination::clamp_page;
+use crate::core::tx_error::classify_tx_error;
+use crate::core::pending_sends::{self, PendingSend};
+use crate::core::leaderboard;
+use crate::core::visited;
+use crate::core::last_seen;
+use crate::core::chat_drafts;
+use crate::core::i18n::{t, tf};
+use crate::core::text::{
+    contains_ignore_case, has_visible_text, safe_truncate, sanitize_display_text, shorten_middle, shorten_pubkey,
+};
+use crate::core::history::{self, HistoryError, LoadProgress};
+use crate::core::markdown_export::{chat_thread_markdown, ChatMarkdownLine};
+use crate::core::cache::{self, DEFAULT_MINT_REWARD_DISPLAY};
+use crate::core::preferences::{self, GroupsDisplayMode, ListViewMode, UiPreferences};
 use crate::core::rpc_profile::{UserDisplayInfo};
+use crate::core::rpc_domain;
 use crate::pages::log_view::add_log_entry;
-use crate::pages::pixel_view::{PixelView, LazyPixelView};
-use crate::core::pixel::Pixel;
+use crate::pages::pixel_view::{PixelView, LazyPixelView, PixelHistoryControls, PixelStringImport, PixelColorPicker, PixelTool, PixelToolSelector};
+use crate::pages::user_text::UserText;
+use crate::pages::view_mode_toggle::ViewModeToggle;
+use crate::pages::id_jump_box::IdJumpBox;
+use crate::pages::creation_notice::{CreationNotice, CreationStatus};
+use crate::pages::progress_overlay::ProgressOverlay;
+use crate::pages::rank_projection::RankProjectionHint;
+use crate::pages::raw_payload_view::RawPayloadView;
+use crate::pages::form_keys::advance_on_enter;
+use crate::pages::global_refresh::GlobalRefresh;
+use crate::pages::pubkey_display::{Nicknames, PubkeyDisplay};
+use crate::pages::relative_time::{format_timestamp, RelativeTime};
+use crate::pages::memo_size::memo_size_status;
+use crate::pages::message_alerts;
+use crate::pages::simulation_report::SimulationResult;
+use crate::pages::burn_network::{is_mainnet, BurnNetworkBadge, MainnetBurnGuard};
+use crate::core::pixel::{Pixel, PixelHistory, GROUP_IMAGE_SIZES};
 use wasm_bindgen_futures::spawn_local;
 use gloo_timers::future::TimeoutFuture;
+use futures::channel::oneshot;
 use web_sys::{HtmlInputElement, FileReader, Event, ProgressEvent, window};
 use wasm_bindgen::{closure::Closure};
 use js_sys::Uint8Array;
 use std::rc::Rc;
-use std::collections::HashMap;
+use std::time::Duration;
+use std::collections::{HashMap, HashSet};
 use futures;
 use gloo_timers::callback::Interval;
 
@@ -25,51 +62,113 @@ enum ChatView {
     ChatRoom(u64), // group_id
 }
 
-// Chat groups display mode
-#[derive(Clone, PartialEq, Debug)]
-enum GroupsDisplayMode {
-    BurnLeaderboard,
-    Latest,
-    Oldest,
-}
-
-impl ToString for GroupsDisplayMode {
-    fn to_string(&self) -> String {
-        match self {
-            GroupsDisplayMode::BurnLeaderboard => "Burn Leaderboard".to_string(),
-            GroupsDisplayMode::Latest => "Latest".to_string(),
-            GroupsDisplayMode::Oldest => "Oldest".to_string(),
-        }
-    }
-}
 
 #[component]
 pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     // state for burn leaderboard
     let (leaderboard_data, set_leaderboard_data) = create_signal::<Option<BurnLeaderboardResponse>>(None);
     let (total_groups, set_total_groups) = create_signal(0u64); // total groups
+    let (groups_total_known, set_groups_total_known) = create_signal(false); // total_groups has been read from chain
     let (leaderboard_group_infos, set_leaderboard_group_infos) = create_signal::<std::collections::HashMap<u64, ChatGroupInfo>>(std::collections::HashMap::new());
+    let (failed_group_infos, set_failed_group_infos) = create_signal::<Vec<u64>>(vec![]); // group infos that failed to load
+    let (retrying_group_infos, set_retrying_group_infos) = create_signal::<HashSet<u64>>(HashSet::new());
+    // parts of the leaderboard load that failed while the rest rendered
+    let (leaderboard_warnings, set_leaderboard_warnings) = create_signal::<Vec<String>>(vec![]);
     let (loading, set_loading) = create_signal(true);
     let (error_message, set_error_message) = create_signal::<Option<String>>(None);
+    // Send and burn errors, shown above the composer. They clear after a few
+    // seconds or on the next keystroke; only the latest timer may clear.
+    let (composer_error, set_composer_error) = create_signal::<Option<String>>(None);
+    let composer_error_generation = store_value(0_u64);
+    let show_composer_error = move |message: String| {
+        composer_error_generation.update_value(|generation| *generation += 1);
+        let generation = composer_error_generation.get_value();
+        set_composer_error.set(Some(message));
+        set_timeout(
+            move || {
+                if composer_error_generation.try_get_value() == Some(generation) {
+                    set_composer_error.set(None);
+                }
+            },
+            Duration::from_millis(COMPOSER_ERROR_DISMISS_MS),
+        );
+    };
     let (current_view, set_current_view) = create_signal(ChatView::GroupsList);
     
     // Featured Activity state
     let (featured_burns, set_featured_burns) = create_signal::<Vec<ChatContractTransaction>>(vec![]);
     let (current_featured_index, set_current_featured_index) = create_signal(0_usize);
     
-    // pagination state
-    let (current_page, set_current_page) = create_signal(1usize);
-    let (groups_per_page, _) = create_signal(10usize); // 10 groups per page
-    
     // groups display mode state
-    let (display_mode, set_display_mode) = create_signal(GroupsDisplayMode::BurnLeaderboard);
+    // the default mode is read once on mount; changing it in settings applies on next load
+    let initial_preferences = UiPreferences::load();
+
+    // pagination state; page sizes come from the preferences and are read
+    // again whenever a list or room loads, see `sync_page_sizes`
+    let (current_page, set_current_page) = create_signal(1usize);
+    let (groups_per_page, set_groups_per_page) = create_signal(initial_preferences.groups_page_size());
+    let messages_per_page = store_value(initial_preferences.messages_page_size());
+    // Pick up page sizes changed in settings. A new groups page size starts
+    // over on page 1, since the old page number may be out of range; returns
+    // whether that happened.
+    let sync_page_sizes = move || {
+        let preferences = UiPreferences::load();
+        messages_per_page.set_value(preferences.messages_page_size());
+        let per_page = preferences.groups_page_size();
+        let resized = per_page != groups_per_page.get_untracked();
+        if resized {
+            set_current_page.set(1);
+            set_groups_per_page.set(per_page);
+        }
+        resized
+    };
+    let initial_display_mode = initial_preferences.default_groups_display_mode;
+    let (display_mode, set_display_mode) = create_signal(initial_display_mode);
+    let (view_mode, set_view_mode) = create_signal(initial_preferences.groups_view_mode);
+    let developer_mode = initial_preferences.developer_mode;
     let (latest_groups, set_latest_groups) = create_signal::<Vec<ChatGroupInfo>>(vec![]);
     let (oldest_groups, set_oldest_groups) = create_signal::<Vec<ChatGroupInfo>>(vec![]);
+    let (favorite_groups, set_favorite_groups) = create_signal::<Vec<ChatGroupInfo>>(vec![]);
+    let (favorite_ids, set_favorite_ids) = create_signal(preferences::load_favorite_groups());
+    let (visited_ids, set_visited_ids) = create_signal(visited::load_visited_groups());
+    let (read_until, set_read_until) = create_signal(last_seen::load_read_until());
     let (mode_loading, set_mode_loading) = create_signal(false);
     
     // Chat room specific states
     let (current_group_info, set_current_group_info) = create_signal::<Option<ChatGroupInfo>>(None);
+    // Whether the connected wallet created the current group
+    let is_group_creator = create_memo(move |_| {
+        let Some(info) = current_group_info.get() else {
+            return false;
+        };
+        session.with(|s| match s.get_public_key() {
+            Ok(pubkey) => pubkey == info.creator,
+            Err(_) => false,
+        })
+    });
     let (messages, set_messages) = create_signal::<Vec<LocalChatMessage>>(vec![]);
+    // Paging back through the room's history: the `before` cursor for the next
+    // older page, whether the chain has nothing older, and a load in progress
+    let (older_cursor, set_older_cursor) = create_signal(Option::<String>::None);
+    let (all_loaded, set_all_loaded) = create_signal(false);
+    // Seconds between automatic refreshes of the open room, None when off
+    let (auto_refresh_secs, set_auto_refresh_secs) = create_signal(Option::<usize>::None);
+    // Bumped to stop the running auto-refresh loop
+    let auto_refresh_generation = store_value(0u64);
+    let (loading_older, set_loading_older) = create_signal(false);
+    // Distinct posters among the loaded messages, None until some are loaded
+    let room_participants = create_memo(move |_| {
+        messages.with(|messages| {
+            let chain = || messages.iter().filter(|m| !m.is_local).map(|m| &m.message);
+            let scanned = chain().count();
+            (scanned > 0).then(|| GroupParticipants {
+                senders: unique_senders(chain()),
+                scanned,
+                complete: all_loaded.get(),
+            })
+        })
+    });
     let (message_input, set_message_input) = create_signal(String::new());
     let (sending, set_sending) = create_signal(false);
 
@@ -79,8 +178,111 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
     // add new state for burn function
     let (action_type, set_action_type) = create_signal("message".to_string()); // "message" 或 "burn"
     let (burn_amount, set_burn_amount) = create_signal("1".to_string());
+    // Entered burn amount is more than the wallet holds
+    let burn_exceeds_balance = move || {
+        burn_amount.get().trim().parse::<f64>().unwrap_or(0.0) > session.with(|s| s.get_token_balance())
+    };
+    // Phone-sized viewport: header buttons show only icons and the composer options start collapsed
+    let (is_narrow, set_is_narrow) = create_signal(is_narrow_viewport());
+    let resize_listener = window_event_listener(ev::resize, move |_| {
+        let narrow = is_narrow_viewport();
+        if narrow != is_narrow.get_untracked() {
+            set_is_narrow.set(narrow);
+        }
+    });
+    on_cleanup(move || resize_listener.remove());
+
+    // Burn presets and hints under the composer, collapsed unless the user expanded them.
+    // On narrow viewports they always start collapsed and toggling is not saved.
+    let (composer_expanded, set_composer_expanded) =
+        create_signal(UiPreferences::load().composer_options_expanded && !is_narrow.get_untracked());
+    let toggle_composer_options = move |_| {
+        let expanded = !composer_expanded.get_untracked();
+        set_composer_expanded.set(expanded);
+        if is_narrow.get_untracked() {
+            return;
+        }
+        if let Err(e) = UiPreferences::update(|p| p.composer_options_expanded = expanded) {
+            log::warn!("Failed to save preferences: {}", e);
+        }
+    };
+    // Not enough XNT left for fees: the composer is disabled until the balance recovers
+    let low_fee_balance = move || session.with(|s| s.has_low_fee_balance());
+    let (checking_balance, set_checking_balance) = create_signal(false);
+    let check_balance = move |_| {
+        set_checking_balance.set(true);
+        spawn_local(async move {
+            let mut session_update = session.get_untracked();
+            match session_update.fetch_and_update_balances().await {
+                Ok(()) => session.update(|s| {
+                    s.set_balances(session_update.get_sol_balance(), session_update.get_token_balance());
+                }),
+                Err(e) => log::error!("Failed to update balances: {}", e),
+            }
+            set_checking_balance.set(false);
+        });
+    };
+    // Estimated leaderboard rank of the current group if the entered burn lands
+    let burn_rank_projection = Signal::derive(move || {
+        let added = burn_amount.get().trim().parse::<u64>().ok().filter(|amount| *amount > 0)?;
+        let info = current_group_info.get()?;
+        leaderboard_data.with(|leaderboard| {
+            let entries: Vec<(u64, u64)> = leaderboard
+                .as_ref()?
+                .entries
+                .iter()
+                .map(|entry| (entry.group_id, entry.burned_amount))
+                .collect();
+            Some(leaderboard::project_rank(&entries, info.group_id, info.burned_amount, added.saturating_mul(LAMPORTS_PER_TOKEN)))
+        })
+    });
     let (burn_message, set_burn_message) = create_signal(String::new());
     let (burning, set_burning) = create_signal(false);
+    // Message the next burn replies to, set by a message's "Reply with burn" action
+    let (burn_reply_to, set_burn_reply_to) = create_signal(Option::<ChatMessage>::None);
+    // Message the next chat message replies to, set by a message's "Reply" action
+    let (message_reply_to, set_message_reply_to) = create_signal(Option::<ChatMessage>::None);
+    // Why the current burn input can't be sent as one memo, if it can't
+    let burn_size_error = move || {
+        let ChatView::ChatRoom(group_id) = current_view.get() else {
+            return None;
+        };
+        let message = burn_message.get();
+        let amount = burn_amount.get().trim().parse::<u64>().unwrap_or(1).saturating_mul(LAMPORTS_PER_TOKEN);
+        let burner = session.with(|s| s.get_public_key()).unwrap_or_default();
+        let reply_to_sig = burn_reply_to.with(|reply| reply.as_ref().map(|m| m.signature.clone()));
+        validate_group_burn_size(group_id, &burner, reply_to_sig.as_deref(), message.trim(), amount).err()
+    };
+    // Bytes of the burn message used and available, for the live counter
+    let burn_message_budget = move || {
+        let ChatView::ChatRoom(group_id) = current_view.get() else {
+            return None;
+        };
+        let message = burn_message.get();
+        let amount = burn_amount.get().trim().parse::<u64>().unwrap_or(1).saturating_mul(LAMPORTS_PER_TOKEN);
+        let burner = session.with(|s| s.get_public_key()).unwrap_or_default();
+        let reply_to_sig = burn_reply_to.with(|reply| reply.as_ref().map(|m| m.signature.clone()));
+        group_burn_message_budget(group_id, &burner, reply_to_sig.as_deref(), message.trim(), amount).ok()
+    };
+    // Pixel image attached to the next chat message, and whether its editor is open
+    let (message_pixel, set_message_pixel) = create_signal(Option::<Pixel>::None);
+    let (pixel_editor_open, set_pixel_editor_open) = create_signal(false);
+    // The next chat message as sent: the input text plus the attached image, if any
+    let outgoing_message = move || {
+        let pixel = message_pixel.with(|pixel| {
+            pixel.as_ref().filter(|p| !p.is_blank()).map(Pixel::to_optimal_string)
+        });
+        encode_message_pixel(message_input.get().trim(), pixel.as_deref())
+    };
+    // Why the current message and image can't be sent as one memo, if they can't
+    let message_size_error = move || {
+        let ChatView::ChatRoom(group_id) = current_view.get() else {
+            return None;
+        };
+        let sender = session.with(|s| s.get_public_key()).unwrap_or_default();
+        let reply_to_sig = message_reply_to.with(|reply| reply.as_ref().map(|m| m.signature.clone()));
+        validate_chat_message_size(group_id, &sender, reply_to_sig.as_deref(), &outgoing_message()).err()
+    };
 
     // Node ref for messages area to enable auto-scroll
     let messages_area_ref = create_node_ref::<Div>();
@@ -88,14 +290,87 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
     // Create Chat Group Dialog states
     let (show_create_dialog, set_show_create_dialog) = create_signal(false);
     
-    // Add countdown state for waiting blockchain update
-    let countdown_seconds = create_rw_signal(0i32);
-    let is_waiting_for_blockchain = create_rw_signal(false);
+    // Newly created group, while waiting for it to be readable on chain
+    let (group_creation, set_group_creation) = create_signal(Option::<CreationStatus>::None);
     
     // Add user display cache state
     let (user_display_cache, set_user_display_cache) = create_signal::<HashMap<String, UserDisplayInfo>>(HashMap::new());
+    // X1NS primary domains of message senders (None: the sender has no domain)
+    let (sender_domains, set_sender_domains) = create_signal::<HashMap<String, Option<String>>>(HashMap::new());
+
+    // Messages hidden locally by the user (client-side only, see core::moderation)
+    let (hidden_signatures, set_hidden_signatures) = create_signal::<HashSet<String>>(moderation::hidden_signatures());
+
+    // Messages actually rendered in the chat room after local filters
+    let visible_messages = move || {
+        let hidden = hidden_signatures.get();
+        messages.get()
+            .into_iter()
+            .filter(|m| !hidden.contains(&m.message.signature))
+            .collect::<Vec<_>>()
+    };
+
+    // Client-side search over the loaded messages of the room
+    let (message_search, set_message_search) = create_signal(String::new());
+    let search_query = Signal::derive(move || message_search.get().trim().to_string());
+    let nicknames = Nicknames::from_context();
+
+    // Visible messages whose text or sender name matches the search, all of them without one
+    let searched_messages = move || {
+        let visible = visible_messages();
+        let query = search_query.get();
+        if query.is_empty() {
+            return visible;
+        }
+        user_display_cache.with(|cache| sender_domains.with(|domains| {
+            visible
+                .into_iter()
+                .filter(|m| {
+                    let sender = &m.message.sender;
+                    contains_ignore_case(&split_message_pixel(&m.message.message).0, &query)
+                        || contains_ignore_case(&format_sender_name(cache, domains, sender), &query)
+                        || nicknames.get(sender).is_some_and(|nickname| contains_ignore_case(&nickname, &query))
+                })
+                .collect::<Vec<_>>()
+        }))
+    };
+
+    let hidden_in_room_count = create_memo(move |_| {
+        messages.get().len() - visible_messages().len()
+    });
+
+    // First message that arrived since the last visit: the "New messages"
+    // divider is drawn above it until the user scrolls past it
+    let (new_since_signature, set_new_since_signature) = create_signal::<Option<String>>(None);
+    let scroll_to_divider = store_value(false);
+    let on_messages_scroll = move |_| {
+        if new_since_signature.get_untracked().is_none() {
+            return;
+        }
+        let Some(messages_area) = messages_area_ref.get_untracked() else { return };
+        let Ok(Some(divider)) = messages_area.query_selector(".new-messages-divider") else { return };
+        if divider.get_bounding_client_rect().bottom() < messages_area.get_bounding_client_rect().top() {
+            set_new_since_signature.set(None);
+        }
+    };
+
+    // Remember the newest on-chain message shown in the open room
+    create_effect(move |_| {
+        let ChatView::ChatRoom(group_id) = current_view.get() else { return };
+        messages.with(|messages| {
+            if let Some(newest) = messages.iter().rev().find(|m| !m.is_local) {
+                if let Err(e) = last_seen::mark_seen(group_id, &newest.message) {
+                    log::warn!("Failed to remember last seen message: {}", e);
+                }
+            }
+        });
+    });
 
-    // Auto-scroll to bottom when messages change
+    // Distance from the bottom of the messages area to restore after older
+    // messages are prepended, instead of jumping to the newest
+    let keep_scroll_from_bottom = store_value(Option::<i32>::None);
+
+    // Auto-scroll to bottom when messages change (to the divider right after entering a room)
     create_effect(move |_| {
         let _ = messages.get(); // Track messages changes
         
@@ -104,6 +379,18 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
             TimeoutFuture::new(100).await;
             
             if let Some(messages_area) = messages_area_ref.get() {
+                if let Some(from_bottom) = keep_scroll_from_bottom.get_value() {
+                    keep_scroll_from_bottom.set_value(None);
+                    messages_area.set_scroll_top(messages_area.scroll_height() - from_bottom);
+                    return;
+                }
+                if scroll_to_divider.get_value() {
+                    scroll_to_divider.set_value(false);
+                    if let Ok(Some(divider)) = messages_area.query_selector(".new-messages-divider") {
+                        divider.scroll_into_view_with_bool(true);
+                        return;
+                    }
+                }
                 // Scroll to maximum position to show new messages above the input area
                 // scrollHeight - clientHeight gives the maximum scrollable position
                 // The 300px bottom padding ensures messages stay visible above the fixed input
@@ -115,120 +402,90 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         });
     });
 
-    // Function to sort leaderboard entries by burned_amount and update ranks
-    let sort_leaderboard = move |mut leaderboard: BurnLeaderboardResponse| -> BurnLeaderboardResponse {
-        log::info!("Sorting {} leaderboard entries by burned_amount", leaderboard.entries.len());
-        
-        // Sort entries by burned_amount in descending order
-        leaderboard.entries.sort_by(|a, b| b.burned_amount.cmp(&a.burned_amount));
-        
-        // Update ranks after sorting
-        for (index, entry) in leaderboard.entries.iter_mut().enumerate() {
-            entry.rank = (index + 1) as u8;
-        }
-        
-        if !leaderboard.entries.is_empty() {
-            log::info!("Top 3 groups after sorting: #1: {} ({}), #2: {} ({}), #3: {} ({})", 
-                      leaderboard.entries.get(0).map(|e| e.group_id).unwrap_or(0),
-                      leaderboard.entries.get(0).map(|e| e.burned_amount / 1_000_000).unwrap_or(0),
-                      leaderboard.entries.get(1).map(|e| e.group_id).unwrap_or(0),
-                      leaderboard.entries.get(1).map(|e| e.burned_amount / 1_000_000).unwrap_or(0),
-                      leaderboard.entries.get(2).map(|e| e.group_id).unwrap_or(0),
-                      leaderboard.entries.get(2).map(|e| e.burned_amount / 1_000_000).unwrap_or(0)
-            );
-        }
-        
-        leaderboard
-    };
-
-    // Load burn leaderboard, global stats, and featured burns on component mount
-    spawn_local(async move {
+    // Load the burn leaderboard (and on first load the featured burns) into the page state;
+    // shared by the initial load and the refresh button so both paths stay in sync
+    let reload_leaderboard = move |is_refresh: bool| spawn_local(async move {
         set_loading.set(true);
         set_error_message.set(None);
         
-        add_log_entry("INFO", "Loading burn leaderboard, global stats, and featured burns...");
-        
-        let rpc = RpcConnection::new();
-        
-        // parallel get leaderboard data, global stats, and recent transactions
-        let leaderboard_future = rpc.get_burn_leaderboard();
-        let global_stats_future = rpc.get_chat_global_statistics();
-        let transactions_future = rpc.get_recent_chat_contract_transactions();
+        if is_refresh {
+            cache::clear_chat_group_infos();
+            add_log_entry("INFO", "Refreshing burn leaderboard and global stats...");
+        } else {
+            add_log_entry("INFO", "Loading burn leaderboard, global stats, and featured burns...");
+        }
         
-        match futures::join!(leaderboard_future, global_stats_future, transactions_future) {
-            (Ok(leaderboard), Ok(global_stats), Ok(transactions_response)) => {
-                // Sort leaderboard by burned_amount
-                let sorted_leaderboard = sort_leaderboard(leaderboard);
-                
-                add_log_entry("INFO", &format!("Loaded {} groups in burn leaderboard, {} total groups, {} recent transactions", 
-                             sorted_leaderboard.entries.len(), global_stats.total_groups, transactions_response.transactions.len()));
-                
-                // Filter and sort burn transactions by burn_amount (descending) to get top burns
-                let mut burn_transactions: Vec<ChatContractTransaction> = transactions_response.transactions.iter()
-                    .filter(|tx| tx.burn_amount > 0)
-                    .cloned()
-                    .collect();
-                
-                // Sort by burn_amount (descending) to show biggest burns first
-                burn_transactions.sort_by(|a, b| b.burn_amount.cmp(&a.burn_amount));
-                
-                // Take top 3 for featured section
-                let featured: Vec<ChatContractTransaction> = burn_transactions.iter()
-                    .take(3)
-                    .cloned()
-                    .collect();
-                
-                add_log_entry("INFO", &format!("Featured {} burn transactions with highest amounts", featured.len()));
-                set_featured_burns.set(featured);
-                
-                // parallel get all group infos in leaderboard
-                let mut group_info_futures = vec![];
-                for entry in &sorted_leaderboard.entries {
-                    group_info_futures.push(rpc.get_chat_group_info(entry.group_id));
+        match load_leaderboard(!is_refresh).await {
+            Ok(loaded) => {
+                if let Some(featured) = loaded.featured_burns {
+                    add_log_entry("INFO", &format!("Featured {} burn transactions with highest amounts", featured.len()));
+                    set_featured_burns.set(featured);
                 }
                 
-                let mut all_group_infos = std::collections::HashMap::new();
-                
-                for (i, future) in group_info_futures.into_iter().enumerate() {
-                    match future.await {
-                        Ok(group_info) => {
-                            all_group_infos.insert(sorted_leaderboard.entries[i].group_id, group_info);
-                        },
-                        Err(e) => {
-                            log::warn!("Failed to get group info for group {}: {}", sorted_leaderboard.entries[i].group_id, e);
-                        }
-                    }
+                // set whatever loaded; a part that failed keeps its previous data
+                if let Some(total_groups) = loaded.total_groups {
+                    set_total_groups.set(total_groups);
+                    set_groups_total_known.set(true);
                 }
-                
-                // set all data
-                set_leaderboard_data.set(Some(sorted_leaderboard));
-                set_total_groups.set(global_stats.total_groups);
-                set_leaderboard_group_infos.set(all_group_infos);
+                for warning in &loaded.warnings {
+                    add_log_entry("WARN", warning);
+                }
+                set_leaderboard_warnings.set(loaded.warnings);
                 set_error_message.set(None);
+                let Some(leaderboard) = loaded.leaderboard else {
+                    set_loading.set(false);
+                    return;
+                };
+                let leaderboard_len = leaderboard.entries.len();
+                set_leaderboard_data.set(Some(leaderboard));
+                set_leaderboard_group_infos.set(loaded.group_infos);
+                if !loaded.failed_group_ids.is_empty() {
+                    add_log_entry("WARN", &format!("{} groups failed to load", loaded.failed_group_ids.len()));
+                }
+                set_failed_group_infos.set(loaded.failed_group_ids);
+                if is_refresh {
+                    // stay on the same page, unless the leaderboard no longer has it
+                    let per_page = groups_per_page.get_untracked();
+                    set_current_page.update(|page| {
+                        *page = clamp_page(*page, leaderboard_len, per_page)
+                    });
+                }
             },
-            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
-                let error_msg = format!("Failed to load data: {}", e);
+            Err(e) => {
+                let error_msg = if is_refresh {
+                    format!("Failed to refresh data: {}", e)
+                } else {
+                    format!("Failed to load data: {}", e)
+                };
                 add_log_entry("ERROR", &error_msg);
+                set_leaderboard_warnings.set(vec![]);
                 set_error_message.set(Some(error_msg));
             }
         }
         
         set_loading.set(false);
     });
+    let load_initial_leaderboard = move || reload_leaderboard(false);
 
-    // Load current mint reward
+    // Re-fetch only the group infos that failed, without a full refresh
+    let retry_group_infos = move |group_ids: Vec<u64>| {
+        set_retrying_group_infos.update(|retrying| retrying.extend(group_ids.iter().copied()));
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            let (loaded, still_failed) = load_group_infos(&rpc, &group_ids).await;
+            
+            add_log_entry("INFO", &format!("Retried {} group infos, {} loaded", group_ids.len(), loaded.len()));
+            set_leaderboard_group_infos.update(|infos| infos.extend(loaded));
+            set_failed_group_infos.update(|failed| {
+                failed.retain(|id| !group_ids.contains(id) || still_failed.contains(id));
+            });
+            set_retrying_group_infos.update(|retrying| retrying.retain(|id| !group_ids.contains(id)));
+        });
+    };
+
+    // Load current mint reward (shared short-TTL cache, falls back to the default reward)
     spawn_local(async move {
-        let rpc = RpcConnection::new();
-        match rpc.get_current_mint_reward_formatted().await {
-            Ok(reward) => {
-                set_current_mint_reward.set(Some(reward));
-            },
-            Err(e) => {
-                log::warn!("Failed to get current mint reward: {}", e);
-                // Use default if unable to fetch
-                set_current_mint_reward.set(Some("+1 MEMO".to_string()));
-            }
-        }
+        set_current_mint_reward.set(Some(cache::get_current_mint_reward_formatted().await));
     });
 
     // Auto-rotate featured cards every 30 seconds
@@ -246,15 +503,107 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         std::mem::forget(interval_handle);
     }
 
+    // Ticking clock for the group cooldown countdown in the chat room
+    let (now_secs, set_now_secs) = create_signal((js_sys::Date::now() / 1000.0) as i64);
+    {
+        let interval_handle = Interval::new(1_000, move || {
+            set_now_secs.set((js_sys::Date::now() / 1000.0) as i64);
+        });
+        on_cleanup(move || drop(interval_handle));
+    }
+
+    // Wait after a send, so the next one doesn't hit the group's min interval
+    // on chain (MemoTooFrequent): the room's group info gets the send as its
+    // last memo until it is reloaded, and the countdown follows its cooldown
+    let start_send_wait = move |group_id: u64| {
+        if current_view.get_untracked() != ChatView::ChatRoom(group_id) {
+            return;
+        }
+        let now = (js_sys::Date::now() / 1000.0) as i64;
+        set_current_group_info.update(|info| {
+            if let Some(info) = info.as_mut().filter(|info| info.group_id == group_id) {
+                info.last_memo_time = info.last_memo_time.max(now);
+            }
+        });
+    };
+    let send_wait_remaining = move || {
+        current_group_info.with(|info| info.as_ref().map_or(0, |info| info.cooldown_remaining(now_secs.get())))
+    };
+
+    // Estimated network fee of a message or burn, None until loaded (or if
+    // it can't be), in which case the composer shows the minimum balance
+    let (fee_estimate, set_fee_estimate) = create_signal(Option::<u64>::None);
+    let load_fee_estimate = move || {
+        let Ok(payer) = session.with_untracked(|s| s.get_public_key()) else { return };
+        let Ok(payer) = payer.parse::<solana_sdk::pubkey::Pubkey>() else { return };
+        spawn_local(async move {
+            match cache::get_fee_estimate(&RpcConnection::new(), &payer).await {
+                Ok(fee) => { let _ = set_fee_estimate.try_set(Some(fee)); }
+                Err(e) => {
+                    log::warn!("Failed to estimate transaction fee: {}", e);
+                    let _ = set_fee_estimate.try_set(None);
+                }
+            }
+        });
+    };
+
+    // Slow-posting notice: shown for long intervals, or while the cooldown is running
+    let interval_notice = move || {
+        current_group_info.with(|info| {
+            let info = info.as_ref()?;
+            let remaining = info.cooldown_remaining(now_secs.get());
+            (info.min_memo_interval >= HIGH_MIN_INTERVAL_SECS || remaining > 0)
+                .then_some((info.min_memo_interval, remaining))
+        })
+    };
+
     // Function to enter a chat room
+    // Keep the open room's unsent inputs so they are back on the next visit
+    let stash_draft = move || {
+        let Some(ChatView::ChatRoom(group_id)) = current_view.try_get_untracked() else {
+            return;
+        };
+        let message = message_input.try_get_untracked().unwrap_or_default();
+        let burn = burn_message.try_get_untracked().unwrap_or_default();
+        if let Err(e) = chat_drafts::update_draft(group_id, |draft| {
+            draft.message = message;
+            draft.burn_message = burn;
+        }) {
+            log::warn!("Failed to save chat draft: {}", e);
+        }
+    };
+    on_cleanup(stash_draft);
+
     let enter_chat_room = move |group_id: u64| {
+        // read before the room's messages update the record
+        let previously_seen = last_seen::load_last_seen(group_id);
+        sync_page_sizes();
+        stash_draft();
+        let draft = chat_drafts::load_draft(group_id);
+        set_message_input.set(draft.message);
+        set_burn_message.set(draft.burn_message);
+        set_new_since_signature.set(None);
+        load_fee_estimate();
         set_current_view.set(ChatView::ChatRoom(group_id));
+        set_burn_reply_to.set(None);
+        set_message_reply_to.set(None);
+        set_message_search.set(String::new());
+        set_older_cursor.set(None);
+        set_all_loaded.set(false);
+        match visited::mark_group_visited(group_id) {
+            Ok(ids) => set_visited_ids.set(ids),
+            Err(e) => log::warn!("Failed to remember visited group: {}", e),
+        }
         
         // get full group info by group_id
         spawn_local(async move {
             let rpc = RpcConnection::new();
-            match rpc.get_chat_group_info(group_id).await {
+            match cache::get_chat_group_info(&rpc, group_id).await {
                 Ok(group_info) => {
+                    match last_seen::mark_opened(group_id, group_info.last_memo_time) {
+                        Ok(()) => set_read_until.set(last_seen::load_read_until()),
+                        Err(e) => log::warn!("Failed to remember opened group: {}", e),
+                    }
                     set_current_group_info.set(Some(group_info));
                 },
                 Err(e) => {
@@ -269,14 +618,26 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
             add_log_entry("INFO", &format!("Loading messages for group {}", group_id));
             
             let rpc = RpcConnection::new();
-            match rpc.get_chat_messages(group_id, Some(20), None).await {
+            match rpc.get_chat_messages(group_id, Some(messages_per_page.get_value()), None).await {
                 Ok(messages_response) => {
                     add_log_entry("INFO", &format!("Loaded {} messages", messages_response.messages.len()));
                     
-                    // Convert chain messages to local messages
+                    // Sends interrupted on a previous visit: drop the ones that landed
+                    let unresolved = pending_sends::reconcile_group(group_id, &messages_response.messages);
+                    if !unresolved.is_empty() {
+                        add_log_entry("WARN", &format!("{} earlier sends could not be confirmed", unresolved.len()));
+                    }
+                    
+                    let first_new = previously_seen
+                        .as_ref()
+                        .and_then(|seen| seen.first_unseen_index(&messages_response.messages))
+                        .map(|index| messages_response.messages[index].signature.clone());
+                    
+                    // Convert chain messages to local messages, keeping unresolved sends as unconfirmed
                     let local_messages: Vec<LocalChatMessage> = messages_response.messages
                         .into_iter()
                         .map(LocalChatMessage::from_chain_message)
+                        .chain(unresolved.iter().map(PendingSend::to_local_message))
                         .collect();
                     
                     // batch get user display info
@@ -290,8 +651,16 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     if !unique_senders.is_empty() {
                         let sender_refs: Vec<&str> = unique_senders.iter().map(|s| s.as_str()).collect();
                         
-                        // batch get user display info
-                        match rpc.get_user_display_info_batch(&sender_refs).await {
+                        // batch get user display info, and the senders' domains alongside
+                        let (display_result, domains_result) = futures::join!(
+                            rpc.get_user_display_info_batch(&sender_refs),
+                            rpc_domain::get_primary_domains_batch(&sender_refs),
+                        );
+                        match domains_result {
+                            Ok(domains) => set_sender_domains.update(|cache| cache.extend(domains)),
+                            Err(e) => log::warn!("Failed to load sender domains: {}", e),
+                        }
+                        match display_result {
                             Ok(display_infos) => {
                                 let mut cache = user_display_cache.get();
                                 for display_info in display_infos {
@@ -306,6 +675,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         }
                     }
                     
+                    scroll_to_divider.set_value(first_new.is_some());
+                    set_new_since_signature.set(first_new);
+                    set_all_loaded.set(!messages_response.has_more);
+                    set_older_cursor.set(messages_response.next_before);
                     set_messages.set(local_messages);
                     set_error_message.set(None);
                 },
@@ -319,96 +692,290 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         });
     };
 
+    // Open a group by id without paging, checking it exists first
+    let (group_jump_pending, set_group_jump_pending) = create_signal(false);
+    let (group_jump_error, set_group_jump_error) = create_signal::<Option<String>>(None);
+    let jump_to_group = move |group_id: u64| {
+        set_group_jump_pending.set(true);
+        set_group_jump_error.set(None);
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            match cache::get_chat_group_info(&rpc, group_id).await {
+                Ok(_) => enter_chat_room(group_id),
+                Err(e) => set_group_jump_error.set(Some(e.to_string())),
+            }
+            set_group_jump_pending.set(false);
+        });
+    };
+
+    // Full-history export of the open group. Sending on (or dropping) the
+    // stored sender stops the paging loop.
+    let (export_progress, set_export_progress) = create_signal::<Option<LoadProgress>>(None);
+    let export_cancel = store_value(Option::<oneshot::Sender<()>>::None);
+    let cancel_export = move || {
+        if let Some(cancel_tx) = export_cancel.try_update_value(|cancel| cancel.take()).flatten() {
+            let _ = cancel_tx.send(());
+        }
+    };
+    let export_history = move |group_id: u64| {
+        cancel_export();
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        export_cancel.set_value(Some(cancel_tx));
+        let estimated_total = current_group_info.get_untracked().map(|info| info.memo_count);
+        add_log_entry("INFO", &format!("Exporting message history of group {}", group_id));
+
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            let result = history::fetch_chat_history(&rpc, group_id, estimated_total, cancel_rx, move |progress| {
+                set_export_progress.set(Some(progress));
+            }).await;
+            set_export_progress.set(None);
+            export_cancel.set_value(None);
+
+            match result {
+                Ok(messages) => {
+                    let exported_at = (js_sys::Date::now() / 1000.0) as i64;
+                    match history::chat_history_json(group_id, &messages, exported_at) {
+                        Ok(json) => {
+                            download_json(&json, &format!("memo-chat-group-{}.json", group_id));
+                            add_log_entry("INFO", &format!("Exported {} messages of group {}", messages.len(), group_id));
+                        }
+                        Err(e) => set_error_message.set(Some(e)),
+                    }
+                }
+                Err(HistoryError::Cancelled) => add_log_entry("INFO", "History export cancelled"),
+                Err(e) => {
+                    add_log_entry("ERROR", &format!("Failed to export history: {}", e));
+                    set_error_message.set(Some(format!("Failed to export history: {}", e)));
+                }
+            }
+        });
+    };
+
     // Function to go back to groups list
     let back_to_groups = move |_| {
+        stash_draft();
+        // a new groups page size reloads the list, see the page size effect below
+        sync_page_sizes();
+        cancel_export();
+        set_composer_error.set(None);
+        set_new_since_signature.set(None);
         set_current_view.set(ChatView::GroupsList);
+        // messages read in the room clear the group's unread dot
+        set_read_until.set(last_seen::load_read_until());
         set_current_group_info.set(None);
         set_messages.set(vec![]);
+        set_message_search.set(String::new());
         set_message_input.set(String::new());
+        set_burn_message.set(String::new());
+        set_message_pixel.set(None);
+        set_pixel_editor_open.set(false);
     };
 
-    // Refresh data function for groups list
-    let refresh_groups_data = move |_| {
+    // Function to load groups by mode. `page` is moved back to the last
+    // page (and `current_page` with it) when the listing has fewer pages.
+    let load_groups_by_mode = move |mode: GroupsDisplayMode, page: usize| {
         spawn_local(async move {
-            set_loading.set(true);
+            set_mode_loading.set(true);
             set_error_message.set(None);
             
-            add_log_entry("INFO", "Refreshing burn leaderboard and global stats...");
-            
             let rpc = RpcConnection::new();
+            let per_page = groups_per_page.get();
             
-            // parallel get leaderboard data and global stats
-            let leaderboard_future = rpc.get_burn_leaderboard();
-            let global_stats_future = rpc.get_chat_global_statistics();
-            
-            match futures::join!(leaderboard_future, global_stats_future) {
-                (Ok(leaderboard), Ok(global_stats)) => {
-                    // Sort leaderboard by burned_amount
-                    let sorted_leaderboard = sort_leaderboard(leaderboard);
-                    
-                    add_log_entry("INFO", &format!("Refreshed {} groups in burn leaderboard, {} total groups", 
-                                 sorted_leaderboard.entries.len(), global_stats.total_groups));
-                    
-                    // parallel get all group infos in leaderboard
-                    let mut group_info_futures = vec![];
-                    for entry in &sorted_leaderboard.entries {
-                        group_info_futures.push(rpc.get_chat_group_info(entry.group_id));
+            match mode {
+                GroupsDisplayMode::Latest => {
+                    // Get total groups count first
+                    match rpc.get_chat_global_statistics().await {
+                        Ok(global_stats) => {
+                            let total_groups = global_stats.total_groups;
+                            set_total_groups.set(total_groups);
+                            set_groups_total_known.set(true);
+                            let page = clamp_page(page, total_groups as usize, per_page);
+                            if display_mode.get_untracked() == mode {
+                                set_current_page.set(page);
+                            }
+                            
+                            // Group ids for this page, latest first
+                            let group_ids = latest_group_ids_for_page(total_groups, page, per_page);
+                            
+                            let mut groups = vec![];
+                            for group_id in group_ids {
+                                // Skip non-existent groups
+                                if let Ok(group_info) = cache::get_chat_group_info(&rpc, group_id).await {
+                                    groups.push(group_info);
+                                }
+                            }
+                            
+                            add_log_entry("INFO", &format!("Loaded {} latest groups for page {}", groups.len(), page));
+                            set_latest_groups.set(groups);
+                        },
+                        Err(e) => {
+                            add_log_entry("ERROR", &format!("Failed to load latest groups: {}", e));
+                            set_error_message.set(Some(format!("Failed to load latest groups: {}", e)));
+                        }
                     }
-                    
-                    let mut all_group_infos = std::collections::HashMap::new();
-                    
-                    for (i, future) in group_info_futures.into_iter().enumerate() {
-                        match future.await {
-                            Ok(group_info) => {
-                                all_group_infos.insert(sorted_leaderboard.entries[i].group_id, group_info);
-                            },
-                            Err(e) => {
-                                log::warn!("Failed to get group info for group {}: {}", sorted_leaderboard.entries[i].group_id, e);
+                },
+                GroupsDisplayMode::Oldest => {
+                    // The total bounds the id range, so no lookups are spent on groups that don't exist
+                    let groups = match rpc.get_chat_global_statistics().await {
+                        Ok(global_stats) => {
+                            let total_groups = global_stats.total_groups;
+                            set_total_groups.set(total_groups);
+                            set_groups_total_known.set(true);
+                            let page = clamp_page(page, total_groups as usize, per_page);
+                            if display_mode.get_untracked() == mode {
+                                set_current_page.set(page);
                             }
+                            
+                            let group_ids = oldest_group_ids_for_page(total_groups, page, per_page);
+                            if group_ids.is_empty() {
+                                Ok(vec![])
+                            } else {
+                                rpc.get_chat_groups_range(group_ids.start, group_ids.end).await
+                            }
+                        },
+                        Err(e) => Err(e),
+                    };
+                    
+                    match groups {
+                        Ok(groups) => {
+                            add_log_entry("INFO", &format!("Loaded {} oldest groups for page {}", groups.len(), page));
+                            set_oldest_groups.set(groups);
+                        },
+                        Err(e) => {
+                            add_log_entry("ERROR", &format!("Failed to load oldest groups: {}", e));
+                            set_error_message.set(Some(format!("Failed to load oldest groups: {}", e)));
                         }
                     }
+                },
+                GroupsDisplayMode::Favorites => {
+                    let favorites = favorite_ids.get_untracked();
+                    let page = clamp_page(page, favorites.len(), per_page);
+                    set_current_page.set(page);
+                    let page_ids: Vec<u64> = favorites.iter()
+                        .skip((page - 1) * per_page)
+                        .take(per_page)
+                        .copied()
+                        .collect();
                     
-                    let total_messages: u64 = all_group_infos.values().map(|info| info.memo_count).sum();
-                    add_log_entry("INFO", &format!("Refreshed total messages in leaderboard: {}", total_messages));
+                    let mut groups = vec![];
+                    for group_id in page_ids {
+                        match cache::get_chat_group_info(&rpc, group_id).await {
+                            Ok(group_info) => groups.push(group_info),
+                            Err(e) => log::warn!("Failed to get favorite group {}: {}", group_id, e),
+                        }
+                    }
                     
-                    // set all data
-                    set_leaderboard_data.set(Some(sorted_leaderboard));
-                    set_total_groups.set(global_stats.total_groups);
-                    set_leaderboard_group_infos.set(all_group_infos);
-                    set_error_message.set(None);
-                    // reset to first page
-                    set_current_page.set(1);
+                    add_log_entry("INFO", &format!("Loaded {} favorite groups for page {}", groups.len(), page));
+                    set_favorite_groups.set(groups);
                 },
-                (Err(e), _) | (_, Err(e)) => {
-                    let error_msg = format!("Failed to refresh data: {}", e);
-                    add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                GroupsDisplayMode::BurnLeaderboard => {
+                    // Do nothing, handled by existing logic
                 }
             }
             
-            set_loading.set(false);
+            set_mode_loading.set(false);
+        });
+    };
+
+    // The leaderboard pages itself from `groups_per_page`; the other modes
+    // fetch one page at a time, so reload them when the page size changes
+    create_effect(move |previous: Option<usize>| {
+        let per_page = groups_per_page.get();
+        if previous.is_some_and(|previous| previous != per_page) {
+            match display_mode.get_untracked() {
+                GroupsDisplayMode::BurnLeaderboard => {}
+                mode => load_groups_by_mode(mode, 1),
+            }
+        }
+        per_page
+    });
+
+    // Refresh data function for groups list: reloads the leaderboard and stats,
+    // and the current page of the other modes, keeping the page the user is on
+    let refresh_groups_data = move || {
+        let resized = sync_page_sizes();
+        reload_leaderboard(true);
+        match display_mode.get_untracked() {
+            GroupsDisplayMode::BurnLeaderboard => {}
+            // already reloaded on page 1 by the page size effect
+            _ if resized => {}
+            mode => load_groups_by_mode(mode, current_page.get_untracked()),
+        }
+    };
+
+    // Chime and/or notify about messages from others that arrived in the open
+    // room while the tab is in the background, as chosen in settings
+    let alert_incoming_messages = move |group_id: u64, incoming: &[ChatMessage]| {
+        let Some(newest) = incoming.last() else { return };
+        if message_alerts::page_focused() {
+            return;
+        }
+        let preferences = UiPreferences::load();
+        if preferences.message_sound {
+            message_alerts::play_message_sound();
+        }
+        if !preferences.message_notifications {
+            return;
+        }
+        let group_name = current_group_info
+            .with_untracked(|info| info.as_ref().map(|info| info.name.clone()))
+            .unwrap_or_else(|| tf("chat.group.number", &[&group_id.to_string()]));
+        let sender = user_display_cache.with_untracked(|cache| {
+            sender_domains.with_untracked(|domains| format_sender_name(cache, domains, &newest.sender))
         });
+        let text = safe_truncate(&split_message_pixel(&newest.message).0, 120);
+        let body = if incoming.len() == 1 {
+            tf("chat.notify.body", &[&sender, &text])
+        } else {
+            tf("chat.notify.body_more", &[&sender, &text, &(incoming.len() - 1).to_string()])
+        };
+        message_alerts::show_notification(
+            &tf("chat.notify.title", &[&group_name]),
+            &body,
+            &format!("chat-group-{}", group_id),
+        );
     };
 
     // Refresh messages function for chat room
     let refresh_messages = move |group_id: u64| {
+        load_fee_estimate();
         spawn_local(async move {
             let rpc = RpcConnection::new();
-            match rpc.get_chat_messages(group_id, Some(20), None).await {
+            match rpc.get_chat_messages(group_id, Some(messages_per_page.get_value()), None).await {
                 Ok(messages_response) => {
                     if !messages_response.messages.is_empty() {
                         add_log_entry("INFO", &format!("Refreshed {} messages", messages_response.messages.len()));
                         
+                        pending_sends::reconcile_group(group_id, &messages_response.messages);
+                        
                         // Convert chain messages to local messages, preserving any local pending messages
                         let current_messages = messages.get();
+                        let own_pubkey = session.with_untracked(|s| s.get_public_key()).unwrap_or_default();
+                        let incoming: Vec<ChatMessage> = incoming_messages(&current_messages, &messages_response.messages, &own_pubkey)
+                            .into_iter()
+                            .cloned()
+                            .collect();
                         let mut new_local_messages: Vec<LocalChatMessage> = messages_response.messages
                             .into_iter()
                             .map(LocalChatMessage::from_chain_message)
                             .collect();
                         
+                        // Keep older history loaded with "Load older messages"
+                        let refreshed: HashSet<String> = new_local_messages
+                            .iter()
+                            .map(|m| m.message.signature.clone())
+                            .collect();
+                        let older_history: Vec<LocalChatMessage> = current_messages
+                            .iter()
+                            .filter(|m| !m.is_local && !refreshed.contains(&m.message.signature))
+                            .cloned()
+                            .collect();
+                        new_local_messages.extend(older_history);
+                        
                         // Add any local pending messages that are not yet on chain
                         for local_msg in current_messages {
-                            if local_msg.is_local && local_msg.status != MessageStatus::Sent {
+                            if local_msg.is_local && !matches!(local_msg.status, MessageStatus::Sent | MessageStatus::Confirmed) {
                                 // Check if this message is already on chain
                                 let is_on_chain = new_local_messages.iter().any(|chain_msg| {
                                     chain_msg.message.sender == local_msg.message.sender 
@@ -434,7 +1001,15 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         if !unique_senders.is_empty() {
                             let sender_refs: Vec<&str> = unique_senders.iter().map(|s| s.as_str()).collect();
                             
-                            match rpc.get_user_display_info_batch(&sender_refs).await {
+                            let (display_result, domains_result) = futures::join!(
+                                rpc.get_user_display_info_batch(&sender_refs),
+                                rpc_domain::get_primary_domains_batch(&sender_refs),
+                            );
+                            match domains_result {
+                                Ok(domains) => set_sender_domains.update(|cache| cache.extend(domains)),
+                                Err(e) => log::warn!("Failed to load sender domains: {}", e),
+                            }
+                            match display_result {
                                 Ok(display_infos) => {
                                     let mut cache = user_display_cache.get();
                                     for display_info in display_infos {
@@ -451,19 +1026,222 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         // Sort by timestamp
                         new_local_messages.sort_by(|a, b| a.message.timestamp.cmp(&b.message.timestamp));
                         set_messages.set(new_local_messages);
+
+                        if current_view.get_untracked() == ChatView::ChatRoom(group_id) {
+                            alert_incoming_messages(group_id, &incoming);
+                        }
+                    }
+                },
+                Err(e) => {
+                    add_log_entry("ERROR", &format!("Failed to refresh messages: {}", e));
+                }
+            }
+        });
+    };
+
+    // More history to page back through: the chain has older signatures and
+    // fewer messages are loaded than the group's memo count
+    let has_older_messages = move || {
+        if all_loaded.get() || older_cursor.with(Option::is_none) {
+            return false;
+        }
+        let loaded = messages.with(|msgs| msgs.iter().filter(|m| !m.is_local).count() as u64);
+        current_group_info.with(|info| info.as_ref().is_none_or(|info| loaded < info.memo_count))
+    };
+
+    // Prepend the page of messages before the oldest one loaded, keeping the scroll position
+    let load_older_messages = move |group_id: u64| {
+        let Some(before) = older_cursor.get_untracked() else { return };
+        set_loading_older.set(true);
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            match rpc.get_chat_messages(group_id, Some(messages_per_page.get_value()), Some(before)).await {
+                // the user left the room while the page loaded
+                Ok(_) if current_view.get_untracked() != ChatView::ChatRoom(group_id) => {}
+                Ok(messages_response) => {
+                    let senders: Vec<String> = messages_response.messages
+                        .iter()
+                        .map(|msg| msg.sender.clone())
+                        .collect::<HashSet<_>>()
+                        .into_iter()
+                        .filter(|sender| !user_display_cache.with_untracked(|cache| cache.contains_key(sender)))
+                        .collect();
+                    if !senders.is_empty() {
+                        let sender_refs: Vec<&str> = senders.iter().map(|s| s.as_str()).collect();
+                        let (display_result, domains_result) = futures::join!(
+                            rpc.get_user_display_info_batch(&sender_refs),
+                            rpc_domain::get_primary_domains_batch(&sender_refs),
+                        );
+                        match domains_result {
+                            Ok(domains) => set_sender_domains.update(|cache| cache.extend(domains)),
+                            Err(e) => log::warn!("Failed to load sender domains: {}", e),
+                        }
+                        match display_result {
+                            Ok(display_infos) => set_user_display_cache.update(|cache| {
+                                cache.extend(display_infos.into_iter().map(|info| (info.pubkey.clone(), info)));
+                            }),
+                            Err(e) => add_log_entry("WARN", &format!("Failed to load user display info: {}", e)),
+                        }
+                    }
+
+                    if let Some(messages_area) = messages_area_ref.get_untracked() {
+                        keep_scroll_from_bottom.set_value(Some(messages_area.scroll_height() - messages_area.scroll_top()));
+                    }
+                    let mut added = 0;
+                    set_messages.update(|msgs| added = prepend_older_messages(msgs, messages_response.messages));
+                    add_log_entry("INFO", &format!("Loaded {} older messages", added));
+                    set_all_loaded.set(!messages_response.has_more);
+                    set_older_cursor.set(messages_response.next_before);
+                }
+                Err(e) => {
+                    let error_msg = format!("Failed to load older messages: {}", e);
+                    add_log_entry("ERROR", &error_msg);
+                    set_error_message.set(Some(error_msg));
+                }
+            }
+            set_loading_older.set(false);
+        });
+    };
+
+    // The header's refresh reloads whichever view is open
+    GlobalRefresh::from_context().register(
+        Some("chat"),
+        move || match current_view.get_untracked() {
+            ChatView::ChatRoom(group_id) => refresh_messages(group_id),
+            ChatView::GroupsList => refresh_groups_data(),
+        },
+        Signal::derive(move || loading.get() || mode_loading.get()),
+    );
+
+    // Refresh the open room on the interval set in settings, skipping while
+    // the tab is hidden. Each room visit runs its own loop, which ends when
+    // the room is left (or the page unmounts and the counter is disposed).
+    create_effect(move |_| {
+        let view = current_view.get();
+        let generation = auto_refresh_generation.get_value() + 1;
+        auto_refresh_generation.set_value(generation);
+        let preferences = UiPreferences::load();
+        let ChatView::ChatRoom(group_id) = view else {
+            set_auto_refresh_secs.set(None);
+            return;
+        };
+        if !preferences.auto_refresh_messages {
+            set_auto_refresh_secs.set(None);
+            return;
+        }
+        let secs = preferences.auto_refresh_interval();
+        set_auto_refresh_secs.set(Some(secs));
+        spawn_local(async move {
+            loop {
+                TimeoutFuture::new(secs as u32 * 1_000).await;
+                if auto_refresh_generation.try_get_value() != Some(generation) {
+                    break;
+                }
+                if message_alerts::page_visible() && !loading.get_untracked() {
+                    refresh_messages(group_id);
+                }
+            }
+        });
+    });
+
+    // With message alerts on, check the open room for new messages while the
+    // tab is in the background, unless auto-refresh already does
+    {
+        let interval_handle = Interval::new(MESSAGE_ALERT_POLL_INTERVAL_MS, move || {
+            let Some(ChatView::ChatRoom(group_id)) = current_view.try_get_untracked() else { return };
+            let preferences = UiPreferences::load();
+            let auto_refreshing = auto_refresh_secs.try_get_untracked().flatten().is_some()
+                && message_alerts::page_visible();
+            if (preferences.message_notifications || preferences.message_sound)
+                && !message_alerts::page_focused()
+                && !auto_refreshing
+                && !loading.get_untracked()
+            {
+                refresh_messages(group_id);
+            }
+        });
+        on_cleanup(move || drop(interval_handle));
+    }
+
+    // Cancel handles for in-flight sends/burns, keyed by local message signature
+    let pending_cancels = store_value(HashMap::<String, oneshot::Sender<()>>::new());
+    let register_cancel = move |local_signature: String| -> oneshot::Receiver<()> {
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        pending_cancels.update_value(|pending| {
+            pending.insert(local_signature, cancel_tx);
+        });
+        cancel_rx
+    };
+
+    // Persist the send so an interrupted one can be reconciled on the next visit
+    let remember_send = move |group_id: u64, local: &LocalChatMessage| {
+        if let Err(e) = pending_sends::record_pending_send(PendingSend::from_local(group_id, local)) {
+            log::warn!("Failed to record pending send: {}", e);
+        }
+    };
+
+    // The send is over: drop its cancel handle and, unless the user only
+    // stopped waiting, its pending record
+    let finish_send = move |local_signature: &str, cancelled: bool| {
+        pending_cancels.update_value(|pending| {
+            pending.remove(local_signature);
+        });
+        if !cancelled {
+            if let Err(e) = pending_sends::remove_pending_send(local_signature) {
+                log::warn!("Failed to remove pending send: {}", e);
+            }
+        }
+    };
+
+    // Stop waiting for a pending send; the input is re-enabled once the wait is dropped
+    let cancel_pending = move |local_signature: String| {
+        if let Some(cancel_tx) = pending_cancels.try_update_value(|pending| pending.remove(&local_signature)).flatten() {
+            let _ = cancel_tx.send(());
+        }
+    };
+
+    // Forget an unconfirmed send after the user has checked it
+    let dismiss_unconfirmed = move |local_signature: String| {
+        if let Err(e) = pending_sends::remove_pending_send(&local_signature) {
+            log::warn!("Failed to remove pending send: {}", e);
+        }
+        set_messages.update(|msgs| msgs.retain(|m| !(m.is_local && m.message.signature == local_signature)));
+    };
+
+    // A cancelled send was only abandoned locally and may still land, so it must
+    // not be offered for retry like a failed one
+    let mark_unconfirmed = move |local_signature: &str| {
+        add_log_entry("WARN", "Stopped waiting for the transaction. It may still land on-chain; check before sending again.");
+        set_messages.update(|msgs| {
+            if let Some(msg) = msgs.iter_mut().find(|m| m.is_local && m.message.signature == local_signature) {
+                msg.status = MessageStatus::Unconfirmed;
+            }
+        });
+    };
+
+    // A returned signature only means the transaction was submitted; follow it
+    // until it is finalized so the checkmark means the message is durable
+    let confirm_in_background = move |signature: String| {
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            match rpc.wait_for_commitment(&signature, Commitment::Finalized, MESSAGE_CONFIRMATION_TIMEOUT_MS).await {
+                Ok(true) => set_messages.update(|msgs| {
+                    if let Some(msg) = msgs.iter_mut().find(|m| {
+                        m.is_local && m.message.signature == signature && m.status == MessageStatus::Sent
+                    }) {
+                        msg.status = MessageStatus::Confirmed;
                     }
-                },
-                Err(e) => {
-                    add_log_entry("ERROR", &format!("Failed to refresh messages: {}", e));
-                }
+                }),
+                Ok(false) => log::info!("Transaction {} not finalized yet, leaving it as sent", signature),
+                Err(e) => log::warn!("Failed to check confirmation of {}: {}", signature, e),
             }
         });
     };
 
     // Handle message sending
     let send_message = move |_ev: web_sys::MouseEvent| {
-        let message_text = message_input.get().trim().to_string();
-        if message_text.is_empty() {
+        let message_text = outgoing_message();
+        if message_text.is_empty() || message_size_error().is_some() {
             return;
         }
         
@@ -472,22 +1250,24 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
             if let Ok(user_pubkey) = session.with_untracked(|s| s.get_public_key()) {
                 // Check SOL balance before sending
                 let sol_balance = session.with_untracked(|s| s.get_sol_balance());
-                if sol_balance < 0.01 {
-                    let error_msg = format!("Balance insufficient! Current XNT balance: {:.4}, sending message requires at least 0.01 SOL as transaction fee. Please top up.", sol_balance);
+                if sol_balance < MIN_FEE_BALANCE_XNT {
+                    let error_msg = tf("chat.error.low_fee_balance", &[&format!("{:.4}", sol_balance), &MIN_FEE_BALANCE_XNT.to_string()]);
                     add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                    show_composer_error(error_msg);
                     return;
                 }
                 
                 // Clear any previous error messages
-                set_error_message.set(None);
+                set_composer_error.set(None);
                 
                 // 1. show message on UI immediately
-                let local_message = LocalChatMessage::new_local(
+                let reply_to_sig = message_reply_to.get_untracked().map(|m| m.signature);
+                let mut local_message = LocalChatMessage::new_local(
                     user_pubkey.clone(),
                     message_text.clone(),
                     group_id
                 );
+                local_message.message.reply_to_sig = reply_to_sig.clone();
                 
                 // add to current message list
                 set_messages.update(|msgs| {
@@ -496,7 +1276,16 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                 
                 // clear input and set sending state
                 set_message_input.set(String::new());
+                set_message_pixel.set(None);
+                set_pixel_editor_open.set(false);
+                set_message_reply_to.set(None);
+                if let Err(e) = chat_drafts::update_draft(group_id, |draft| draft.message.clear()) {
+                    log::warn!("Failed to clear chat draft: {}", e);
+                }
                 set_sending.set(true);
+                let local_signature = local_message.message.signature.clone();
+                remember_send(group_id, &local_message);
+                let cancel = register_cancel(local_signature.clone());
                 
                 // 2. short delay to update UI
                 spawn_local(async move {
@@ -507,15 +1296,19 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         group_id,
                         &message_text,
                         None, // receiver
-                        None, // reply_to_sig
-                        Some(30000) // timeout_ms: 30 seconds timeout
+                        reply_to_sig,
+                        Some(30000), // timeout_ms: 30 seconds timeout
+                        Some(cancel),
                     ).await;
+                    finish_send(&local_signature, matches!(result, Err(SessionError::Cancelled)));
                     
                     log::info!("Chat page: Received result from session: success={}", result.is_ok());
                     
                     match result {
+                        Err(SessionError::Cancelled) => mark_unconfirmed(&local_signature),
                         Ok(signature) => {
                             add_log_entry("INFO", &format!("Message sent successfully! Signature: {}", signature));
+                            start_send_wait(group_id);
                             
                             // 4. update local message status to sent
                             set_messages.update(|msgs| {
@@ -525,9 +1318,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     m.message.sender == user_pubkey
                                 }) {
                                     msg.status = MessageStatus::Sent;
-                                    msg.message.signature = signature; // update to real signature
+                                    msg.message.signature = signature.clone(); // update to real signature
                                 }
                             });
+                            confirm_in_background(signature);
                             
                             // 5. update session balance - directly update balance instead of just marking update needed
                             spawn_local(async move {
@@ -555,43 +1349,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         Err(e) => {
                             log::error!("Chat page: Error received from session: {}", e);
                             
-                            // Parse error to extract specific error message
-                            let error_string = e.to_string();
-                            let user_friendly_error = 
-                                // Try to extract specific error message after " - "
-                                if let Some(dash_pos) = error_string.rfind(" - ") {
-                                    let specific_msg = &error_string[dash_pos + 3..];
-                                    // Clean up the message (remove trailing dots if any)
-                                    let cleaned_msg = specific_msg.trim_end_matches('.');
-                                    if !cleaned_msg.is_empty() {
-                                        cleaned_msg.to_string()
-                                    } else {
-                                        // Fallback to checking known error types
-                                        if error_string.contains("MemoTooFrequent") || error_string.contains("6009") {
-                                            "Message sent too frequently. Please wait before sending another message.".to_string()
-                                        } else if error_string.contains("timeout") {
-                                            "Message send timeout. Please try again.".to_string()
-                                        } else if error_string.contains("insufficient") {
-                                            "Insufficient balance".to_string()
-                                        } else {
-                                            "Failed to send message. Please try again.".to_string()
-                                        }
-                                    }
-                                } else {
-                                    // Fallback to checking known error types
-                                    if error_string.contains("MemoTooFrequent") || error_string.contains("6009") {
-                                        "Message sent too frequently. Please wait before sending another message.".to_string()
-                                    } else if error_string.contains("timeout") {
-                                        "Message send timeout. Please try again.".to_string()
-                                    } else if error_string.contains("insufficient") {
-                                        "Insufficient balance".to_string()
-                                    } else {
-                                        "Failed to send message. Please try again.".to_string()
-                                    }
-                                };
+                            let user_friendly_error = chat_send_error(&e);
                             
                             add_log_entry("ERROR", &format!("Failed to send message: {}", user_friendly_error));
-                            set_error_message.set(Some(user_friendly_error.to_string()));
+                            show_composer_error(user_friendly_error.to_string());
                             
                             // 6. update local message status to failed
                             set_messages.update(|msgs| {
@@ -628,17 +1389,18 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
             if let Ok(user_pubkey) = session.with_untracked(|s| s.get_public_key()) {
                 // Check SOL balance before sending
                 let sol_balance = session.with_untracked(|s| s.get_sol_balance());
-                if sol_balance < 0.01 {
-                    let error_msg = format!("Balance insufficient! Current XNT balance: {:.4}, sending message requires at least 0.01 SOL as transaction fee. Please top up.", sol_balance);
+                if sol_balance < MIN_FEE_BALANCE_XNT {
+                    let error_msg = tf("chat.error.low_fee_balance", &[&format!("{:.4}", sol_balance), &MIN_FEE_BALANCE_XNT.to_string()]);
                     add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                    show_composer_error(error_msg);
                     return;
                 }
                 
                 // Clear any previous error messages
-                set_error_message.set(None);
+                set_composer_error.set(None);
                 
                 // 1. Update the failed message back to sending status
+                let mut retried = None;
                 set_messages.update(|msgs| {
                     if let Some(msg) = msgs.iter_mut().find(|m| {
                         m.is_local && 
@@ -648,8 +1410,17 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     }) {
                         log::info!("Updating message status from {:?} to Sending for retry", msg.status);
                         msg.status = MessageStatus::Sending;
+                        retried = Some(msg.clone());
                     }
                 });
+                let Some(retried) = retried else {
+                    log::error!("Could not find message to retry");
+                    return;
+                };
+                let local_signature = retried.message.signature.clone();
+                let reply_to_sig = retried.message.reply_to_sig.clone();
+                remember_send(group_id, &retried);
+                let cancel = register_cancel(local_signature.clone());
                 
                 set_sending.set(true);
                 
@@ -662,15 +1433,19 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         group_id,
                         &message_content,
                         None, // receiver
-                        None, // reply_to_sig
-                        Some(30000) // timeout_ms: 30 seconds timeout
+                        reply_to_sig,
+                        Some(30000), // timeout_ms: 30 seconds timeout
+                        Some(cancel),
                     ).await;
+                    finish_send(&local_signature, matches!(result, Err(SessionError::Cancelled)));
                     
                     log::info!("Retry result: success={}", result.is_ok());
                     
                     match result {
+                        Err(SessionError::Cancelled) => mark_unconfirmed(&local_signature),
                         Ok(signature) => {
                             add_log_entry("INFO", &format!("Message retry sent successfully! Signature: {}", signature));
+                            start_send_wait(group_id);
                             
                             // 4. update local message status to sent
                             set_messages.update(|msgs| {
@@ -680,9 +1455,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     m.message.sender == user_pubkey
                                 }) {
                                     msg.status = MessageStatus::Sent;
-                                    msg.message.signature = signature; // update to real signature
+                                    msg.message.signature = signature.clone(); // update to real signature
                                 }
                             });
+                            confirm_in_background(signature);
                             
                             // 5. update session balance - directly update balance instead of just marking update needed
                             spawn_local(async move {
@@ -710,19 +1486,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         Err(e) => {
                             log::error!("Retry failed: {}", e);
                             
-                            // Parse error to show user-friendly English message
-                            let user_friendly_error = if e.to_string().contains("MemoTooFrequent") || e.to_string().contains("6009") {
-                                "Message sent too frequently. Please wait before sending another message."
-                            } else if e.to_string().contains("timeout") {
-                                "Message send timeout. Please try again."
-                            } else if e.to_string().contains("insufficient") {
-                                "Insufficient balance"
-                            } else {
-                                "Failed to send message. Please try again."
-                            };
+                            let user_friendly_error = chat_send_error(&e);
                             
                             add_log_entry("ERROR", &format!("Retry failed: {}", user_friendly_error));
-                            set_error_message.set(Some(user_friendly_error.to_string()));
+                            show_composer_error(user_friendly_error.to_string());
                             
                             // 6. update local message status back to failed
                             set_messages.update(|msgs| {
@@ -756,19 +1523,6 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         }
     };
 
-    // Helper function to extract fallback error messages
-    let _extract_fallback_error_message = |error_str: &str| -> String {
-        if error_str.contains("MemoTooFrequent") || error_str.contains("6009") {
-            "Message sent too frequently. Please wait before sending another message.".to_string()
-        } else if error_str.contains("timeout") {
-            "Message send timeout. Please try again.".to_string()
-        } else if error_str.contains("insufficient") {
-            "Insufficient balance".to_string()
-        } else {
-            "Failed to send message. Please try again.".to_string()
-        }
-    };
-
     // Function to open create chat group dialog
     let open_create_dialog = move |_| {
         set_show_create_dialog.set(true);
@@ -783,33 +1537,30 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
     let on_group_created = move |signature: String, group_id: u64| {
         add_log_entry("INFO", &format!("Chat group created successfully! ID: {}, Signature: {}", group_id, signature));
         set_show_create_dialog.set(false);
+        set_group_creation.set(Some(CreationStatus::Waiting(group_id)));
         
-        // Start countdown
-        is_waiting_for_blockchain.set(true);
-        countdown_seconds.set(20);
-        
-        // Wait 20 seconds for blockchain state to update, then refresh groups
-        let countdown_clone = countdown_seconds.clone();
-        let waiting_clone = is_waiting_for_blockchain.clone();
-        
+        // Wait until the new group can be read, then refresh groups
         spawn_local(async move {
-            // Countdown from 20 to 0
-            for remaining in (0..=20).rev() {
-                countdown_clone.set(remaining);
-                if remaining > 0 {
-                    TimeoutFuture::new(1_000).await; // Wait 1 second
-                }
-            }
+            let confirmed = wait_for_group(group_id).await;
             
             add_log_entry("INFO", "Refreshing group list after group creation...");
-            refresh_groups_data(web_sys::MouseEvent::new("click").unwrap());
+            refresh_groups_data();
             
-            // Reset waiting state
-            countdown_clone.set(0);
-            waiting_clone.set(false);
+            // a newer creation replaces this notice
+            if group_creation.get_untracked() == Some(CreationStatus::Waiting(group_id)) {
+                set_group_creation.set(Some(match confirmed {
+                    Some(_) => CreationStatus::Ready(group_id),
+                    None => CreationStatus::NotVisible(group_id),
+                }));
+            }
         });
     };
 
+    let open_created_group = move |group_id: u64| {
+        set_group_creation.set(None);
+        enter_chat_room(group_id);
+    };
+
     // Function to handle group creation error
     let on_group_creation_error = move |error: String| {
         add_log_entry("ERROR", &format!("Failed to create chat group: {}", error));
@@ -832,35 +1583,48 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         // get current group ID
         if let ChatView::ChatRoom(group_id) = current_view.get() {
             if let Ok(user_pubkey) = session.with_untracked(|s| s.get_public_key()) {
-                // check token balance
+                // check maximum amount and token balance
                 let token_balance = session.with_untracked(|s| s.get_token_balance());
-                if token_balance < burn_tokens_amount as f64 {
-                    let error_msg = format!("Insufficient token balance! Required: {} MEMO, Available: {:.2} MEMO", 
-                                          burn_tokens_amount, token_balance);
+                if let Err(e) = validate_burn_tokens(burn_tokens_amount, token_balance) {
+                    let error_msg = e.to_string();
                     add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                    show_composer_error(error_msg);
                     return;
                 }
                 
                 // check SOL balance
                 let sol_balance = session.with_untracked(|s| s.get_sol_balance());
-                if sol_balance < 0.01 {
-                    let error_msg = format!("Insufficient SOL balance for transaction fee! Current: {:.4} SOL, Required: at least 0.01 SOL", sol_balance);
+                if sol_balance < MIN_FEE_BALANCE_XNT {
+                    let error_msg = tf("chat.error.low_fee_balance", &[&format!("{:.4}", sol_balance), &MIN_FEE_BALANCE_XNT.to_string()]);
                     add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                    show_composer_error(error_msg);
+                    return;
+                }
+                
+                let reply_to_sig = burn_reply_to.get_untracked().map(|m| m.signature);
+                if let Err(e) = validate_group_burn_size(
+                    group_id,
+                    &user_pubkey,
+                    reply_to_sig.as_deref(),
+                    &burn_msg,
+                    burn_tokens_amount.saturating_mul(LAMPORTS_PER_TOKEN),
+                ) {
+                    add_log_entry("ERROR", &e);
+                    show_composer_error(e);
                     return;
                 }
                 
                 // Clear any previous error messages
-                set_error_message.set(None);
+                set_composer_error.set(None);
                 
                 // 1. show burn message on UI immediately (like regular message)
-                let local_burn_message = LocalChatMessage::new_local_burn(
+                let mut local_burn_message = LocalChatMessage::new_local_burn(
                     user_pubkey.clone(),
                     burn_msg.clone(),
                     burn_tokens_amount,
                     group_id
                 );
+                local_burn_message.message.reply_to_sig = reply_to_sig.clone();
                 
                 // add to current message list
                 set_messages.update(|msgs| {
@@ -870,7 +1634,14 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                 // clear input and set burning state
                 set_burn_message.set(String::new());
                 set_burn_amount.set("1".to_string());
+                set_burn_reply_to.set(None);
+                if let Err(e) = chat_drafts::update_draft(group_id, |draft| draft.burn_message.clear()) {
+                    log::warn!("Failed to clear chat draft: {}", e);
+                }
                 set_burning.set(true);
+                let local_signature = local_burn_message.message.signature.clone();
+                remember_send(group_id, &local_burn_message);
+                let cancel = register_cancel(local_signature.clone());
                 
                 // 2. short delay to update UI (like sending message)
                 spawn_local(async move {
@@ -878,11 +1649,17 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     
                     // 3. actually execute burn operation
                     let mut session_copy = session.get_untracked();
-                    let result = session_copy.burn_tokens_for_group(group_id, burn_tokens_amount, &burn_msg).await;
+                    let result = cancellable(
+                        session_copy.burn_tokens_for_group(group_id, burn_tokens_amount, &burn_msg, reply_to_sig.as_deref()),
+                        cancel,
+                    ).await;
+                    finish_send(&local_signature, matches!(result, Err(SessionError::Cancelled)));
                     
                     match result {
+                        Err(SessionError::Cancelled) => mark_unconfirmed(&local_signature),
                         Ok(signature) => {
                             add_log_entry("SUCCESS", &format!("Tokens burned successfully! Signature: {}", signature));
+                            start_send_wait(group_id);
                             
                             // 4. update local message status to sent
                             set_messages.update(|msgs| {
@@ -893,9 +1670,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     m.message.message_type == "burn"
                                 }) {
                                     msg.status = MessageStatus::Sent;
-                                    msg.message.signature = signature; // update to real signature
+                                    msg.message.signature = signature.clone(); // update to real signature
                                 }
                             });
+                            confirm_in_background(signature);
                             
                             // 5. update original session balance state
                             session.update(|s| {
@@ -905,7 +1683,7 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                             // 6. update group info (burn total)
                             spawn_local(async move {
                                 let rpc = crate::core::rpc_base::RpcConnection::new();
-                                match rpc.get_chat_group_info(group_id).await {
+                                match cache::get_chat_group_info(&rpc, group_id).await {
                                     Ok(updated_group_info) => {
                                         set_current_group_info.set(Some(updated_group_info));
                                     },
@@ -918,27 +1696,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         Err(e) => {
                             log::error!("Failed to burn tokens: {}", e);
                             
-                            // Parse error to extract specific error message (like regular messages)
-                            let error_string = e.to_string();
-                            let user_friendly_error = 
-                                if let Some(dash_pos) = error_string.rfind(" - ") {
-                                    let specific_msg = &error_string[dash_pos + 3..];
-                                    let cleaned_msg = specific_msg.trim_end_matches('.');
-                                    if !cleaned_msg.is_empty() {
-                                        cleaned_msg.to_string()
-                                    } else {
-                                        "Failed to burn tokens. Please try again.".to_string()
-                                    }
-                                } else {
-                                    if error_string.contains("insufficient") {
-                                        "Insufficient balance".to_string()
-                                    } else {
-                                        "Failed to burn tokens. Please try again.".to_string()
-                                    }
-                                };
+                            let user_friendly_error = burn_error(&e);
                             
                             add_log_entry("ERROR", &format!("Failed to burn tokens: {}", user_friendly_error));
-                            set_error_message.set(Some(user_friendly_error.to_string()));
+                            show_composer_error(user_friendly_error.to_string());
                             
                             // 7. update local message status to failed
                             set_messages.update(|msgs| {
@@ -967,10 +1728,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
     // modify send message logic, decide to send message or burn tokens based on selected operation type
     let send_message_or_burn = move |_ev: web_sys::MouseEvent| {
         match action_type.get().as_str() {
-            "burn" => {
+            "burn" => burn_guard.run(move || {
                 let dummy_event = web_sys::MouseEvent::new("click").unwrap();
                 handle_burn_tokens(dummy_event);
-            },
+            }),
             _ => {
                 let dummy_event = web_sys::MouseEvent::new("click").unwrap();
                 send_message(dummy_event);
@@ -989,22 +1750,23 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     let error_msg = format!("Insufficient token balance! Required: {} MEMO, Available: {:.2} MEMO", 
                                           burn_tokens_amount, token_balance);
                     add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                    show_composer_error(error_msg);
                     return;
                 }
                 
                 let sol_balance = session.with_untracked(|s| s.get_sol_balance());
-                if sol_balance < 0.01 {
-                    let error_msg = format!("Insufficient SOL balance for transaction fee! Current: {:.4} SOL, Required: at least 0.01 SOL", sol_balance);
+                if sol_balance < MIN_FEE_BALANCE_XNT {
+                    let error_msg = tf("chat.error.low_fee_balance", &[&format!("{:.4}", sol_balance), &MIN_FEE_BALANCE_XNT.to_string()]);
                     add_log_entry("ERROR", &error_msg);
-                    set_error_message.set(Some(error_msg));
+                    show_composer_error(error_msg);
                     return;
                 }
                 
                 // Clear any previous error messages
-                set_error_message.set(None);
+                set_composer_error.set(None);
                 
                 // 1. Update the failed message back to sending status
+                let mut retried = None;
                 set_messages.update(|msgs| {
                     if let Some(msg) = msgs.iter_mut().find(|m| {
                         m.is_local && 
@@ -1015,8 +1777,17 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     }) {
                         log::info!("Updating burn message status from {:?} to Sending for retry", msg.status);
                         msg.status = MessageStatus::Sending;
+                        retried = Some(msg.clone());
                     }
                 });
+                let Some(retried) = retried else {
+                    log::error!("Could not find message to retry");
+                    return;
+                };
+                let local_signature = retried.message.signature.clone();
+                let reply_to_sig = retried.message.reply_to_sig.clone();
+                remember_send(group_id, &retried);
+                let cancel = register_cancel(local_signature.clone());
                 
                 set_burning.set(true);
                 
@@ -1026,11 +1797,17 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     
                     // 3. actually retry burn operation
                     let mut session_copy = session.get_untracked();
-                    let result = session_copy.burn_tokens_for_group(group_id, burn_tokens_amount, &burn_content).await;
+                    let result = cancellable(
+                        session_copy.burn_tokens_for_group(group_id, burn_tokens_amount, &burn_content, reply_to_sig.as_deref()),
+                        cancel,
+                    ).await;
+                    finish_send(&local_signature, matches!(result, Err(SessionError::Cancelled)));
                     
                     match result {
+                        Err(SessionError::Cancelled) => mark_unconfirmed(&local_signature),
                         Ok(signature) => {
                             add_log_entry("INFO", &format!("Burn retry successful! Signature: {}", signature));
+                            start_send_wait(group_id);
                             
                             // 4. update local message status to sent
                             set_messages.update(|msgs| {
@@ -1041,9 +1818,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     m.message.message_type == "burn"
                                 }) {
                                     msg.status = MessageStatus::Sent;
-                                    msg.message.signature = signature; // update to real signature
+                                    msg.message.signature = signature.clone(); // update to real signature
                                 }
                             });
+                            confirm_in_background(signature);
                             
                             // 5. update session balance
                             session.update(|s| {
@@ -1053,7 +1831,7 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                             // 6. update group info
                             spawn_local(async move {
                                 let rpc = crate::core::rpc_base::RpcConnection::new();
-                                match rpc.get_chat_group_info(group_id).await {
+                                match cache::get_chat_group_info(&rpc, group_id).await {
                                     Ok(updated_group_info) => {
                                         set_current_group_info.set(Some(updated_group_info));
                                     },
@@ -1066,14 +1844,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         Err(e) => {
                             log::error!("Burn retry failed: {}", e);
                             
-                            let user_friendly_error = if e.to_string().contains("insufficient") {
-                                "Insufficient balance"
-                            } else {
-                                "Failed to burn tokens. Please try again."
-                            };
+                            let user_friendly_error = burn_error(&e);
                             
                             add_log_entry("ERROR", &format!("Retry failed: {}", user_friendly_error));
-                            set_error_message.set(Some(user_friendly_error.to_string()));
+                            show_composer_error(user_friendly_error.to_string());
                             
                             // 7. update local message status back to failed
                             set_messages.update(|msgs| {
@@ -1099,6 +1873,84 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         }
     };
 
+    // Switch the input to burn mode, replying to `message`
+    let start_burn_reply = move |message: ChatMessage| {
+        set_burn_reply_to.set(Some(message));
+        set_action_type.set("burn".to_string());
+    };
+
+    // Reply to a message with a chat message
+    let start_reply = move |message: ChatMessage| {
+        set_message_reply_to.set(Some(message));
+        set_action_type.set("message".to_string());
+    };
+
+    // Hide a message in this browser only
+    let hide_message = move |message: ChatMessage| {
+        let group_id = match current_view.get_untracked() {
+            ChatView::ChatRoom(group_id) => group_id,
+            ChatView::GroupsList => return,
+        };
+        match moderation::hide_message(&message.signature, group_id, &message.sender, &message.message) {
+            Ok(()) => {
+                set_hidden_signatures.update(|hidden| {
+                    hidden.insert(message.signature.clone());
+                });
+                add_log_entry("INFO", "Message hidden locally. You can unhide it in Settings.");
+            }
+            Err(e) => {
+                log::error!("Failed to hide message: {}", e);
+                set_error_message.set(Some(format!("Failed to hide message: {}", e)));
+            }
+        }
+    };
+
+    // Keep a local record of a reported message (nothing is sent to a server)
+    let report_message = move |message: ChatMessage| {
+        let group_id = match current_view.get_untracked() {
+            ChatView::ChatRoom(group_id) => group_id,
+            ChatView::GroupsList => return,
+        };
+        match moderation::report_message(&message.signature, group_id, &message.sender, &message.message) {
+            Ok(()) => add_log_entry("INFO", &format!("Recorded local report for message {}", message.signature)),
+            Err(e) => log::error!("Failed to record report: {}", e),
+        }
+    };
+
+    // Copy the loaded messages from `signature` to the newest as Markdown, skipping
+    // hidden ones and sends that haven't landed; returns whether anything was copied
+    let copy_thread_from = move |signature: String| -> bool {
+        let all_visible = visible_messages();
+        let Some(start) = all_visible.iter().position(|m| m.message.signature == signature) else {
+            return false;
+        };
+        let domains = sender_domains.get_untracked();
+        let lines = user_display_cache.with_untracked(|cache| {
+            all_visible[start..].iter()
+                .filter(|m| !m.is_local)
+                .map(|m| ChatMarkdownLine {
+                    author: format_sender_name(cache, &domains, &m.message.sender),
+                    time: format_timestamp(m.message.timestamp),
+                    text: m.message.message.clone(),
+                    burned_memo: m.message.burn_amount.filter(|_| m.message.message_type == "burn").map(format_memo),
+                })
+                .collect::<Vec<_>>()
+        });
+        let group_name = current_group_info.with_untracked(|info| match info {
+            Some(info) => info.name.clone(),
+            None => match current_view.get_untracked() {
+                ChatView::ChatRoom(group_id) => tf("chat.group.number", &[&group_id.to_string()]),
+                ChatView::GroupsList => String::new(),
+            },
+        });
+        let Some(window) = web_sys::window() else {
+            return false;
+        };
+        let _ = window.navigator().clipboard().write_text(&chat_thread_markdown(&group_name, &lines));
+        add_log_entry("INFO", &format!("Copied {} messages as Markdown", lines.len()));
+        true
+    };
+
     // calculate pagination data
     let get_paginated_groups = create_memo(move |_| {
         if let Some(leaderboard) = leaderboard_data.get() {
@@ -1123,90 +1975,6 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
         }
     });
 
-    // Function to load groups by mode
-    let load_groups_by_mode = move |mode: GroupsDisplayMode, page: usize| {
-        spawn_local(async move {
-            set_mode_loading.set(true);
-            set_error_message.set(None);
-            
-            let rpc = RpcConnection::new();
-            let per_page = groups_per_page.get();
-            
-            match mode {
-                GroupsDisplayMode::Latest => {
-                    // Get total groups count first
-                    match rpc.get_chat_global_statistics().await {
-                        Ok(global_stats) => {
-                            let total_groups = global_stats.total_groups;
-                            if total_groups == 0 {
-                                set_latest_groups.set(vec![]);
-                                set_mode_loading.set(false);
-                                return;
-                            }
-                            
-                            // Calculate range for latest groups (reverse order)
-                            let start_idx = (page - 1) * per_page;
-                            let start_id = if total_groups > start_idx as u64 {
-                                total_groups - 1 - start_idx as u64
-                            } else {
-                                set_latest_groups.set(vec![]);
-                                set_mode_loading.set(false);
-                                return;
-                            };
-                            
-                            let end_id = if start_id >= per_page as u64 {
-                                start_id - per_page as u64 + 1
-                            } else {
-                                0
-                            };
-                            
-                            // Get groups in range
-                            let mut group_ids: Vec<u64> = (end_id..=start_id).collect();
-                            group_ids.reverse(); // Latest first
-                            
-                            let mut groups = vec![];
-                            for group_id in group_ids {
-                                match rpc.get_chat_group_info(group_id).await {
-                                    Ok(group_info) => groups.push(group_info),
-                                    Err(_) => {} // Skip non-existent groups
-                                }
-                            }
-                            
-                            add_log_entry("INFO", &format!("Loaded {} latest groups for page {}", groups.len(), page));
-                            set_latest_groups.set(groups);
-                        },
-                        Err(e) => {
-                            add_log_entry("ERROR", &format!("Failed to load latest groups: {}", e));
-                            set_error_message.set(Some(format!("Failed to load latest groups: {}", e)));
-                        }
-                    }
-                },
-                GroupsDisplayMode::Oldest => {
-                    // Calculate range for oldest groups
-                    let start_idx = (page - 1) * per_page;
-                    let start_id = start_idx as u64;
-                    let end_id = start_id + per_page as u64;
-                    
-                    match rpc.get_chat_groups_range(start_id, end_id).await {
-                        Ok(groups) => {
-                            add_log_entry("INFO", &format!("Loaded {} oldest groups for page {}", groups.len(), page));
-                            set_oldest_groups.set(groups);
-                        },
-                        Err(e) => {
-                            add_log_entry("ERROR", &format!("Failed to load oldest groups: {}", e));
-                            set_error_message.set(Some(format!("Failed to load oldest groups: {}", e)));
-                        }
-                    }
-                },
-                GroupsDisplayMode::BurnLeaderboard => {
-                    // Do nothing, handled by existing logic
-                }
-            }
-            
-            set_mode_loading.set(false);
-        });
-    };
-
     // pagination navigation function
     let go_to_page = move |page: usize| {
         set_current_page.set(page);
@@ -1223,7 +1991,7 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     set_current_page.set(new_page);
                 }
             },
-            GroupsDisplayMode::Latest | GroupsDisplayMode::Oldest => {
+            GroupsDisplayMode::Latest | GroupsDisplayMode::Oldest | GroupsDisplayMode::Favorites => {
                 set_current_page.set(new_page);
                 load_groups_by_mode(current_mode, new_page);
             }
@@ -1237,7 +2005,7 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
             set_current_page.set(new_page);
             
             match current_mode {
-                GroupsDisplayMode::Latest | GroupsDisplayMode::Oldest => {
+                GroupsDisplayMode::Latest | GroupsDisplayMode::Oldest | GroupsDisplayMode::Favorites => {
                     load_groups_by_mode(current_mode, new_page);
                 },
                 GroupsDisplayMode::BurnLeaderboard => {
@@ -1262,19 +2030,69 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
 
     // Handle display mode change
     let handle_mode_change = move |new_mode: GroupsDisplayMode| {
-        set_display_mode.set(new_mode.clone());
-        set_current_page.set(1); // Reset to first page
+        // Page numbers of one mode mean nothing in another (rank order vs. group
+        // ids vs. favorites), so a new mode always starts on its first page.
+        // Refreshes keep the page instead, see `refresh_groups_data`.
+        if new_mode != display_mode.get_untracked() {
+            set_current_page.set(1);
+        }
+        set_display_mode.set(new_mode);
         
         match new_mode {
-            GroupsDisplayMode::Latest | GroupsDisplayMode::Oldest => {
-                load_groups_by_mode(new_mode, 1);
+            GroupsDisplayMode::Latest | GroupsDisplayMode::Oldest | GroupsDisplayMode::Favorites => {
+                load_groups_by_mode(new_mode, current_page.get_untracked());
             },
             GroupsDisplayMode::BurnLeaderboard => {
-                // Do nothing, use existing leaderboard data
+                // Use existing leaderboard data, loading it first if the page opened in another mode
+                if leaderboard_data.get_untracked().is_none() {
+                    load_initial_leaderboard();
+                }
+            }
+        }
+    };
+
+    // Without the ranking there is nothing to show in leaderboard mode; fall
+    // back to the newest groups, which only need the group accounts
+    create_effect(move |_| {
+        if !leaderboard_warnings.get().is_empty()
+            && leaderboard_data.get_untracked().is_none()
+            && display_mode.get_untracked() == GroupsDisplayMode::BurnLeaderboard
+        {
+            add_log_entry("INFO", "Showing the latest groups while the burn ranking is unavailable");
+            handle_mode_change(GroupsDisplayMode::Latest);
+        }
+    });
+
+    // Toggle a group in the local favorites list
+    let toggle_favorite = move |group_id: u64| {
+        match preferences::toggle_favorite_group(group_id) {
+            Ok(favorites) => {
+                set_favorite_ids.set(favorites);
+                if display_mode.get_untracked() == GroupsDisplayMode::Favorites {
+                    set_favorite_groups.update(|groups| groups.retain(|g| g.group_id != group_id));
+                }
             }
+            Err(e) => log::error!("Failed to update favorite groups: {}", e),
+        }
+    };
+
+    // Switch between card grid and compact list, remembering the choice
+    let change_view_mode = move |mode: ListViewMode| {
+        set_view_mode.set(mode);
+        if let Err(e) = UiPreferences::update(|p| p.groups_view_mode = mode) {
+            log::error!("Failed to save groups view mode: {}", e);
         }
     };
 
+    // Initial data fetch follows the preferred default mode
+    match initial_display_mode {
+        GroupsDisplayMode::BurnLeaderboard => load_initial_leaderboard(),
+        mode => {
+            set_loading.set(false);
+            load_groups_by_mode(mode, 1);
+        }
+    }
+
     // Function to auto-resize textarea based on target element
     let auto_resize_textarea = move |target: web_sys::EventTarget| {
         if let Ok(textarea) = target.dyn_into::<web_sys::HtmlTextAreaElement>() {
@@ -1314,11 +2132,16 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                     // Chat Room View
                     view! {
                         <div class="chat-room-container">
+                            <ProgressOverlay
+                                title=t("chat.room.exporting")
+                                progress=export_progress
+                                on_cancel=cancel_export
+                            />
                             <div class="chat-room-header">
                                 <div class="header-left">
                                     <button class="back-button" on:click=back_to_groups>
                                         <i class="fas fa-arrow-left"></i>
-                                        "Back to Groups"
+                                        {t("chat.room.back")}
                                     </button>
                                 </div>
                                 
@@ -1326,7 +2149,7 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     when=move || current_group_info.get().is_some()
                                     fallback=|| view! {
                                         <div class="group-title">
-                                            <h1>"Loading Group..."</h1>
+                                            <h1>{t("chat.room.loading_group")}</h1>
                                         </div>
                                     }
                                 >
@@ -1339,8 +2162,20 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                                         {info.name.clone()}
                                                         <span class="burn-total">
                                                             <i class="fas fa-fire"></i>
-                                                            {format!("{}", info.burned_amount / 1_000_000)}
+                                                            {format_memo(info.burned_amount)}
                                                         </span>
+                                                        {move || room_participants.get().map(|found| view! {
+                                                            <span class="participant-count">
+                                                                <i class="fas fa-users"></i>
+                                                                {participants_label(&found)}
+                                                            </span>
+                                                        })}
+                                                        <Show when=move || is_group_creator.get()>
+                                                            <span class="creator-badge" title=t("chat.room.creator_badge_title")>
+                                                                <i class="fas fa-crown"></i>
+                                                                {t("chat.room.creator_badge")}
+                                                            </span>
+                                                        </Show>
                                                     </h1>
                                                     <p class="group-description">{info.description}</p>
                                                 </div>
@@ -1350,6 +2185,25 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                 </Show>
                                 
                                 <div class="header-right">
+                                    <button
+                                        class="refresh-button"
+                                        on:click=move |_| {
+                                            if let ChatView::ChatRoom(group_id) = current_view.get() {
+                                                export_history(group_id);
+                                            }
+                                        }
+                                        disabled=move || export_progress.get().is_some()
+                                        title=t("chat.room.export_title")
+                                    >
+                                        <i class="fas fa-file-export"></i>
+                                        {move || (!is_narrow.get()).then(|| t("chat.room.export"))}
+                                    </button>
+                                    {move || auto_refresh_secs.get().map(|secs| view! {
+                                        <span class="live-indicator" title=tf("chat.room.live_title", &[&secs.to_string()])>
+                                            <span class="live-dot"></span>
+                                            {t("chat.room.live")}
+                                        </span>
+                                    })}
                                     <button 
                                         class="refresh-button"
                                         on:click=move |_| {
@@ -1358,9 +2212,10 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                             }
                                         }
                                         disabled=move || loading.get()
+                                        title=t("chat.room.refresh")
                                     >
                                         <i class="fas fa-sync-alt"></i>
-                                        "Refresh"
+                                        {move || (!is_narrow.get()).then(|| t("chat.room.refresh"))}
                                     </button>
                                 </div>
                             </div>
@@ -1375,50 +2230,315 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                 </div>
                             </Show>
                             
+                            <div class="message-search">
+                                <i class="fas fa-search"></i>
+                                <input
+                                    type="search"
+                                    class="message-search-input"
+                                    placeholder=t("chat.room.search_placeholder")
+                                    prop:value=move || message_search.get()
+                                    on:input=move |ev| set_message_search.set(event_target_value(&ev))
+                                    on:keydown=move |ev| {
+                                        if ev.key() == "Escape" {
+                                            set_message_search.set(String::new());
+                                        }
+                                    }
+                                />
+                                <Show when=move || !search_query.get().is_empty()>
+                                    <span class="message-search-count">
+                                        {move || tf("chat.room.search_count", &[&searched_messages().len().to_string(), &visible_messages().len().to_string()])}
+                                    </span>
+                                    <button
+                                        class="message-search-clear"
+                                        on:click=move |_| set_message_search.set(String::new())
+                                        title=t("chat.room.search_clear")
+                                    >
+                                        <i class="fas fa-times"></i>
+                                    </button>
+                                </Show>
+                            </div>
+
                             <div class="chat-container">
-                                <div class="messages-area" node_ref=messages_area_ref>
+                                <div class="messages-area" node_ref=messages_area_ref on:scroll=on_messages_scroll>
                                     <Show
                                         when=move || !loading.get()
                                         fallback=|| view! {
                                             <div class="loading-container">
                                                 <div class="loading-spinner"></div>
-                                                <p>"Loading messages..."</p>
+                                                <p>{t("chat.room.loading_messages")}</p>
                                             </div>
                                         }
                                     >
                                         <Show
-                                            when=move || !messages.get().is_empty()
-                                            fallback=|| view! {
-                                                <div class="empty-messages">
-                                                    <i class="fas fa-comments-slash"></i>
-                                                    <p>"No messages in this group yet"</p>
-                                                    <p class="hint">"Be the first to start the conversation!"</p>
-                                                </div>
+                                            when=move || !searched_messages().is_empty()
+                                            fallback=move || if search_query.get().is_empty() {
+                                                view! {
+                                                    <div class="empty-messages">
+                                                        <i class="fas fa-comments-slash"></i>
+                                                        <p>{t("chat.room.empty")}</p>
+                                                        <p class="hint">{t("chat.room.empty_hint")}</p>
+                                                    </div>
+                                                }
+                                            } else {
+                                                view! {
+                                                    <div class="empty-messages">
+                                                        <i class="fas fa-search"></i>
+                                                        <p>{t("chat.room.no_matches")}</p>
+                                                        <p class="hint">{t("chat.room.no_matches_hint")}</p>
+                                                    </div>
+                                                }
                                             }
                                         >
                                             <div class="messages-list">
+                                                <Show when=has_older_messages>
+                                                    <div class="load-older-messages">
+                                                        <button
+                                                            class="load-older-button"
+                                                            on:click=move |_| {
+                                                                if let ChatView::ChatRoom(group_id) = current_view.get_untracked() {
+                                                                    load_older_messages(group_id);
+                                                                }
+                                                            }
+                                                            disabled=move || loading_older.get()
+                                                        >
+                                                            <i class="fas fa-history" class:fa-spin=move || loading_older.get()></i>
+                                                            " "
+                                                            {move || if loading_older.get() {
+                                                                t("chat.room.loading_older")
+                                                            } else {
+                                                                t("chat.room.load_older")
+                                                            }}
+                                                        </button>
+                                                    </div>
+                                                </Show>
+                                                <Show when=move || { hidden_in_room_count.get() > 0 }>
+                                                    <div class="hidden-messages-note">
+                                                        <i class="fas fa-eye-slash"></i>
+                                                        {move || tf("chat.room.hidden_count", &[&hidden_in_room_count.get().to_string()])}
+                                                    </div>
+                                                </Show>
                                                 <For
-                                                    each=move || messages.get()
+                                                    each=searched_messages
                                                     key=|message| format!("{}_{:?}", message.message.signature, message.status)
                                                     children=move |message: LocalChatMessage| {
+                                                        let replied_to = message.message.reply_to_sig.as_ref().and_then(|sig| {
+                                                            messages.with_untracked(|msgs| {
+                                                                msgs.iter().find(|m| &m.message.signature == sig).map(|m| m.message.clone())
+                                                            })
+                                                        });
+                                                        let signature = message.message.signature.clone();
+                                                        let starts_new = move || new_since_signature.get().as_deref() == Some(signature.as_str());
                                                         view! { 
+                                                            <Show when=starts_new>
+                                                                <div class="new-messages-divider">
+                                                                    <span>{t("chat.room.new_messages")}</span>
+                                                                </div>
+                                                            </Show>
                                                             <MessageItem 
                                                                 message=message 
+                                                                replied_to=replied_to
                                                                 current_mint_reward=current_mint_reward 
                                                                 session=session 
                                                                 user_display_cache=user_display_cache
+                                                                sender_domains=sender_domains
+                                                                search=search_query
                                                                 retry_callback=retry_message
                                                                 retry_burn_callback=retry_burn_message
+                                                                hide_callback=hide_message
+                                                                report_callback=report_message
+                                                                cancel_callback=cancel_pending
+                                                                dismiss_callback=dismiss_unconfirmed
+                                                                reply_callback=start_reply
+                                                                burn_reply_callback=start_burn_reply
+                                                                copy_thread_callback=copy_thread_from
+                                                                developer_mode=developer_mode
                                                             /> 
                                                         }
                                                     }
                                                 />
                                             </div>
-                                        </Show>
+                                        </Show>
+                                    </Show>
+                                </div>
+                                
+                                <div class="message-input-area">
+                                    {move || interval_notice().map(|(interval, remaining)| view! {
+                                        <div class="interval-notice" class:cooling-down={remaining > 0}>
+                                            <i class="fas fa-hourglass-half"></i>
+                                            <span>{tf("chat.room.min_interval", &[&format_duration(interval)])}</span>
+                                            {(remaining > 0).then(|| view! {
+                                                <span class="interval-notice-countdown">
+                                                    {tf("chat.room.next_message_in", &[&format_duration(remaining)])}
+                                                </span>
+                                            })}
+                                        </div>
+                                    })}
+                                    {move || composer_error.get().map(|error| view! {
+                                        <div class="composer-error">
+                                            <i class="fas fa-exclamation-circle"></i>
+                                            <span class="composer-error-text">{error}</span>
+                                            <button
+                                                class="composer-error-dismiss"
+                                                on:click=move |_| set_composer_error.set(None)
+                                                title=t("chat.input.dismiss_error")
+                                            >
+                                                <i class="fas fa-times"></i>
+                                            </button>
+                                        </div>
+                                    })}
+                                    {move || low_fee_balance().then(|| view! {
+                                        <div class="fee-balance-notice">
+                                            <i class="fas fa-wallet"></i>
+                                            <span class="fee-balance-notice-text">
+                                                {move || tf("chat.input.top_up", &[
+                                                    &format!("{:.4}", session.with(|s| s.get_sol_balance())),
+                                                    &MIN_FEE_BALANCE_XNT.to_string(),
+                                                ])}
+                                            </span>
+                                            <button
+                                                class="fee-balance-check"
+                                                on:click=check_balance
+                                                disabled=move || checking_balance.get()
+                                                title=t("chat.input.check_balance_title")
+                                            >
+                                                <i class="fas fa-sync-alt" class:fa-spin=move || checking_balance.get()></i>
+                                                " "{t("chat.input.check_balance")}
+                                            </button>
+                                        </div>
+                                    })}
+                                    {move || (action_type.get() == "message").then(|| message_reply_to.get()).flatten().map(|original| view! {
+                                        <div class="burn-reply-banner message-reply-banner">
+                                            <i class="fas fa-reply"></i>
+                                            <div class="burn-reply-quote">
+                                                <span class="burn-reply-label">{tf("chat.input.reply_to", &[&shorten_address(&original.sender)])}</span>
+                                                <span class="burn-reply-text">{message_preview(&original.message)}</span>
+                                            </div>
+                                            <button
+                                                class="burn-reply-cancel"
+                                                on:click=move |_| set_message_reply_to.set(None)
+                                                title=t("chat.input.reply_cancel")
+                                                disabled=move || sending.get()
+                                            >
+                                                <i class="fas fa-times"></i>
+                                            </button>
+                                        </div>
+                                    })}
+                                    {move || (action_type.get() == "burn").then(|| burn_reply_to.get()).flatten().map(|original| view! {
+                                        <div class="burn-reply-banner">
+                                            <i class="fas fa-reply"></i>
+                                            <div class="burn-reply-quote">
+                                                <span class="burn-reply-label">{tf("chat.input.burn_reply_to", &[&shorten_address(&original.sender)])}</span>
+                                                <span class="burn-reply-text">{message_preview(&original.message)}</span>
+                                            </div>
+                                            <button
+                                                class="burn-reply-cancel"
+                                                on:click=move |_| set_burn_reply_to.set(None)
+                                                title=t("chat.input.burn_reply_cancel")
+                                                disabled=move || burning.get()
+                                            >
+                                                <i class="fas fa-times"></i>
+                                            </button>
+                                        </div>
+                                    })}
+                                    <Show when=move || action_type.get() == "burn">
+                                        <BurnNetworkBadge/>
+                                    </Show>
+                                    // Quick burn amount presets (only show when burn mode and the options are expanded)
+                                    <Show when=move || action_type.get() == "burn" && composer_expanded.get()>
+                                        <div class="burn-presets">
+                                            {BURN_PRESETS.into_iter().map(|amount| view! {
+                                                <button
+                                                    class="burn-preset-btn"
+                                                    class:active=move || burn_amount.get().trim() == amount.to_string()
+                                                    on:click=move |_| set_burn_amount.set(amount.to_string())
+                                                    disabled=move || burning.get() || session.with(|s| s.get_token_balance()) < amount as f64
+                                                >
+                                                    {format_number_with_commas(amount)}
+                                                </button>
+                                            }).collect::<Vec<_>>()}
+                                            <button
+                                                class="burn-preset-btn"
+                                                on:click=move |_| {
+                                                    let max = max_burn_tokens(session.with_untracked(|s| s.get_token_balance()));
+                                                    set_burn_amount.set(max.to_string());
+                                                }
+                                                disabled=move || burning.get() || max_burn_tokens(session.with(|s| s.get_token_balance())) < 1
+                                                title=format!("Balance minus {} MEMO kept as a buffer", MAX_BURN_BUFFER_TOKENS)
+                                            >
+                                                {t("chat.input.max")}
+                                            </button>
+                                            <RankProjectionHint projection=burn_rank_projection/>
+                                        </div>
+                                    </Show>
+                                    <Show when=move || action_type.get() == "burn">
+                                        <Show when=burn_exceeds_balance>
+                                            <div class="burn-preset-warning">
+                                                <i class="fas fa-exclamation-triangle"></i>
+                                                {move || tf("chat.input.exceeds_balance", &[&format!("{:.2}", session.with(|s| s.get_token_balance()))])}
+                                            </div>
+                                        </Show>
+                                        {move || burn_size_error().map(|error| view! {
+                                            <div class="burn-size-warning">
+                                                <i class="fas fa-exclamation-triangle"></i>
+                                                " "{error}
+                                            </div>
+                                        })}
+                                    </Show>
+                                    // Pixel image attached to the next message
+                                    <Show when=move || action_type.get() == "message" && pixel_editor_open.get()>
+                                        <div class="message-pixel-editor">
+                                            {move || {
+                                                let art_string = message_pixel.get()
+                                                    .unwrap_or_else(|| Pixel::new_with_size(MESSAGE_PIXEL_SIZE))
+                                                    .to_optimal_string();
+                                                let click_handler = Box::new(move |row, col| {
+                                                    let mut new_art = message_pixel.get_untracked()
+                                                        .unwrap_or_else(|| Pixel::new_with_size(MESSAGE_PIXEL_SIZE));
+                                                    new_art.toggle_pixel(row, col);
+                                                    set_message_pixel.set(Some(new_art));
+                                                });
+
+                                                view! {
+                                                    <PixelView
+                                                        art=art_string
+                                                        size=160
+                                                        editable=true
+                                                        show_grid=true
+                                                        on_click=click_handler
+                                                    />
+                                                }
+                                            }}
+                                            <div class="message-pixel-actions">
+                                                <span class="message-pixel-hint">{t("chat.input.pixel_hint")}</span>
+                                                <button
+                                                    class="message-pixel-remove"
+                                                    on:click=move |_| {
+                                                        set_message_pixel.set(None);
+                                                        set_pixel_editor_open.set(false);
+                                                    }
+                                                    title=t("chat.input.pixel_remove_title")
+                                                >
+                                                    <i class="fas fa-trash"></i>
+                                                    " "{t("chat.input.pixel_remove")}
+                                                </button>
+                                                <button
+                                                    class="message-pixel-done"
+                                                    on:click=move |_| set_pixel_editor_open.set(false)
+                                                >
+                                                    <i class="fas fa-check"></i>
+                                                    " "{t("chat.input.pixel_done")}
+                                                </button>
+                                            </div>
+                                        </div>
+                                    </Show>
+                                    <Show when=move || action_type.get() == "message">
+                                        {move || message_size_error().map(|error| view! {
+                                            <div class="burn-size-warning">
+                                                <i class="fas fa-exclamation-triangle"></i>
+                                                " "{error}
+                                            </div>
+                                        })}
                                     </Show>
-                                </div>
-                                
-                                <div class="message-input-area">
                                     <div class="input-wrapper-container">
                                         // Text input with embedded toggle
                                         <div class="input-with-toggle">
@@ -1431,20 +2551,21 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                                             class="message-input"
                                                             placeholder=move || {
                                                                 if sending.get() {
-                                                                    "Sending, please wait...".to_string()
-                                                                } else if session.with(|s| s.get_sol_balance()) < 0.005 {
-                                                                    format!("Insufficient balance, sending message requires at least 0.005 SOL (current: {:.4} SOL)", session.with(|s| s.get_sol_balance()))
+                                                                    t("chat.input.sending").to_string()
+                                                                } else if low_fee_balance() {
+                                                                    tf("chat.input.low_balance_send", &[&MIN_FEE_BALANCE_XNT.to_string(), &format!("{:.4}", session.with(|s| s.get_sol_balance()))])
                                                                 } else {
-                                                                    "Type your message...".to_string()
+                                                                    t("chat.input.placeholder").to_string()
                                                                 }
                                                             }
                                                             prop:value=move || message_input.get()
                                                             on:input=move |ev| {
                                                                 set_message_input.set(event_target_value(&ev));
+                                                                set_composer_error.set(None);
                                                                 auto_resize_textarea(event_target(&ev));
                                                             }
                                                             on:keypress=handle_key_press
-                                                            disabled=move || sending.get() || session.with(|s| s.get_sol_balance()) < 0.005
+                                                            disabled=move || sending.get() || low_fee_balance()
                                                         ></textarea>
                                                     }
                                                 }
@@ -1454,20 +2575,30 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                                     class="message-input"
                                                     placeholder=move || {
                                                         if burning.get() {
-                                                            "Burning tokens, please wait...".to_string()
-                                                        } else if session.with(|s| s.get_sol_balance()) < 0.005 {
-                                                            format!("Insufficient balance, burning requires at least 0.005 SOL (current: {:.4} SOL)", session.with(|s| s.get_sol_balance()))
+                                                            t("chat.input.burning").to_string()
+                                                        } else if low_fee_balance() {
+                                                            tf("chat.input.low_balance_burn", &[&MIN_FEE_BALANCE_XNT.to_string(), &format!("{:.4}", session.with(|s| s.get_sol_balance()))])
                                                         } else {
-                                                            "Type your burn message...".to_string()
+                                                            t("chat.input.burn_placeholder").to_string()
                                                         }
                                                     }
                                                     prop:value=move || burn_message.get()
                                                     on:input=move |ev| {
                                                         set_burn_message.set(event_target_value(&ev));
+                                                        set_composer_error.set(None);
                                                         auto_resize_textarea(event_target(&ev));
                                                     }
-                                                    disabled=move || burning.get() || session.with(|s| s.get_sol_balance()) < 0.005
+                                                    disabled=move || burning.get() || low_fee_balance()
                                                 ></textarea>
+                                                {move || burn_message_budget().map(|(used, max)| view! {
+                                                    <span
+                                                        class="burn-byte-counter"
+                                                        class:over={used > max}
+                                                        title=t("chat.input.burn_bytes_title")
+                                                    >
+                                                        {tf("chat.input.burn_bytes", &[&used.to_string(), &max.to_string()])}
+                                                    </span>
+                                                })}
                                             </Show>
                                             
                                             // Compact toggle switch inside input
@@ -1476,7 +2607,7 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                                     class="mode-btn"
                                                     class:active=move || action_type.get() == "message"
                                                     on:click=move |_| set_action_type.set("message".to_string())
-                                                    title="Send Message"
+                                                    title=t("chat.input.mode_message")
                                                 >
                                                     <i class="fas fa-comment"></i>
                                                 </button>
@@ -1484,16 +2615,42 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                                     class="mode-btn burn-mode"
                                                     class:active=move || action_type.get() == "burn"
                                                     on:click=move |_| set_action_type.set("burn".to_string())
-                                                    title="Burn Tokens"
+                                                    title=t("chat.input.mode_burn")
                                                 >
                                                     <i class="fas fa-fire"></i>
                                                 </button>
+                                                <Show when=move || action_type.get() == "message">
+                                                    <button
+                                                        class="mode-btn pixel-attach"
+                                                        class:active=move || {
+                                                            pixel_editor_open.get()
+                                                                || message_pixel.with(|pixel| pixel.as_ref().is_some_and(|p| !p.is_blank()))
+                                                        }
+                                                        on:click=move |_| set_pixel_editor_open.update(|open| *open = !*open)
+                                                        disabled=move || sending.get()
+                                                        title=t("chat.input.attach_pixel")
+                                                    >
+                                                        <i class="fas fa-image"></i>
+                                                    </button>
+                                                </Show>
+                                                <button
+                                                    class="mode-btn options-toggle"
+                                                    class:active=move || composer_expanded.get()
+                                                    on:click=toggle_composer_options
+                                                    title=move || if composer_expanded.get() {
+                                                        t("chat.input.hide_options")
+                                                    } else {
+                                                        t("chat.input.show_options")
+                                                    }
+                                                >
+                                                    <i class="fas fa-sliders-h"></i>
+                                                </button>
                                             </div>
                                         </div>
                                         
                                         // Burn amount input (only show when burn mode)
                                         <Show when=move || action_type.get() == "burn">
-                                            <div class="burn-amount-inline">
+                                            <div class="burn-amount-inline" class:invalid=burn_exceeds_balance>
                                                 <input 
                                                     type="number" 
                                                     class="burn-input-inline"
@@ -1513,33 +2670,41 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                         <button
                                             class="send-button-redesign"
                                             class:burn-mode-btn=move || action_type.get() == "burn"
+                                            class:mainnet-burn=move || action_type.get() == "burn" && is_mainnet()
                                             on:click=send_message_or_burn
                                             disabled=move || {
-                                                if action_type.get() == "burn" {
+                                                if send_wait_remaining() > 0 {
+                                                    true
+                                                } else if action_type.get() == "burn" {
                                                     burning.get() || 
                                                     burn_message.get().trim().is_empty() ||
                                                     burn_amount.get().trim().is_empty() ||
                                                     burn_amount.get().trim().parse::<u64>().unwrap_or(0) < 1 ||
-                                                    session.with(|s| s.get_sol_balance()) < 0.01 ||
-                                                    session.with(|s| s.get_token_balance()) < burn_amount.get().trim().parse::<f64>().unwrap_or(0.0)
+                                                    low_fee_balance() ||
+                                                    burn_exceeds_balance() ||
+                                                    burn_size_error().is_some()
                                                 } else {
-                                                    message_input.get().trim().is_empty() || 
+                                                    outgoing_message().is_empty() ||
                                                     sending.get() || 
-                                                    session.with(|s| s.get_sol_balance()) < 0.005
+                                                    low_fee_balance() ||
+                                                    message_size_error().is_some()
                                                 }
                                             }
                                             title=move || {
-                                                if action_type.get() == "burn" {
+                                                let wait = send_wait_remaining();
+                                                if wait > 0 {
+                                                    tf("chat.input.wait", &[&format_duration(wait)])
+                                                } else if action_type.get() == "burn" {
                                                     if burning.get() {
-                                                        "Burning...".to_string()
+                                                        t("chat.input.burning_short").to_string()
                                                     } else {
-                                                        "Burn".to_string()
+                                                        t("chat.input.burn").to_string()
                                                     }
                                                 } else {
                                                     if sending.get() {
-                                                        "Sending...".to_string()
+                                                        t("chat.input.sending_short").to_string()
                                                     } else {
-                                                        "Send".to_string()
+                                                        t("chat.input.send").to_string()
                                                     }
                                                 }
                                             }
@@ -1547,10 +2712,13 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                             <Show
                                                 when=move || (action_type.get() == "burn" && burning.get()) || (action_type.get() == "message" && sending.get())
                                                 fallback=move || {
-                                                    if action_type.get() == "burn" {
-                                                        view! { <i class="fas fa-fire"></i> }
+                                                    let wait = send_wait_remaining();
+                                                    if wait > 0 {
+                                                        view! { <span class="send-wait">{tf("chat.input.wait", &[&format_duration(wait)])}</span> }.into_view()
+                                                    } else if action_type.get() == "burn" {
+                                                        view! { <i class="fas fa-fire"></i> }.into_view()
                                                     } else {
-                                                        view! { <i class="fas fa-paper-plane"></i> }
+                                                        view! { <i class="fas fa-paper-plane"></i> }.into_view()
                                                     }
                                                 }
                                             >
@@ -1560,25 +2728,32 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     </div>
                                     
                                     // Info hint at bottom
-                                    <div class="input-hint">
+                                    <div class="input-hint" class:collapsed=move || !composer_expanded.get()>
                                         <Show
                                             when=move || action_type.get() == "burn"
                                             fallback=move || {
                                                 view! {
                                                     <span class="hint-text">
                                                         <i class="fas fa-gift"></i>
-                                                        "Earn "
-                                                        <strong>{move || current_mint_reward.get().unwrap_or_else(|| "+1 MEMO".to_string())}</strong>
-                                                        " per message"
+                                                        {t("chat.input.earn_prefix")}
+                                                        <strong>{move || current_mint_reward.get().unwrap_or_else(|| DEFAULT_MINT_REWARD_DISPLAY.to_string())}</strong>
+                                                        {t("chat.input.earn_suffix")}
                                                     </span>
                                                 }
                                             }
                                         >
                                             <span class="hint-text burn">
                                                 <i class="fas fa-chart-line"></i>
-                                                "Burn tokens to boost your leaderboard ranking"
+                                                {t("chat.input.burn_hint")}
                                             </span>
                                         </Show>
+                                        <span class="hint-text fee-hint">
+                                            <i class="fas fa-receipt"></i>
+                                            {move || match fee_estimate.get() {
+                                                Some(fee) => tf("chat.input.fee_estimate", &[&format_xnt(fee)]),
+                                                None => tf("chat.input.fee_minimum", &[&MIN_FEE_BALANCE_XNT.to_string()]),
+                                            }}
+                                        </span>
                                     </div>
                                 </div>
                             </div>
@@ -1593,9 +2768,9 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                             <div class="header-text">
                                 <h1>
                                     <i class="fas fa-comments"></i>
-                                    "Chat Groups"
+                                    {t("chat.groups.title")}
                                 </h1>
-                                <p class="page-subtitle">"Connect and communicate on X1 Blockchain"</p>
+                                <p class="page-subtitle">{t("chat.groups.subtitle")}</p>
                             </div>
                             <div class="header-actions">
                                 <button 
@@ -1604,23 +2779,23 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                                     disabled=move || loading.get()
                                     title=move || {
                                         if !session.with(|s| s.has_user_profile()) {
-                                            "Please create your profile first".to_string()
+                                            t("chat.groups.profile_required").to_string()
                                         } else {
-                                            "Create new chat group".to_string()
+                                            t("chat.groups.create_title").to_string()
                                         }
                                     }
                                 >
                                     <i class="fas fa-plus"></i>
-                                    "Create Group"
+                                    {t("chat.groups.create")}
                                 </button>
                                 <button 
                                     class="refresh-button"
-                                    on:click=refresh_groups_data
+                                    on:click=move |_| refresh_groups_data()
                                     disabled=move || loading.get()
-                                    title="Refresh chat groups"
+                                    title=t("chat.groups.refresh_title")
                                 >
                                     <i class="fas fa-sync-alt" class:fa-spin=move || loading.get()></i>
-                                    "Refresh"
+                                    {t("chat.groups.refresh")}
                                 </button>
                             </div>
                         </div>
@@ -1636,156 +2811,164 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
                         </div>
                     </Show>
 
-                    // Display countdown message while waiting for blockchain update
-                    {move || if is_waiting_for_blockchain.get() && countdown_seconds.get() > 0 {
-                        view! {
-                            <div class="alert alert-info">
-                                <div class="countdown-display">
-                                    <i class="fas fa-clock"></i>
-                                    <span class="countdown-message">
-                                        "Group created successfully! Waiting for blockchain confirmation... ("
-                                        {move || countdown_seconds.get()}
-                                        " seconds remaining)"
-                                    </span>
-                                </div>
-                            </div>
-                        }
-                    } else {
-                        view! { <div></div> }
-                    }}
+                    <Show when=move || !leaderboard_warnings.get().is_empty()>
+                        <div class="partial-failure-notice">
+                            <i class="fas fa-exclamation-circle"></i>
+                            <span>{move || leaderboard_warnings.get().join(". ")}</span>
+                            <button
+                                class="partial-failure-retry"
+                                disabled=move || loading.get()
+                                on:click=move |_| reload_leaderboard(true)
+                            >
+                                <i class="fas fa-redo"></i>
+                                {t("chat.groups.retry")}
+                            </button>
+                        </div>
+                    </Show>
+
+                    // Progress of a newly created group, with a link once it is confirmed
+                    <CreationNotice
+                        kind="group"
+                        status=group_creation
+                        on_open=open_created_group
+                        on_dismiss=move || set_group_creation.set(None)
+                    />
 
                     <Show
-                        when=move || !loading.get() && leaderboard_data.get().is_some()
+                        when=move || !loading.get() && (leaderboard_data.get().is_some() || display_mode.get() != GroupsDisplayMode::BurnLeaderboard)
                         fallback=move || view! {
                             <div class="loading-container">
                                 <div class="loading-spinner"></div>
-                                <p>"Loading burn leaderboard..."</p>
+                                <p>{t("chat.groups.loading_leaderboard")}</p>
                             </div>
                         }
                     >
                         {move || {
-                            leaderboard_data.get().map(|leaderboard| {
-                                view! {
-                                    <div class="leaderboard-overview">
-                                        // Featured Activity Section (with 3D carousel effect)
-                                        <Show when=move || !featured_burns.get().is_empty()>
-                                            <div class="chat-featured-section">
-                                                <h2 class="section-title">
-                                                    <i class="fas fa-star"></i>
-                                                    "Featured Activity"
-                                                </h2>
-                                                <div class="chat-carousel-container">
-                                                    <div class="chat-carousel-track">
-                                                        {move || {
-                                                            let featured = featured_burns.get();
-                                                            let idx = current_featured_index.get();
-                                                            
-                                                            if featured.is_empty() {
-                                                                return view! { <div class="empty-featured"></div> }.into_view();
-                                                            }
+                            view! {
+                                <div class="leaderboard-overview">
+                                    // Featured Activity Section (with 3D carousel effect)
+                                    <Show when=move || !featured_burns.get().is_empty()>
+                                        <div class="chat-featured-section">
+                                            <h2 class="section-title">
+                                                <i class="fas fa-star"></i>
+                                                {t("chat.groups.featured")}
+                                            </h2>
+                                            <div class="chat-carousel-container">
+                                                <div class="chat-carousel-track">
+                                                    {move || {
+                                                        let featured = featured_burns.get();
+                                                        let idx = current_featured_index.get();
+                                                        
+                                                        if featured.is_empty() {
+                                                            return view! { <div class="empty-featured"></div> }.into_view();
+                                                        }
+                                                        
+                                                        let len = featured.len();
+                                                        let prev_idx = if idx == 0 { len - 1 } else { idx - 1 };
+                                                        let next_idx = (idx + 1) % len;
+                                                        
+                                                        view! {
+                                                            // Back card (prev) - clickable
+                                                            <div class="carousel-card back" on:click=move |_| {
+                                                                set_current_featured_index.update(|i| *i = prev_idx);
+                                                            }>
+                                                                {render_chat_featured_card(featured[prev_idx].clone(), session, leaderboard_group_infos)}
+                                                            </div>
                                                             
-                                                            let len = featured.len();
-                                                            let prev_idx = if idx == 0 { len - 1 } else { idx - 1 };
-                                                            let next_idx = (idx + 1) % len;
+                                                            // Front card (current) - main focus
+                                                            <div class="carousel-card front">
+                                                                {render_chat_featured_card(featured[idx].clone(), session, leaderboard_group_infos)}
+                                                            </div>
                                                             
+                                                            // Next card - clickable
+                                                            <div class="carousel-card next" on:click=move |_| {
+                                                                set_current_featured_index.update(|i| *i = next_idx);
+                                                            }>
+                                                                {render_chat_featured_card(featured[next_idx].clone(), session, leaderboard_group_infos)}
+                                                            </div>
+                                                        }.into_view()
+                                                    }}
+                                                </div>
+                                                
+                                                // Carousel indicators
+                                                <div class="carousel-indicators">
+                                                    {move || {
+                                                        let featured = featured_burns.get();
+                                                        let idx = current_featured_index.get();
+                                                        
+                                                        featured.iter().enumerate().map(|(i, _)| {
                                                             view! {
-                                                                // Back card (prev) - clickable
-                                                                <div class="carousel-card back" on:click=move |_| {
-                                                                    set_current_featured_index.update(|i| *i = prev_idx);
-                                                                }>
-                                                                    {render_chat_featured_card(featured[prev_idx].clone(), session, leaderboard_group_infos)}
-                                                                </div>
-                                                                
-                                                                // Front card (current) - main focus
-                                                                <div class="carousel-card front">
-                                                                    {render_chat_featured_card(featured[idx].clone(), session, leaderboard_group_infos)}
-                                                                </div>
-                                                                
-                                                                // Next card - clickable
-                                                                <div class="carousel-card next" on:click=move |_| {
-                                                                    set_current_featured_index.update(|i| *i = next_idx);
-                                                                }>
-                                                                    {render_chat_featured_card(featured[next_idx].clone(), session, leaderboard_group_infos)}
-                                                                </div>
-                                                            }.into_view()
-                                                        }}
-                                                    </div>
-                                                    
-                                                    // Carousel indicators
-                                                    <div class="carousel-indicators">
-                                                        {move || {
-                                                            let featured = featured_burns.get();
-                                                            let idx = current_featured_index.get();
-                                                            
-                                                            featured.iter().enumerate().map(|(i, _)| {
-                                                                view! {
-                                                                    <button
-                                                                        class="indicator"
-                                                                        class:active=move || i == idx
-                                                                        on:click=move |_| set_current_featured_index.set(i)
-                                                                    >
-                                                                    </button>
-                                                                }
-                                                            }).collect::<Vec<_>>()
-                                                        }}
-                                                    </div>
+                                                                <button
+                                                                    class="indicator"
+                                                                    class:active=move || i == idx
+                                                                    on:click=move |_| set_current_featured_index.set(i)
+                                                                >
+                                                                </button>
+                                                            }
+                                                        }).collect::<Vec<_>>()
+                                                    }}
                                                 </div>
                                             </div>
-                                        </Show>
-                                        
-                                        <div class="display-mode-selector">
-                                            <label for="display-mode">
-                                                <i class="fas fa-filter"></i>
-                                                "Display Mode:"
-                                            </label>
-                                            <select 
-                                                id="display-mode"
-                                                on:change=move |ev| {
-                                                    let value = event_target_value(&ev);
-                                                    let new_mode = match value.as_str() {
-                                                        "Latest" => GroupsDisplayMode::Latest,
-                                                        "Oldest" => GroupsDisplayMode::Oldest,
-                                                        _ => GroupsDisplayMode::BurnLeaderboard,
-                                                    };
-                                                    handle_mode_change(new_mode);
-                                                }
-                                            >
-                                                <option 
-                                                    value="Burn Leaderboard"
-                                                    prop:selected=move || display_mode.get() == GroupsDisplayMode::BurnLeaderboard
-                                                >
-                                                    "Burn Leaderboard"
-                                                </option>
-                                                <option 
-                                                    value="Latest"
-                                                    prop:selected=move || display_mode.get() == GroupsDisplayMode::Latest
-                                                >
-                                                    "Latest"
-                                                </option>
+                                        </div>
+                                    </Show>
+                                    
+                                    <div class="display-mode-selector">
+                                        <label for="display-mode">
+                                            <i class="fas fa-filter"></i>
+                                            {t("chat.groups.display_mode")}
+                                        </label>
+                                        <select 
+                                            id="display-mode"
+                                            on:change=move |ev| {
+                                                let value = event_target_value(&ev);
+                                                handle_mode_change(GroupsDisplayMode::from_label(&value));
+                                            }
+                                        >
+                                            {GroupsDisplayMode::ALL.into_iter().map(|mode| view! {
                                                 <option 
-                                                    value="Oldest"
-                                                    prop:selected=move || display_mode.get() == GroupsDisplayMode::Oldest
+                                                    value=mode.label()
+                                                    prop:selected=move || display_mode.get() == mode
                                                 >
-                                                    "Oldest"
+                                                    {mode.label()}
                                                 </option>
-                                            </select>
-                                        </div>
-                                        <PaginatedLeaderboardList 
-                                            display_mode=display_mode
-                                            paginated_groups=get_paginated_groups
-                                            latest_groups=latest_groups
-                                            oldest_groups=oldest_groups
-                                            current_page=current_page
-                                            mode_loading=mode_loading
-                                            go_to_page=go_to_page
-                                            next_page=next_page
-                                            prev_page=prev_page
-                                            enter_chat_room=enter_chat_room
-                                            leaderboard_group_infos=leaderboard_group_infos
+                                            }).collect::<Vec<_>>()}
+                                        </select>
+                                        <ViewModeToggle view_mode=view_mode on_change=change_view_mode/>
+                                        <IdJumpBox
+                                            placeholder=t("chat.groups.jump_placeholder")
+                                            pending=group_jump_pending
+                                            error=group_jump_error
+                                            on_jump=jump_to_group
                                         />
                                     </div>
-                                }
-                            })
+                                    <PaginatedLeaderboardList 
+                                        display_mode=display_mode
+                                        view_mode=view_mode
+                                        paginated_groups=get_paginated_groups
+                                        latest_groups=latest_groups
+                                        oldest_groups=oldest_groups
+                                        current_page=current_page
+                                        groups_per_page=groups_per_page
+                                        mode_loading=mode_loading
+                                        go_to_page=go_to_page
+                                        next_page=next_page
+                                        prev_page=prev_page
+                                        enter_chat_room=enter_chat_room
+                                        leaderboard_group_infos=leaderboard_group_infos
+                                        favorite_groups=favorite_groups
+                                        favorite_ids=favorite_ids
+                                        visited_ids=visited_ids
+                                        read_until=read_until
+                                        toggle_favorite=toggle_favorite
+                                        total_groups=total_groups
+                                        failed_group_infos=failed_group_infos
+                                        retrying_group_infos=retrying_group_infos
+                                        retry_group_infos=retry_group_infos
+                                        no_groups_yet=Signal::derive(move || groups_total_known.get() && total_groups.get() == 0)
+                                        open_create_dialog=open_create_dialog
+                                    />
+                                </div>
+                            }
                         }}
                     </Show>
                 </div>
@@ -1806,6 +2989,176 @@ pub fn ChatPage(session: RwSignal<Session>) -> impl IntoView {
     }
 }
 
+/// Burn leaderboard data loaded for the groups list
+struct LeaderboardLoad {
+    /// None when the ranking could not be fetched
+    leaderboard: Option<BurnLeaderboardResponse>,
+    /// None when the global statistics could not be fetched
+    total_groups: Option<u64>,
+    group_infos: HashMap<u64, ChatGroupInfo>,
+    /// Ranked groups whose info could not be fetched
+    failed_group_ids: Vec<u64>,
+    /// Top burns for the featured carousel, only when requested
+    featured_burns: Option<Vec<ChatContractTransaction>>,
+    /// Parts that failed while the rest loaded, for a non-blocking warning
+    warnings: Vec<String>,
+}
+
+/// Fetch the burn leaderboard, global stats and the info of every ranked group.
+/// Each part may fail on its own and is reported in `warnings`; only when both
+/// the leaderboard and the stats fail is the whole load an error.
+async fn load_leaderboard(include_featured: bool) -> Result<LeaderboardLoad, RpcError> {
+    let rpc = RpcConnection::new();
+    
+    // parallel get leaderboard data, global stats, and (optionally) recent transactions
+    let transactions_future = async {
+        if include_featured {
+            Some(rpc.get_recent_chat_contract_transactions().await)
+        } else {
+            None
+        }
+    };
+    let (leaderboard, global_stats, transactions) = futures::join!(
+        rpc.get_burn_leaderboard(),
+        rpc.get_chat_global_statistics(),
+        transactions_future
+    );
+    
+    let (leaderboard, global_stats) = match (leaderboard, global_stats) {
+        (Err(e), Err(_)) => return Err(e),
+        results => results,
+    };
+    
+    let mut warnings = Vec::new();
+    let total_groups = match global_stats {
+        Ok(global_stats) => Some(global_stats.total_groups),
+        Err(e) => {
+            warnings.push(format!("Group statistics are unavailable: {}", e));
+            None
+        }
+    };
+    let featured_burns = match transactions {
+        Some(Ok(response)) => Some(top_burn_transactions(&response.transactions, 3)),
+        Some(Err(e)) => {
+            log::warn!("Failed to load featured burns: {}", e);
+            None
+        }
+        None => None,
+    };
+    
+    let (leaderboard, group_infos, failed_group_ids) = match leaderboard {
+        Ok(mut leaderboard) => {
+            // Sort leaderboard by burned_amount unless the stored ranks are preferred
+            leaderboard.order_entries(UiPreferences::load().keep_leaderboard_order);
+            add_log_entry("INFO", &format!("Loaded {} groups in burn leaderboard", leaderboard.entries.len()));
+            
+            let group_ids: Vec<u64> = leaderboard.entries.iter().map(|entry| entry.group_id).collect();
+            let (group_infos, failed_group_ids) = load_group_infos(&rpc, &group_ids).await;
+            (Some(leaderboard), group_infos, failed_group_ids)
+        }
+        Err(e) => {
+            warnings.push(format!("The burn ranking is unavailable: {}", e));
+            (None, HashMap::new(), vec![])
+        }
+    };
+    
+    Ok(LeaderboardLoad {
+        leaderboard,
+        total_groups,
+        group_infos,
+        failed_group_ids,
+        featured_burns,
+        warnings,
+    })
+}
+
+/// Fetch group infos one by one, returning the loaded infos and the ids that failed
+async fn load_group_infos(rpc: &RpcConnection, group_ids: &[u64]) -> (HashMap<u64, ChatGroupInfo>, Vec<u64>) {
+    let mut group_infos = HashMap::new();
+    let mut failed_group_ids = vec![];
+    for &group_id in group_ids {
+        match cache::get_chat_group_info(rpc, group_id).await {
+            Ok(group_info) => {
+                group_infos.insert(group_id, group_info);
+            },
+            Err(e) => {
+                log::warn!("Failed to get group info for group {}: {}", group_id, e);
+                failed_group_ids.push(group_id);
+            }
+        }
+    }
+    (group_infos, failed_group_ids)
+}
+
+/// How long a sent message is followed until it is finalized
+const MESSAGE_CONFIRMATION_TIMEOUT_MS: u32 = 60_000;
+
+/// How often the open room is checked for new messages while alerts are on
+/// and the tab is in the background
+const MESSAGE_ALERT_POLL_INTERVAL_MS: u32 = 30_000;
+
+/// Group min intervals (seconds) at or above this get a notice in the chat room input area
+const HIGH_MIN_INTERVAL_SECS: i64 = 3_600;
+
+/// Quick-select burn amounts (MEMO tokens) in the chat burn panel
+const BURN_PRESETS: [u64; 3] = [10, 100, 1_000];
+
+/// How long a send or burn error stays above the composer
+const COMPOSER_ERROR_DISMISS_MS: u64 = 6_000;
+
+/// Tokens left untouched by the "Max" burn preset
+const MAX_BURN_BUFFER_TOKENS: u64 = 1;
+
+/// Largest whole-token burn that keeps the safety buffer in the wallet
+fn max_burn_tokens(token_balance: f64) -> u64 {
+    (token_balance.max(0.0).floor() as u64).saturating_sub(MAX_BURN_BUFFER_TOKENS)
+}
+
+/// Media query matching the phone breakpoint in `chat_page.css`
+const NARROW_VIEWPORT_QUERY: &str = "(max-width: 768px)";
+
+fn is_narrow_viewport() -> bool {
+    window()
+        .and_then(|win| win.match_media(NARROW_VIEWPORT_QUERY).ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+/// Save `json` as a downloaded file via a temporary data: URL link
+fn download_json(json: &str, filename: &str) {
+    let link = window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.create_element("a").ok())
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+    let Some(link) = link else {
+        log::error!("Failed to create download link");
+        return;
+    };
+    let href = format!("data:application/json;charset=utf-8,{}", js_sys::encode_uri_component(json));
+    let _ = link.set_attribute("href", &href);
+    let _ = link.set_attribute("download", filename);
+    link.click();
+}
+
+/// How often and how many times to re-read a newly created group
+const GROUP_POLL_INTERVAL_MS: u32 = 2_000;
+const GROUP_POLL_ATTEMPTS: u32 = 15;
+
+/// Poll the group info until it can be read, or give up and return None
+async fn wait_for_group(group_id: u64) -> Option<ChatGroupInfo> {
+    let rpc = RpcConnection::new();
+    for _ in 0..GROUP_POLL_ATTEMPTS {
+        TimeoutFuture::new(GROUP_POLL_INTERVAL_MS).await;
+        match rpc.get_chat_group_info(group_id).await {
+            Ok(info) => {
+                cache::invalidate_chat_group_info(group_id);
+                return Some(info);
+            }
+            Err(e) => log::warn!("Failed to read group {} while waiting for it: {}", group_id, e),
+        }
+    }
+    None
+}
+
 #[component]
 fn OverviewStats(stats: ChatStatistics) -> impl IntoView {
     view! {
@@ -1817,7 +3170,7 @@ fn OverviewStats(stats: ChatStatistics) -> impl IntoView {
                         <i class="fas fa-users"></i>
                     </div>
                     <div class="stat-content">
-                        <h3>{stats.total_groups}</h3>
+                        <h3>{format_number_with_commas(stats.total_groups)}</h3>
                         <p>"Total Groups"</p>
                     </div>
                 </div>
@@ -1827,7 +3180,7 @@ fn OverviewStats(stats: ChatStatistics) -> impl IntoView {
                         <i class="fas fa-comments"></i>
                     </div>
                     <div class="stat-content">
-                        <h3>{stats.total_memos}</h3>
+                        <h3>{format_number_with_commas(stats.total_memos)}</h3>
                         <p>"Total Messages"</p>
                     </div>
                 </div>
@@ -1837,7 +3190,14 @@ fn OverviewStats(stats: ChatStatistics) -> impl IntoView {
 }
 
 #[component]
-fn GroupsList(groups: Vec<ChatGroupInfo>, enter_chat_room: impl Fn(u64) + 'static + Copy) -> impl IntoView {
+fn GroupsList(
+    groups: Vec<ChatGroupInfo>,
+    enter_chat_room: impl Fn(u64) + 'static + Copy,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
     // Sort groups by burned amount (descending) for display
     let mut sorted_groups = groups;
     sorted_groups.sort_by(|a, b| b.burned_amount.cmp(&a.burned_amount));
@@ -1863,7 +3223,7 @@ fn GroupsList(groups: Vec<ChatGroupInfo>, enter_chat_room: impl Fn(u64) + 'stati
                         each=move || groups_signal.get()
                         key=|group| group.group_id
                         children=move |group: ChatGroupInfo| {
-                            view! { <GroupCard group=group enter_chat_room=enter_chat_room/> }
+                            view! { <GroupCard group=group enter_chat_room=enter_chat_room favorite_ids=favorite_ids visited_ids=visited_ids read_until=read_until toggle_favorite=toggle_favorite/> }
                         }
                     />
                 </div>
@@ -1873,23 +3233,42 @@ fn GroupsList(groups: Vec<ChatGroupInfo>, enter_chat_room: impl Fn(u64) + 'stati
 }
 
 #[component]
-fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Copy) -> impl IntoView {
+fn GroupCard(
+    group: ChatGroupInfo,
+    enter_chat_room: impl Fn(u64) + 'static + Copy,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
     // Create signals for the data that will be used in reactive contexts
     let group_name = create_memo(move |_| group.name.clone());
     let group_id = create_memo(move |_| group.group_id);
     let group_image = create_memo(move |_| group.image.clone());
     let group_description = create_memo(move |_| {
-        if group.description.len() > 100 {
-            format!("{}...", &group.description[..97])
-        } else {
-            group.description.clone()
-        }
+        safe_truncate(&group.description, 100)
     });
     let group_tags = create_memo(move |_| group.tags.clone());
     let group_memo_count = create_memo(move |_| group.memo_count);
     let group_burned_amount = create_memo(move |_| group.burned_amount);
     let group_creator = create_memo(move |_| group.creator.clone());
     let group_min_memo_interval = create_memo(move |_| group.min_memo_interval);
+    let last_memo_time = group.last_memo_time;
+    let unread = Signal::derive(move || {
+        read_until.with(|read_until| last_seen::has_unread(read_until, group_id.get(), last_memo_time))
+    });
+
+    // Distinct recent posters; a page of messages per card, so cached
+    let (participants, set_participants) = create_signal(None::<GroupParticipants>);
+    if group.memo_count > 0 {
+        let participants_group_id = group.group_id;
+        spawn_local(async move {
+            match cache::get_chat_group_participants(&RpcConnection::new(), participants_group_id).await {
+                Ok(found) => { let _ = set_participants.try_set(Some(found)); }
+                Err(e) => log::warn!("Failed to load participants of group {}: {}", participants_group_id, e),
+            }
+        });
+    }
 
     // Format timestamps using our helper function
     let created_at_formatted = format_timestamp(group.created_at);
@@ -1905,10 +3284,12 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
     };
 
     view! {
-        <div class="group-card clickable" on:click=handle_click>
+        <div class="group-card clickable" class:visited=move || visited_ids.get().contains(&group_id.get()) on:click=handle_click>
             <div class="group-header">
                 <h3 class="group-name">{move || group_name.get()}</h3>
+                <UnreadDot unread=unread/>
                 <div class="group-id">#{move || group_id.get()}</div>
+                <FavoriteButton group_id=group_id.get_untracked() favorite_ids=favorite_ids toggle_favorite=toggle_favorite/>
             </div>
             
             <Show
@@ -1934,6 +3315,7 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
                                         <LazyPixelView
                                             art={fake_pixel_art}
                                             size=64
+                                            hide_copy=true
                                         />
                                     }.into_view()
                                 } else {
@@ -1954,6 +3336,7 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
                                     <LazyPixelView
                                         art={fake_pixel_art}
                                         size=64
+                                        hide_copy=true
                                     />
                                 }.into_view()
                             }
@@ -1977,6 +3360,7 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
                                 <LazyPixelView
                                     art={fake_pixel_art}
                                     size=64
+                                    hide_copy=true
                                 />
                             }.into_view()
                         }
@@ -1994,12 +3378,18 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
             <div class="group-stats">
                 <div class="stat-item">
                     <i class="fas fa-comments"></i>
-                    <span>{move || group_memo_count.get()} " messages"</span>
+                    <span>{move || format_number_with_commas(group_memo_count.get())} " messages"</span>
                 </div>
                 <div class="stat-item">
                     <i class="fas fa-fire"></i>
-                    <span>{move || format!("{}", group_burned_amount.get() / 1_000_000)} " MEMO"</span>
+                    <span>{move || format_memo(group_burned_amount.get())} " MEMO"</span>
                 </div>
+                {move || participants.get().map(|found| view! {
+                    <div class="stat-item">
+                        <i class="fas fa-users"></i>
+                        <span>{participants_label(&found)}</span>
+                    </div>
+                })}
             </div>
             
             <Show
@@ -2019,27 +3409,26 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
             
             <div class="group-meta">
                 <div class="meta-item">
-                    <label>"Creator:"</label>
+                    <label>{t("chat.group.creator")}</label>
                     <span class="creator-address" title={move || group_creator.get()}>
                         {move || {
-                            let creator = group_creator.get();
-                            format!("{}...{}", &creator[..4], &creator[creator.len()-4..])
+                            shorten_pubkey(&group_creator.get())
                         }}
                     </span>
                 </div>
                 <div class="meta-item">
-                    <label>"Created:"</label>
+                    <label>{t("chat.group.created")}</label>
                     <span>{created_at_formatted}</span>
                 </div>
                 <div class="meta-item">
-                    <label>"Last message:"</label>
+                    <label>{t("chat.group.last_message")}</label>
                     <span>{last_memo_formatted}</span>
                 </div>
                 {
                     if group.min_memo_interval > 0 {
                         view! {
                             <div class="meta-item">
-                                <label>"Min interval:"</label>
+                                <label>{t("chat.group.min_interval")}</label>
                                 <span>{move || group_min_memo_interval.get()} "s"</span>
                             </div>
                         }
@@ -2051,7 +3440,7 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
             
             <div class="enter-chat-hint">
                 <i class="fas fa-arrow-right"></i>
-                <span>"Click to enter chat group"</span>
+                <span>{t("chat.group.enter_hint")}</span>
             </div>
         </div>
     }
@@ -2060,11 +3449,25 @@ fn GroupCard(group: ChatGroupInfo, enter_chat_room: impl Fn(u64) + 'static + Cop
 #[component]
 fn MessageItem(
     message: LocalChatMessage, 
+    /// The message this one replies to, when it is among the loaded messages
+    replied_to: Option<ChatMessage>,
     current_mint_reward: ReadSignal<Option<String>>, 
     session: RwSignal<Session>,
     user_display_cache: ReadSignal<HashMap<String, UserDisplayInfo>>,
+    sender_domains: ReadSignal<HashMap<String, Option<String>>>,
+    /// In-room search term, highlighted in the message text
+    search: Signal<String>,
     retry_callback: impl Fn(String) + 'static + Copy,
-    retry_burn_callback: impl Fn(String, u64) + 'static + Copy
+    retry_burn_callback: impl Fn(String, u64) + 'static + Copy,
+    hide_callback: impl Fn(ChatMessage) + 'static + Copy,
+    report_callback: impl Fn(ChatMessage) + 'static + Copy,
+    cancel_callback: impl Fn(String) + 'static + Copy,
+    dismiss_callback: impl Fn(String) + 'static + Copy,
+    reply_callback: impl Fn(ChatMessage) + 'static + Copy,
+    burn_reply_callback: impl Fn(ChatMessage) + 'static + Copy,
+    /// Copy this message and the ones after it as Markdown; true once copied
+    copy_thread_callback: impl Fn(String) -> bool + 'static + Copy,
+    developer_mode: bool,
 ) -> impl IntoView {
     // Store values in variables to make them accessible in closures
     let timestamp = message.message.timestamp;
@@ -2074,12 +3477,43 @@ fn MessageItem(
     let is_local = message.is_local;
     let message_type = message.message.message_type.clone();
     let burn_amount = message.message.burn_amount;
+    let reply_to_sig = message.message.reply_to_sig.clone();
     
+    let chain_message = message.message.clone();
+    // Copy the message text, with a short "Copied!" tip
+    let copy_text = store_value(message_copy_text(&chain_message));
+    let (text_copied, set_text_copied) = create_signal(false);
+    let copy_button = move || view! {
+        <span class="message-copy">
+            <button
+                class="message-action-btn"
+                title=t("chat.message.copy")
+                on:click=move |_| {
+                    if let Some(window) = window() {
+                        let _ = window.navigator().clipboard().write_text(&copy_text.get_value());
+                        set_text_copied.set(true);
+                        set_timeout(move || set_text_copied.set(false), Duration::from_secs(2));
+                    }
+                }
+            >
+                <i class=move || if text_copied.get() { "fas fa-check" } else { "fas fa-copy" }></i>
+            </button>
+            <Show when=move || text_copied.get()>
+                <span class="message-copied-tip">{t("chat.message.copied")}</span>
+            </Show>
+        </span>
+    };
+    let (reported, set_reported) = create_signal(
+        !is_local && moderation::load_reported_messages().contains(&chain_message.signature)
+    );
+
     // Create clones for different uses to avoid move issues
     let message_type_for_class = message_type.clone();
     let message_type_for_status = message_type.clone();
     let message_type_for_meta = message_type.clone();
     let message_content_for_status = message_content.clone();
+    let signature_for_cancel = chain_message.signature.clone();
+    let sender_for_status = sender.clone();
     
     // Check if this message is from the current user
     let is_current_user = session.with_untracked(|s| {
@@ -2092,28 +3526,7 @@ fn MessageItem(
     
     // Helper function to format sender with username and pubkey
     let get_display_name = move |sender: &str| -> String {
-        let cache = user_display_cache.get();
-        
-        // create short pubkey display
-        let short_pubkey = if sender.is_empty() {
-            "unknown".to_string()
-        } else if sender.len() >= 8 {
-            format!("{}...{}", &sender[..4], &sender[sender.len()-4..])
-        } else {
-            sender.to_string()
-        };
-        
-        if let Some(display_info) = cache.get(sender) {
-            // if has username, display "username (abcd...efgh)" format
-            format!("{} ({})", display_info.username, short_pubkey)
-        } else {
-            // if no username in cache, only display short pubkey
-            if sender.is_empty() {
-                "Anonymous".to_string()
-            } else {
-                short_pubkey
-            }
-        }
+        user_display_cache.with(|cache| sender_domains.with(|domains| format_sender_name(cache, domains, sender)))
     };
     
     // Get avatar image data for display
@@ -2158,23 +3571,180 @@ fn MessageItem(
         >
             <div class="message-header">
                 {get_avatar_view(&sender)}
-                <span class="sender" title=format!("Full address: {}", sender)>
-                    {get_display_name(&sender)}
-                </span>
-                <span class="timestamp">
-                    {move || {
-                        if timestamp > 0 {
-                            format_timestamp(timestamp)
-                        } else {
-                            "Unknown time".to_string()
-                        }
-                    }}
-                </span>
+                <PubkeyDisplay
+                    class="sender"
+                    address=sender.clone()
+                    label=Signal::derive({
+                        let sender = sender.clone();
+                        move || get_display_name(&sender)
+                    })
+                />
+                {if timestamp > 0 {
+                    view! {
+                        <RelativeTime class="timestamp" timestamp=timestamp absolute=format_timestamp(timestamp)/>
+                    }.into_view()
+                } else {
+                    view! { <span class="timestamp">"Unknown time"</span> }.into_view()
+                }}
+                {
+                    // moderation actions only apply to confirmed on-chain messages
+                    if !is_local {
+                        let message_for_hide = chain_message.clone();
+                        let message_for_report = chain_message.clone();
+                        let message_for_reply = chain_message.clone();
+                        let message_for_burn_reply = chain_message.clone();
+                        let signature_for_copy = chain_message.signature.clone();
+                        let explorer_url = explorer_tx_url(&chain_message.signature);
+                        let (copied, set_copied) = create_signal(false);
+                        // on-demand check of the rendered message against its transaction
+                        let message_for_verify = chain_message.clone();
+                        let (verifying, set_verifying) = create_signal(false);
+                        let (verification, set_verification) = create_signal::<Option<Result<MessageVerification, String>>>(None);
+                        let verify = move |_| {
+                            let message = message_for_verify.clone();
+                            set_verifying.set(true);
+                            spawn_local(async move {
+                                let rpc = RpcConnection::new();
+                                let result = rpc.verify_chat_message(&message).await.map_err(|e| e.to_string());
+                                if let Ok(MessageVerification::Mismatch(reason)) = &result {
+                                    add_log_entry("WARN", &format!("Message {} does not match chain: {}", message.signature, reason));
+                                }
+                                set_verification.set(Some(result));
+                                set_verifying.set(false);
+                            });
+                        };
+                        view! {
+                            <>
+                                {move || verification.get().map(|result| match result {
+                                    Ok(MessageVerification::Verified) => view! {
+                                        <span class="message-verification verified" title=t("chat.message.verified_title")>
+                                            <i class="fas fa-shield-alt"></i>" "{t("chat.message.verified")}
+                                        </span>
+                                    }.into_view(),
+                                    Ok(MessageVerification::Mismatch(reason)) => view! {
+                                        <span class="message-verification mismatch" title=reason>
+                                            <i class="fas fa-exclamation-triangle"></i>" "{t("chat.message.mismatch")}
+                                        </span>
+                                    }.into_view(),
+                                    Ok(MessageVerification::NotFound) => view! {
+                                        <span class="message-verification not-found" title=t("chat.message.not_found_title")>
+                                            <i class="fas fa-hourglass-half"></i>" "{t("chat.message.not_found")}
+                                        </span>
+                                    }.into_view(),
+                                    Err(e) => view! {
+                                        <span class="message-verification failed" title=tf("chat.message.verify_failed", &[&e])>
+                                            <i class="fas fa-question-circle"></i>
+                                        </span>
+                                    }.into_view(),
+                                })}
+                                <div class="message-actions">
+                                    {copy_button()}
+                                    {explorer_url.map(|url| view! {
+                                        <a
+                                            class="message-action-btn"
+                                            href=url
+                                            target="_blank"
+                                            rel="noopener noreferrer"
+                                            title=t("chat.message.explorer")
+                                        >
+                                            <i class="fas fa-external-link-alt"></i>
+                                        </a>
+                                    })}
+                                    <button
+                                        class="message-action-btn"
+                                        title=move || if verifying.get() { t("chat.message.verifying") } else { t("chat.message.verify") }
+                                        disabled=move || verifying.get()
+                                        on:click=verify
+                                    >
+                                        <i class=move || if verifying.get() { "fas fa-spinner fa-spin" } else { "fas fa-shield-alt" }></i>
+                                    </button>
+                                    <button
+                                        class="message-action-btn"
+                                        title=move || if copied.get() { t("chat.message.copied_markdown") } else { t("chat.message.copy_markdown") }
+                                        on:click=move |_| {
+                                            if copy_thread_callback(signature_for_copy.clone()) {
+                                                set_copied.set(true);
+                                                set_timeout(move || set_copied.set(false), Duration::from_secs(2));
+                                            }
+                                        }
+                                    >
+                                        <i class=move || if copied.get() { "fas fa-check" } else { "fab fa-markdown" }></i>
+                                    </button>
+                                    <button
+                                        class="message-action-btn"
+                                        title=t("chat.message.reply")
+                                        on:click=move |_| reply_callback(message_for_reply.clone())
+                                    >
+                                        <i class="fas fa-reply"></i>
+                                    </button>
+                                    <button
+                                        class="message-action-btn"
+                                        title=t("chat.message.reply_burn")
+                                        on:click=move |_| burn_reply_callback(message_for_burn_reply.clone())
+                                    >
+                                        <i class="fas fa-fire"></i>
+                                    </button>
+                                    <button
+                                        class="message-action-btn"
+                                        title=t("chat.message.hide")
+                                        on:click=move |_| hide_callback(message_for_hide.clone())
+                                    >
+                                        <i class="fas fa-eye-slash"></i>
+                                    </button>
+                                    <button
+                                        class="message-action-btn"
+                                        class:reported=move || reported.get()
+                                        title=move || if reported.get() {
+                                            t("chat.message.reported")
+                                        } else {
+                                            t("chat.message.report")
+                                        }
+                                        disabled=move || reported.get()
+                                        on:click=move |_| {
+                                            report_callback(message_for_report.clone());
+                                            set_reported.set(true);
+                                        }
+                                    >
+                                        <i class="fas fa-flag"></i>
+                                    </button>
+                                </div>
+                            </>
+                        }.into_view()
+                    } else {
+                        // pending and failed messages keep their status buttons in the corner
+                        view! { <div class="message-actions">{copy_button()}</div> }.into_view()
+                    }
+                }
             </div>
             <div class="message-content-wrapper">
-                <div class="message-content">
-                    {message_content.clone()}
-                </div>
+                {reply_to_sig.map(|sig| {
+                    let (author, text) = match &replied_to {
+                        Some(original) => (get_display_name(&original.sender), message_preview(&original.message)),
+                        None => (tf("chat.message.reply_author_unknown", &[&shorten_address(&sig)]), t("chat.message.reply_not_loaded").to_string()),
+                    };
+                    view! {
+                        <div class="message-reply-quote" title=tf("chat.message.reply_to", &[&sig])>
+                            <i class="fas fa-reply"></i>
+                            <span class="message-reply-author">{author}</span>
+                            <span class="message-reply-text">{text}</span>
+                        </div>
+                    }
+                })}
+                {
+                    let (text, pixel) = split_message_pixel(&message_content);
+                    view! {
+                        {(!text.is_empty()).then(|| view! {
+                            <div class="message-content user-text">
+                                <UserText text=text highlight=search markdown=true/>
+                            </div>
+                        })}
+                        {pixel.map(|art| view! {
+                            <div class="message-pixel">
+                                <LazyPixelView art=art size=96/>
+                            </div>
+                        })}
+                    }
+                }
                 // show status for local messages
                 {
                     move || {
@@ -2183,16 +3753,33 @@ fn MessageItem(
                                 <div class="message-status-corner">
                                     {
                                         match status {
-                                            MessageStatus::Sending => view! {
-                                                <span class="status-sending">
-                                                    <i class="fas fa-clock"></i>
-                                                    "Sending..."
-                                                </span>
-                                            }.into_view(),
+                                            MessageStatus::Sending => {
+                                                let local_signature = signature_for_cancel.clone();
+                                                view! {
+                                                    <span class="status-sending">
+                                                        <i class="fas fa-clock"></i>
+                                                        {t("chat.status.sending")}
+                                                        <button
+                                                            class="cancel-send-button"
+                                                            on:click=move |_| cancel_callback(local_signature.clone())
+                                                            title=t("chat.status.cancel_title")
+                                                        >
+                                                            <i class="fas fa-times"></i>
+                                                            {t("chat.status.cancel")}
+                                                        </button>
+                                                    </span>
+                                                }.into_view()
+                                            },
                                             MessageStatus::Sent => view! {
-                                                <span class="status-sent">
+                                                <span class="status-sent" title=t("chat.status.sent_title")>
                                                     <i class="fas fa-check"></i>
-                                                    "Sent"
+                                                    {t("chat.status.sent")}
+                                                </span>
+                                            }.into_view(),
+                                            MessageStatus::Confirmed => view! {
+                                                <span class="status-confirmed" title=t("chat.status.confirmed_title")>
+                                                    <i class="fas fa-check-double"></i>
+                                                    {t("chat.status.confirmed")}
                                                 </span>
                                             }.into_view(),
                                             MessageStatus::Failed => {
@@ -2203,7 +3790,7 @@ fn MessageItem(
                                                 view! {
                                                     <span class="status-failed">
                                                         <i class="fas fa-exclamation-triangle"></i>
-                                                        "Failed"
+                                                        {t("chat.status.failed")}
                                                         <button 
                                                             class="retry-button"
                                                             on:click={
@@ -2222,10 +3809,40 @@ fn MessageItem(
                                                                     }
                                                                 }
                                                             }
-                                                            title="Retry this operation"
+                                                            title=t("chat.status.retry_title")
                                                         >
                                                             <i class="fas fa-redo"></i>
-                                                            "Retry"
+                                                            {t("chat.status.retry")}
+                                                        </button>
+                                                    </span>
+                                                }.into_view()
+                                            },
+                                            MessageStatus::Unconfirmed => {
+                                                let local_signature = signature_for_cancel.clone();
+                                                view! {
+                                                    <span
+                                                        class="status-unconfirmed"
+                                                        title=t("chat.status.unconfirmed_title")
+                                                    >
+                                                        <i class="fas fa-question-circle"></i>
+                                                        {t("chat.status.unconfirmed")}
+                                                        <a
+                                                            class="retry-button"
+                                                            href=explorer_address_url(&sender_for_status).unwrap_or_default()
+                                                            target="_blank"
+                                                            rel="noopener noreferrer"
+                                                            title=t("chat.status.explorer_title")
+                                                        >
+                                                            <i class="fas fa-external-link-alt"></i>
+                                                            {t("chat.status.explorer")}
+                                                        </a>
+                                                        <button
+                                                            class="retry-button"
+                                                            on:click=move |_| dismiss_callback(local_signature.clone())
+                                                            title=t("chat.status.dismiss_title")
+                                                        >
+                                                            <i class="fas fa-times"></i>
+                                                            {t("chat.status.dismiss")}
                                                         </button>
                                                     </span>
                                                 }.into_view()
@@ -2238,7 +3855,7 @@ fn MessageItem(
                                                 view! {
                                                     <span class="status-timeout">
                                                         <i class="fas fa-clock"></i>
-                                                        "Timeout"
+                                                        {t("chat.status.timeout")}
                                                         <button 
                                                             class="retry-button"
                                                             on:click={
@@ -2257,10 +3874,10 @@ fn MessageItem(
                                                                     }
                                                                 }
                                                             }
-                                                            title="Retry this operation"
+                                                            title=t("chat.status.retry_title")
                                                         >
                                                             <i class="fas fa-redo"></i>
-                                                            "Retry"
+                                                            {t("chat.status.retry")}
                                                         </button>
                                                     </span>
                                                 }.into_view()
@@ -2284,7 +3901,7 @@ fn MessageItem(
                                 <span>
                                     {move || {
                                         if let Some(amount) = burn_amount {
-                                            format!("Burn {} MEMO", amount / 1_000_000)
+                                            format!("Burn {} MEMO", format_memo(amount))
                                         } else {
                                             "Burn operation".to_string()
                                         }
@@ -2297,55 +3914,52 @@ fn MessageItem(
                             <div class="memo-amount">
                                 <i class="fas fa-coins"></i>
                                 <span>
-                                    {move || current_mint_reward.get().unwrap_or_else(|| "+1 MEMO".to_string())}
+                                    {move || current_mint_reward.get().unwrap_or_else(|| DEFAULT_MINT_REWARD_DISPLAY.to_string())}
                                 </span>
                             </div>
                         }.into_view()
                     }
                 }
             </div>
+            {(developer_mode && !is_local).then(|| view! {
+                <RawPayloadView signature=chain_message.signature.clone() payload=chain_message.message.clone()/>
+            })}
         </div>
     }
 }
 
-// Helper function to format unix timestamp to readable date
-fn format_timestamp(timestamp: i64) -> String {
-    log::info!("Formatting timestamp: {}", timestamp);
-    
-    if timestamp <= 0 {
-        log::warn!("Invalid timestamp: {}", timestamp);
-        return "Unknown".to_string();
-    }
-    
-    use wasm_bindgen::prelude::*;
-    use js_sys::Date;
-    
-    // Convert unix timestamp to milliseconds for JavaScript Date
-    let date = Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0));
-    let iso_string = date.to_iso_string();
+/// Sender as "username (abcd...efgh)" when their profile is cached, else the short pubkey
+fn format_sender_name(
+    cache: &HashMap<String, UserDisplayInfo>,
+    domains: &HashMap<String, Option<String>>,
+    sender: &str,
+) -> String {
+    // create short pubkey display
+    let short_pubkey = if sender.is_empty() {
+        "unknown".to_string()
+    } else {
+        shorten_pubkey(sender)
+    };
     
-    match iso_string.as_string() {
-        Some(iso_str) => {
-            log::info!("ISO string: {}", iso_str);
-            if iso_str.len() >= 19 {
-                let date_part = &iso_str[0..10];
-                let time_part = &iso_str[11..16];
-                let formatted = format!("{} {}", date_part, time_part);
-                log::info!("Formatted time: {}", formatted);
-                formatted
-            } else {
-                let fallback = format!("Timestamp: {}", timestamp);
-                log::warn!("Short ISO string, using fallback: {}", fallback);
-                fallback
-            }
-        },
-        None => {
-            let fallback = format!("Timestamp: {}", timestamp);
-            log::warn!("Failed to get ISO string, using fallback: {}", fallback);
-            fallback
+    let display_info = cache.get(sender);
+    let domain = domains.get(sender).and_then(|domain| domain.as_deref());
+    if let Some(display_info) = display_info.filter(|info| info.has_profile) {
+        // if has username, display "username (abcd...efgh)" format
+        format!("{} ({})", display_info.username, short_pubkey)
+    } else if let Some(domain) = domain {
+        // no profile, but an X1NS domain: "name.x1 (abcd...efgh)"
+        format!("{} ({})", domain, short_pubkey)
+    } else if let Some(display_info) = display_info {
+        format!("{} ({})", display_info.username, short_pubkey)
+    } else {
+        // if no username in cache, only display short pubkey
+        if sender.is_empty() {
+            "Anonymous".to_string()
+        } else {
+            short_pubkey
         }
     }
-} 
+}
 
 // generate random pixel art string (simplest random fill)
 fn generate_random_pixel_art(seed: u64) -> String {
@@ -2374,7 +3988,7 @@ fn generate_random_pixel_art(seed: u64) -> String {
     let result = pixel.to_optimal_string();
     log::info!("Generated pixel art for seed {}: length={}, preview={}", 
         seed, result.len(), 
-        if result.len() > 30 { &result[..30] } else { &result }
+        safe_truncate(&result, 30)
     );
     result
 } 
@@ -2386,6 +4000,7 @@ fn CreateChatGroupForm(
     on_success: Rc<dyn Fn(String, u64)>,
     on_error: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     // Wrap callbacks in signals for easy access in closures
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
@@ -2404,9 +4019,28 @@ fn CreateChatGroupForm(
     let (error_message, set_error_message) = create_signal(String::new());
     let (show_copied, set_show_copied) = create_signal(false);
     let (creating_status, set_creating_status) = create_signal(String::new());
+    let (is_simulating, set_is_simulating) = create_signal(false);
+    let (simulation, set_simulation) = create_signal(None::<SimulationReport>);
 
     // Grid size for pixel art
     let (grid_size, set_grid_size) = create_signal(16usize);
+    // Undo/redo of the image; each edit records the image before it
+    let pixel_history = create_rw_signal(PixelHistory::default());
+    // Palette color the editor paints with (black by default)
+    let pixel_color = create_rw_signal(1u8);
+    let pixel_tool = create_rw_signal(PixelTool::default());
+    let edit_pixel_art = move |art: Pixel| {
+        pixel_history.update(|h| h.record(pixel_art.get_untracked()));
+        set_pixel_art.set(art);
+    };
+    let restore_pixel_art = move |art: Pixel| {
+        set_grid_size.set(art.dimensions().0);
+        set_pixel_art.set(art);
+    };
+    let load_pixel_art = move |art: Pixel| {
+        set_grid_size.set(art.dimensions().0);
+        edit_pixel_art(art);
+    };
 
     // Create combined image data
     let get_image_data = move || -> String {
@@ -2415,18 +4049,18 @@ fn CreateChatGroupForm(
 
     // Calculate current memo size in bytes (Borsh + Base64)
     let calculate_memo_size = move || -> (usize, bool, String) {
-        let name = group_name.get().trim().to_string();
+        let name = sanitize_display_text(&group_name.get());
         let description = group_description.get().trim().to_string();
         let image_data = get_image_data();
         // Parse tags inline here
         let tags = group_tags.get()
             .split(',')
-            .map(|s| s.trim().to_string())
+            .map(sanitize_display_text)
             .filter(|s| !s.is_empty())
             .take(4) // Maximum 4 tags
             .collect();
         let interval = Some(min_memo_interval.get());
-        let amount = burn_amount.get() * 1_000_000; // Convert to lamports
+        let amount = burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN); // Convert to lamports
         
         // Create temporary ChatGroupCreationData for size calculation
         let group_data = crate::core::rpc_chat::ChatGroupCreationData::new(
@@ -2440,40 +4074,33 @@ fn CreateChatGroupForm(
         
         match group_data.calculate_final_memo_size(amount) {
             Ok(size) => {
-                let is_valid = size >= 69 && size <= 800;
-                let status = if is_valid {
-                    "✅ Valid".to_string()
-                } else if size < 69 {
-                    "❌ Too short".to_string()
-                } else {
-                    "❌ Too long".to_string()
-                };
+                let (is_valid, status) = memo_size_status(size, grid_size.get());
                 (size, is_valid, status)
             },
             Err(e) => (0, false, format!("❌ Error: {}", e))
         }
     };
 
+    // MEMO still missing for the entered burn amount (0 when the balance
+    // covers it), or None while the balance hasn't been fetched yet
+    let burn_shortfall = move || {
+        let amount = burn_amount.get() as f64;
+        session.with(|s| s.balances_loaded().then(|| (amount - s.get_token_balance()).max(0.0)))
+    };
+
     // Parse tags from comma-separated string
     let parse_tags = move || -> Vec<String> {
         group_tags.get()
             .split(',')
-            .map(|s| s.trim().to_string())
+            .map(sanitize_display_text)
             .filter(|s| !s.is_empty())
             .take(4) // Maximum 4 tags
-            .collect()
-    };
-
-    // Handle form submission
-    let handle_submit = move |ev: leptos::leptos_dom::ev::SubmitEvent| {
-        ev.prevent_default();
-
-        if is_creating.get() {
-            return;
-        }
+            .collect()
+    };
 
-        // Validate form
-        let name = group_name.get().trim().to_string();
+    // Validate the form, returning (name, description, tags, interval, amount)
+    let validate_form = move || -> Result<(String, String, Vec<String>, i64, u64), String> {
+        let name = sanitize_display_text(&group_name.get());
         let description = group_description.get().trim().to_string();
         let tags = parse_tags();
         let interval = min_memo_interval.get();
@@ -2481,43 +4108,70 @@ fn CreateChatGroupForm(
 
         // Validation
         if name.is_empty() || name.len() > 64 {
-            set_error_message.set("❌ Group name must be 1-64 characters, got {}".to_string().replace("{}", &name.len().to_string()));
-            return;
+            return Err("❌ Group name must be 1-64 characters, got {}".to_string().replace("{}", &name.len().to_string()));
+        }
+        if !has_visible_text(&name) {
+            return Err("❌ Group name must contain a letter, digit or emoji".to_string());
         }
         if description.len() > 128 {
-            set_error_message.set("❌ Group description must be at most 128 characters, got {}".to_string().replace("{}", &description.len().to_string()));
-            return;
+            return Err("❌ Group description must be at most 128 characters, got {}".to_string().replace("{}", &description.len().to_string()));
         }
         if amount < 42069 {
-            set_error_message.set("❌ Burn amount must be at least 42,069 MEMO tokens".to_string());
-            return;
+            return Err("❌ Burn amount must be at least 42,069 MEMO tokens".to_string());
         }
         if tags.len() > 4 {
-            set_error_message.set("❌ Maximum 4 tags allowed".to_string());
-            return;
+            return Err("❌ Maximum 4 tags allowed".to_string());
         }
         for tag in &tags {
             if tag.len() > 32 {
-                set_error_message.set("❌ Each tag must be at most 32 characters".to_string());
-                return;
+                return Err("❌ Each tag must be at most 32 characters".to_string());
+            }
+            if !has_visible_text(tag) {
+                return Err(format!("❌ Tag \"{}\" must contain a letter, digit or emoji", tag));
             }
         }
         if interval < 0 || interval > 86400 {
-            set_error_message.set("❌ Memo interval must be between 0 and 86400 seconds (24 hours)".to_string());
-            return;
+            return Err("❌ Memo interval must be between 0 and 86400 seconds (24 hours)".to_string());
+        }
+        let (memo_size, memo_size_valid, memo_size_status) = calculate_memo_size();
+        if !memo_size_valid {
+            return Err(format!("{} ({} bytes, must be 69-800)", memo_size_status, memo_size));
         }
 
-        // Check balance
-        let token_balance = session.with_untracked(|s| s.get_token_balance());
-        if token_balance < amount as f64 {
-            set_error_message.set(format!("❌ Insufficient balance. Required: {} MEMO, Available: {:.2} MEMO", amount, token_balance));
+        Ok((name, description, tags, interval, amount))
+    };
+
+    // Handle form submission
+    let handle_submit = move |ev: leptos::leptos_dom::ev::SubmitEvent| {
+        ev.prevent_default();
+
+        if is_creating.get() {
             return;
         }
 
+        let (name, description, tags, interval, amount) = match validate_form() {
+            Ok(fields) => fields,
+            Err(e) => {
+                set_error_message.set(e);
+                return;
+            }
+        };
+
+        // Check maximum and balance
+        let token_balance = session.with_untracked(|s| s.get_token_balance());
+        let amount_lamports = match validate_burn_tokens(amount, token_balance) {
+            Ok(lamports) => lamports,
+            Err(e) => {
+                set_error_message.set(format!("❌ {}", e));
+                return;
+            }
+        };
+
         // Set UI state
         set_is_creating.set(true);
         set_creating_status.set("Creating chat group...".to_string());
         set_error_message.set(String::new());
+        set_simulation.set(None);
 
         // Create chat group
         spawn_local(async move {
@@ -2531,7 +4185,7 @@ fn CreateChatGroupForm(
                 &get_image_data(),
                 tags,
                 Some(interval),
-                amount * 1_000_000, // Convert to lamports
+                amount_lamports,
             ).await;
 
             set_is_creating.set(false);
@@ -2564,6 +4218,49 @@ fn CreateChatGroupForm(
         });
     };
 
+    // Dry-run the creation: no signing, no fee. The balance isn't checked
+    // here so the simulation can report a shortfall itself.
+    let handle_simulate = move |_| {
+        if is_creating.get_untracked() || is_simulating.get_untracked() {
+            return;
+        }
+        let (name, description, tags, interval, amount) = match validate_form() {
+            Ok(fields) => fields,
+            Err(e) => {
+                set_error_message.set(e);
+                return;
+            }
+        };
+        let amount_lamports = match tokens_to_lamports(amount) {
+            Ok(lamports) => lamports,
+            Err(e) => {
+                set_error_message.set(format!("❌ {}", e));
+                return;
+            }
+        };
+
+        set_is_simulating.set(true);
+        set_error_message.set(String::new());
+        set_simulation.set(None);
+
+        spawn_local(async move {
+            let result = session.get_untracked().simulate_create_chat_group(
+                &name,
+                &description,
+                &get_image_data(),
+                tags,
+                Some(interval),
+                amount_lamports,
+            ).await;
+
+            set_is_simulating.set(false);
+            match result {
+                Ok(report) => set_simulation.set(Some(report)),
+                Err(e) => set_error_message.set(format!("❌ Simulation failed: {}", e)),
+            }
+        });
+    };
+
     // Handle image import (similar to mint_form.rs)
     let handle_import = move |ev: web_sys::MouseEvent| {
         ev.prevent_default();
@@ -2579,7 +4276,6 @@ fn CreateChatGroupForm(
         input.set_type("file");
         input.set_accept("image/*");
         
-        let pixel_art_write = set_pixel_art;
         let error_signal = set_error_message;
         let grid_size_signal = grid_size;
         
@@ -2597,7 +4293,7 @@ fn CreateChatGroupForm(
                         
                         match Pixel::from_image_data_with_size(&data, current_grid_size) {
                             Ok(new_art) => {
-                                pixel_art_write.set(new_art);
+                                edit_pixel_art(new_art);
                                 error_signal.set(String::new());
                             }
                             Err(e) => {
@@ -2665,7 +4361,10 @@ fn CreateChatGroupForm(
                 </button>
             </div>
             
-            <form class="chat-group-form" on:submit=handle_submit>
+            <form class="chat-group-form" on:keydown=advance_on_enter on:submit=move |ev: leptos::leptos_dom::ev::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                 <div class="form-layout">
                     // Left side: Basic Information
                     <div class="form-left">
@@ -2688,6 +4387,11 @@ fn CreateChatGroupForm(
                                 prop:disabled=move || is_creating.get()
                                 required
                             />
+                            // memo-chat has no update instruction for groups
+                            <small class="form-hint">
+                                <i class="fas fa-info-circle"></i>
+                                "Name, description, image, tags and message interval can't be changed after the group is created"
+                            </small>
                         </div>
 
                         // Group Description
@@ -2776,13 +4480,14 @@ fn CreateChatGroupForm(
                                             let value = event_target_value(&ev);
                                             if let Ok(size) = value.parse::<usize>() {
                                                 set_grid_size.set(size);
-                                                set_pixel_art.set(Pixel::new_with_size(size));
+                                                edit_pixel_art(Pixel::new_with_size(size));
                                             }
                                         }
                                         prop:disabled=move || is_creating.get()
                                     >
-                                        <option value="16">"16×16 pixels"</option>
-                                        <option value="32">"32×32 pixels"</option>
+                                        {GROUP_IMAGE_SIZES.into_iter().map(|size| view! {
+                                            <option value=size.to_string()>{format!("{}×{} pixels", size, size)}</option>
+                                        }).collect::<Vec<_>>()}
                                     </select>
                                     <button 
                                         type="button"
@@ -2793,16 +4498,36 @@ fn CreateChatGroupForm(
                                         <i class="fas fa-upload"></i>
                                         "Import Image"
                                     </button>
+                                    <PixelHistoryControls
+                                        history=pixel_history
+                                        art=pixel_art
+                                        on_restore=restore_pixel_art
+                                        disabled=Signal::derive(move || is_creating.get())
+                                    />
                                 </div>
                             </div>
+
+                            <PixelToolSelector
+                                tool=pixel_tool
+                                disabled=Signal::derive(move || is_creating.get())
+                            />
+                            <PixelColorPicker
+                                color=pixel_color
+                                disabled=Signal::derive(move || is_creating.get())
+                            />
+                            <PixelStringImport
+                                sizes=GROUP_IMAGE_SIZES.to_vec()
+                                on_load=load_pixel_art
+                                disabled=Signal::derive(move || is_creating.get())
+                            />
                             
                             // Pixel Art Canvas
                             {move || {
                                 let art_string = pixel_art.get().to_optimal_string();
                                 let click_handler = Box::new(move |row, col| {
                                     let mut new_art = pixel_art.get();
-                                    new_art.toggle_pixel(row, col);
-                                    set_pixel_art.set(new_art);
+                                    pixel_tool.get_untracked().apply(&mut new_art, row, col, pixel_color.get_untracked());
+                                    edit_pixel_art(new_art);
                                 });
                                 
                                 view! {
@@ -2826,11 +4551,7 @@ fn CreateChatGroupForm(
                                     <span class="value">
                                         {move || {
                                             let art_string = pixel_art.get().to_optimal_string();
-                                            if art_string.len() <= 20 {
-                                                art_string
-                                            } else {
-                                                format!("{}...{}", &art_string[..10], &art_string[art_string.len()-10..])
-                                            }
+                                            shorten_middle(&art_string, 10, 10)
                                         }}
                                     </span>
                                     <div class="copy-container">
@@ -2885,16 +4606,27 @@ fn CreateChatGroupForm(
                                 <i class="fas fa-wallet"></i>
                                 {move || {
                                     let balance = session.with(|s| s.get_token_balance());
-                                    let is_sufficient = balance >= 42069.0;
+                                    let available = match burn_shortfall() {
+                                        None => view! { <span class="balance-checking">"checking balance…"</span> },
+                                        Some(shortfall) => view! {
+                                            <span class={if shortfall > 0.0 { "balance-insufficient" } else { "balance-sufficient" }}>
+                                                {format!("{:.2} MEMO", balance)}
+                                            </span>
+                                        },
+                                    };
                                     view! {
                                         "Minimum: 42,069 MEMO tokens (Available: "
-                                        <span class={if is_sufficient { "balance-sufficient" } else { "balance-insufficient" }}>
-                                            {format!("{:.2} MEMO", balance)}
-                                        </span>
+                                        {available}
                                         ")"
                                     }
                                 }}
                             </small>
+                            {move || burn_shortfall().filter(|shortfall| *shortfall > 0.0).map(|shortfall| view! {
+                                <small class="form-hint balance-insufficient">
+                                    <i class="fas fa-exclamation-triangle"></i>
+                                    {format!(" Insufficient balance: you need {:.2} more MEMO", shortfall)}
+                                </small>
+                            })}
                         </div>
                     </div>
                 </div>
@@ -2976,11 +4708,34 @@ fn CreateChatGroupForm(
                     }
                 }}
 
+                {move || simulation.get().map(|report| view! {
+                    <SimulationResult report=report on_dismiss=Callback::new(move |_| set_simulation.set(None))/>
+                })}
+
+                <BurnNetworkBadge/>
+
                 // Submit button
                 <div class="button-group">
+                    <button
+                        type="button"
+                        class="simulate-btn"
+                        title="Check whether this transaction would succeed, without sending it or paying a fee"
+                        on:click=handle_simulate
+                        prop:disabled=move || {
+                            is_creating.get() ||
+                            is_simulating.get() ||
+                            group_name.get().trim().is_empty() ||
+                            burn_amount.get() < 42069 ||
+                            !calculate_memo_size().1
+                        }
+                    >
+                        <i class=move || if is_simulating.get() { "fas fa-spinner fa-spin" } else { "fas fa-vial" }></i>
+                        {move || if is_simulating.get() { "Simulating..." } else { "Simulate" }}
+                    </button>
                     <button
                         type="submit"
                         class="create-group-btn"
+                        class:mainnet-burn=is_mainnet()
                         prop:disabled=move || {
                             is_creating.get() ||
                             group_name.get().trim().is_empty() ||
@@ -2990,7 +4745,8 @@ fn CreateChatGroupForm(
                             min_memo_interval.get() < 0 ||
                             min_memo_interval.get() > 86400 ||
                             burn_amount.get() < 42069 ||
-                            session.with(|s| s.get_token_balance()) < burn_amount.get() as f64
+                            !calculate_memo_size().1 ||
+                            burn_shortfall().is_none_or(|shortfall| shortfall > 0.0)
                         }
                     >
                         <i class="fas fa-rocket"></i>
@@ -3022,8 +4778,8 @@ fn render_chat_featured_card(
 ) -> impl IntoView {
     use crate::core::rpc_chat::ChatOperationDetails;
     
-    let burn_amount_display = format!("{} MEMO", transaction.burn_amount / 1_000_000);
-    let time_display = format_relative_time(transaction.timestamp);
+    let burn_amount_display = tf("chat.group.burned", &[&format_memo(transaction.burn_amount)]);
+    let timestamp = transaction.timestamp;
     
     // Render different cards based on operation type
     match transaction.details {
@@ -3060,13 +4816,13 @@ fn render_chat_featured_card(
                             }.into_view()
                         } else {
                             view! {
-                                <h3 class="group-name">{format!("Group #{}", group_id)}</h3>
+                                <h3 class="group-name">{tf("chat.group.number", &[&group_id.to_string()])}</h3>
                             }.into_view()
                         }}
                         
                         {if !message.is_empty() {
                             view! {
-                                <p class="burn-message">{message}</p>
+                                <p class="burn-message user-text"><UserText text=message/></p>
                             }.into_view()
                         } else {
                             view! { <div></div> }.into_view()
@@ -3083,7 +4839,7 @@ fn render_chat_featured_card(
                             </div>
                             <div class="meta-item">
                                 <i class="fas fa-clock"></i>
-                                <span>{time_display}</span>
+                                <RelativeTime timestamp=timestamp absolute=format_timestamp(timestamp)/>
                             </div>
                         </div>
                     </div>
@@ -3129,7 +4885,7 @@ fn render_chat_featured_card(
                             </div>
                             <div class="meta-item">
                                 <i class="fas fa-clock"></i>
-                                <span>{time_display}</span>
+                                <RelativeTime timestamp=timestamp absolute=format_timestamp(timestamp)/>
                             </div>
                         </div>
                     </div>
@@ -3155,11 +4911,11 @@ fn render_chat_featured_card(
                             }.into_view()
                         } else {
                             view! {
-                                <h3 class="group-name">{format!("Group #{}", group_id)}</h3>
+                                <h3 class="group-name">{tf("chat.group.number", &[&group_id.to_string()])}</h3>
                             }.into_view()
                         }}
                         
-                        <p class="message-content">{message}</p>
+                        <p class="message-content user-text"><UserText text=message/></p>
                         
                         <div class="featured-meta">
                             <div class="meta-item">
@@ -3168,7 +4924,7 @@ fn render_chat_featured_card(
                             </div>
                             <div class="meta-item">
                                 <i class="fas fa-clock"></i>
-                                <span>{time_display}</span>
+                                <RelativeTime timestamp=timestamp absolute=format_timestamp(timestamp)/>
                             </div>
                         </div>
                     </div>
@@ -3178,105 +4934,201 @@ fn render_chat_featured_card(
     }
 }
 
-/// Format relative time from Unix timestamp
-fn format_relative_time(timestamp: i64) -> String {
-    let now = js_sys::Date::new_0().get_time() as i64 / 1000;
-    let diff = now - timestamp;
-    
-    if diff < 60 {
-        "Just now".to_string()
-    } else if diff < 3600 {
-        let minutes = diff / 60;
-        format!("{} minute{} ago", minutes, if minutes > 1 { "s" } else { "" })
-    } else if diff < 86400 {
-        let hours = diff / 3600;
-        format!("{} hour{} ago", hours, if hours > 1 { "s" } else { "" })
-    } else if diff < 2592000 {
-        let days = diff / 86400;
-        format!("{} day{} ago", days, if days > 1 { "s" } else { "" })
-    } else {
-        let months = diff / 2592000;
-        format!("{} month{} ago", months, if months > 1 { "s" } else { "" })
+/// The contract's own message in a `SolanaRpcError` (after " - "), if any
+fn contract_error_message(error: &str) -> Option<String> {
+    let (_, specific) = error.rsplit_once(" - ")?;
+    let specific = specific.trim_end_matches('.');
+    (!specific.is_empty()).then(|| specific.to_string())
+}
+
+/// Wording for a failed chat message
+fn chat_send_error(error: &SessionError) -> String {
+    match error {
+        SessionError::Rpc(e @ (RpcError::MemoTooFrequent | RpcError::InsufficientBalance | RpcError::Timeout)) => e.to_string(),
+        SessionError::Rpc(RpcError::SolanaRpcError(msg)) => contract_error_message(msg)
+            .unwrap_or_else(|| classify_tx_error(msg).chat_message().to_string()),
+        e => classify_tx_error(&e.to_string()).chat_message().to_string(),
+    }
+}
+
+/// Wording for a failed burn
+fn burn_error(error: &SessionError) -> String {
+    const BURN_FAILED: &str = "Failed to burn tokens. Please try again.";
+    match error {
+        SessionError::Rpc(e @ (RpcError::MemoTooFrequent | RpcError::InsufficientBalance)) => e.to_string(),
+        SessionError::Rpc(RpcError::SolanaRpcError(msg)) => {
+            contract_error_message(msg).unwrap_or_else(|| BURN_FAILED.to_string())
+        }
+        _ => BURN_FAILED.to_string(),
+    }
+}
+
+/// Text of a message for reply quotes, with an attached pixel image
+/// reduced to a short label
+fn message_preview(message: &str) -> String {
+    match split_message_pixel(message) {
+        (text, Some(_)) if text.is_empty() => t("chat.message.pixel_image").to_string(),
+        (text, _) => text,
+    }
+}
+
+/// Text a message's copy action puts on the clipboard: the message without
+/// an attached image, prefixed with the amount for burns
+fn message_copy_text(message: &ChatMessage) -> String {
+    let (text, _) = split_message_pixel(&message.message);
+    match message.burn_amount.filter(|_| message.message_type == "burn") {
+        Some(amount) => tf("chat.message.copy_burn_text", &[&format_memo(amount), &text]),
+        None => text,
     }
 }
 
 /// Shorten address for display
 fn shorten_address(address: &str) -> String {
-    if address.len() > 8 {
-        format!("{}...{}", &address[..4], &address[address.len()-4..])
-    } else {
-        address.to_string()
-    }
+    shorten_pubkey(address)
 }
 
 #[component]
 fn PaginatedLeaderboardList(
     display_mode: ReadSignal<GroupsDisplayMode>,
+    view_mode: ReadSignal<ListViewMode>,
     paginated_groups: Memo<(Vec<LeaderboardEntry>, usize, usize)>,
     latest_groups: ReadSignal<Vec<ChatGroupInfo>>,
     oldest_groups: ReadSignal<Vec<ChatGroupInfo>>,
     current_page: ReadSignal<usize>,
+    /// Groups shown per page in every mode
+    groups_per_page: ReadSignal<usize>,
     mode_loading: ReadSignal<bool>,
     go_to_page: impl Fn(usize) + 'static + Copy,
     next_page: impl Fn(web_sys::MouseEvent) + 'static + Copy,
     prev_page: impl Fn(web_sys::MouseEvent) + 'static + Copy,
     enter_chat_room: impl Fn(u64) + 'static + Copy,
     leaderboard_group_infos: ReadSignal<std::collections::HashMap<u64, ChatGroupInfo>>,
+    favorite_groups: ReadSignal<Vec<ChatGroupInfo>>,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+    total_groups: ReadSignal<u64>,
+    failed_group_infos: ReadSignal<Vec<u64>>,
+    retrying_group_infos: ReadSignal<HashSet<u64>>,
+    retry_group_infos: impl Fn(Vec<u64>) + 'static + Copy,
+    /// The network has no chat groups at all (as opposed to an empty page or mode)
+    no_groups_yet: Signal<bool>,
+    open_create_dialog: impl Fn(web_sys::MouseEvent) + 'static + Copy,
 ) -> impl IntoView {
+    let is_list_view = move || view_mode.get() == ListViewMode::List;
+
     view! {
         <div class="paginated-leaderboard">
-            {move || {
+            // Same first-time state in every mode, without pagination
+            <Show when=move || no_groups_yet.get()>
+                <div class="empty-state first-group-state">
+                    <i class="fas fa-comments"></i>
+                    <h3>{t("chat.groups.none_yet")}</h3>
+                    <p>{t("chat.groups.none_yet_hint")}</p>
+                    <button class="create-group-button" on:click=open_create_dialog>
+                        <i class="fas fa-plus"></i>
+                        {t("chat.groups.create_first")}
+                    </button>
+                </div>
+            </Show>
+            {move || (!no_groups_yet.get()).then(|| {
                 match display_mode.get() {
                     GroupsDisplayMode::BurnLeaderboard => {
                         view! {
-                            <h2>"Chat Groups Ranking"</h2>
+                            <h2>{t("chat.groups.ranking")}</h2>
                             
                             // pagination info for burn leaderboard
                             <div class="pagination-info">
                                 {move || {
                                     let (entries, total_pages, total_groups) = paginated_groups.get();
                                     let page = current_page.get();
-                                    let start_rank = if entries.is_empty() { 0 } else { (page - 1) * 10 + 1 };
-                                    let end_rank = if entries.is_empty() { 0 } else { (page - 1) * 10 + entries.len() };
                                     
-                                    view! {
+                                    // nothing to count on an empty page
+                                    leaderboard_rank_range(page, 10, entries.len()).map(|(start_rank, end_rank)| view! {
                                         <p>
-                                            "Showing rank " {start_rank} " - " {end_rank} 
-                                            " of " {total_groups} " groups"
+                                            {tf("chat.groups.showing_ranks", &[&start_rank.to_string(), &end_rank.to_string(), &total_groups.to_string()])}
                                             {if total_pages > 1 {
-                                                format!(" (Page {} of {})", page, total_pages)
+                                                tf("chat.groups.page_of", &[&page.to_string(), &total_pages.to_string()])
                                             } else {
                                                 String::new()
                                             }}
                                         </p>
-                                    }
+                                    })
                                 }}
                             </div>
                             
+                            <Show when=move || !failed_group_infos.get().is_empty()>
+                                <div class="partial-failure-notice">
+                                    <i class="fas fa-exclamation-circle"></i>
+                                    <span>
+                                        {move || {
+                                            let count = failed_group_infos.get().len();
+                                            tf(if count == 1 { "chat.groups.failed_one" } else { "chat.groups.failed_many" }, &[&count.to_string()])
+                                        }}
+                                    </span>
+                                    <button
+                                        class="partial-failure-retry"
+                                        disabled=move || !retrying_group_infos.get().is_empty()
+                                        on:click=move |_| retry_group_infos(failed_group_infos.get_untracked())
+                                    >
+                                        <i class="fas fa-redo"></i>
+                                        {t("chat.groups.retry")}
+                                    </button>
+                                </div>
+                            </Show>
+                            
                             <Show
                                 when=move || !paginated_groups.get().0.is_empty()
                                 fallback=|| view! {
                                     <div class="empty-state">
                                         <i class="fas fa-trophy"></i>
-                                        <p>"No groups in burn leaderboard yet"</p>
+                                        <p>{t("chat.groups.empty_leaderboard")}</p>
                                     </div>
                                 }
                             >
-                                <div class="leaderboard-grid">
+                                <div class="leaderboard-grid" class:list-view=is_list_view>
                                     <For
                                         each=move || paginated_groups.get().0
                                         key=|entry| entry.group_id
                                         children=move |entry: LeaderboardEntry| {
                                             let group_id = entry.group_id;
-                                            let group_infos = leaderboard_group_infos.get();
-                                            let group_info = group_infos.get(&group_id).cloned();
+                                            let group_info = Signal::derive(move || {
+                                                leaderboard_group_infos.get().get(&group_id).cloned()
+                                            });
+                                            let load_failed = Signal::derive(move || failed_group_infos.get().contains(&group_id));
+                                            let retrying = Signal::derive(move || retrying_group_infos.get().contains(&group_id));
                                             
-                                            view! { 
-                                                <LeaderboardCard 
-                                                    entry=entry 
-                                                    group_info=group_info
-                                                    enter_chat_room=enter_chat_room
-                                                /> 
+                                            move || if is_list_view() {
+                                                view! {
+                                                    <LeaderboardRow
+                                                        entry=entry.clone()
+                                                        group_info=group_info
+                                                        load_failed=load_failed
+                                                        retrying=retrying
+                                                        retry_group_info=move |id| retry_group_infos(vec![id])
+                                                        enter_chat_room=enter_chat_room
+                                                        favorite_ids=favorite_ids
+                                                        visited_ids=visited_ids
+                                                        read_until=read_until
+                                                        toggle_favorite=toggle_favorite
+                                                    />
+                                                }.into_view()
+                                            } else {
+                                                view! { 
+                                                    <LeaderboardCard 
+                                                        entry=entry.clone() 
+                                                        group_info=group_info
+                                                        load_failed=load_failed
+                                                        retrying=retrying
+                                                        retry_group_info=move |id| retry_group_infos(vec![id])
+                                                        enter_chat_room=enter_chat_room
+                                                        favorite_ids=favorite_ids
+                                                        visited_ids=visited_ids
+                                                        read_until=read_until
+                                                        toggle_favorite=toggle_favorite
+                                                    /> 
+                                                }.into_view()
                                             }
                                         }
                                     />
@@ -3296,7 +5148,7 @@ fn PaginatedLeaderboardList(
                                                     on:click=prev_page
                                                 >
                                                     <i class="fas fa-chevron-left"></i>
-                                                    "Previous"
+                                                    {t("chat.groups.previous")}
                                                 </button>
                                                 
                                                 <div class="page-numbers">
@@ -3358,7 +5210,7 @@ fn PaginatedLeaderboardList(
                                                     disabled=move || page >= total_pages
                                                     on:click=next_page
                                                 >
-                                                    "Next"
+                                                    {t("chat.groups.next")}
                                                     <i class="fas fa-chevron-right"></i>
                                                 </button>
                                             </div>
@@ -3372,11 +5224,11 @@ fn PaginatedLeaderboardList(
                     },
                     GroupsDisplayMode::Latest => {
                         view! {
-                            <h2>"Latest Chat Groups"</h2>
+                            <h2>{t("chat.groups.latest")}</h2>
                             
                             <div class="pagination-info">
                                 <p>
-                                    "Page " {move || current_page.get()} " - Latest groups"
+                                    {move || tf("chat.groups.page_latest", &[&current_page.get().to_string()])}
                                 </p>
                             </div>
                             
@@ -3385,7 +5237,7 @@ fn PaginatedLeaderboardList(
                                 fallback=|| view! {
                                     <div class="loading-container">
                                         <div class="loading-spinner"></div>
-                                        <p>"Loading latest groups..."</p>
+                                        <p>{t("chat.groups.loading_latest")}</p>
                                     </div>
                                 }
                             >
@@ -3394,19 +5246,24 @@ fn PaginatedLeaderboardList(
                                     fallback=|| view! {
                                         <div class="empty-state">
                                             <i class="fas fa-clock"></i>
-                                            <p>"No groups found"</p>
+                                            <p>{t("chat.groups.empty")}</p>
                                         </div>
                                     }
                                 >
-                                    <div class="groups-grid">
+                                    <div class="groups-grid" class:list-view=is_list_view>
                                         <For
                                             each=move || latest_groups.get()
                                             key=|group| group.group_id
                                             children=move |group: ChatGroupInfo| {
                                                 view! { 
-                                                    <GroupCard 
+                                                    <GroupItem 
                                                         group=group 
+                                                        view_mode=view_mode
                                                         enter_chat_room=enter_chat_room
+                                                        favorite_ids=favorite_ids
+                                                        visited_ids=visited_ids
+                                                        read_until=read_until
+                                                        toggle_favorite=toggle_favorite
                                                     /> 
                                                 }
                                             }
@@ -3420,19 +5277,19 @@ fn PaginatedLeaderboardList(
                                             on:click=prev_page
                                         >
                                             <i class="fas fa-chevron-left"></i>
-                                            "Previous"
+                                            {t("chat.groups.previous")}
                                         </button>
                                         
                                         <span class="page-info">
-                                            "Page " {move || current_page.get()}
+                                            {move || tf("chat.groups.page", &[&current_page.get().to_string()])}
                                         </span>
                                         
                                         <button 
                                             class="pagination-btn"
-                                            disabled=move || latest_groups.get().len() < 10
+                                            disabled=move || !has_next_groups_page(total_groups.get(), current_page.get(), groups_per_page.get())
                                             on:click=next_page
                                         >
-                                            "Next"
+                                            {t("chat.groups.next")}
                                             <i class="fas fa-chevron-right"></i>
                                         </button>
                                     </div>
@@ -3442,11 +5299,11 @@ fn PaginatedLeaderboardList(
                     },
                     GroupsDisplayMode::Oldest => {
                         view! {
-                            <h2>"Oldest Chat Groups"</h2>
+                            <h2>{t("chat.groups.oldest")}</h2>
                             
                             <div class="pagination-info">
                                 <p>
-                                    "Page " {move || current_page.get()} " - Oldest groups"
+                                    {move || tf("chat.groups.page_oldest", &[&current_page.get().to_string()])}
                                 </p>
                             </div>
                             
@@ -3455,7 +5312,7 @@ fn PaginatedLeaderboardList(
                                 fallback=|| view! {
                                     <div class="loading-container">
                                         <div class="loading-spinner"></div>
-                                        <p>"Loading oldest groups..."</p>
+                                        <p>{t("chat.groups.loading_oldest")}</p>
                                     </div>
                                 }
                             >
@@ -3464,19 +5321,99 @@ fn PaginatedLeaderboardList(
                                     fallback=|| view! {
                                         <div class="empty-state">
                                             <i class="fas fa-history"></i>
-                                            <p>"No groups found"</p>
+                                            <p>{t("chat.groups.empty")}</p>
                                         </div>
                                     }
                                 >
-                                    <div class="groups-grid">
+                                    <div class="groups-grid" class:list-view=is_list_view>
                                         <For
                                             each=move || oldest_groups.get()
                                             key=|group| group.group_id
                                             children=move |group: ChatGroupInfo| {
                                                 view! { 
-                                                    <GroupCard 
+                                                    <GroupItem 
+                                                        group=group 
+                                                        view_mode=view_mode
+                                                        enter_chat_room=enter_chat_room
+                                                        favorite_ids=favorite_ids
+                                                        visited_ids=visited_ids
+                                                        read_until=read_until
+                                                        toggle_favorite=toggle_favorite
+                                                    /> 
+                                                }
+                                            }
+                                        />
+                                    </div>
+                                    
+                                    <div class="pagination-controls">
+                                        <button 
+                                            class="pagination-btn"
+                                            disabled=move || current_page.get() <= 1
+                                            on:click=prev_page
+                                        >
+                                            <i class="fas fa-chevron-left"></i>
+                                            {t("chat.groups.previous")}
+                                        </button>
+                                        
+                                        <span class="page-info">
+                                            {move || tf("chat.groups.page", &[&current_page.get().to_string()])}
+                                        </span>
+                                        
+                                        <button 
+                                            class="pagination-btn"
+                                            disabled=move || !has_next_groups_page(total_groups.get(), current_page.get(), groups_per_page.get())
+                                            on:click=next_page
+                                        >
+                                            {t("chat.groups.next")}
+                                            <i class="fas fa-chevron-right"></i>
+                                        </button>
+                                    </div>
+                                </Show>
+                            </Show>
+                        }.into_view()
+                    },
+                    GroupsDisplayMode::Favorites => {
+                        view! {
+                            <h2>{t("chat.groups.favorites")}</h2>
+                            
+                            <div class="pagination-info">
+                                <p>
+                                    {move || tf("chat.groups.favorites_count", &[&favorite_ids.get().len().to_string()])}
+                                </p>
+                            </div>
+                            
+                            <Show
+                                when=move || !mode_loading.get()
+                                fallback=|| view! {
+                                    <div class="loading-container">
+                                        <div class="loading-spinner"></div>
+                                        <p>{t("chat.groups.loading_favorites")}</p>
+                                    </div>
+                                }
+                            >
+                                <Show
+                                    when=move || !favorite_groups.get().is_empty()
+                                    fallback=|| view! {
+                                        <div class="empty-state">
+                                            <i class="fas fa-star"></i>
+                                            <p>{t("chat.groups.empty_favorites")}</p>
+                                        </div>
+                                    }
+                                >
+                                    <div class="groups-grid" class:list-view=is_list_view>
+                                        <For
+                                            each=move || favorite_groups.get()
+                                            key=|group| group.group_id
+                                            children=move |group: ChatGroupInfo| {
+                                                view! { 
+                                                    <GroupItem 
                                                         group=group 
+                                                        view_mode=view_mode
                                                         enter_chat_room=enter_chat_room
+                                                        favorite_ids=favorite_ids
+                                                        visited_ids=visited_ids
+                                                        read_until=read_until
+                                                        toggle_favorite=toggle_favorite
                                                     /> 
                                                 }
                                             }
@@ -3490,19 +5427,19 @@ fn PaginatedLeaderboardList(
                                             on:click=prev_page
                                         >
                                             <i class="fas fa-chevron-left"></i>
-                                            "Previous"
+                                            {t("chat.groups.previous")}
                                         </button>
                                         
                                         <span class="page-info">
-                                            "Page " {move || current_page.get()}
+                                            {move || tf("chat.groups.page", &[&current_page.get().to_string()])}
                                         </span>
                                         
                                         <button 
                                             class="pagination-btn"
-                                            disabled=move || oldest_groups.get().len() < 10
+                                            disabled=move || !has_next_groups_page(favorite_ids.get().len() as u64, current_page.get(), groups_per_page.get())
                                             on:click=next_page
                                         >
-                                            "Next"
+                                            {t("chat.groups.next")}
                                             <i class="fas fa-chevron-right"></i>
                                         </button>
                                     </div>
@@ -3511,7 +5448,7 @@ fn PaginatedLeaderboardList(
                         }.into_view()
                     }
                 }
-            }}
+            })}
         </div>
     }
 }
@@ -3519,15 +5456,24 @@ fn PaginatedLeaderboardList(
 #[component]
 fn LeaderboardCard(
     entry: LeaderboardEntry, 
-    group_info: Option<ChatGroupInfo>,
+    group_info: Signal<Option<ChatGroupInfo>>,
+    load_failed: Signal<bool>,
+    retrying: Signal<bool>,
+    retry_group_info: impl Fn(u64) + 'static + Copy,
     enter_chat_room: impl Fn(u64) + 'static + Copy,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
 ) -> impl IntoView {
     let group_id = entry.group_id;
     let rank = entry.rank;
     let burned_amount = entry.burned_amount;
-    
-    // convert group_info to signal to avoid FnOnce problem
-    let (group_info_signal, _) = create_signal(group_info);
+    let unread = Signal::derive(move || {
+        let last_memo_time = group_info.with(|info| info.as_ref().map_or(0, |info| info.last_memo_time));
+        read_until.with(|read_until| last_seen::has_unread(read_until, group_id, last_memo_time))
+    });
+
 
     // Handle click to enter chat group
     let handle_click = move |_| {
@@ -3537,26 +5483,53 @@ fn LeaderboardCard(
     view! {
         <div 
             class="leaderboard-card clickable" 
+            class:visited=move || visited_ids.get().contains(&group_id)
             class:rank-1=move || rank == 1 
             class:rank-2=move || rank == 2 
             class:rank-3=move || rank == 3
             on:click=handle_click
         >
             <Show
-                when=move || group_info_signal.get().is_some()
-                fallback=|| view! {
-                    <div class="loading-placeholder">
-                        <div class="loading-spinner-small"></div>
-                        <p>"Loading group info..."</p>
-                    </div>
+                when=move || group_info.get().is_some()
+                fallback=move || if load_failed.get() && !retrying.get() {
+                    view! {
+                        <div class="group-not-found">
+                            <h3>{tf("chat.group.number", &[&group_id.to_string()])}</h3>
+                            <div class="burn-stat">
+                                <i class="fas fa-fire"></i>
+                                <span>{format_memo(burned_amount)} " MEMO"</span>
+                            </div>
+                            <p>{t("chat.group.info_unavailable")}</p>
+                            <button
+                                class="card-retry-btn"
+                                on:click=move |ev| {
+                                    // don't enter the chat room when retrying
+                                    ev.stop_propagation();
+                                    retry_group_info(group_id);
+                                }
+                            >
+                                <i class="fas fa-redo"></i>
+                                "Retry"
+                            </button>
+                        </div>
+                    }.into_view()
+                } else {
+                    view! {
+                        <div class="loading-placeholder">
+                            <div class="loading-spinner-small"></div>
+                            <p>{t("chat.group.loading_info")}</p>
+                        </div>
+                    }.into_view()
                 }
             >
                 {move || {
-                    if let Some(info) = group_info_signal.get() {
+                    if let Some(info) = group_info.get() {
                         view! {
                             <div class="group-header">
                                 <h3 class="group-name">{info.name.clone()}</h3>
+                                <UnreadDot unread=unread/>
                                 <div class="group-id">#{group_id}</div>
+                                <FavoriteButton group_id=group_id favorite_ids=favorite_ids toggle_favorite=toggle_favorite/>
                             </div>
                             
                             <div class="group-image">
@@ -3577,6 +5550,7 @@ fn LeaderboardCard(
                                                     <LazyPixelView
                                                         art={fake_pixel_art}
                                                         size=64
+                                                        hide_copy=true
                                                     />
                                                 }.into_view()
                                             } else {
@@ -3596,6 +5570,7 @@ fn LeaderboardCard(
                                                 <LazyPixelView
                                                     art={fake_pixel_art}
                                                     size=64
+                                                    hide_copy=true
                                                 />
                                             }.into_view()
                                         }
@@ -3618,6 +5593,7 @@ fn LeaderboardCard(
                                             <LazyPixelView
                                                 art={fake_pixel_art}
                                                 size=64
+                                                hide_copy=true
                                             />
                                         }.into_view()
                                     }
@@ -3627,28 +5603,28 @@ fn LeaderboardCard(
                             <div class="leaderboard-stats">
                                 <div class="burn-stat">
                                     <i class="fas fa-fire"></i>
-                                    <span>{format!("{}", burned_amount / 1_000_000)} " MEMO"</span>
+                                    <span>{format_memo(burned_amount)} " MEMO"</span>
                                 </div>
                                 <div class="message-stat">
                                     <i class="fas fa-comments"></i>
-                                    <span>{info.memo_count} " messages"</span>
+                                    <span>{format_number_with_commas(info.memo_count)} " messages"</span>
                                 </div>
                             </div>
                             
                             <div class="enter-chat-hint">
                                 <i class="fas fa-arrow-right"></i>
-                                <span>"Click to enter chat group"</span>
+                                <span>{t("chat.group.enter_hint")}</span>
                             </div>
                         }.into_view()
                     } else {
                         view! {
                             <div class="group-not-found">
-                                <h3>"Group #{group_id}"</h3>
+                                <h3>{tf("chat.group.number", &[&group_id.to_string()])}</h3>
                                 <div class="burn-stat">
                                     <i class="fas fa-fire"></i>
-                                    <span>{format!("{}", burned_amount / 1_000_000)}</span>
+                                    <span>{format_memo(burned_amount)}</span>
                                 </div>
-                                <p>"Group info not available"</p>
+                                <p>{t("chat.group.info_unavailable")}</p>
                             </div>
                         }.into_view()
                     }
@@ -3656,4 +5632,187 @@ fn LeaderboardCard(
             </Show>
         </div>
     }
-}
\ No newline at end of file
+}
+
+/// A group rendered as a card or a compact row, following the view mode
+#[component]
+fn GroupItem(
+    group: ChatGroupInfo,
+    view_mode: ReadSignal<ListViewMode>,
+    enter_chat_room: impl Fn(u64) + 'static + Copy,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
+    move || match view_mode.get() {
+        ListViewMode::Grid => view! {
+            <GroupCard group=group.clone() enter_chat_room=enter_chat_room favorite_ids=favorite_ids visited_ids=visited_ids read_until=read_until toggle_favorite=toggle_favorite/>
+        }.into_view(),
+        ListViewMode::List => view! {
+            <GroupRow group=group.clone() enter_chat_room=enter_chat_room favorite_ids=favorite_ids visited_ids=visited_ids read_until=read_until toggle_favorite=toggle_favorite/>
+        }.into_view(),
+    }
+}
+
+/// Compact one-line group entry for the list view
+#[component]
+fn GroupRow(
+    group: ChatGroupInfo,
+    enter_chat_room: impl Fn(u64) + 'static + Copy,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
+    let group_id = group.group_id;
+    let last_memo_time = group.last_memo_time;
+    let unread = Signal::derive(move || {
+        read_until.with(|read_until| last_seen::has_unread(read_until, group_id, last_memo_time))
+    });
+
+    view! {
+        <div class="group-row clickable" class:visited=move || visited_ids.get().contains(&group_id) on:click=move |_| enter_chat_room(group_id)>
+            <span class="group-row-id">#{group_id}</span>
+            <span class="group-row-name" title=group.name.clone()>{group.name.clone()}</span>
+            <UnreadDot unread=unread/>
+            <span class="group-row-stat">
+                <i class="fas fa-fire"></i>
+                {tf("chat.group.burned", &[&format_memo(group.burned_amount)])}
+            </span>
+            <span class="group-row-stat">
+                <i class="fas fa-comments"></i>
+                {tf("chat.group.messages", &[&format_number_with_commas(group.memo_count)])}
+            </span>
+            <FavoriteButton group_id=group_id favorite_ids=favorite_ids toggle_favorite=toggle_favorite/>
+            <button class="group-row-enter" title=t("chat.group.enter_title")>
+                {t("chat.group.enter")}
+                <i class="fas fa-arrow-right"></i>
+            </button>
+        </div>
+    }
+}
+
+/// Compact one-line leaderboard entry for the list view
+#[component]
+fn LeaderboardRow(
+    entry: LeaderboardEntry,
+    group_info: Signal<Option<ChatGroupInfo>>,
+    load_failed: Signal<bool>,
+    retrying: Signal<bool>,
+    retry_group_info: impl Fn(u64) + 'static + Copy,
+    enter_chat_room: impl Fn(u64) + 'static + Copy,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    visited_ids: ReadSignal<Vec<u64>>,
+    read_until: ReadSignal<HashMap<u64, i64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
+    let group_id = entry.group_id;
+    let rank = entry.rank;
+    let burned_amount = entry.burned_amount;
+    let unread = Signal::derive(move || {
+        let last_memo_time = group_info.with(|info| info.as_ref().map_or(0, |info| info.last_memo_time));
+        read_until.with(|read_until| last_seen::has_unread(read_until, group_id, last_memo_time))
+    });
+
+    view! {
+        <div
+            class="group-row leaderboard-row clickable"
+            class:visited=move || visited_ids.get().contains(&group_id)
+            class:rank-1=move || rank == 1
+            class:rank-2=move || rank == 2
+            class:rank-3=move || rank == 3
+            on:click=move |_| enter_chat_room(group_id)
+        >
+            <span class="group-row-rank">{format!("#{}", rank)}</span>
+            {move || match group_info.get() {
+                Some(info) => view! {
+                    <span class="group-row-name" title=info.name.clone()>{info.name.clone()}</span>
+                    <UnreadDot unread=unread/>
+                    <span class="group-row-stat">
+                        <i class="fas fa-fire"></i>
+                        {tf("chat.group.burned", &[&format_memo(burned_amount)])}
+                    </span>
+                    <span class="group-row-stat">
+                        <i class="fas fa-comments"></i>
+                        {tf("chat.group.messages", &[&format_number_with_commas(info.memo_count)])}
+                    </span>
+                }.into_view(),
+                None if load_failed.get() && !retrying.get() => view! {
+                    <span class="group-row-name muted">{tf("chat.group.number_unavailable", &[&group_id.to_string()])}</span>
+                    <span class="group-row-stat">
+                        <i class="fas fa-fire"></i>
+                        {tf("chat.group.burned", &[&format_memo(burned_amount)])}
+                    </span>
+                    <button
+                        class="card-retry-btn"
+                        on:click=move |ev| {
+                            // don't enter the chat room when retrying
+                            ev.stop_propagation();
+                            retry_group_info(group_id);
+                        }
+                    >
+                        <i class="fas fa-redo"></i>
+                        "Retry"
+                    </button>
+                }.into_view(),
+                None => view! {
+                    <span class="group-row-name muted">{tf("chat.group.number", &[&group_id.to_string()])}</span>
+                    <span class="group-row-stat">
+                        <div class="loading-spinner-small"></div>
+                    </span>
+                }.into_view(),
+            }}
+            <FavoriteButton group_id=group_id favorite_ids=favorite_ids toggle_favorite=toggle_favorite/>
+            <button class="group-row-enter" title=t("chat.group.enter_title")>
+                {t("chat.group.enter")}
+                <i class="fas fa-arrow-right"></i>
+            </button>
+        </div>
+    }
+}
+
+/// Dot shown on a group with messages newer than what was read there
+#[component]
+fn UnreadDot(unread: Signal<bool>) -> impl IntoView {
+    view! {
+        <Show when=move || unread.get()>
+            <span class="unread-dot" title=t("chat.group.unread")></span>
+        </Show>
+    }
+}
+
+/// "N participants", qualified with the messages scanned unless that was all of them
+fn participants_label(participants: &GroupParticipants) -> String {
+    let count = participants.senders.len().to_string();
+    if participants.complete {
+        tf("chat.group.participants", &[&count])
+    } else {
+        tf("chat.group.participants_recent", &[&count, &participants.scanned.to_string()])
+    }
+}
+
+/// Star toggle for adding a group to the local favorites list
+#[component]
+fn FavoriteButton(
+    group_id: u64,
+    favorite_ids: ReadSignal<Vec<u64>>,
+    toggle_favorite: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
+    let is_favorite = move || favorite_ids.get().contains(&group_id);
+
+    view! {
+        <button
+            class="favorite-btn"
+            class:active=is_favorite
+            title=move || if is_favorite() { "Remove from favorites" } else { "Add to favorites" }
+            on:click=move |ev| {
+                // don't enter the chat room when toggling
+                ev.stop_propagation();
+                toggle_favorite(group_id);
+            }
+        >
+            <i class=move || if is_favorite() { "fas fa-star" } else { "far fa-star" }></i>
+        </button>
+    }
+}
@@ -0,0 +1,59 @@
+use leptos::*;
+
+/// Progress of a just-created group or project, by id, while the page waits
+/// for it to become readable on chain
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CreationStatus {
+    Waiting(u64),
+    Ready(u64),
+    /// Polling gave up; the creation was sent but can't be read back yet
+    NotVisible(u64),
+}
+
+/// Banner shown after creating a group or project. Once the new item is
+/// confirmed it offers to open it; on timeout it only explains the delay.
+/// `kind` is the lowercase item name, e.g. "group".
+#[component]
+pub fn CreationNotice(
+    kind: &'static str,
+    status: ReadSignal<Option<CreationStatus>>,
+    on_open: impl Fn(u64) + 'static + Copy,
+    on_dismiss: impl Fn() + 'static + Copy,
+) -> impl IntoView {
+    let dismiss_button = move || view! {
+        <button class="creation-notice-dismiss" on:click=move |_| on_dismiss() title="Dismiss">
+            <i class="fas fa-times"></i>
+        </button>
+    };
+
+    move || status.get().map(|status| match status {
+        CreationStatus::Waiting(id) => view! {
+            <div class="creation-notice waiting">
+                <i class="fas fa-spinner fa-spin"></i>
+                <span class="creation-notice-text">
+                    {format!("Your {} #{} was created! Waiting for blockchain confirmation...", kind, id)}
+                </span>
+            </div>
+        },
+        CreationStatus::Ready(id) => view! {
+            <div class="creation-notice ready">
+                <i class="fas fa-check-circle"></i>
+                <span class="creation-notice-text">{format!("Your {} #{} is live.", kind, id)}</span>
+                <button class="creation-notice-open" on:click=move |_| on_open(id)>
+                    {format!("Go to your {}", kind)}
+                    " "<i class="fas fa-arrow-right"></i>
+                </button>
+                {dismiss_button()}
+            </div>
+        },
+        CreationStatus::NotVisible(id) => view! {
+            <div class="creation-notice not-visible">
+                <i class="fas fa-exclamation-circle"></i>
+                <span class="creation-notice-text">
+                    {format!("Your {} #{} was created but is not visible yet. Refresh the list in a moment.", kind, id)}
+                </span>
+                {dismiss_button()}
+            </div>
+        },
+    })
+}
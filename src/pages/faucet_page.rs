@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use wasm_bindgen_futures::spawn_local;
 use web_sys::window;
 use crate::core::session::Session;
+use crate::core::network_config::explorer_tx_url;
 use crate::pages::log_view::add_log_entry;
 use gloo_timers::future::TimeoutFuture;
 
@@ -262,8 +263,7 @@ pub fn FaucetPage(session: RwSignal<Session>) -> impl IntoView {
                     }
 
                     // Open transaction in explorer if user wants
-                    if let Some(window) = window() {
-                        let explorer_url = format!("https://explorer.x1.xyz/tx/{}", response.signature);
+                    if let (Some(window), Some(explorer_url)) = (window(), explorer_tx_url(&response.signature)) {
                         let _ = window.open_with_url_and_target(&explorer_url, "_blank");
                     }
                 },
@@ -0,0 +1,41 @@
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, HtmlInputElement};
+
+/// Fields Enter moves between, in document order
+const ENTER_FIELDS: &str = "input[type=text]:not([disabled]), input[type=number]:not([disabled]), textarea:not([disabled])";
+
+/// Form `on:keydown` handler: Enter in a single-line field moves to the next
+/// field, and submits the form (running its validation) from the last one.
+/// Textareas keep Enter and Shift+Enter for newlines; other controls, like
+/// the pixel editor's buttons, are left alone.
+pub fn advance_on_enter(ev: ev::KeyboardEvent) {
+    if ev.key() != "Enter" || ev.is_composing() || ev.shift_key() || ev.ctrl_key() || ev.alt_key() || ev.meta_key() {
+        return;
+    }
+    let Some(input) = ev.target().and_then(|t| t.dyn_into::<HtmlInputElement>().ok()) else {
+        return;
+    };
+    if !matches!(input.type_().as_str(), "text" | "number") {
+        return;
+    }
+    let Some(form) = input.form() else { return };
+    ev.prevent_default();
+
+    let next = form.query_selector_all(ENTER_FIELDS).ok().and_then(|fields| {
+        let current = (0..fields.length()).find(|&i| {
+            fields.get(i).is_some_and(|field| field.is_same_node(Some(input.as_ref())))
+        })?;
+        fields.get(current + 1)
+    });
+    match next.and_then(|field| field.dyn_into::<HtmlElement>().ok()) {
+        Some(field) => {
+            let _ = field.focus();
+        }
+        None => {
+            if let Err(e) = form.request_submit() {
+                log::warn!("Failed to submit form: {:?}", e);
+            }
+        }
+    }
+}
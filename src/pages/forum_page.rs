@@ -1,7 +1,9 @@
 use leptos::*;
 use crate::core::session::Session;
 use crate::core::rpc_forum::PostReply;
-use crate::core::rpc_base::RpcConnection;
+use crate::core::rpc_base::{RpcConnection, validate_burn_tokens};
+use crate::core::format::{format_memo, format_number_with_commas};
+use crate::core::constants::LAMPORTS_PER_TOKEN;
 use wasm_bindgen_futures::spawn_local;
 use gloo_timers::future::TimeoutFuture;
 use wasm_bindgen::JsValue;
@@ -11,6 +13,8 @@ use web_sys::{Event, HtmlInputElement, FileReader, ProgressEvent};
 use js_sys::Uint8Array;
 use std::rc::Rc;
 use crate::pages::pixel_view::{PixelView, LazyPixelView};
+use crate::pages::burn_network::{is_mainnet, BurnNetworkBadge, MainnetBurnGuard};
+use crate::pages::global_refresh::GlobalRefresh;
 use crate::core::pixel::Pixel;
 
 /// Post row data for table display
@@ -105,6 +109,7 @@ pub fn ForumPage(
     create_effect(move |_| {
         load_posts_data.dispatch(());
     });
+    GlobalRefresh::from_context().register(Some("forum"), move || load_posts_data.dispatch(()), loading.into());
 
     // Function to open create post dialog
     let open_create_dialog = move |_| {
@@ -248,7 +253,7 @@ pub fn ForumPage(
                                                             </thead>
                                                             <tbody>
                                                                 {post_list.into_iter().enumerate().map(|(index, post)| {
-                                                                    let burned_tokens = post.burned_amount / 1_000_000;
+                                                                    let burned_display = format_memo(post.burned_amount);
                                                                     let post_clone = post.clone();
                                                                     let rank = index + 1;
                                                                     
@@ -319,13 +324,13 @@ pub fn ForumPage(
                                                                             <td class="burned-cell">
                                                                                 <span class="burned-amount">
                                                                                     <i class="fas fa-fire"></i>
-                                                                                    {format_number_with_commas(burned_tokens)}
+                                                                                    {burned_display}
                                                                                 </span>
                                                                             </td>
                                                                             <td class="replies-cell">
                                                                                 <span class="reply-count">
                                                                                     <i class="fas fa-comment"></i>
-                                                                                    {post.reply_count.to_string()}
+                                                                                    {format_number_with_commas(post.reply_count)}
                                                                                 </span>
                                                                             </td>
                                                                             <td class="time-cell">
@@ -399,20 +404,6 @@ fn format_timestamp(timestamp: i64) -> String {
     )
 }
 
-/// Format number with commas for readability
-fn format_number_with_commas(num: u64) -> String {
-    let s = num.to_string();
-    let mut result = String::new();
-    let chars: Vec<char> = s.chars().collect();
-    for (i, c) in chars.iter().enumerate() {
-        if i > 0 && (chars.len() - i) % 3 == 0 {
-            result.push(',');
-        }
-        result.push(*c);
-    }
-    result
-}
-
 /// Shorten address for display
 fn shorten_address(addr: &str) -> String {
     if addr.len() > 12 {
@@ -571,8 +562,7 @@ fn PostDetailsView(
     // Computed values
     let burned_display = move || {
         let post = current_post();
-        let burned_tokens = post.burned_amount / 1_000_000;
-        format_number_with_commas(burned_tokens)
+        format_memo(post.burned_amount)
     };
     
     let created_at_display = move || {
@@ -753,7 +743,7 @@ fn PostDetailsView(
                             </div>
                             <div class="stat-item">
                                 <i class="fas fa-comment"></i>
-                                <span class="stat-value">{move || current_post().reply_count.to_string()}</span>
+                                <span class="stat-value">{move || format_number_with_commas(current_post().reply_count)}</span>
                                 <span class="stat-label">"Replies"</span>
                             </div>
                             <div class="stat-item">
@@ -812,7 +802,7 @@ fn PostDetailsView(
                                                 let burn_display = if reply.is_mint {
                                                     "1 MEMO (mint)".to_string()
                                                 } else {
-                                                    format!("{} MEMO", reply.burn_amount / 1_000_000)
+                                                    format!("{} MEMO", format_memo(reply.burn_amount))
                                                 };
                                                 
                                                 view! {
@@ -925,6 +915,7 @@ fn CreatePostForm(
     on_success: Rc<dyn Fn(String, u64)>,
     on_error: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     let on_error_signal = create_rw_signal(Some(on_error));
@@ -962,7 +953,7 @@ fn CreatePostForm(
         let content_val = content.get();
         let image_val = get_image_data();
         let creator_val = get_creator_pubkey();
-        let burn_val = burn_amount.get() * 1_000_000;
+        let burn_val = burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN);
         
         // Estimate memo size using ForumConfig
         let estimated_size = ForumConfig::estimate_create_post_memo_size(
@@ -1024,13 +1015,14 @@ fn CreatePostForm(
             return;
         }
         
-        let burn_lamports = burn_val * 1_000_000;
-        
-        // Check balance
-        if token_balance() < burn_val as f64 {
-            set_error_message.set("Insufficient MEMO balance".to_string());
-            return;
-        }
+        // Check maximum and balance
+        let burn_lamports = match validate_burn_tokens(burn_val, token_balance()) {
+            Ok(lamports) => lamports,
+            Err(e) => {
+                set_error_message.set(e.to_string());
+                return;
+            }
+        };
         
         // Check memo size
         let (_, is_valid, _) = calculate_memo_size();
@@ -1168,7 +1160,10 @@ fn CreatePostForm(
                 </button>
             </div>
             
-            <form class="project-form" on:submit=handle_submit>
+            <form class="project-form" on:submit=move |ev: web_sys::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                 <div class="form-layout">
                     // Left side: Basic Information
                     <div class="form-left">
@@ -1396,11 +1391,14 @@ fn CreatePostForm(
                     }
                 }}
 
+                <BurnNetworkBadge/>
+
                 // Submit button
                 <div class="button-group">
                     <button
                         type="submit"
                         class="create-post-btn"
+                        class:mainnet-burn=is_mainnet()
                         prop:disabled=move || {
                             is_submitting.get() ||
                             title.get().trim().is_empty() ||
@@ -1443,6 +1441,7 @@ fn ReplyPostForm(
     on_close: Rc<dyn Fn()>,
     on_success: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     
@@ -1477,7 +1476,7 @@ fn ReplyPostForm(
         let user_val = get_user_pubkey();
         let message_val = reply_content.get();
         let burn_val = if reply_type.get() == ReplyType::Burn { 
-            burn_amount.get() * 1_000_000 
+            burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN)
         } else { 
             0 
         };
@@ -1548,9 +1547,11 @@ fn ReplyPostForm(
             return;
         }
         
-        if is_burn && token_balance() < burn_val as f64 {
-            set_error_message.set("Insufficient MEMO balance".to_string());
-            return;
+        if is_burn {
+            if let Err(e) = validate_burn_tokens(burn_val, token_balance()) {
+                set_error_message.set(e.to_string());
+                return;
+            }
         }
         
         // Check memo size
@@ -1563,7 +1564,7 @@ fn ReplyPostForm(
         set_is_submitting.set(true);
         set_error_message.set(String::new());
         
-        let burn_lamports = burn_val * 1_000_000;
+        let burn_lamports = burn_val.saturating_mul(LAMPORTS_PER_TOKEN);
         let session_clone = session;
         let on_success_signal = on_success_signal.clone();
         
@@ -1688,7 +1689,14 @@ fn ReplyPostForm(
                 </button>
             </div>
             
-            <form class="project-form" on:submit=handle_submit>
+            <form class="project-form" on:submit=move |ev: web_sys::SubmitEvent| {
+                ev.prevent_default();
+                if reply_type.get_untracked() == ReplyType::Burn {
+                    burn_guard.run(move || handle_submit(ev.clone()));
+                } else {
+                    handle_submit(ev);
+                }
+            }>
                 <div class="form-layout">
                     // Left side: Title and Content
                     <div class="form-left">
@@ -1924,6 +1932,10 @@ fn ReplyPostForm(
                     }
                 }}
 
+                <Show when=move || reply_type.get() == ReplyType::Burn>
+                    <BurnNetworkBadge/>
+                </Show>
+
                 // Submit button with mode toggle
                 <div class="form-actions-with-toggle">
                     // Mode toggle
@@ -1951,6 +1963,7 @@ fn ReplyPostForm(
                         type="submit"
                         class="submit-btn"
                         class:burn-submit=move || reply_type.get() == ReplyType::Burn
+                        class:mainnet-burn=move || reply_type.get() == ReplyType::Burn && is_mainnet()
                         class:mint-submit=move || reply_type.get() == ReplyType::Mint
                         prop:disabled=move || {
                             is_submitting.get() ||
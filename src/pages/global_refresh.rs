@@ -0,0 +1,78 @@
+use leptos::*;
+
+/// Pull distance (px) past which releasing refreshes
+pub const PULL_REFRESH_THRESHOLD: f64 = 70.0;
+
+#[derive(Clone, Copy)]
+struct RefreshSource {
+    id: u64,
+    /// Page the source belongs to; None refreshes with every page
+    page: Option<&'static str>,
+    refresh: Callback<()>,
+    loading: Signal<bool>,
+}
+
+impl RefreshSource {
+    fn applies_to(&self, page: &str) -> bool {
+        self.page.is_none() || self.page == Some(page)
+    }
+}
+
+/// The header's "refresh everything" action. Pages register their own
+/// refresh and loading state; a refresh runs the sources of the page being
+/// shown plus the shared ones (balances), and is ignored while any of them
+/// is still loading so fetches don't overlap.
+#[derive(Clone, Copy)]
+pub struct GlobalRefresh {
+    sources: RwSignal<Vec<RefreshSource>>,
+    next_id: StoredValue<u64>,
+}
+
+impl GlobalRefresh {
+    /// Create the refresh action and make it available to child pages
+    pub fn provide() -> Self {
+        let refresh = Self {
+            sources: create_rw_signal(Vec::new()),
+            next_id: store_value(0),
+        };
+        provide_context(refresh);
+        refresh
+    }
+
+    /// The refresh action provided by the main page
+    pub fn from_context() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Add a refresh source for as long as the calling component is mounted
+    pub fn register(self, page: Option<&'static str>, refresh: impl Fn() + 'static, loading: Signal<bool>) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        let source = RefreshSource { id, page, refresh: Callback::new(move |_| refresh()), loading };
+        self.sources.update(|sources| sources.push(source));
+        on_cleanup(move || {
+            // the registry may already be gone when the whole main page unmounts
+            let _ = self.sources.try_update(|sources| sources.retain(|source| source.id != id));
+        });
+    }
+
+    /// Whether anything refreshed along with `page` is loading (reactive)
+    pub fn is_refreshing(self, page: &'static str) -> bool {
+        self.sources.with(|sources| {
+            sources.iter()
+                .filter(|source| source.applies_to(page))
+                .any(|source| source.loading.get())
+        })
+    }
+
+    /// Refresh the shared sources and those of `page`, unless one is still loading
+    pub fn refresh(self, page: &'static str) {
+        if untrack(|| self.is_refreshing(page)) {
+            return;
+        }
+        let sources = self.sources.get_untracked();
+        for source in sources.iter().filter(|source| source.applies_to(page)) {
+            source.refresh.call(());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use leptos::*;
+use wasm_bindgen_futures::spawn_local;
+use crate::core::session::Session;
+use crate::core::rpc_base::RpcConnection;
+use crate::core::format::format_memo;
+use crate::core::network_config::explorer_tx_url;
+use crate::core::text::shorten_middle;
+use crate::core::wallet_history::{ActivityKind, ActivityTarget, WalletActivity};
+use crate::pages::global_refresh::GlobalRefresh;
+use crate::pages::relative_time::{format_timestamp, RelativeTime};
+use crate::pages::user_text::UserText;
+
+/// The connected wallet's recent transactions, newest first, with a
+/// "Load more" button that continues from the oldest one shown
+#[component]
+pub fn HistoryPage(
+    session: RwSignal<Session>,
+) -> impl IntoView {
+    let (activity, set_activity) = create_signal::<Vec<WalletActivity>>(vec![]);
+    let (next_before, set_next_before) = create_signal::<Option<String>>(None);
+    let (loading, set_loading) = create_signal(false);
+    let (error, set_error) = create_signal::<Option<String>>(None);
+    let my_pubkey = create_memo(move |_| session.with(|s| s.get_public_key().ok()));
+    // bumped by every fresh load, so a slower older load can't overwrite it
+    let generation = store_value(0_u32);
+
+    // Load the first page (`before` None) or the page after `before`
+    let load = move |before: Option<String>| {
+        let Some(address) = my_pubkey.get_untracked() else {
+            return;
+        };
+        let append = before.is_some();
+        if !append {
+            generation.update_value(|g| *g += 1);
+        }
+        let current = generation.get_value();
+        set_loading.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            let result = RpcConnection::new().get_wallet_activity(&address, before).await;
+            if generation.try_get_value() != Some(current) {
+                return;
+            }
+            match result {
+                Ok(page) => {
+                    if append {
+                        set_activity.update(|items| items.extend(page.items));
+                    } else {
+                        set_activity.set(page.items);
+                    }
+                    set_next_before.set(page.next_before);
+                }
+                Err(e) => {
+                    log::error!("Failed to load wallet history: {}", e);
+                    set_error.set(Some(format!("Failed to load transactions: {}", e)));
+                }
+            }
+            set_loading.set(false);
+        });
+    };
+
+    // (Re)load when the wallet becomes available or changes
+    create_effect(move |_| {
+        if my_pubkey.get().is_some() {
+            load(None);
+        }
+    });
+    GlobalRefresh::from_context().register(Some("history"), move || load(None), loading.into());
+
+    view! {
+        <div class="history-page">
+            <div class="history-header">
+                <h1>"Transaction History"</h1>
+                <button
+                    class="refresh-button"
+                    on:click=move |_| load(None)
+                    disabled=move || loading.get() || my_pubkey.get().is_none()
+                    title="Refresh transactions"
+                >
+                    <i class="fas fa-sync-alt" class:fa-spin=move || loading.get()></i>
+                    "Refresh"
+                </button>
+            </div>
+
+            {move || error.get().map(|error| view! {
+                <div class="error-state"><p>{error}</p></div>
+            })}
+
+            {move || {
+                let items = activity.get();
+                if items.is_empty() && loading.get() {
+                    view! { <div class="loading-state"><p>"Loading transactions..."</p></div> }.into_view()
+                } else if items.is_empty() && error.get().is_none() {
+                    view! { <div class="empty-state"><p>"No transactions for this wallet yet."</p></div> }.into_view()
+                } else {
+                    view! {
+                        <div class="history-list">
+                            {items.into_iter().map(|item| view! { <ActivityRow item=item/> }).collect::<Vec<_>>()}
+                        </div>
+                    }.into_view()
+                }
+            }}
+
+            <Show when=move || next_before.get().is_some()>
+                <div class="history-load-more">
+                    <button
+                        class="pagination-btn"
+                        on:click=move |_| load(next_before.get_untracked())
+                        disabled=move || loading.get()
+                    >
+                        {move || if loading.get() { "Loading..." } else { "Load more" }}
+                    </button>
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+/// One transaction: type, amount, target, text and an explorer link
+#[component]
+fn ActivityRow(item: WalletActivity) -> impl IntoView {
+    let icon = match item.kind {
+        ActivityKind::Message => "fas fa-comment",
+        ActivityKind::Burn => "fas fa-fire",
+        ActivityKind::Mint => "fas fa-hammer",
+        ActivityKind::Create => "fas fa-plus-circle",
+        ActivityKind::Update => "fas fa-edit",
+        ActivityKind::Other => "fas fa-receipt",
+    };
+    let target = item.target.map(|target| match target {
+        ActivityTarget::Group(id) => format!("Chat group #{}", id),
+        ActivityTarget::Project(id) => format!("Project #{}", id),
+    });
+    let raw = item.kind == ActivityKind::Other;
+    let explorer_url = explorer_tx_url(&item.signature).unwrap_or_default();
+
+    view! {
+        <div class="history-row" class:failed=item.failed>
+            <div class="history-kind">
+                <i class=icon></i>
+                <span>{item.kind.label()}</span>
+            </div>
+            <div class="history-body">
+                <div class="history-meta">
+                    {target.map(|target| view! { <span class="history-target">{target}</span> })}
+                    {item.amount.map(|amount| view! {
+                        <span class="history-amount">{format!("{} MEMO", format_memo(amount))}</span>
+                    })}
+                    {item.failed.then(|| view! { <span class="history-failed">"Failed"</span> })}
+                </div>
+                {(!item.text.is_empty()).then(|| if raw {
+                    view! { <code class="history-raw-memo">{item.text.clone()}</code> }.into_view()
+                } else {
+                    view! { <p class="history-text user-text"><UserText text=item.text.clone()/></p> }.into_view()
+                })}
+            </div>
+            <div class="history-side">
+                {(item.timestamp > 0).then(|| view! {
+                    <RelativeTime timestamp=item.timestamp absolute=format_timestamp(item.timestamp)/>
+                })}
+                <a href=explorer_url target="_blank" rel="noopener noreferrer" class="history-explorer-link" title=item.signature.clone()>
+                    {shorten_middle(&item.signature, 6, 6)}
+                    <i class="fas fa-external-link-alt"></i>
+                </a>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,67 @@
+use leptos::*;
+
+/// Numeric id input with a "Go" button, for opening a group or project directly.
+/// The caller looks the id up and navigates; `error` is shown below the input
+/// (e.g. when the id doesn't exist) and `pending` disables the box meanwhile.
+#[component]
+pub fn IdJumpBox(
+    placeholder: &'static str,
+    pending: ReadSignal<bool>,
+    error: ReadSignal<Option<String>>,
+    on_jump: impl Fn(u64) + 'static + Copy,
+) -> impl IntoView {
+    let (input, set_input) = create_signal(String::new());
+    let (parse_error, set_parse_error) = create_signal(Option::<String>::None);
+
+    let submit = move || {
+        match input.get_untracked().trim().parse::<u64>() {
+            Ok(id) => {
+                set_parse_error.set(None);
+                on_jump(id);
+            }
+            Err(_) => set_parse_error.set(Some("Enter a numeric id".to_string())),
+        }
+    };
+
+    view! {
+        <div class="id-jump-box">
+            <form
+                class="id-jump-form"
+                on:submit=move |ev| {
+                    ev.prevent_default();
+                    submit();
+                }
+            >
+                <input
+                    type="number"
+                    min="0"
+                    class="id-jump-input"
+                    placeholder=placeholder
+                    prop:value=move || input.get()
+                    on:input=move |ev| {
+                        set_input.set(event_target_value(&ev));
+                        set_parse_error.set(None);
+                    }
+                    prop:disabled=move || pending.get()
+                />
+                <button
+                    type="submit"
+                    class="id-jump-btn"
+                    disabled=move || pending.get() || input.get().trim().is_empty()
+                >
+                    {move || if pending.get() {
+                        view! { <i class="fas fa-spinner fa-spin"></i> }.into_view()
+                    } else {
+                        "Go".into_view()
+                    }}
+                </button>
+            </form>
+            {move || parse_error.get().or_else(|| error.get()).map(|message| view! {
+                <p class="id-jump-error">
+                    <i class="fas fa-exclamation-circle"></i>
+                    " "{message}
+                </p>
+            })}
+        </div>
+    }
+}
@@ -2,6 +2,7 @@ use leptos::*;
 use serde::{Serialize, Deserialize};
 use std::sync::RwLock;
 use once_cell::sync::Lazy;
+use crate::core::network_config::explorer_tx_url;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LogEntry {
@@ -37,6 +38,32 @@ pub fn add_log_entry(level: &str, message: &str) {
     }
 }
 
+/// Split a message logged as "... Signature: <sig> ..." around the
+/// signature, so it can be shown as an explorer link
+fn split_signature(message: &str) -> Option<(&str, &str, &str)> {
+    const MARKER: &str = "Signature: ";
+    let start = message.find(MARKER)? + MARKER.len();
+    let rest = &message[start..];
+    let end = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+    (end > 0).then(|| (&message[..start], &rest[..end], &rest[end..]))
+}
+
+/// The message, with its signature (if any) linked to the explorer
+fn log_message_view(message: String) -> View {
+    match split_signature(&message).and_then(|(before, signature, after)| {
+        explorer_tx_url(signature).map(|url| (before.to_string(), signature.to_string(), after.to_string(), url))
+    }) {
+        Some((before, signature, after, url)) => view! {
+            <>
+                {before}
+                <a href=url target="_blank" rel="noopener noreferrer">{signature}</a>
+                {after}
+            </>
+        }.into_view(),
+        None => message.into_view(),
+    }
+}
+
 pub fn get_log_entries() -> Vec<LogEntry> {
     LOG_ENTRIES.read().map(|entries| entries.clone()).unwrap_or_default()
 }
@@ -209,7 +236,7 @@ pub fn LogView() -> impl IntoView {
                                         flex: 1;
                                         color: #333;
                                         word-break: break-word;
-                                    ">{entry.message}</div>
+                                    ">{log_message_view(entry.message)}</div>
                                 </div>
                             }
                         }).collect::<Vec<_>>()
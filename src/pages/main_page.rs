@@ -1,16 +1,30 @@
 use leptos::*;
 use crate::core::rpc_base::RpcConnection;
-use crate::core::rpc_domain::get_primary_domain;
+use crate::core::rpc_domain::{get_primary_domain_cached, parse_recipient, resolve_recipient};
+use crate::core::constants::MIN_FEE_BALANCE_XNT;
+use crate::core::network_config::explorer_tx_url;
 use crate::core::session::Session;
 use crate::core::NetworkType;
+use crate::core::preferences::{ThemePreference, UiPreferences};
+use crate::core::theme;
+use crate::core::rpc_inspector;
+use crate::core::text::shorten_pubkey;
 use crate::pages::profile_page::ProfilePage;
 use crate::pages::settings_page::SettingsPage;
+use crate::pages::rpc_inspector_view::RpcInspectorPanel;
+use crate::pages::rpc_health_view::RpcHealthIndicator;
+use crate::pages::account_switcher::AccountSwitcher;
+use crate::pages::burn_network::{MainnetBurnAck, MainnetBurnGuard};
+use crate::pages::global_refresh::{GlobalRefresh, PULL_REFRESH_THRESHOLD};
+use crate::pages::pubkey_display::Nicknames;
+use crate::pages::relative_time::MinuteClock;
 use crate::pages::mint_page::MintPage;
 use crate::pages::chat_page::ChatPage;
 use crate::pages::project_page::ProjectPage;
 use crate::pages::blog_page::BlogPage;
 use crate::pages::forum_page::ForumPage;
 use crate::pages::faucet_page::FaucetPage;
+use crate::pages::history_page::HistoryPage;
 use crate::pages::log_view::add_log_entry;
 use crate::pages::pixel_view::LazyPixelView;
 
@@ -18,6 +32,9 @@ use web_sys::window;
 use std::time::Duration;
 use gloo_timers::future::TimeoutFuture;
 
+// how often the header balances are refreshed while the app is visible
+const BALANCE_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
 // menu item enum
 #[derive(Clone, PartialEq)]
 enum MenuItem {
@@ -28,9 +45,27 @@ enum MenuItem {
     Blog,
     Faucet,
     Profile,
+    History,
     Settings,
 }
 
+impl MenuItem {
+    // key the page registers its global refresh under
+    fn refresh_key(&self) -> &'static str {
+        match self {
+            MenuItem::Mint => "mint",
+            MenuItem::Project => "project",
+            MenuItem::Forum => "forum",
+            MenuItem::Chat => "chat",
+            MenuItem::Blog => "blog",
+            MenuItem::Faucet => "faucet",
+            MenuItem::Profile => "profile",
+            MenuItem::History => "history",
+            MenuItem::Settings => "settings",
+        }
+    }
+}
+
 // Helper function to check if a menu item is available for the current network
 fn is_menu_available(menu_item: &MenuItem, network: Option<NetworkType>) -> bool {
     match network {
@@ -39,8 +74,8 @@ fn is_menu_available(menu_item: &MenuItem, network: Option<NetworkType>) -> bool
             true
         }
         Some(NetworkType::ProdStaging) | Some(NetworkType::Mainnet) => {
-            // Production and Staging: Mint, Project, Forum, Chat, Blog, Profile, and Settings available
-            matches!(menu_item, MenuItem::Mint | MenuItem::Project | MenuItem::Forum | MenuItem::Chat | MenuItem::Blog | MenuItem::Profile | MenuItem::Settings)
+            // Production and Staging: Mint, Project, Forum, Chat, Blog, Profile, History, and Settings available
+            matches!(menu_item, MenuItem::Mint | MenuItem::Project | MenuItem::Forum | MenuItem::Chat | MenuItem::Blog | MenuItem::Profile | MenuItem::History | MenuItem::Settings)
         }
         None => {
             // If network not set (shouldn't happen), default to restricted mode
@@ -64,43 +99,29 @@ pub fn MainPage(
     
     let (show_copied, set_show_copied) = create_signal(false);
     
-    // Theme state - true for dark mode, false for light mode
-    let (is_dark_mode, set_is_dark_mode) = create_signal(false);
-    
-    // Initialize theme from localStorage on component mount
-    create_effect(move |_| {
-        if let Some(window) = window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                if let Ok(Some(theme)) = storage.get_item("theme") {
-                    let is_dark = theme == "dark";
-                    set_is_dark_mode.set(is_dark);
-                    // Apply theme to document
-                    if let Some(document) = window.document() {
-                        if let Some(html) = document.document_element() {
-                            let _ = html.set_attribute("data-theme", if is_dark { "dark" } else { "light" });
-                        }
-                    }
-                }
-            }
-        }
-    });
+    // Theme state - true for dark mode, false for light mode (applied before mount)
+    let (is_dark_mode, set_is_dark_mode) = create_signal(theme::is_dark_applied());
+    let (rpc_inspector_enabled, set_rpc_inspector_enabled) = create_signal(rpc_inspector::is_enabled());
+    // shared by every page that burns, so the mainnet acknowledgment is asked once
+    let burn_guard = MainnetBurnGuard::provide();
+    // header refresh of balances and the page being shown
+    let global_refresh = GlobalRefresh::provide();
+    // local address nicknames, shown by every PubkeyDisplay
+    Nicknames::provide();
+    // "5m ago" times in chat and devlogs age with it
+    MinuteClock::provide();
+    let (balances_loading, set_balances_loading) = create_signal(false);
+    // pull-to-refresh distance on touch devices, None when not pulling
+    let (pull_distance, set_pull_distance) = create_signal::<Option<f64>>(None);
     
     // Theme toggle handler
     let toggle_theme = move |_| {
         let new_is_dark = !is_dark_mode.get();
         set_is_dark_mode.set(new_is_dark);
-        
-        if let Some(window) = window() {
-            // Save to localStorage
-            if let Ok(Some(storage)) = window.local_storage() {
-                let _ = storage.set_item("theme", if new_is_dark { "dark" } else { "light" });
-            }
-            // Apply theme to document
-            if let Some(document) = window.document() {
-                if let Some(html) = document.document_element() {
-                    let _ = html.set_attribute("data-theme", if new_is_dark { "dark" } else { "light" });
-                }
-            }
+        theme::apply_theme(new_is_dark);
+        let preference = if new_is_dark { ThemePreference::Dark } else { ThemePreference::Light };
+        if let Err(e) = UiPreferences::update(|p| p.theme = preference) {
+            log::error!("Failed to save theme: {}", e);
         }
         
         add_log_entry("INFO", &format!("Theme changed to {}", if new_is_dark { "Dark Mode" } else { "Light Mode" }));
@@ -108,6 +129,8 @@ pub fn MainPage(
     
     // Primary domain from X1NS
     let (primary_domain, set_primary_domain) = create_signal(Option::<String>::None);
+    // bumped when the internal wallet switches account, reloading per-account data
+    let (account_generation, set_account_generation) = create_signal(0u32);
     
     // Initialize Burn Stats dialog states
     let (show_init_dialog, set_show_init_dialog) = create_signal(false);
@@ -131,7 +154,7 @@ pub fn MainPage(
     let (transfer_success, set_transfer_success) = create_signal(false);
     let (transfer_tx_hash, set_transfer_tx_hash) = create_signal(String::new());
     let (show_confirm_dialog, set_show_confirm_dialog) = create_signal(false);
-    let (confirm_transfer_data, set_confirm_transfer_data) = create_signal(Option::<(String, String, String)>::None);
+    let (confirm_transfer_data, set_confirm_transfer_data) = create_signal(Option::<(String, String, String, Option<String>)>::None);
     
     // Now using global constant - no need to define locally
     
@@ -179,9 +202,10 @@ pub fn MainPage(
     // listen to balance update needed in session
     create_effect(move |_| {
         let needs_update = session.with(|s| s.is_balance_update_needed());
-        if needs_update {
+        if needs_update && !balances_loading.get_untracked() {
             log::info!("Balance update needed, fetching latest balances...");
             let session_clone = session;
+            set_balances_loading.set(true);
             spawn_local(async move {
                 let mut session_update = session_clone.get_untracked();
                 match session_update.fetch_and_update_balances().await {
@@ -189,19 +213,41 @@ pub fn MainPage(
                         log::info!("Successfully updated balances");
                         // update balance info in session
                         session_clone.update(|s| {
-                            s.set_balances(session_update.get_sol_balance(), session_update.get_token_balance());
+                            // skip balances of an account switched away from meanwhile
+                            if s.get_public_key().ok() == session_update.get_public_key().ok() {
+                                s.set_balances(session_update.get_sol_balance(), session_update.get_token_balance());
+                            }
                         });
                     },
                     Err(e) => {
                         log::error!("Failed to update balances: {}", e);
                     }
                 }
+                set_balances_loading.set(false);
             });
         }
     });
+    global_refresh.register(None, move || session.update(|s| s.mark_balance_update_needed()), balances_loading.into());
+    
+    // keep the header balances current: refresh them periodically while the app is visible
+    let balance_refresh = set_interval_with_handle(
+        move || {
+            let hidden = window().and_then(|w| w.document()).map(|d| d.hidden()).unwrap_or(false);
+            if !hidden {
+                session.update(|s| s.mark_balance_update_needed());
+            }
+        },
+        BALANCE_REFRESH_INTERVAL,
+    );
+    on_cleanup(move || {
+        if let Ok(handle) = balance_refresh {
+            handle.clear();
+        }
+    });
     
-    // check and get user profile on startup
+    // check and get user profile on startup (and after an account switch)
     create_effect(move |_| {
+        let _ = account_generation.get();
         let session_clone = session;
         spawn_local(async move {
             let has_profile = session_clone.with_untracked(|s| s.get_user_profile().is_some());
@@ -235,6 +281,7 @@ pub fn MainPage(
     
     // simplify burn stats check logic  
     create_effect(move |_| {
+        let _ = account_generation.get();
         let session_clone = session;
         spawn_local(async move {
             let has_burn_stats = session_clone.with_untracked(|s| s.has_burn_stats_initialized());
@@ -274,17 +321,22 @@ pub fn MainPage(
         });
     });
     
-    // Fetch primary domain from X1NS
-    {
+    // Fetch primary domain from X1NS (again after an account switch)
+    create_effect(move |_| {
+        let _ = account_generation.get();
         let session_clone = session;
+        set_primary_domain.set(None);
         spawn_local(async move {
             let addr = session_clone.get_untracked().get_public_key().unwrap_or_else(|_| String::new());
             if !addr.is_empty() && addr != "Not initialized" {
                 log::info!("Fetching primary domain for address: {}", addr);
-                match get_primary_domain(&addr).await {
+                match get_primary_domain_cached(&addr).await {
                     Ok(Some(domain)) => {
                         log::info!("Primary domain found: {}", domain);
-                        set_primary_domain.set(Some(domain));
+                        // the account may have changed while this was loading
+                        if session_clone.with_untracked(|s| s.get_public_key().ok()) == Some(addr) {
+                            let _ = set_primary_domain.try_set(Some(domain));
+                        }
                     },
                     Ok(None) => {
                         log::debug!("No primary domain set for this address");
@@ -295,7 +347,7 @@ pub fn MainPage(
                 }
             }
         });
-    }
+    });
     
     // test rpc connection
     spawn_local(async move {
@@ -461,11 +513,41 @@ pub fn MainPage(
     // current selected menu item - changed default from Home to Mint
     let (current_menu, set_current_menu) = create_signal(MenuItem::Mint);
 
+    let is_refreshing_all = move || global_refresh.is_refreshing(current_menu.get().refresh_key());
+    let refresh_all = move || global_refresh.refresh(current_menu.get_untracked().refresh_key());
+    let on_account_switch = Callback::new(move |_| {
+        set_account_generation.update(|generation| *generation += 1);
+        refresh_all();
+    });
+
+    // pull-to-refresh: pulling down from the top of the content area
+    let content_ref = create_node_ref::<html::Div>();
+    let pull_start_y = store_value::<Option<f64>>(None);
+    let on_touch_start = move |ev: ev::TouchEvent| {
+        let at_top = content_ref.get_untracked().map(|c| c.scroll_top() <= 0).unwrap_or(false);
+        let touch_y = ev.touches().get(0).map(|t| t.client_y() as f64);
+        pull_start_y.set_value(touch_y.filter(|_| at_top));
+    };
+    let on_touch_move = move |ev: ev::TouchEvent| {
+        let (Some(start), Some(touch)) = (pull_start_y.get_value(), ev.touches().get(0)) else { return };
+        let distance = touch.client_y() as f64 - start;
+        set_pull_distance.set((distance > 0.0).then_some(distance));
+    };
+    let on_touch_end = move |_: ev::TouchEvent| {
+        if pull_distance.get_untracked().is_some_and(|d| d >= PULL_REFRESH_THRESHOLD) {
+            refresh_all();
+        }
+        pull_start_y.set_value(None);
+        set_pull_distance.set(None);
+    };
+
     view! {
         <div class="main-page">
             <div class="top-bar">
                 // Left side - Control buttons
                 <div class="left-controls">
+                    <RpcHealthIndicator />
+                    
                     // Theme toggle button
                     <button
                         class="theme-toggle-btn"
@@ -501,6 +583,17 @@ pub fn MainPage(
                         </button>
                     </Show>
                     
+                    // Refresh balances and the current page
+                    <button
+                        class="refresh-all-btn"
+                        on:click=move |_| refresh_all()
+                        disabled=is_refreshing_all
+                        title="Refresh balances and this page"
+                    >
+                        <i class="fas fa-sync-alt" class:fa-spin=is_refreshing_all></i>
+                        <span>"Refresh"</span>
+                    </button>
+                    
                     // Initialize Burn Stats button (only show if needed)
                     <Show when=move || needs_burn_stats_init()>
                         <button
@@ -516,6 +609,11 @@ pub fn MainPage(
                 
                 // Right side - profile avatar and wallet info
                 <div class="wallet-address">
+                    // Account switcher - only for internal wallet
+                    <Show when=move || session.with(|s| s.is_internal_wallet())>
+                        <AccountSwitcher session=session on_switch=on_account_switch/>
+                    </Show>
+                    
                     // Profile avatar
                     {move || {
                         session.with(|s| {
@@ -556,8 +654,16 @@ pub fn MainPage(
                         on:click=move |_| set_show_transfer_dialog.set(true)
                         title="Click to transfer tokens"
                     >
-                        <span class="token-balance">{move || format!("{:.2} MEMO", token_balance())}</span>
-                        <span class="balance">{move || format!("{:.4} XNT", sol_balance())}</span>
+                        <span class="token-balance" class:zero-balance=move || token_balance() <= 0.0>
+                            {move || format!("{:.2} MEMO", token_balance())}
+                        </span>
+                        <span
+                            class="balance"
+                            class:low-balance=move || session.with(|s| s.has_low_fee_balance())
+                            title=move || if session.with(|s| s.has_low_fee_balance()) { "Low XNT balance: transaction fees may fail" } else { "" }
+                        >
+                            {move || format!("{:.4} XNT", sol_balance())}
+                        </span>
                         <span class="address-label">"Wallet: "</span>
                         <span 
                             class="address-value" 
@@ -575,6 +681,17 @@ pub fn MainPage(
                             }}
                         </span>
                     </button>
+                    // No MEMO yet: point to the mint page
+                    <Show when=move || token_balance() <= 0.0 && current_menu.get() != MenuItem::Mint>
+                        <button
+                            class="get-memo-btn"
+                            on:click=move |_| set_current_menu.set(MenuItem::Mint)
+                            title="You have no MEMO yet. Mint some to burn, chat and post."
+                        >
+                            <i class="fas fa-hammer"></i>
+                            <span>"Get MEMO"</span>
+                        </button>
+                    </Show>
                     <div class="copy-container">
                         <button
                             class="copy-button"
@@ -678,6 +795,18 @@ pub fn MainPage(
                         </div>
                     </Show>
 
+                    // History - the wallet's own transactions
+                    <Show when=move || is_menu_available(&MenuItem::History, current_network())>
+                        <div
+                            class="menu-item"
+                            class:active=move || current_menu.get() == MenuItem::History
+                            on:click=move |_| set_current_menu.set(MenuItem::History)
+                        >
+                            <i class="fas fa-history"></i>
+                            <span>"History"</span>
+                        </div>
+                    </Show>
+
                     // Settings - available on all networks
                     <Show when=move || is_menu_available(&MenuItem::Settings, current_network())>
                         <div
@@ -723,7 +852,23 @@ pub fn MainPage(
                     </div>
                 </div>
 
-                <div class="content">
+                <div
+                    class="content"
+                    node_ref=content_ref
+                    on:touchstart=on_touch_start
+                    on:touchmove=on_touch_move
+                    on:touchend=on_touch_end
+                >
+                    // Pull-to-refresh indicator
+                    {move || pull_distance.get().map(|distance| {
+                        let ready = distance >= PULL_REFRESH_THRESHOLD;
+                        view! {
+                            <div class="pull-refresh-indicator" style=format!("height: {}px", distance.min(PULL_REFRESH_THRESHOLD))>
+                                <i class="fas fa-arrow-down" class:ready=ready></i>
+                                <span>{if ready { "Release to refresh" } else { "Pull to refresh" }}</span>
+                            </div>
+                        }
+                    })}
                     // Mint - always visible
                     <div style=move || if current_menu.get() == MenuItem::Mint { "display: block;" } else { "display: none;" }>
                         <MintPage session=session/>
@@ -771,10 +916,20 @@ pub fn MainPage(
                         </div>
                     </Show>
 
+                    // History - the wallet's own transactions
+                    <Show when=move || is_menu_available(&MenuItem::History, current_network())>
+                        <div style=move || if current_menu.get() == MenuItem::History { "display: block;" } else { "display: none;" }>
+                            <HistoryPage session=session/>
+                        </div>
+                    </Show>
+
                     // Settings - available on all networks
                     <Show when=move || is_menu_available(&MenuItem::Settings, current_network())>
                         <div style=move || if current_menu.get() == MenuItem::Settings { "display: block;" } else { "display: none;" }>
-                            <SettingsPage/>
+                            <SettingsPage
+                                on_theme_change=move |is_dark| set_is_dark_mode.set(is_dark)
+                                on_rpc_inspector_change=move |enabled| set_rpc_inspector_enabled.set(enabled)
+                            />
                         </div>
                     </Show>
                 </div>
@@ -783,6 +938,13 @@ pub fn MainPage(
             // Global log viewer - always visible at the bottom
             // Temporarily disabled by user request
             // <LogView/>
+
+            <MainnetBurnAck guard=burn_guard/>
+
+            // RPC request inspector - developer setting
+            <Show when=move || rpc_inspector_enabled.get()>
+                <RpcInspectorPanel/>
+            </Show>
             
             // Welcome Info Dialog (shown after login/registration if burn stats not initialized)
             <Show when=move || show_welcome_info.get()>
@@ -940,6 +1102,12 @@ pub fn MainPage(
                                                 <div class="tx-info">
                                                     <span class="tx-label">"Transaction Signature:"</span>
                                                     <div class="tx-hash">{transfer_tx_hash}</div>
+                                                    {move || explorer_tx_url(&transfer_tx_hash.get()).map(|url| view! {
+                                                        <a href=url target="_blank" rel="noopener noreferrer" class="tx-explorer-link">
+                                                            <i class="fas fa-external-link-alt"></i>
+                                                            " View in explorer"
+                                                        </a>
+                                                    })}
                                                 </div>
                                             </div>
                                         }.into_view()
@@ -980,12 +1148,12 @@ pub fn MainPage(
                                     <div class="form-group">
                                         <label>
                                             <i class="fas fa-wallet"></i>
-                                            "Recipient Address:"
+                                            "Recipient:"
                                         </label>
                                         <input 
                                             type="text"
                                             class="form-control"
-                                            placeholder="Enter recipient address"
+                                            placeholder="Enter recipient address or .x1 name"
                                             prop:value=move || transfer_address.get()
                                             on:input=move |ev| {
                                                 set_transfer_address.set(event_target_value(&ev));
@@ -1128,9 +1296,47 @@ pub fn MainPage(
                                             return;
                                         }
                                         
-                                        // Show confirmation dialog
-                                        set_confirm_transfer_data.set(Some((token_type.clone(), address.clone(), amount_str.clone())));
-                                        set_show_confirm_dialog.set(true);
+                                        // Same fee guard as burns: keep enough XNT to pay for the transaction
+                                        let fee_balance = if token_type == "MEMO" { sol_balance() } else { sol_balance() - amount };
+                                        if fee_balance < MIN_FEE_BALANCE_XNT {
+                                            set_transfer_message.set(format!(
+                                                "Insufficient XNT balance for transaction fee! Keep at least {} XNT after the transfer",
+                                                MIN_FEE_BALANCE_XNT
+                                            ));
+                                            set_timeout(move || {
+                                                set_transfer_message.set(String::new());
+                                            }, Duration::from_millis(3000));
+                                            return;
+                                        }
+                                        
+                                        if parse_recipient(&address).is_none() {
+                                            set_transfer_message.set("Enter a wallet address or an .x1 name".to_string());
+                                            set_timeout(move || {
+                                                set_transfer_message.set(String::new());
+                                            }, Duration::from_millis(3000));
+                                            return;
+                                        }
+                                        
+                                        // Resolve .x1 names, then show confirmation dialog
+                                        set_transfer_loading.set(true);
+                                        set_transfer_message.set("Checking recipient...".to_string());
+                                        spawn_local(async move {
+                                            match resolve_recipient(&address).await {
+                                                Ok((recipient, domain)) => {
+                                                    let _ = set_transfer_loading.try_set(false);
+                                                    let _ = set_transfer_message.try_set(String::new());
+                                                    let _ = set_confirm_transfer_data.try_set(Some((token_type, recipient, amount_str, domain)));
+                                                    let _ = set_show_confirm_dialog.try_set(true);
+                                                }
+                                                Err(e) => {
+                                                    let _ = set_transfer_loading.try_set(false);
+                                                    let _ = set_transfer_message.try_set(format!("Could not find recipient: {}", e));
+                                                    set_timeout(move || {
+                                                        let _ = set_transfer_message.try_set(String::new());
+                                                    }, Duration::from_millis(3000));
+                                                }
+                                            }
+                                        });
                                     }
                                 >
                                     <i class="fas fa-paper-plane"></i>
@@ -1161,7 +1367,7 @@ pub fn MainPage(
                         
                         <div class="modal-body">
                             {move || {
-                                if let Some((token_type, address, amount)) = confirm_transfer_data.get() {
+                                if let Some((token_type, address, amount, domain)) = confirm_transfer_data.get() {
                                     view! {
                                         <div class="confirm-details">
                                             <p>
@@ -1169,10 +1375,17 @@ pub fn MainPage(
                                                 <strong>"Token Type: "</strong>
                                                 <span>{token_type.clone()}</span>
                                             </p>
+                                            {domain.map(|domain| view! {
+                                                <p>
+                                                    <i class="fas fa-at"></i>
+                                                    <strong>"Name: "</strong>
+                                                    <span>{domain}</span>
+                                                </p>
+                                            })}
                                             <p>
                                                 <i class="fas fa-wallet"></i>
                                                 <strong>"Recipient: "</strong>
-                                                <span>{format!("{}...{}", &address[..8], &address[address.len()-8..])}</span>
+                                                <span title=address.clone()>{shorten_pubkey(&address)}</span>
                                             </p>
                                             <p>
                                                 <i class="fas fa-money-bill-wave"></i>
@@ -1204,7 +1417,7 @@ pub fn MainPage(
                                 on:click=move |_| {
                                     set_show_confirm_dialog.set(false);
                                     
-                                    if let Some((token_type, address, amount_str)) = confirm_transfer_data.get() {
+                                    if let Some((token_type, address, amount_str, _)) = confirm_transfer_data.get() {
                                         set_transfer_loading.set(true);
                                         set_transfer_message.set("Processing transfer...".to_string());
                                         
@@ -0,0 +1,17 @@
+/// Smallest and largest memo (Borsh + Base64 bytes) the programs accept
+pub const MEMO_SIZE_RANGE: std::ops::RangeInclusive<usize> = 69..=800;
+
+/// Whether a memo of `size` bytes fits the 69-800 byte window, and the status
+/// shown for it. A memo that is too long with a large image points at the
+/// image, the usual culprit.
+pub fn memo_size_status(size: usize, grid_size: usize) -> (bool, String) {
+    if size < *MEMO_SIZE_RANGE.start() {
+        (false, "❌ Too short".to_string())
+    } else if size > *MEMO_SIZE_RANGE.end() && grid_size >= 64 {
+        (false, format!("❌ Too long: simplify the {0}×{0} image or use a smaller grid", grid_size))
+    } else if size > *MEMO_SIZE_RANGE.end() {
+        (false, "❌ Too long".to_string())
+    } else {
+        (true, "✅ Valid".to_string())
+    }
+}
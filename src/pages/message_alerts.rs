@@ -0,0 +1,102 @@
+use std::cell::RefCell;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AudioContext, AudioScheduledSourceNode, Notification, NotificationOptions, NotificationPermission};
+
+thread_local! {
+    /// Reused for every chime; browsers limit how many contexts a page may open
+    static AUDIO: RefCell<Option<AudioContext>> = const { RefCell::new(None) };
+}
+
+/// Whether this browser (or webview) has the Notification API
+pub fn notifications_supported() -> bool {
+    web_sys::window()
+        .map(|window| js_sys::Reflect::has(&window, &JsValue::from_str("Notification")).unwrap_or(false))
+        .unwrap_or(false)
+}
+
+/// Whether the user allowed notifications for the app
+pub fn notifications_allowed() -> bool {
+    notifications_supported() && Notification::permission() == NotificationPermission::Granted
+}
+
+/// Ask for permission to show notifications (only prompts the first time).
+/// Returns whether notifications are allowed afterwards.
+pub async fn request_notification_permission() -> bool {
+    if !notifications_supported() {
+        return false;
+    }
+    if Notification::permission() != NotificationPermission::Default {
+        return notifications_allowed();
+    }
+    if let Ok(promise) = Notification::request_permission() {
+        if let Err(e) = JsFuture::from(promise).await {
+            log::warn!("Notification permission request failed: {:?}", e);
+        }
+    }
+    notifications_allowed()
+}
+
+/// Whether the app's tab is shown (not in the background or minimized)
+pub fn page_visible() -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .is_none_or(|document| !document.hidden())
+}
+
+/// Whether the app's tab has focus, so alerts would only add noise
+pub fn page_focused() -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.has_focus().ok())
+        .unwrap_or(true)
+}
+
+/// Show a system notification; `tag` replaces an earlier one with the same tag
+pub fn show_notification(title: &str, body: &str, tag: &str) {
+    if !notifications_allowed() {
+        return;
+    }
+    let options = NotificationOptions::new();
+    options.set_body(body);
+    options.set_tag(tag);
+    if let Err(e) = Notification::new_with_options(title, &options) {
+        log::warn!("Failed to show notification: {:?}", e);
+    }
+}
+
+/// Play a short two-tone chime
+pub fn play_message_sound() {
+    if let Err(e) = try_play_message_sound() {
+        log::warn!("Failed to play message sound: {:?}", e);
+    }
+}
+
+fn try_play_message_sound() -> Result<(), JsValue> {
+    let context = AUDIO.with(|audio| -> Result<AudioContext, JsValue> {
+        let mut audio = audio.borrow_mut();
+        if let Some(context) = audio.as_ref() {
+            return Ok(context.clone());
+        }
+        let context = AudioContext::new()?;
+        *audio = Some(context.clone());
+        Ok(context)
+    })?;
+
+    let start = context.current_time();
+    for (offset, frequency) in [(0.0, 880.0), (0.12, 1320.0)] {
+        let oscillator = context.create_oscillator()?;
+        let gain = context.create_gain()?;
+        oscillator.frequency().set_value(frequency);
+        gain.gain().set_value_at_time(0.15, start + offset)?;
+        gain.gain().exponential_ramp_to_value_at_time(0.001, start + offset + 0.2)?;
+        oscillator.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&context.destination())?;
+        let source: &AudioScheduledSourceNode = &oscillator;
+        source.start_with_when(start + offset)?;
+        source.stop_with_when(start + offset + 0.2)?;
+    }
+    // a context created without a user gesture may start suspended
+    let _ = context.resume();
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use leptos::*;
 use leptos::leptos_dom::ev::SubmitEvent;
 use crate::core::session::Session;
-use crate::core::pixel::Pixel;
-use crate::pages::pixel_view::PixelView;
+use crate::core::pixel::{Pixel, PixelHistory};
+use crate::pages::pixel_view::{PixelView, PixelHistoryControls, PixelStringImport, PixelColorPicker, PixelTool, PixelToolSelector};
 use web_sys::{HtmlInputElement, FileReader, Event, ProgressEvent, window};
 use wasm_bindgen::{JsCast, closure::Closure};
 use js_sys::Uint8Array;
@@ -72,6 +72,10 @@ impl GridSize {
             GridSize::Size1024,
         ]
     }
+
+    pub fn from_size(size: usize) -> Option<Self> {
+        Self::all_sizes().into_iter().find(|grid| grid.to_size() == size)
+    }
 }
 
 #[component]
@@ -120,11 +124,35 @@ pub fn MintForm(
     // --- NEW: Manual signal to control immediate UI state on submit ---
     let (is_submitting, set_is_submitting) = create_signal(false);
 
+    // Undo/redo of the image; each edit records the image before it
+    let pixel_history = create_rw_signal(PixelHistory::default());
+    // Palette color the editor paints with (black by default)
+    let pixel_color = create_rw_signal(1u8);
+    let pixel_tool = create_rw_signal(PixelTool::default());
+    let edit_pixel_art = move |art: Pixel| {
+        pixel_history.update(|h| h.record(pixel_art.get_untracked()));
+        set_pixel_art.set(art);
+    };
+    let restore_pixel_art = move |art: Pixel| {
+        if let Some(size) = GridSize::from_size(art.dimensions().0) {
+            set_grid_size.set(size);
+        }
+        set_pixel_art.set(art);
+    };
+    let load_pixel_art = move |art: Pixel| {
+        if let Some(size) = GridSize::from_size(art.dimensions().0) {
+            set_grid_size.set(size);
+            edit_pixel_art(art);
+        }
+    };
+
     // when the size changes, recreate the pixel art
-    create_effect(move |_| {
-        let size = grid_size.get().to_size();
-        set_pixel_art.set(Pixel::new_with_size(size));
-    });
+    let change_grid_size = move |size: GridSize| {
+        if grid_size.get_untracked() != size {
+            set_grid_size.set(size);
+            edit_pixel_art(Pixel::new_with_size(size.to_size()));
+        }
+    };
 
     // create combined memo function
     let create_combined_memo = |title: &str, content: &str, pixel_data: &str| -> String {
@@ -293,6 +321,7 @@ pub fn MintForm(
                         set_title_text.set(String::new());
                         set_content_text.set(String::new());
                         set_pixel_art.set(Pixel::new_with_size(grid_size.get_untracked().to_size()));
+                        pixel_history.update(PixelHistory::clear);
                     },
                     Err(e) => {
                         on_mint_error_signal.with_untracked(|cb_opt| {
@@ -343,7 +372,6 @@ pub fn MintForm(
         input.set_type("file");
         input.set_accept("image/*");
         
-        let pixel_art_write = set_pixel_art;
         let error_signal = set_error_message;
         let current_grid_size = grid_size.get();  // get the current selected size
         
@@ -362,7 +390,7 @@ pub fn MintForm(
                         
                         match Pixel::from_image_data_with_size(&data, size) {
                             Ok(new_art) => {
-                                pixel_art_write.set(new_art);
+                                edit_pixel_art(new_art);
                                 error_signal.set(String::new());
                             }
                             Err(e) => {
@@ -599,7 +627,7 @@ pub fn MintForm(
                                                     type="radio"
                                                     name="grid-size"
                                                     checked=move || grid_size.get() == size
-                                                    on:change=move |_| set_grid_size.set(size)
+                                                    on:change=move |_| change_grid_size(size)
                                                     prop:disabled=move || single_mint_action.pending().get() || auto_mint_action.pending().get()
                                                 />
                                                 <span class="radio-text">{size.to_display_string()}</span>
@@ -625,13 +653,32 @@ pub fn MintForm(
                                     >
                                         "Import Image"
                                     </button>
+                                    <PixelHistoryControls
+                                        history=pixel_history
+                                        art=pixel_art
+                                        on_restore=restore_pixel_art
+                                        disabled=Signal::derive(move || single_mint_action.pending().get() || auto_mint_action.pending().get())
+                                    />
                                 </div>
+                                <PixelToolSelector
+                                    tool=pixel_tool
+                                    disabled=Signal::derive(move || single_mint_action.pending().get() || auto_mint_action.pending().get())
+                                />
+                                <PixelColorPicker
+                                    color=pixel_color
+                                    disabled=Signal::derive(move || single_mint_action.pending().get() || auto_mint_action.pending().get())
+                                />
+                                <PixelStringImport
+                                    sizes=GridSize::all_sizes().into_iter().map(GridSize::to_size).collect()
+                                    on_load=load_pixel_art
+                                    disabled=Signal::derive(move || single_mint_action.pending().get() || auto_mint_action.pending().get())
+                                />
                                 {move || {
                                     let art_string = pixel_art.get().to_optimal_string();
                                     let click_handler = Box::new(move |row, col| {
                                         let mut new_art = pixel_art.get();
-                                        new_art.toggle_pixel(row, col);
-                                        set_pixel_art.set(new_art);
+                                        pixel_tool.get_untracked().apply(&mut new_art, row, col, pixel_color.get_untracked());
+                                        edit_pixel_art(new_art);
                                     });
                                     
                                     // fixed display area size to 320px, not change with grid_size
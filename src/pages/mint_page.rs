@@ -1,12 +1,17 @@
 use leptos::*;
 use crate::core::session::Session;
 use crate::core::rpc_base::RpcConnection;
+use crate::core::memo_client::MemoClient;
 use crate::core::rpc_mint::{MintConfig, SupplyTier};
+use crate::core::cache;
+use crate::core::burn_stats::BurnOverview;
+use crate::core::format::{format_memo, format_number_with_commas};
 use crate::core::rpc_profile::UserDisplayInfo;
 use crate::pages::pixel_view::LazyPixelView;
 use wasm_bindgen_futures::spawn_local;
 use gloo_timers::future::TimeoutFuture;
 use web_sys::window;
+use crate::pages::global_refresh::GlobalRefresh;
 use std::collections::HashMap;
 
 // Mint mode enumeration
@@ -95,6 +100,8 @@ pub fn SupplyProgressBar() -> impl IntoView {
     let (loading, set_loading) = create_signal(true);
     let (error, set_error) = create_signal::<Option<String>>(None);
     let (timer_active, set_timer_active) = create_signal(false);
+    // Some(None) once the holder count failed to load
+    let (holder_count, set_holder_count) = create_signal::<Option<Option<u64>>>(None);
 
     // fetch supply data
     let fetch_supply_data = move |is_initial_load: bool| {
@@ -105,7 +112,7 @@ pub fn SupplyProgressBar() -> impl IntoView {
             set_error.set(None);
             
             let rpc = RpcConnection::new();
-            match rpc.get_current_supply_tier_info().await {
+            match cache::get_current_supply_tier_info(&rpc).await {
                 Ok((supply, tier)) => {
                     set_supply_info.set(Some((supply, tier)));
                     set_loading.set(false);
@@ -128,9 +135,10 @@ pub fn SupplyProgressBar() -> impl IntoView {
                                 
                                 log::info!("Auto-refreshing supply data...");
                                 
-                                // background update data (no loading state)
+                                // background update data (no loading state), bypassing the cached supply
+                                cache::invalidate_mint_reward();
                                 let rpc = RpcConnection::new();
-                                match rpc.get_current_supply_tier_info().await {
+                                match cache::get_current_supply_tier_info(&rpc).await {
                                     Ok((supply, tier)) => {
                                         set_supply_info.set(Some((supply, tier)));
                                         log::info!("Supply data auto-refreshed successfully");
@@ -158,12 +166,37 @@ pub fn SupplyProgressBar() -> impl IntoView {
         fetch_supply_data(true); // first load, show loading state
     });
 
+    // the holder count is a slow scan of all token accounts, so it loads on its own
+    create_effect(move |_| {
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            let count = match cache::get_token_holder_count(&rpc).await {
+                Ok(count) => Some(count),
+                Err(e) => {
+                    log::warn!("Failed to fetch token holder count: {}", e);
+                    None
+                }
+            };
+            let _ = set_holder_count.try_set(Some(count));
+        });
+    });
+
     // stop timer on component unmount
     on_cleanup(move || {
         set_timer_active.set(false);
         log::info!("Stopped supply data auto-refresh timer");
     });
 
+    // The header's refresh re-reads the supply, bypassing the cache
+    GlobalRefresh::from_context().register(
+        Some("mint"),
+        move || {
+            cache::invalidate_mint_reward();
+            fetch_supply_data(false);
+        },
+        loading.into(),
+    );
+
     // Manual refresh handler
     let handle_refresh = move |_| {
         log::info!("Manual refresh triggered");
@@ -246,10 +279,23 @@ pub fn SupplyProgressBar() -> impl IntoView {
                             <div class="supply-current-info">
                                 <div class="supply-info-item">
                                     <div class="supply-info-label">"Current Supply"</div>
-                                    <div class="supply-info-value">
+                                    <div
+                                        class="supply-info-value"
+                                        title=format!("{} MEMO", format_number_with_commas(supply / 1_000_000))
+                                    >
                                         {format_supply_display(supply_tokens)}
                                     </div>
                                 </div>
+                                <div class="supply-info-item">
+                                    <div class="supply-info-label">"Holders"</div>
+                                    <div class="supply-info-value">
+                                        {move || match holder_count.get() {
+                                            None => "...".to_string(),
+                                            Some(None) => "-".to_string(),
+                                            Some(Some(count)) => format_number_with_commas(count),
+                                        }}
+                                    </div>
+                                </div>
                                 <div class="supply-info-item">
                                     <div class="supply-info-label">"Current Reward"</div>
                                     <div class="supply-info-value">
@@ -267,6 +313,108 @@ pub fn SupplyProgressBar() -> impl IntoView {
     }
 }
 
+/// MEMO burned across chat groups and projects, next to the minted supply
+#[component]
+pub fn BurnStatsOverview() -> impl IntoView {
+    let (overview, set_overview) = create_signal::<Option<BurnOverview>>(None);
+    let (loading, set_loading) = create_signal(true);
+
+    let fetch_overview = move || {
+        spawn_local(async move {
+            set_loading.set(true);
+            let rpc = RpcConnection::new();
+            let result = cache::get_burn_overview(&rpc).await;
+            for failure in &result.failures {
+                log::warn!("Burn overview incomplete, failed to load {}", failure);
+            }
+            let _ = set_overview.try_set(Some(result));
+            let _ = set_loading.try_set(false);
+        });
+    };
+
+    create_effect(move |_| {
+        fetch_overview();
+    });
+
+    // Manual refresh reads everything again instead of the cached totals
+    let handle_refresh = move |_| {
+        cache::invalidate_burn_overview();
+        cache::invalidate_mint_reward();
+        fetch_overview();
+    };
+
+    let amount = |lamports: Option<u64>| lamports.map_or_else(|| "-".to_string(), format_memo);
+
+    view! {
+        <div class="burn-stats-overview">
+            <div class="burn-stats-header">
+                <h3>
+                    <i class="fas fa-fire"></i>
+                    "MEMO Burn Statistics"
+                </h3>
+                <button
+                    class="supply-refresh-btn"
+                    on:click=handle_refresh
+                    disabled=move || loading.get()
+                    title="Refresh burn statistics"
+                >
+                    <i class="fas fa-sync-alt" class:fa-spin=move || loading.get()></i>
+                    <span>"Refresh"</span>
+                </button>
+            </div>
+
+            {move || match overview.get() {
+                None => view! {
+                    <div class="supply-loading">
+                        <i class="fas fa-spinner fa-spin"></i>
+                        " Loading burn statistics..."
+                    </div>
+                }.into_view(),
+                Some(stats) => view! {
+                    <div>
+                        {(!stats.is_complete()).then(|| view! {
+                            <div class="burn-stats-warning">
+                                <i class="fas fa-exclamation-triangle"></i>
+                                " Some statistics could not be loaded, the totals may be incomplete."
+                            </div>
+                        })}
+                        <div class="stats-grid">
+                            <div class="stat-card">
+                                <div class="stat-icon"><i class="fas fa-fire"></i></div>
+                                <div class="stat-content">
+                                    <h3>{amount(stats.total_burned())}</h3>
+                                    <p>"Total MEMO Burned"</p>
+                                </div>
+                            </div>
+                            <div class="stat-card">
+                                <div class="stat-icon"><i class="fas fa-comments"></i></div>
+                                <div class="stat-content">
+                                    <h3>{amount(stats.chat_burned)}</h3>
+                                    <p>"Burned in Chat Groups"</p>
+                                </div>
+                            </div>
+                            <div class="stat-card">
+                                <div class="stat-icon"><i class="fas fa-project-diagram"></i></div>
+                                <div class="stat-content">
+                                    <h3>{amount(stats.project_burned)}</h3>
+                                    <p>"Burned in Projects"</p>
+                                </div>
+                            </div>
+                            <div class="stat-card">
+                                <div class="stat-icon"><i class="fas fa-coins"></i></div>
+                                <div class="stat-content">
+                                    <h3>{amount(stats.supply)}</h3>
+                                    <p>"Total Minted Supply"</p>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                }.into_view(),
+            }}
+        </div>
+    }
+}
+
 #[component]
 pub fn SwapBridgeLink() -> impl IntoView {
     let handle_click = move |_| {
@@ -311,9 +459,9 @@ pub fn TokenHoldersLeaderboard() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
             
-            let rpc = RpcConnection::new();
+            let client = MemoClient::new();
             
-            match rpc.get_token_holders(MAX_DISPLAY).await {
+            match client.top_holders(MAX_DISPLAY).await {
                 Ok(all_holders) => {
                     set_holders.set(all_holders);
                     set_loading.set(false);
@@ -475,9 +623,9 @@ pub fn BurnerLeaderboard() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
             
-            let rpc = RpcConnection::new();
+            let client = MemoClient::new();
             
-            match rpc.get_top_burners(MAX_DISPLAY).await {
+            match client.top_burners(MAX_DISPLAY).await {
                 Ok(all_burners) => {
                     set_burners.set(all_burners.clone());
                     set_loading.set(false);
@@ -493,7 +641,7 @@ pub fn BurnerLeaderboard() -> impl IntoView {
                         
                         log::info!("Fetching profile info for top {} burners", top_addresses.len());
                         
-                        match rpc.get_user_display_info_batch(&top_addresses).await {
+                        match client.display_infos(&top_addresses).await {
                             Ok(display_infos) => {
                                 let mut cache = HashMap::new();
                                 for display_info in display_infos {
@@ -934,6 +1082,8 @@ pub fn MintPage(
             // Add the supply progress bar here
             <SupplyProgressBar />
             
+            <BurnStatsOverview />
+            
             <div class="mint-content">
                 // Mint mode selection
                 <div class="mint-mode-section">
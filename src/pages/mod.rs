@@ -5,8 +5,27 @@ pub mod mint_page;
 pub mod mint_form;
 pub mod log_view;
 pub mod pixel_view;
+pub mod user_text;
+pub mod view_mode_toggle;
+pub mod id_jump_box;
+pub mod creation_notice;
+pub mod progress_overlay;
+pub mod rank_projection;
+pub mod raw_payload_view;
+pub mod burn_network;
+pub mod form_keys;
+pub mod memo_size;
+pub mod global_refresh;
+pub mod pubkey_display;
+pub mod relative_time;
+pub mod message_alerts;
+pub mod simulation_report;
+pub mod rpc_inspector_view;
+pub mod rpc_health_view;
+pub mod account_switcher;
 pub mod chat_page;
 pub mod faucet_page;
+pub mod history_page;
 pub mod project_page;
 pub mod blog_page;
 pub mod forum_page;
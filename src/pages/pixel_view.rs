@@ -1,25 +1,35 @@
 use leptos::*;
-use leptos::html::Canvas;
+use leptos::html::{Canvas, Div};
 use web_sys::{HtmlCanvasElement, CanvasRenderingContext2d, MouseEvent};
 use wasm_bindgen::JsCast;
-use crate::core::pixel::Pixel;
+use crate::core::pixel::{Pixel, PixelAnimation, PixelHistory, PALETTE, PALETTE_SIZE};
 use wasm_bindgen_futures::spawn_local;
 use gloo_timers::future::TimeoutFuture;
 
+/// Smallest display size that gets the "copy string" button - tiny thumbnails stay clean
+const MIN_COPY_BUTTON_SIZE: u32 = 48;
+
 #[component]
 pub fn PixelView(
-    #[prop(into)] art: String,
+    #[prop(into)] art: MaybeSignal<String>,
     #[prop(optional)] size: Option<u32>,
     #[prop(optional)] editable: bool,
     #[prop(optional)] on_click: Option<Box<dyn Fn(usize, usize)>>,
     #[prop(optional)] show_grid: Option<bool>,
+    /// don't offer copying the encoded string (e.g. for generated placeholder art)
+    #[prop(optional)] hide_copy: bool,
 ) -> impl IntoView {
     let display_size = size.unwrap_or(64);
     let show_grid = show_grid.unwrap_or(true);
     
+    // read-only views of real pixel art offer a copy of the encoded string for remixing
+    let copyable_art = (!editable && !hide_copy && display_size >= MIN_COPY_BUTTON_SIZE)
+        .then(|| art.get_untracked())
+        .filter(|art| Pixel::from_optimal_string(art).is_some());
+    
     // create memo for pixel data
     let pixel_data = create_memo(move |_| {
-        Pixel::from_optimal_string(&art).unwrap_or_else(Pixel::new)
+        art.with(|art| Pixel::from_optimal_string(art)).unwrap_or_else(Pixel::new)
     });
     
     // Canvas element reference
@@ -59,34 +69,32 @@ pub fn PixelView(
             // clear Canvas
             context.clear_rect(0.0, 0.0, canvas_size, canvas_size);
             
-            // check if dark mode is enabled and set appropriate background color
-            let is_dark_mode = web_sys::window()
-                .and_then(|w| w.document())
-                .and_then(|d| d.document_element())
-                .map(|el| el.get_attribute("data-theme").map_or(false, |t| t == "dark"))
-                .unwrap_or(false);
-            
-            let bg_color = if is_dark_mode { "#d8d8d8" } else { "white" };
-            context.set_fill_style_str(bg_color);
-            context.fill_rect(0.0, 0.0, canvas_size, canvas_size);
+            // the background is left transparent so the canvas' CSS
+            // background (--pixel-canvas-bg) follows theme switches
             
-            // draw black pixels
+            // draw set pixels, in their palette color for palette images
             context.set_fill_style_str("black");
+            let mut fill_color = 1;
             for row in 0..rows {
                 for col in 0..cols {
-                    if pixel.get_pixel(row, col) {
-                        let x = col as f64 * pixel_size;
-                        let y = row as f64 * pixel_size;
-                        context.fill_rect(x, y, pixel_size, pixel_size);
+                    let color = pixel.get_color(row, col);
+                    if color == 0 {
+                        continue;
                     }
+                    if color != fill_color {
+                        context.set_fill_style_str(PALETTE[color as usize]);
+                        fill_color = color;
+                    }
+                    let x = col as f64 * pixel_size;
+                    let y = row as f64 * pixel_size;
+                    context.fill_rect(x, y, pixel_size, pixel_size);
                 }
             }
             
             // draw grid lines (if enabled and editable)
             if show_grid && editable {
-                // use darker grid color for better visibility on light gray background
-                let grid_color = if is_dark_mode { "#999" } else { "#ddd" };
-                context.set_stroke_style_str(grid_color);
+                // translucent so it stays visible on both themes' canvas backgrounds
+                context.set_stroke_style_str("rgba(0, 0, 0, 0.18)");
                 context.set_line_width(0.5);
                 
                 // vertical lines
@@ -150,7 +158,7 @@ pub fn PixelView(
         }
     };
     
-    view! {
+    let canvas = view! {
         <canvas
             node_ref=canvas_ref
             width=display_size
@@ -164,6 +172,275 @@ pub fn PixelView(
             )
             on:click=handle_canvas_click
         />
+    };
+    
+    match copyable_art {
+        Some(art_string) => view! {
+            <div class="pixel-view-copyable">
+                {canvas}
+                <PixelCopyButton art=art_string/>
+            </div>
+        }.into_view(),
+        None => canvas.into_view(),
+    }
+}
+
+/// Small hover button that copies an encoded `c:`/`n:` pixel string
+#[component]
+fn PixelCopyButton(art: String) -> impl IntoView {
+    let (show_copied, set_show_copied) = create_signal(false);
+    
+    let copy_string = move |ev: MouseEvent| {
+        // the image is often inside a clickable card
+        ev.prevent_default();
+        ev.stop_propagation();
+        
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().clipboard().write_text(&art);
+            set_show_copied.set(true);
+            
+            spawn_local(async move {
+                TimeoutFuture::new(1500).await;
+                set_show_copied.set(false);
+            });
+        }
+    };
+    
+    view! {
+        <button
+            type="button"
+            class="pixel-copy-btn"
+            class:copied=move || show_copied.get()
+            on:click=copy_string
+            title="Copy encoded string to clipboard"
+        >
+            <i class=move || if show_copied.get() { "fas fa-check" } else { "fas fa-copy" }></i>
+        </button>
+    }
+}
+
+/// Undo/redo buttons for a pixel editor, also bound to Ctrl+Z and Ctrl+Y
+/// (or Ctrl+Shift+Z) while the editor is on screen and no text field has
+/// focus. `history` belongs to the form owning the editor; `on_restore`
+/// puts an image from it back on screen.
+#[component]
+pub fn PixelHistoryControls(
+    history: RwSignal<PixelHistory>,
+    /// The image on screen
+    #[prop(into)] art: Signal<Pixel>,
+    on_restore: impl Fn(Pixel) + 'static + Copy,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    let controls_ref = create_node_ref::<Div>();
+    let undo = move || {
+        let current = art.get_untracked();
+        if let Some(previous) = history.try_update(|h| h.undo(current)).flatten() {
+            on_restore(previous);
+        }
+    };
+    let redo = move || {
+        let current = art.get_untracked();
+        if let Some(next) = history.try_update(|h| h.redo(current)).flatten() {
+            on_restore(next);
+        }
+    };
+
+    let shortcuts = window_event_listener(ev::keydown, move |ev| {
+        if !(ev.ctrl_key() || ev.meta_key()) || disabled.get_untracked() {
+            return;
+        }
+        // pages stay mounted while hidden, so only the visible editor reacts
+        let visible = controls_ref.get_untracked().is_some_and(|el| el.offset_parent().is_some());
+        let in_text_field = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT"));
+        if !visible || in_text_field {
+            return;
+        }
+        match ev.key().to_lowercase().as_str() {
+            "z" if ev.shift_key() => redo(),
+            "z" => undo(),
+            "y" => redo(),
+            _ => return,
+        }
+        ev.prevent_default();
+    });
+    on_cleanup(move || shortcuts.remove());
+
+    view! {
+        <div class="pixel-history-controls" node_ref=controls_ref>
+            <button
+                type="button"
+                class="pixel-history-btn"
+                title="Undo (Ctrl+Z)"
+                prop:disabled=move || disabled.get() || !history.with(|h| h.can_undo())
+                on:click=move |_| undo()
+            >
+                <i class="fas fa-undo"></i>
+            </button>
+            <button
+                type="button"
+                class="pixel-history-btn"
+                title="Redo (Ctrl+Y)"
+                prop:disabled=move || disabled.get() || !history.with(|h| h.can_redo())
+                on:click=move |_| redo()
+            >
+                <i class="fas fa-redo"></i>
+            </button>
+        </div>
+    }
+}
+
+/// Text field loading an encoded `c:`/`n:` string (as copied from an image)
+/// into a pixel editor. `sizes` are the square grids the editor offers;
+/// anything else is rejected with a message below the field.
+#[component]
+pub fn PixelStringImport(
+    sizes: Vec<usize>,
+    on_load: impl Fn(Pixel) + 'static + Copy,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    let (encoded, set_encoded) = create_signal(String::new());
+    let (error, set_error) = create_signal(String::new());
+    let sizes = store_value(sizes);
+
+    let load = move || match sizes.with_value(|sizes| Pixel::from_shared_string(&encoded.get_untracked(), sizes)) {
+        Ok(pixel) => {
+            on_load(pixel);
+            set_encoded.set(String::new());
+            set_error.set(String::new());
+        }
+        Err(e) => set_error.set(e),
+    };
+
+    view! {
+        <div class="pixel-string-import">
+            <div class="pixel-string-import-row">
+                <input
+                    type="text"
+                    class="pixel-string-input"
+                    placeholder="Paste an encoded image string (c:... or n:...)"
+                    prop:value=move || encoded.get()
+                    on:input=move |ev| {
+                        set_encoded.set(event_target_value(&ev));
+                        set_error.set(String::new());
+                    }
+                    on:keydown=move |ev| {
+                        // the editors sit inside forms; Enter loads instead of submitting
+                        if ev.key() == "Enter" {
+                            ev.prevent_default();
+                            load();
+                        }
+                    }
+                    prop:disabled=move || disabled.get()
+                />
+                <button
+                    type="button"
+                    class="pixel-string-load-btn"
+                    on:click=move |_| load()
+                    prop:disabled=move || disabled.get() || encoded.with(|s| s.trim().is_empty())
+                >
+                    "Load"
+                </button>
+            </div>
+            <Show when=move || !error.get().is_empty()>
+                <div class="pixel-string-error">{move || error.get()}</div>
+            </Show>
+        </div>
+    }
+}
+
+/// What clicking a cell of a pixel editor does
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PixelTool {
+    /// Paint the cell, or clear it if it already has the color
+    #[default]
+    Draw,
+    /// Clear the cell
+    Erase,
+    /// Paint the cell and the same-colored area around it
+    Fill,
+}
+
+impl PixelTool {
+    const ALL: [PixelTool; 3] = [PixelTool::Draw, PixelTool::Erase, PixelTool::Fill];
+
+    fn label(self) -> &'static str {
+        match self {
+            PixelTool::Draw => "Draw",
+            PixelTool::Erase => "Erase",
+            PixelTool::Fill => "Fill",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            PixelTool::Draw => "fas fa-pencil-alt",
+            PixelTool::Erase => "fas fa-eraser",
+            PixelTool::Fill => "fas fa-fill-drip",
+        }
+    }
+
+    /// Apply the tool to the cell at `row`, `col`, painting with `color`
+    pub fn apply(self, art: &mut Pixel, row: usize, col: usize, color: u8) {
+        match self {
+            PixelTool::Draw => art.toggle_color(row, col, color),
+            PixelTool::Erase => art.set_color(row, col, 0),
+            PixelTool::Fill => {
+                art.flood_fill_color(col, row, color);
+            }
+        }
+    }
+}
+
+/// Draw/erase/fill buttons choosing the `PixelTool` of an editor
+#[component]
+pub fn PixelToolSelector(
+    tool: RwSignal<PixelTool>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    view! {
+        <div class="pixel-tool-selector">
+            {PixelTool::ALL.into_iter().map(|option| view! {
+                <button
+                    type="button"
+                    class="pixel-tool-btn"
+                    class:selected=move || tool.get() == option
+                    title=option.label()
+                    on:click=move |_| tool.set(option)
+                    prop:disabled=move || disabled.get()
+                >
+                    <i class=option.icon()></i>
+                    " "
+                    {option.label()}
+                </button>
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+/// Swatches choosing the color a pixel editor paints with. Black is the
+/// default; picking any other color makes the image a palette image.
+#[component]
+pub fn PixelColorPicker(
+    color: RwSignal<u8>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    view! {
+        <div class="pixel-color-picker">
+            {(1..PALETTE_SIZE as u8).map(|index| view! {
+                <button
+                    type="button"
+                    class="pixel-color-swatch"
+                    class:selected=move || color.get() == index
+                    style:background-color=PALETTE[index as usize]
+                    title=PALETTE[index as usize]
+                    on:click=move |_| color.set(index)
+                    prop:disabled=move || disabled.get()
+                ></button>
+            }).collect::<Vec<_>>()}
+        </div>
     }
 }
 
@@ -191,11 +468,33 @@ fn request_animation_frame(f: impl FnOnce() + 'static) {
 pub fn LazyPixelView(
     art: String,
     size: u32,
+    #[prop(optional)] hide_copy: bool,
 ) -> impl IntoView {
     let (is_loaded, set_is_loaded) = create_signal(false);
     
-    // use signal to store art string, avoid moving issues
-    let (art_signal, _) = create_signal(art);
+    // animated art cycles its frames; anything else (including animations
+    // that don't decode) is drawn as a still image
+    let animation = PixelAnimation::from_encoded_string(&art)
+        .filter(|animation| animation.frames.len() > 1);
+    let art_signal: Signal<String> = match animation {
+        Some(animation) => {
+            let frames: Vec<String> = animation.frames.iter().map(Pixel::to_optimal_string).collect();
+            let frame_count = frames.len();
+            let frame = create_rw_signal(0usize);
+            spawn_local(async move {
+                loop {
+                    TimeoutFuture::new(animation.frame_ms).await;
+                    // stops once the view (and with it the signal) is gone
+                    if frame.try_update(|frame| *frame = (*frame + 1) % frame_count).is_none() {
+                        break;
+                    }
+                }
+            });
+            Signal::derive(move || frames[frame.get()].clone())
+        }
+        // use signal to store art string, avoid moving issues
+        None => create_signal(art).0.into(),
+    };
     
     // async decode, add delay to avoid blocking UI
     create_effect(move |_| {
@@ -211,10 +510,11 @@ pub fn LazyPixelView(
             if is_loaded.get() {
                 view! {
                     <PixelView
-                        art={art_signal.get()}
+                        art=art_signal
                         size=size
                         editable=false
                         show_grid=false
+                        hide_copy=hide_copy
                     />
                 }.into_view()
             } else {
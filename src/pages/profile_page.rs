@@ -2,7 +2,7 @@ use leptos::*;
 use crate::core::session::Session;
 use crate::core::rpc_profile::UserProfile;
 use crate::pages::pixel_view::{PixelView, LazyPixelView};
-use crate::core::pixel::Pixel;
+use crate::core::pixel::{Pixel, PixelAnimation, DEFAULT_FRAME_MS, FRAME_MS_RANGE, MAX_ANIMATION_FRAMES};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::JsCast;
 use web_sys::{HtmlInputElement, FileReader, Event, ProgressEvent};
@@ -30,22 +30,41 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
     let username = create_rw_signal(String::new());
     let about_me = create_rw_signal(String::new());
     let pixel_art = create_rw_signal(Pixel::new_with_size(32)); // fixed size 32x32
+    // Animation frames of the image; `pixel_art` is the frame being edited
+    // and is written back to `frames` when switching frames
+    let frames = create_rw_signal(vec![Pixel::new_with_size(32)]);
+    let current_frame = create_rw_signal(0usize);
+    let frame_ms = create_rw_signal(DEFAULT_FRAME_MS);
     let burn_amount = create_rw_signal(420u64); // Default minimum burn amount
     
     // Original values for change detection
     let original_username = create_rw_signal(String::new());
     let original_about_me = create_rw_signal(String::new());
-    let original_pixel_art = create_rw_signal(Pixel::new_with_size(32)); // fixed size 32x32
+    let original_image = create_rw_signal(Pixel::new_with_size(32).to_optimal_string());
     
     // Pixel art editor state - remove grid_size and current_pixel_size, because fixed size 32x32
     let show_copied = create_rw_signal(false);
     
+    // Encoded image: a still image, or an `a:` animation with several frames
+    let image_string = create_memo(move |_| {
+        let mut all_frames = frames.get();
+        // checked: frames and the index change in separate steps
+        if let Some(frame) = all_frames.get_mut(current_frame.get()) {
+            *frame = pixel_art.get();
+        }
+        PixelAnimation { frames: all_frames, frame_ms: frame_ms.get() }.to_encoded_string()
+    });
+    let reset_image = move |animation: PixelAnimation| {
+        pixel_art.set(animation.frames[0].clone());
+        current_frame.set(0);
+        frame_ms.set(animation.frame_ms);
+        frames.set(animation.frames);
+    };
+    
     // Change detection signals
     let username_changed = create_memo(move |_| username.get() != original_username.get());
     let about_me_changed = create_memo(move |_| about_me.get() != original_about_me.get());
-    let pixel_art_changed = create_memo(move |_| {
-        pixel_art.get().to_optimal_string() != original_pixel_art.get().to_optimal_string()
-    });
+    let pixel_art_changed = create_memo(move |_| image_string.get() != original_image.get());
     let has_changes = create_memo(move |_| {
         username_changed.get() || about_me_changed.get() || pixel_art_changed.get()
     });
@@ -149,7 +168,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
 
     // Handle copy pixel art string
     let copy_string = move |_| {
-        let art_string = pixel_art.get().to_optimal_string();
+        let art_string = image_string.get();
         
         if let Some(window) = web_sys::window() {
             // Fix: navigator().clipboard() returns Clipboard directly, not Option<Clipboard>
@@ -188,7 +207,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
         success_message.set(None);
         
         let username_val = username.get();
-        let image_val = pixel_art.get().to_optimal_string(); // Use pixel art string
+        let image_val = image_string.get(); // Use pixel art string
         let about_val = if about_me.get().is_empty() { None } else { Some(about_me.get()) };
         let burn_val = burn_amount.get(); // remove unit conversion, keep as tokens amount
         
@@ -208,7 +227,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
         }
         
         if image_val.len() > 256 {
-            error_message.set(Some("Pixel art string too long (max 256 characters)".to_string()));
+            error_message.set(Some(image_too_long_message(frames.get().len())));
             loading.set(false);
             clear_messages();
             return;
@@ -239,7 +258,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                 
                 // clear form
                 username.set(String::new());
-                pixel_art.set(Pixel::new_with_size(32));
+                reset_image(PixelAnimation { frames: vec![Pixel::new_with_size(32)], frame_ms: DEFAULT_FRAME_MS });
                 about_me.set(String::new());
                 
                 // start countdown
@@ -346,7 +365,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
         
         // Always send complete profile data, not just changes
         let username_val = Some(username.get());
-        let image_val = Some(pixel_art.get().to_optimal_string());
+        let image_val = Some(image_string.get());
         let about_val = if about_me.get().is_empty() { None } else { Some(about_me.get()) };
         let burn_val = burn_amount.get();
         
@@ -358,8 +377,8 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
             return;
         }
         
-        if pixel_art.get().to_optimal_string().len() > 256 {
-            error_message.set(Some("Pixel art string too long (max 256 characters)".to_string()));
+        if image_string.get().len() > 256 {
+            error_message.set(Some(image_too_long_message(frames.get().len())));
             loading.set(false);
             clear_messages();
             return;
@@ -525,17 +544,22 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
             // Set current values
             username.set(current_profile.username.clone());
             
-            // Set pixel art - always use 32x32
-            if let Some(parsed_pixel) = Pixel::from_optimal_string(&current_profile.image) {
-                log::info!("Successfully parsed pixel art from string");
-                pixel_art.set(parsed_pixel.clone());
-                original_pixel_art.set(parsed_pixel);
-            } else {
-                log::warn!("Failed to parse pixel art from string, using empty 32x32: {}", current_profile.image);
-                // always create 32x32 pixel art
-                pixel_art.set(Pixel::new_with_size(32));
-                original_pixel_art.set(Pixel::new_with_size(32));
-            }
+            // Set pixel art (all frames of an animation) - always use 32x32
+            let animation = match PixelAnimation::from_encoded_string(&current_profile.image) {
+                Some(animation) => {
+                    log::info!("Successfully parsed pixel art from string");
+                    animation
+                }
+                None => {
+                    // a damaged animation still keeps its first frame
+                    log::warn!("Failed to parse pixel art from string, using first frame or empty 32x32: {}", current_profile.image);
+                    let frame = Pixel::from_optimal_string(&current_profile.image)
+                        .unwrap_or_else(|| Pixel::new_with_size(32));
+                    PixelAnimation { frames: vec![frame], frame_ms: DEFAULT_FRAME_MS }
+                }
+            };
+            original_image.set(animation.to_encoded_string());
+            reset_image(animation);
             
             // Set about me
             let about_text = current_profile.about_me.clone().unwrap_or_default();
@@ -608,7 +632,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                     // 1. user image (top center)
                                     <div class="profile-avatar-section">
                                         {if !user_profile.image.is_empty() {
-                                            if user_profile.image.starts_with("c:") || user_profile.image.starts_with("n:") {
+                                            if ["c:", "n:", "p:", "a:"].iter().any(|prefix| user_profile.image.starts_with(prefix)) {
                                                 view! {
                                                     <div class="profile-avatar">
                                                         <LazyPixelView
@@ -844,6 +868,14 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                             }
                                         }}
 
+                                        <AvatarFramesEditor
+                                            pixel_art=pixel_art
+                                            frames=frames
+                                            current_frame=current_frame
+                                            frame_ms=frame_ms
+                                            disabled=Signal::derive(move || loading.get())
+                                        />
+
                                         // Pixel art info
                                         <div class="pixel-string-info">
                                             <div class="string-display">
@@ -853,7 +885,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                                 </span>
                                                 <span class="value">
                                                     {move || {
-                                                        let art_string = pixel_art.get().to_optimal_string();
+                                                        let art_string = image_string.get();
                                                         if art_string.len() <= 20 {
                                                             art_string
                                                         } else {
@@ -884,7 +916,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                                     "Length: "
                                                 </span>
                                                 <span class="value">
-                                                    {move || format!("{} bytes", pixel_art.get().to_optimal_string().len())}
+                                                    {move || format!("{} bytes", image_string.get().len())}
                                                 </span>
                                             </div>
                                         </div>
@@ -1046,6 +1078,14 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                             }}
                                         </div>
 
+                                        <AvatarFramesEditor
+                                            pixel_art=pixel_art
+                                            frames=frames
+                                            current_frame=current_frame
+                                            frame_ms=frame_ms
+                                            disabled=Signal::derive(move || loading.get())
+                                        />
+
                                         // Pixel art info
                                         <div class="pixel-string-info">
                                             <div class="string-display">
@@ -1055,7 +1095,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                                 </span>
                                                 <span class="value">
                                                     {move || {
-                                                        let art_string = pixel_art.get().to_optimal_string();
+                                                        let art_string = image_string.get();
                                                         if art_string.len() <= 20 {
                                                             art_string
                                                         } else {
@@ -1086,7 +1126,7 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
                                                     "Length: "
                                                 </span>
                                                 <span class="value">
-                                                    {move || format!("{} bytes", pixel_art.get().to_optimal_string().len())}
+                                                    {move || format!("{} bytes", image_string.get().len())}
                                                 </span>
                                             </div>
                                         </div>
@@ -1311,4 +1351,108 @@ pub fn ProfilePage(session: RwSignal<Session>) -> impl IntoView {
             view! { <span></span> }.into_view()
         }}
     }
-} 
\ No newline at end of file
+}
+
+/// Error for a profile image over the 256 character limit
+fn image_too_long_message(frame_count: usize) -> String {
+    if frame_count > 1 {
+        "Pixel art string too long (max 256 characters) - try fewer or simpler frames".to_string()
+    } else {
+        "Pixel art string too long (max 256 characters)".to_string()
+    }
+}
+
+/// Frame list of an animated profile image: pick the frame to edit, add a
+/// copy of it, remove it, and set how long each frame shows. A single frame
+/// is a still image.
+#[component]
+fn AvatarFramesEditor(
+    /// The frame being edited
+    pixel_art: RwSignal<Pixel>,
+    frames: RwSignal<Vec<Pixel>>,
+    current_frame: RwSignal<usize>,
+    frame_ms: RwSignal<u32>,
+    #[prop(into)] disabled: Signal<bool>,
+) -> impl IntoView {
+    // write the edited frame back before switching away from it
+    let store_current = move || {
+        let art = pixel_art.get_untracked();
+        frames.update(|frames| frames[current_frame.get_untracked()] = art);
+    };
+    let select = move |index: usize| {
+        store_current();
+        current_frame.set(index);
+        pixel_art.set(frames.with_untracked(|frames| frames[index].clone()));
+    };
+    let add_frame = move |_| {
+        store_current();
+        let index = current_frame.get_untracked() + 1;
+        frames.update(|frames| frames.insert(index, pixel_art.get_untracked()));
+        select(index);
+    };
+    let add_frame_disabled = move || disabled.get() || frames.with(Vec::len) >= MAX_ANIMATION_FRAMES;
+    let remove_frame = move |_| {
+        let index = current_frame.get_untracked();
+        frames.update(|frames| {
+            frames.remove(index);
+        });
+        let index = index.min(frames.with_untracked(Vec::len) - 1);
+        current_frame.set(index);
+        pixel_art.set(frames.with_untracked(|frames| frames[index].clone()));
+    };
+
+    view! {
+        <div class="avatar-frames">
+            <div class="avatar-frames-list">
+                <span class="label">"Frames: "</span>
+                {move || (0..frames.with(Vec::len)).map(|index| view! {
+                    <button
+                        type="button"
+                        class="avatar-frame-btn"
+                        class:selected=move || current_frame.get() == index
+                        on:click=move |_| select(index)
+                        prop:disabled=move || disabled.get()
+                    >
+                        {index + 1}
+                    </button>
+                }).collect::<Vec<_>>()}
+                <button
+                    type="button"
+                    class="avatar-frame-btn"
+                    title="Add a copy of this frame"
+                    on:click=add_frame
+                    prop:disabled=add_frame_disabled
+                >
+                    <i class="fas fa-plus"></i>
+                </button>
+                <button
+                    type="button"
+                    class="avatar-frame-btn"
+                    title="Remove this frame"
+                    on:click=remove_frame
+                    prop:disabled=move || disabled.get() || frames.with(Vec::len) <= 1
+                >
+                    <i class="fas fa-trash"></i>
+                </button>
+            </div>
+            <Show when=move || { frames.with(Vec::len) > 1 }>
+                <label class="avatar-frame-duration">
+                    "Frame duration (ms): "
+                    <input
+                        type="number"
+                        min=*FRAME_MS_RANGE.start()
+                        max=*FRAME_MS_RANGE.end()
+                        step="50"
+                        prop:value=move || frame_ms.get().to_string()
+                        on:change=move |ev| {
+                            if let Ok(ms) = event_target_value(&ev).parse::<u32>() {
+                                frame_ms.set(ms.clamp(*FRAME_MS_RANGE.start(), *FRAME_MS_RANGE.end()));
+                            }
+                        }
+                        prop:disabled=move || disabled.get()
+                    />
+                </label>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,34 @@
+use leptos::*;
+use crate::core::history::LoadProgress;
+
+/// Blocking overlay for long paged operations (e.g. exporting a group's
+/// history): shows how much is loaded and lets the user stop. Renders
+/// nothing while `progress` is None.
+#[component]
+pub fn ProgressOverlay(
+    title: &'static str,
+    progress: ReadSignal<Option<LoadProgress>>,
+    on_cancel: impl Fn() + 'static + Copy,
+) -> impl IntoView {
+    move || progress.get().map(|progress| {
+        let width = progress.fraction().map(|fraction| format!("width: {:.0}%", fraction * 100.0));
+        view! {
+            <div class="modal-overlay progress-overlay">
+                <div class="modal-content progress-overlay-content">
+                    <h3>
+                        <i class="fas fa-spinner fa-spin"></i>
+                        " "{title}
+                    </h3>
+                    <div class="progress-overlay-bar" class:indeterminate=width.is_none()>
+                        <div class="progress-overlay-fill" style=width></div>
+                    </div>
+                    <p class="progress-overlay-label">{progress.label()}</p>
+                    <button class="progress-overlay-cancel" on:click=move |_| on_cancel()>
+                        <i class="fas fa-times"></i>
+                        " Cancel"
+                    </button>
+                </div>
+            </div>
+        }
+    })
+}
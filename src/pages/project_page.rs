@@ -1,19 +1,45 @@
 use leptos::*;
-use crate::core::session::Session;
+use crate::core::session::{Session, SessionError};
 use crate::core::rpc_project::{
     ProjectCreationData, ProjectBurnMessage, ProjectContractTransaction,
-    ProjectOperationDetails,
+    ProjectOperationDetails, ProjectInfo, ProjectLeaderboardEntry,
 };
-use crate::core::rpc_base::RpcConnection;
+use crate::core::pagination::clamp_page;
+use crate::core::rpc_base::{RpcConnection, SimulationReport, validate_burn_tokens};
+use crate::core::format::format_memo;
+use crate::core::constants::LAMPORTS_PER_TOKEN;
 use wasm_bindgen_futures::spawn_local;
 use gloo_timers::future::TimeoutFuture;
 use web_sys::{HtmlInputElement, FileReader, Event, ProgressEvent, window};
 use wasm_bindgen::{closure::Closure, JsCast};
 use js_sys::Uint8Array;
-use wasm_bindgen::JsValue;
 use std::rc::Rc;
+use std::collections::HashSet;
+use futures::stream::{self, StreamExt};
 use crate::pages::pixel_view::{PixelView, LazyPixelView};
+use crate::pages::user_text::UserText;
+use crate::pages::view_mode_toggle::ViewModeToggle;
+use crate::pages::id_jump_box::IdJumpBox;
+use crate::pages::creation_notice::{CreationNotice, CreationStatus};
+use crate::pages::rank_projection::RankProjectionHint;
+use crate::pages::raw_payload_view::RawPayloadView;
+use crate::pages::form_keys::advance_on_enter;
+use crate::pages::global_refresh::GlobalRefresh;
+use crate::pages::pubkey_display::PubkeyDisplay;
+use crate::pages::relative_time::{format_timestamp, RelativeTime};
+use crate::pages::burn_network::{is_mainnet, BurnNetworkBadge, MainnetBurnGuard};
+use crate::pages::simulation_report::SimulationResult;
+use crate::pages::memo_size::memo_size_status;
+use crate::core::cache;
+use crate::core::network_config::explorer_tx_url;
+use crate::core::leaderboard;
+use crate::core::tx_error::classify_tx_error;
+use crate::core::visited;
+use crate::core::text::{contains_ignore_case, has_visible_text, safe_truncate, sanitize_display_text, shorten_middle};
+use crate::core::preferences::{ListViewMode, ProjectSortKey, UiPreferences};
 use crate::core::pixel::Pixel;
+use crate::core::markdown_export::{devlog_markdown, DevlogMarkdown};
+use crate::core::devlog::{merge_devlog_edits, DevlogPost, ParsedDevlog};
 
 /// Devlog message status for UI display
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -23,105 +49,46 @@ enum DevlogStatus {
     Failed,
 }
 
-/// Parsed devlog data from JSON message
-#[derive(Debug, Clone, PartialEq)]
-struct ParsedDevlog {
-    title: String,
-    content: String,
-    image: String,
-}
-
-impl ParsedDevlog {
-    /// Parse devlog from JSON message string
-    fn from_message(message: &str) -> Option<Self> {
-        // Try to parse as JSON devlog format: {"type":"devlog","title":"...","content":"...","image":"..."}
-        if !message.contains("\"type\":\"devlog\"") {
-            return None;
-        }
-        
-        // Simple JSON parsing (avoiding external dependency)
-        let title = Self::extract_json_field(message, "title").unwrap_or_default();
-        let content = Self::extract_json_field(message, "content").unwrap_or_default();
-        let image = Self::extract_json_field(message, "image").unwrap_or_default();
-        
-        Some(Self { title, content, image })
-    }
-    
-    /// Extract a field value from JSON string
-    fn extract_json_field(json: &str, field: &str) -> Option<String> {
-        let pattern = format!("\"{}\":\"", field);
-        let start = json.find(&pattern)? + pattern.len();
-        let remaining = &json[start..];
-        
-        // Find the closing quote, handling escaped quotes
-        // Use byte offset instead of char index to avoid UTF-8 boundary issues
-        let mut end_byte = 0;
-        let mut escaped = false;
-        for c in remaining.chars() {
-            if escaped {
-                escaped = false;
-                end_byte += c.len_utf8();
-                continue;
-            }
-            if c == '\\' {
-                escaped = true;
-                end_byte += c.len_utf8();
-                continue;
-            }
-            if c == '"' {
-                break;
-            }
-            end_byte += c.len_utf8();
-        }
-        
-        let value = &remaining[..end_byte];
-        // Unescape the string
-        Some(value.replace("\\\"", "\"").replace("\\\\", "\\"))
-    }
-}
-
 /// Local devlog message for immediate UI display
 #[derive(Debug, Clone, PartialEq)]
 struct LocalDevlogMessage {
-    message: ProjectBurnMessage,
-    parsed: Option<ParsedDevlog>,
+    post: DevlogPost,
     status: DevlogStatus,
     is_local: bool, // true if this is a local message not yet confirmed on chain
+    error: Option<String>, // user-facing reason of the last failed post
 }
 
 impl LocalDevlogMessage {
     /// Create a new local devlog for immediate UI display
     fn new_local(burner: String, title: String, content: String, image: String, burn_amount: u64) -> Self {
-        let message_json = format!(
-            r#"{{"type":"devlog","title":"{}","content":"{}","image":"{}"}}"#,
-            title.replace('\\', "\\\\").replace('"', "\\\""),
-            content.replace('\\', "\\\\").replace('"', "\\\""),
-            image.replace('\\', "\\\\").replace('"', "\\\"")
-        );
+        let message_json = DevlogData::new(title.clone(), content.clone(), image.clone()).to_json();
         
         Self {
-            message: ProjectBurnMessage {
-                signature: format!("local_devlog_{}", js_sys::Date::now() as u64),
-                burner,
-                message: message_json.clone(),
-                timestamp: (js_sys::Date::now() / 1000.0) as i64,
-                slot: 0,
-                burn_amount: burn_amount * 1_000_000, // Convert to lamports
+            post: DevlogPost {
+                message: ProjectBurnMessage {
+                    signature: format!("local_devlog_{}", js_sys::Date::now() as u64),
+                    burner,
+                    message: message_json,
+                    timestamp: (js_sys::Date::now() / 1000.0) as i64,
+                    slot: 0,
+                    burn_amount: burn_amount.saturating_mul(LAMPORTS_PER_TOKEN), // Convert to lamports
+                },
+                parsed: ParsedDevlog { title, content, image, edited_of: None },
+                edited_at: None,
             },
-            parsed: Some(ParsedDevlog { title, content, image }),
             status: DevlogStatus::Sending,
             is_local: true,
+            error: None,
         }
     }
     
-    /// Create from chain message
-    fn from_chain_message(message: ProjectBurnMessage) -> Self {
-        let parsed = ParsedDevlog::from_message(&message.message);
+    /// Create from a devlog loaded from chain
+    fn from_chain_post(post: DevlogPost) -> Self {
         Self {
-            message,
-            parsed,
+            post,
             status: DevlogStatus::Sent,
             is_local: false,
+            error: None,
         }
     }
 }
@@ -138,6 +105,102 @@ struct ProjectRow {
     last_memo_time: i64,
     rank: u8,
     creator: String, // Base58 encoded pubkey
+    /// False while only the leaderboard entry is known; the other fields
+    /// are filled in once the project's page is shown
+    details_loaded: bool,
+}
+
+impl ProjectRow {
+    /// A leaderboard entry whose project info hasn't been fetched yet
+    fn pending(entry: &ProjectLeaderboardEntry) -> Self {
+        Self {
+            project_id: entry.project_id,
+            name: String::new(),
+            description: String::new(),
+            image: String::new(),
+            website: String::new(),
+            burned_amount: entry.burned_amount,
+            last_memo_time: 0,
+            rank: entry.rank,
+            creator: String::new(),
+            details_loaded: false,
+        }
+    }
+
+    /// Fill in the fetched info, keeping the leaderboard's burn amount and rank
+    fn fill_details(&mut self, project_info: ProjectInfo) {
+        self.name = project_info.name;
+        self.description = project_info.description;
+        self.image = project_info.image;
+        self.website = project_info.website;
+        self.last_memo_time = project_info.last_memo_time;
+        self.creator = project_info.creator;
+        self.details_loaded = true;
+    }
+}
+
+/// Leaderboard rows per page; project info is fetched one page at a time
+const PROJECTS_PER_PAGE: usize = 20;
+
+/// Order rows by a table column. Ties keep their burn-rank order, and
+/// unranked rows (rank 0) come after the ranked ones when sorting by rank.
+fn sort_projects(rows: &mut [ProjectRow], key: ProjectSortKey, descending: bool) {
+    rows.sort_by(|a, b| {
+        let order = match key {
+            ProjectSortKey::Rank => {
+                let rank = |row: &ProjectRow| if row.rank == 0 { u16::MAX } else { row.rank as u16 };
+                rank(a).cmp(&rank(b))
+            }
+            ProjectSortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ProjectSortKey::Burned => a.burned_amount.cmp(&b.burned_amount),
+            ProjectSortKey::LastMemo => a.last_memo_time.cmp(&b.last_memo_time),
+        };
+        if descending { order.reverse() } else { order }
+    });
+}
+
+/// How often and how many times to look for a newly created project
+const PROJECT_CREATION_POLL_INTERVAL_MS: u32 = 2_000;
+const PROJECT_CREATION_POLL_ATTEMPTS: u32 = 15;
+
+/// Poll until the new project can be read, or give up and return false
+async fn wait_for_project_created(project_id: u64) -> bool {
+    let rpc = RpcConnection::new();
+    for _ in 0..PROJECT_CREATION_POLL_ATTEMPTS {
+        TimeoutFuture::new(PROJECT_CREATION_POLL_INTERVAL_MS).await;
+        match rpc.get_project_info(project_id).await {
+            Ok(_) => return true,
+            Err(e) => log::info!("Project {} not readable yet: {}", project_id, e),
+        }
+    }
+    false
+}
+
+/// Projects listed when the burn ranking can't be loaded
+const FALLBACK_PROJECT_COUNT: u64 = 20;
+
+/// The newest projects, newest first and unranked (rank 0), for when the
+/// burn ranking is unavailable
+async fn load_latest_projects(session: &Session) -> Result<Vec<ProjectRow>, SessionError> {
+    let total = session.get_total_projects().await?;
+    if total == 0 {
+        return Ok(vec![]);
+    }
+    let rpc = RpcConnection::new();
+    let projects = rpc.get_projects_range(total.saturating_sub(FALLBACK_PROJECT_COUNT), total).await
+        .map_err(|e| SessionError::InvalidData(format!("Get projects failed: {}", e)))?;
+    Ok(projects.into_iter().rev().map(|project_info| ProjectRow {
+        project_id: project_info.project_id,
+        name: project_info.name,
+        description: project_info.description,
+        image: project_info.image,
+        website: project_info.website,
+        burned_amount: project_info.burned_amount,
+        last_memo_time: project_info.last_memo_time,
+        rank: 0,
+        creator: project_info.creator,
+        details_loaded: true,
+    }).collect())
 }
 
 /// Page view state
@@ -155,15 +218,32 @@ pub fn ProjectPage(
     let (projects, set_projects) = create_signal::<Vec<ProjectRow>>(vec![]);
     let (loading, set_loading) = create_signal(true);
     let (error_message, set_error_message) = create_signal::<Option<String>>(None);
+    // set while the list shows the newest projects because the ranking failed to load
+    let (ranking_warning, set_ranking_warning) = create_signal::<Option<String>>(None);
     
     // Page navigation state
     let (current_view, set_current_view) = create_signal(PageView::Leaderboard);
+    let (view_mode, set_view_mode) = create_signal(UiPreferences::load().projects_view_mode);
+    let (visited_ids, set_visited_ids) = create_signal(visited::load_visited_projects());
+    // Show only the projects the connected wallet created (over the loaded rows)
+    let (mine_only, set_mine_only) = create_signal(false);
+    let my_pubkey = create_memo(move |_| session.with(|s| s.get_public_key().ok()));
+    // Table sort (remembered) and text filter over the loaded rows
+    let (sort, set_sort) = create_signal({
+        let preferences = UiPreferences::load();
+        (preferences.project_sort, preferences.project_sort_descending)
+    });
+    let (project_filter, set_project_filter) = create_signal(String::new());
+    // Leaderboard page, and the projects whose info was requested or failed
+    let (current_page, set_current_page) = create_signal(1_usize);
+    let details_requested = store_value(HashSet::<u64>::new());
+    let (failed_details, set_failed_details) = create_signal(HashSet::<u64>::new());
     
     // Create Project Dialog states
     let (show_create_dialog, set_show_create_dialog) = create_signal(false);
     
-    // Countdown state
-    let (countdown_seconds, set_countdown_seconds) = create_signal::<Option<i32>>(None);
+    // Newly created project, while waiting for it to be readable on chain
+    let (project_creation, set_project_creation) = create_signal(Option::<CreationStatus>::None);
     
     // Featured transactions state
     let (featured_transactions, set_featured_transactions) = create_signal::<Vec<ProjectContractTransaction>>(vec![]);
@@ -217,6 +297,8 @@ pub fn ProjectPage(
         async move {
             set_loading.set(true);
             set_error_message.set(None);
+            details_requested.set_value(HashSet::new());
+            set_failed_details.set(HashSet::new());
             
             let session_read = session_clone.get_untracked();
             
@@ -224,44 +306,46 @@ pub fn ProjectPage(
                 Ok(leaderboard) => {
                     log::info!("Fetched burn leaderboard with {} projects", leaderboard.entries.len());
                     
-                    let mut project_rows = Vec::new();
+                    // Only the ranking for now: info is fetched per page as it is shown
+                    let mut project_rows: Vec<ProjectRow> = leaderboard.entries.iter().map(ProjectRow::pending).collect();
                     
-                    // Fetch detailed info for each project in leaderboard
-                    for entry in leaderboard.entries {
-                        match session_read.get_project_info(entry.project_id).await {
-                            Ok(project_info) => {
-                                project_rows.push(ProjectRow {
-                                    project_id: entry.project_id,
-                                    name: project_info.name,
-                                    description: project_info.description,
-                                    image: project_info.image,
-                                    website: project_info.website,
-                                    burned_amount: entry.burned_amount,
-                                    last_memo_time: project_info.last_memo_time,
-                                    rank: entry.rank,
-                                    creator: project_info.creator,
-                                });
-                            },
-                            Err(e) => {
-                                log::warn!("Failed to fetch project {} info: {}", entry.project_id, e);
-                            }
+                    if UiPreferences::load().keep_leaderboard_order {
+                        project_rows.sort_by_key(|project| project.rank);
+                    } else {
+                        // Sort by burned_amount in descending order (highest burn first),
+                        // ties by project id, and reassign ranks based on actual burn amounts
+                        project_rows.sort_by(|a, b| {
+                            b.burned_amount
+                                .cmp(&a.burned_amount)
+                                .then(a.project_id.cmp(&b.project_id))
+                        });
+                        
+                        // Reassign ranks based on sorted order
+                        for (index, project) in project_rows.iter_mut().enumerate() {
+                            project.rank = (index + 1) as u8;
                         }
                     }
                     
-                    // Sort by burned_amount in descending order (highest burn first)
-                    // and reassign ranks based on actual burn amounts
-                    project_rows.sort_by(|a, b| b.burned_amount.cmp(&a.burned_amount));
-                    
-                    // Reassign ranks based on sorted order
-                    for (index, project) in project_rows.iter_mut().enumerate() {
-                        project.rank = (index + 1) as u8;
-                    }
-                    
                     set_projects.set(project_rows);
+                    set_ranking_warning.set(None);
                 },
                 Err(e) => {
                     log::error!("Failed to fetch project burn leaderboard: {}", e);
-                    set_error_message.set(Some(format!("Failed to load projects: {}", e)));
+                    // The project accounts may still be readable: list the newest ones unranked
+                    match load_latest_projects(&session_read).await {
+                        Ok(project_rows) => {
+                            log::warn!("Project ranking unavailable, showing the {} newest projects", project_rows.len());
+                            set_projects.set(project_rows);
+                            set_ranking_warning.set(Some(format!(
+                                "The burn ranking is unavailable ({}). Showing the newest projects instead.", e
+                            )));
+                        }
+                        Err(fallback_error) => {
+                            log::error!("Failed to fetch latest projects: {}", fallback_error);
+                            set_ranking_warning.set(None);
+                            set_error_message.set(Some(format!("Failed to load projects: {}", e)));
+                        }
+                    }
                 }
             }
             
@@ -273,6 +357,98 @@ pub fn ProjectPage(
     create_effect(move |_| {
         load_projects_data.dispatch(());
     });
+    GlobalRefresh::from_context().register(Some("project"), move || load_projects_data.dispatch(()), loading.into());
+
+    // Filtering by creator or text and sorting by name or time need every
+    // project's info, not just the shown page's
+    let needs_all_details = move || {
+        mine_only.get()
+            || !project_filter.with(|query| query.trim().is_empty())
+            || matches!(sort.get().0, ProjectSortKey::Name | ProjectSortKey::LastMemo)
+    };
+    // Rows after the creator and text filters, in table order
+    let listed_projects = create_memo(move |_| {
+        let mut project_list = projects.get();
+        if mine_only.get() {
+            let me = my_pubkey.get().unwrap_or_default();
+            project_list.retain(|project| project.creator == me);
+        }
+        let query = project_filter.get();
+        let query = query.trim();
+        if !query.is_empty() {
+            project_list.retain(|project| {
+                contains_ignore_case(&project.name, query)
+                    || contains_ignore_case(&project.description, query)
+                    || contains_ignore_case(&project.website, query)
+            });
+        }
+        let (sort_key, descending) = sort.get();
+        sort_projects(&mut project_list, sort_key, descending);
+        project_list
+    });
+    let page_count = move || listed_projects.with(|rows| rows.len().div_ceil(PROJECTS_PER_PAGE)).max(1);
+    // The current page, kept valid when the list shrinks
+    let shown_page = move || clamp_page(current_page.get(), listed_projects.with(Vec::len), PROJECTS_PER_PAGE);
+    let show_pagination = move || !loading.get() && error_message.get().is_none() && page_count() > 1;
+    let on_last_page = move || shown_page() >= page_count();
+
+    // Fetch the info of the shown page's projects (or of all of them when a
+    // filter needs it); fetched rows keep their info, so paging back is free
+    create_effect(move |_| {
+        if loading.get() {
+            return;
+        }
+        let wanted: Vec<u64> = if needs_all_details() {
+            projects.with(|rows| rows.iter().filter(|row| !row.details_loaded).map(|row| row.project_id).collect())
+        } else {
+            let start = (shown_page() - 1) * PROJECTS_PER_PAGE;
+            listed_projects.with(|rows| {
+                rows.iter().skip(start).take(PROJECTS_PER_PAGE)
+                    .filter(|row| !row.details_loaded)
+                    .map(|row| row.project_id)
+                    .collect()
+            })
+        };
+        let wanted: Vec<u64> = details_requested.with_value(|requested| {
+            wanted.into_iter().filter(|id| !requested.contains(id)).collect()
+        });
+        if wanted.is_empty() {
+            return;
+        }
+        details_requested.update_value(|requested| requested.extend(wanted.iter().copied()));
+        spawn_local(async move {
+            // at most a page's worth of requests in flight; rows fill in as
+            // their info arrives
+            let rpc = RpcConnection::new();
+            let mut results = stream::iter(wanted)
+                .map(|project_id| {
+                    let rpc = rpc.clone();
+                    async move { (project_id, cache::get_project_info(&rpc, project_id).await) }
+                })
+                .buffer_unordered(PROJECTS_PER_PAGE);
+            while let Some((project_id, result)) = results.next().await {
+                let updated = match result {
+                    Ok(project_info) => set_projects.try_update(|rows| {
+                        if let Some(row) = rows.iter_mut().find(|row| row.project_id == project_id) {
+                            row.fill_details(project_info);
+                        }
+                    }),
+                    Err(e) => {
+                        log::warn!("Failed to fetch project {} info: {}", project_id, e);
+                        // fetched again the next time its row is wanted
+                        details_requested.try_update_value(|requested| requested.remove(&project_id));
+                        set_failed_details.try_update(|ids| {
+                            ids.insert(project_id);
+                        })
+                    }
+                };
+                if updated.is_none() {
+                    // the page is gone
+                    return;
+                }
+            }
+        });
+    });
 
     // Function to open create project dialog
     let open_create_dialog = move |_| {
@@ -286,9 +462,76 @@ pub fn ProjectPage(
 
     // Function to view project details
     let view_project_details = move |project: ProjectRow| {
+        match visited::mark_project_visited(project.project_id) {
+            Ok(ids) => set_visited_ids.set(ids),
+            Err(e) => log::warn!("Failed to remember visited project: {}", e),
+        }
         set_current_view.set(PageView::ProjectDetails(project));
     };
 
+    // Open a project by id without paging; rank 0 means not on the leaderboard
+    let (project_jump_pending, set_project_jump_pending) = create_signal(false);
+    let (project_jump_error, set_project_jump_error) = create_signal::<Option<String>>(None);
+    let jump_to_project = move |project_id: u64| {
+        let known = projects.with_untracked(|rows| rows.iter().find(|p| p.project_id == project_id).cloned());
+        if let Some(project) = known.as_ref().filter(|p| p.details_loaded) {
+            view_project_details(project.clone());
+            return;
+        }
+        let rank = known.map_or(0, |p| p.rank);
+        set_project_jump_pending.set(true);
+        set_project_jump_error.set(None);
+        spawn_local(async move {
+            let rpc = RpcConnection::new();
+            match cache::get_project_info(&rpc, project_id).await {
+                Ok(project_info) => view_project_details(ProjectRow {
+                    project_id: project_info.project_id,
+                    name: project_info.name,
+                    description: project_info.description,
+                    image: project_info.image,
+                    website: project_info.website,
+                    burned_amount: project_info.burned_amount,
+                    last_memo_time: project_info.last_memo_time,
+                    rank,
+                    creator: project_info.creator,
+                    details_loaded: true,
+                }),
+                Err(e) => set_project_jump_error.set(Some(e.to_string())),
+            }
+            set_project_jump_pending.set(false);
+        });
+    };
+
+    // Switch between card grid and table, remembering the choice
+    let change_view_mode = move |mode: ListViewMode| {
+        set_view_mode.set(mode);
+        if let Err(e) = UiPreferences::update(|p| p.projects_view_mode = mode) {
+            log::error!("Failed to save projects view mode: {}", e);
+        }
+    };
+
+    // Sort by `key`, flipping the direction when it is already the sort column
+    let sort_by = move |key: ProjectSortKey| {
+        let (current, descending) = sort.get_untracked();
+        let next = if current == key { (key, !descending) } else { (key, key.default_descending()) };
+        set_sort.set(next);
+        set_current_page.set(1);
+        if let Err(e) = UiPreferences::update(|p| (p.project_sort, p.project_sort_descending) = next) {
+            log::error!("Failed to save project sort: {}", e);
+        }
+    };
+    // Header cell that sorts by its column, with an arrow on the active one
+    let sort_header = move |label: &'static str, key: ProjectSortKey| view! {
+        <th class="sortable" class:sorted=move || sort.get().0 == key on:click=move |_| sort_by(key)>
+            {label}
+            <i class=move || match sort.get() {
+                (current, _) if current != key => "fas fa-sort",
+                (_, true) => "fas fa-sort-down",
+                (_, false) => "fas fa-sort-up",
+            }></i>
+        </th>
+    };
+
     // Function to go back to leaderboard
     let back_to_leaderboard = move || {
         set_current_view.set(PageView::Leaderboard);
@@ -298,27 +541,31 @@ pub fn ProjectPage(
     let on_project_created = move |signature: String, project_id: u64| {
         log::info!("Project created successfully! ID: {}, Signature: {}", project_id, signature);
         set_show_create_dialog.set(false);
+        set_project_creation.set(Some(CreationStatus::Waiting(project_id)));
         
-        // start 20 seconds countdown
-        set_countdown_seconds.set(Some(20));
-        spawn_local(async move {
-            for i in (1..=20).rev() {
-                TimeoutFuture::new(1000).await;
-                set_countdown_seconds.set(Some(i - 1));
-            }
-            set_countdown_seconds.set(None);
-        });
-        
-        // Wait 20 seconds before refreshing to allow blockchain to update
+        // Wait until the new project can be read, then refresh the list
         spawn_local(async move {
-            log::info!("Waiting 20 seconds for blockchain to update...");
-            TimeoutFuture::new(20_000).await; // Wait 20 seconds
+            let confirmed = wait_for_project_created(project_id).await;
             
             log::info!("Refreshing project list after project creation...");
             load_projects_data.dispatch(());
+            
+            // a newer creation replaces this notice
+            if project_creation.get_untracked() == Some(CreationStatus::Waiting(project_id)) {
+                set_project_creation.set(Some(if confirmed {
+                    CreationStatus::Ready(project_id)
+                } else {
+                    CreationStatus::NotVisible(project_id)
+                }));
+            }
         });
     };
 
+    let open_created_project = move |project_id: u64| {
+        set_project_creation.set(None);
+        jump_to_project(project_id);
+    };
+
     // Function to handle project creation error
     let on_project_creation_error = move |error: String| {
         log::error!("Project creation failed: {}", error);
@@ -341,6 +588,26 @@ pub fn ProjectPage(
                                             <p class="project-subtitle">"Top 100 Projects on X1 Blockchain"</p>
                                         </div>
                                         <div class="header-actions">
+                                            <IdJumpBox
+                                                placeholder="Project ID"
+                                                pending=project_jump_pending
+                                                error=project_jump_error
+                                                on_jump=jump_to_project
+                                            />
+                                            <ViewModeToggle view_mode=view_mode on_change=change_view_mode/>
+                                            <button
+                                                class="my-projects-toggle"
+                                                class:active=move || mine_only.get()
+                                                on:click=move |_| {
+                                                    set_mine_only.update(|mine| *mine = !*mine);
+                                                    set_current_page.set(1);
+                                                }
+                                                disabled=move || my_pubkey.get().is_none()
+                                                title="Show only projects you created"
+                                            >
+                                                <i class="fas fa-user"></i>
+                                                "My Projects"
+                                            </button>
                                             <button 
                                                 class="new-project-button"
                                                 on:click=open_create_dialog
@@ -363,17 +630,25 @@ pub fn ProjectPage(
                                     </div>
                                 </div>
                                 
-                                // countdown banner display
-                                <Show when=move || countdown_seconds.get().is_some()>
-                                    <div class="countdown-banner">
-                                        <div class="countdown-content">
-                                            <i class="fas fa-clock"></i>
-                                            <span>
-                                                "Project created successfully! Leaderboard will refresh in "
-                                                <strong>{move || countdown_seconds.get().unwrap_or(0).to_string()}</strong>
-                                                " seconds..."
-                                            </span>
-                                        </div>
+                                // Progress of a newly created project, with a link once it is confirmed
+                                <CreationNotice
+                                    kind="project"
+                                    status=project_creation
+                                    on_open=open_created_project
+                                    on_dismiss=move || set_project_creation.set(None)
+                                />
+                                
+                                <Show when=move || ranking_warning.get().is_some() && !loading.get()>
+                                    <div class="partial-failure-notice">
+                                        <i class="fas fa-exclamation-circle"></i>
+                                        <span>{move || ranking_warning.get().unwrap_or_default()}</span>
+                                        <button
+                                            class="partial-failure-retry"
+                                            on:click=move |_| load_projects_data.dispatch(())
+                                        >
+                                            <i class="fas fa-redo"></i>
+                                            "Retry"
+                                        </button>
                                     </div>
                                 </Show>
                                 
@@ -387,6 +662,20 @@ pub fn ProjectPage(
                                     />
                                 </Show>
                                 
+                                <div class="project-filter">
+                                    <i class="fas fa-filter"></i>
+                                    <input
+                                        type="search"
+                                        class="project-filter-input"
+                                        placeholder="Filter by name, description or website"
+                                        prop:value=move || project_filter.get()
+                                        on:input=move |ev| {
+                                            set_project_filter.set(event_target_value(&ev));
+                                            set_current_page.set(1);
+                                        }
+                                    />
+                                </div>
+                                
                                 <div class="project-content">
                                     {move || {
                                         if loading.get() {
@@ -402,32 +691,84 @@ pub fn ProjectPage(
                                                 </div>
                                             }.into_view()
                                         } else {
-                                            let project_list = projects.get();
-                                            if project_list.is_empty() {
+                                            let filter_mine = mine_only.get();
+                                            let query = project_filter.get();
+                                            let query = query.trim();
+                                            let project_list = listed_projects.get();
+                                            let failed = failed_details.get();
+                                            // filtered rows may still be missing among the unfetched ones
+                                            let details_pending = needs_all_details() && projects.with(|rows| {
+                                                rows.iter().any(|row| !row.details_loaded && !failed.contains(&row.project_id))
+                                            });
+                                            let page = shown_page();
+                                            let project_list: Vec<ProjectRow> = project_list.into_iter()
+                                                .skip((page - 1) * PROJECTS_PER_PAGE)
+                                                .take(PROJECTS_PER_PAGE)
+                                                .collect();
+                                            let pending_label = move |project_id: u64| if failed.contains(&project_id) {
+                                                "Info unavailable"
+                                            } else {
+                                                "Loading..."
+                                            };
+                                            if project_list.is_empty() && details_pending {
+                                                view! {
+                                                    <div class="loading-state">
+                                                        <p>"Loading project details..."</p>
+                                                    </div>
+                                                }.into_view()
+                                            } else if project_list.is_empty() && filter_mine {
+                                                view! {
+                                                    <div class="empty-state my-projects-empty">
+                                                        <p>"You haven't created any of the loaded projects yet."</p>
+                                                        <button class="new-project-button" on:click=open_create_dialog>
+                                                            <i class="fas fa-plus"></i>
+                                                            "Create your first project"
+                                                        </button>
+                                                    </div>
+                                                }.into_view()
+                                            } else if project_list.is_empty() && !query.is_empty() {
+                                                view! {
+                                                    <div class="empty-state">
+                                                        <p>"No loaded projects match the filter."</p>
+                                                    </div>
+                                                }.into_view()
+                                            } else if project_list.is_empty() {
                                                 view! {
                                                     <div class="empty-state">
                                                         <p>"No projects found in burn leaderboard."</p>
                                                     </div>
                                                 }.into_view()
+                                            } else if view_mode.get() == ListViewMode::Grid {
+                                                view! {
+                                                    <div class="project-grid">
+                                                        {project_list.into_iter().map(|project| {
+                                                            let status = pending_label(project.project_id);
+                                                            view! {
+                                                                <ProjectGridCard project=project status=status visited_ids=visited_ids on_details=view_project_details/>
+                                                            }
+                                                        }).collect::<Vec<_>>()}
+                                                    </div>
+                                                }.into_view()
                                             } else {
                                                 view! {
                                                     <div class="project-table-container">
                                                         <table class="project-table">
                                                             <thead>
                                                                 <tr>
-                                                                    <th>"Rank"</th>
+                                                                    {sort_header("Rank", ProjectSortKey::Rank)}
                                                                     <th>"ID"</th>
                                                                     <th>"Logo"</th>
-                                                                    <th>"Name"</th>
+                                                                    {sort_header("Name", ProjectSortKey::Name)}
                                                                     <th>"Website"</th>
                                                                     <th>"Description"</th>
-                                                                    <th>"Burned (MEMO)"</th>
+                                                                    {sort_header("Burned (MEMO)", ProjectSortKey::Burned)}
+                                                                    {sort_header("Last Memo", ProjectSortKey::LastMemo)}
                                                                     <th>"Details"</th>
                                                                 </tr>
                                                             </thead>
                                                             <tbody>
                                                                 {project_list.into_iter().map(|project| {
-                                                                    let burned_tokens = project.burned_amount / 1_000_000;
+                                                                    let burned_display = format_memo(project.burned_amount);
                                                                     let website_display = if project.website.is_empty() {
                                                                         "-".to_string()
                                                                     } else {
@@ -435,9 +776,16 @@ pub fn ProjectPage(
                                                                     };
                                                                     let description_display = truncate_description(&project.description);
                                                                     let project_clone = project.clone();
+                                                                    let project_id = project.project_id;
+                                                                    let details_loaded = project.details_loaded;
+                                                                    let name_display = if details_loaded {
+                                                                        project.name.clone()
+                                                                    } else {
+                                                                        pending_label(project_id).to_string()
+                                                                    };
                                                                     
                                                                     view! {
-                                                                        <tr class="project-row">
+                                                                        <tr class="project-row" class:visited=move || visited_ids.get().contains(&project_id) class:details-pending=!details_loaded>
                                                                             <td class="rank-cell">
                                                                                 {
                                                                                     let rank_num = project.rank;
@@ -469,6 +817,8 @@ pub fn ProjectPage(
                                                                                                 <span class="rank-number">{rank_num.to_string()}</span>
                                                                                             </span>
                                                                                         }.into_view()
+                                                                                    } else if rank_num == 0 {
+                                                                                        view! { <span class="rank-icon rank-others">"-"</span> }.into_view()
                                                                                     } else {
                                                                                         view! {
                                                                                             <span class="rank-icon rank-others">
@@ -507,7 +857,7 @@ pub fn ProjectPage(
                                                                                 }}
                                                                             </td>
                                                                             <td class="name-cell">
-                                                                                <span class="project-name">{project.name}</span>
+                                                                                <span class="project-name">{name_display}</span>
                                                                             </td>
                                                                             <td class="website-cell">
                                                                                 {if !project.website.is_empty() {
@@ -532,12 +882,22 @@ pub fn ProjectPage(
                                                                             </td>
                                                                             <td class="burned-cell">
                                                                                 <i class="fas fa-fire burned-fire-icon"></i>
-                                                                                <span class="burned-number">{format_number_with_commas(burned_tokens)}</span>
+                                                                                <span class="burned-number">{burned_display}</span>
+                                                                            </td>
+                                                                            <td class="last-memo-cell">
+                                                                                {if project.last_memo_time > 0 {
+                                                                                    view! {
+                                                                                        <RelativeTime timestamp=project.last_memo_time absolute=format_timestamp(project.last_memo_time)/>
+                                                                                    }.into_view()
+                                                                                } else {
+                                                                                    "-".into_view()
+                                                                                }}
                                                                             </td>
                                                                             <td class="actions-cell">
                                                                                 <button 
                                                                                     class="details-button"
                                                                                     on:click=move |_| view_project_details(project_clone.clone())
+                                                                                    disabled=!details_loaded
                                                                                     title="View project details"
                                                                                 >
                                                                                     <i class="fas fa-info-circle"></i>
@@ -555,6 +915,30 @@ pub fn ProjectPage(
                                         }
                                     }}
                                 </div>
+                                
+                                <Show when=show_pagination>
+                                    <div class="pagination-bottom project-pagination">
+                                        <button
+                                            class="pagination-btn"
+                                            disabled=move || shown_page() <= 1
+                                            on:click=move |_| set_current_page.set(shown_page() - 1)
+                                        >
+                                            <i class="fas fa-chevron-left"></i>
+                                            "Previous"
+                                        </button>
+                                        <span class="pagination-info">
+                                            {move || format!("Page {} of {}", shown_page(), page_count())}
+                                        </span>
+                                        <button
+                                            class="pagination-btn"
+                                            disabled=on_last_page
+                                            on:click=move |_| set_current_page.set(shown_page() + 1)
+                                        >
+                                            "Next"
+                                            <i class="fas fa-chevron-right"></i>
+                                        </button>
+                                    </div>
+                                </Show>
                             </div>
                         }.into_view()
                     },
@@ -564,6 +948,7 @@ pub fn ProjectPage(
                                 project=project
                                 on_back=Rc::new(back_to_leaderboard)
                                 session=session
+                                projects=projects
                             />
                         }.into_view()
                     }
@@ -587,11 +972,7 @@ pub fn ProjectPage(
 
 /// Shorten address for display (e.g., "ABC123...XYZ9")
 fn shorten_address(addr: &str) -> String {
-    if addr.len() > 12 {
-        format!("{}...{}", &addr[..6], &addr[addr.len()-4..])
-    } else {
-        addr.to_string()
-    }
+    shorten_middle(addr, 6, 4)
 }
 
 /// Project Details View component - displays project information in a clean card layout
@@ -600,8 +981,11 @@ fn ProjectDetailsView(
     project: ProjectRow,
     on_back: Rc<dyn Fn()>,
     session: RwSignal<Session>,
+    projects: ReadSignal<Vec<ProjectRow>>,
 ) -> impl IntoView {
     let on_back_signal = create_rw_signal(Some(on_back));
+    // read once per opened project; toggling it in settings applies on next open
+    let developer_mode = UiPreferences::load().developer_mode;
     
     // Store project data as reactive signal for updates
     let project_data = create_rw_signal(project.clone());
@@ -620,22 +1004,13 @@ fn ProjectDetailsView(
     // Reactive computed values based on project_data
     let burned_display = move || {
         let proj = current_project();
-        let burned_tokens = proj.burned_amount / 1_000_000;
-        format_number_with_commas(burned_tokens)
+        format_memo(proj.burned_amount)
     };
     
     let last_memo_display = move || {
         let proj = current_project();
         if proj.last_memo_time > 0 {
-            let date = web_sys::js_sys::Date::new(&JsValue::from_f64(proj.last_memo_time as f64 * 1000.0));
-            format!(
-                "{}-{:02}-{:02} {:02}:{:02}",
-                date.get_full_year(),
-                date.get_month() + 1,
-                date.get_date(),
-                date.get_hours(),
-                date.get_minutes()
-            )
+            format_timestamp(proj.last_memo_time)
         } else {
             "Never".to_string()
         }
@@ -662,16 +1037,6 @@ fn ProjectDetailsView(
         })
     };
     
-    let is_creator_for_hint = move || {
-        let proj = current_project();
-        session.with(|s| {
-            match s.get_public_key() {
-                Ok(pubkey) => pubkey == proj.creator,
-                Err(_) => false,
-            }
-        })
-    };
-    
     // Create a reactive memo for is_creator check (used in devlog list)
     let is_creator_memo = create_memo(move |_| {
         let proj = current_project();
@@ -695,6 +1060,8 @@ fn ProjectDetailsView(
     
     // Devlog dialog state
     let (show_devlog_dialog, set_show_devlog_dialog) = create_signal(false);
+    // Devlog being edited in the dialog, None for a new one
+    let (editing_devlog, set_editing_devlog) = create_signal(None::<LocalDevlogMessage>);
     
     // Devlog list state
     let (devlogs, set_devlogs) = create_signal::<Vec<LocalDevlogMessage>>(vec![]);
@@ -719,10 +1086,13 @@ fn ProjectDetailsView(
                 match rpc.get_project_burn_messages(project_id, 50, None).await {
                     Ok(response) => {
                         // Filter only devlog messages and convert to LocalDevlogMessage
-                        let devlog_messages: Vec<LocalDevlogMessage> = response.messages
+                        let devlog_posts: Vec<DevlogPost> = response.messages
                             .into_iter()
-                            .filter(|msg| msg.message.contains("\"type\":\"devlog\""))
-                            .map(LocalDevlogMessage::from_chain_message)
+                            .filter_map(DevlogPost::from_message)
+                            .collect();
+                        let devlog_messages: Vec<LocalDevlogMessage> = merge_devlog_edits(devlog_posts)
+                            .into_iter()
+                            .map(LocalDevlogMessage::from_chain_post)
                             .collect();
                         
                         log::info!("Loaded {} devlogs for project {}", devlog_messages.len(), project_id);
@@ -830,6 +1200,7 @@ fn ProjectDetailsView(
                         last_memo_time: project_info.last_memo_time,
                         rank: original_rank,
                         creator: project_info.creator,
+                        details_loaded: true,
                     };
                     
                     // Update project data - this will trigger all UI updates
@@ -849,18 +1220,27 @@ fn ProjectDetailsView(
 
     // Open devlog dialog
     let open_devlog_dialog = move |_| {
+        set_editing_devlog.set(None);
         set_show_devlog_dialog.set(true);
     };
+
+    // Open devlog dialog pre-filled to edit one of the devlogs
+    let edit_devlog = Callback::new(move |devlog: LocalDevlogMessage| {
+        set_editing_devlog.set(Some(devlog));
+        set_show_devlog_dialog.set(true);
+    });
     
     // Close devlog dialog
     let close_devlog_dialog = move || {
         set_show_devlog_dialog.set(false);
+        set_editing_devlog.set(None);
     };
     
     // Handle devlog success
     let on_devlog_success = move |_signature: String| {
         log::info!("Devlog posted successfully!");
         set_show_devlog_dialog.set(false);
+        set_editing_devlog.set(None);
     };
 
     view! {
@@ -941,8 +1321,11 @@ fn ProjectDetailsView(
                                 {move || {
                                     let proj = current_project();
                                     view! {
-                                        <span class={format!("rank-badge rank-{}", if proj.rank <= 3 { proj.rank.to_string() } else if proj.rank <= 10 { "top10".to_string() } else { "other".to_string() })}>
-                                            {if proj.rank == 1 {
+                                        <span class={format!("rank-badge rank-{}", if proj.rank == 0 { "other".to_string() } else if proj.rank <= 3 { proj.rank.to_string() } else if proj.rank <= 10 { "top10".to_string() } else { "other".to_string() })}>
+                                            {if proj.rank == 0 {
+                                                // opened by id and not on the leaderboard
+                                                view! { <><i class="fas fa-fire"></i> " Unranked"</> }.into_view()
+                                            } else if proj.rank == 1 {
                                                 view! { <><i class="fas fa-trophy"></i> " #1"</> }.into_view()
                                             } else if proj.rank <= 3 {
                                                 view! { <><i class="fas fa-medal"></i> {format!(" #{}", proj.rank)}</> }.into_view()
@@ -1068,7 +1451,11 @@ fn ProjectDetailsView(
                                 "Created by"
                             </span>
                             <div class="creator-info">
-                                <span class="pd-creator-name">{move || creator_display.get()}</span>
+                                <PubkeyDisplay
+                                    class="pd-creator-name"
+                                    address=project.creator.clone()
+                                    label=creator_display
+                                />
                                 // Show address hint if we have a username
                                 {move || {
                                     let proj = current_project();
@@ -1151,7 +1538,8 @@ fn ProjectDetailsView(
                                 view! {
                                     <For
                                         each=move || devlogs.get()
-                                        key=|devlog| devlog.message.signature.clone()
+                                        // an edit keeps the signature, so re-render on it too
+                                        key=|devlog| (devlog.post.message.signature.clone(), devlog.post.edited_at)
                                         children=move |devlog| {
                                             view! {
                                                 <DevlogCard 
@@ -1159,6 +1547,9 @@ fn ProjectDetailsView(
                                                     session=session
                                                     devlogs=set_devlogs
                                                     project_id=project_id_for_devlogs
+                                                    project_name=Signal::derive(move || project_data.with(|p| p.name.clone()))
+                                                    developer_mode=developer_mode
+                                                    on_edit=edit_devlog
                                                 />
                                             }
                                         }
@@ -1188,7 +1579,9 @@ fn ProjectDetailsView(
                     <DevlogForm
                         session=session
                         project=project_data
+                        projects=projects
                         devlogs=set_devlogs
+                        editing=editing_devlog.get_untracked()
                         on_close=Rc::new(close_devlog_dialog)
                         on_success=Rc::new(on_devlog_success)
                     />
@@ -1205,20 +1598,28 @@ fn DevlogCard(
     session: RwSignal<Session>,
     devlogs: WriteSignal<Vec<LocalDevlogMessage>>,
     project_id: u64,
+    project_name: Signal<String>,
+    developer_mode: bool,
+    /// Open the devlog form to edit this devlog
+    on_edit: Callback<LocalDevlogMessage>,
 ) -> impl IntoView {
+    // Only the burner can post an edit that replaces it
+    let can_edit = !devlog.is_local
+        && session.with_untracked(|s| s.get_public_key().is_ok_and(|pubkey| pubkey == devlog.post.message.burner));
+    let edited_at = devlog.post.edited_at;
+    let devlog_for_edit = devlog.clone();
     let status = devlog.status;
     let is_local = devlog.is_local;
-    let signature = devlog.message.signature.clone();
-    let burner = devlog.message.burner.clone();
-    let timestamp = devlog.message.timestamp;
-    let burn_amount = devlog.message.burn_amount;
-    let message_raw = devlog.message.message.clone();
+    let signature = devlog.post.message.signature.clone();
+    let explorer_url = explorer_tx_url(&signature);
+    let burner = devlog.post.message.burner.clone();
+    let timestamp = devlog.post.message.timestamp;
+    let burn_amount = devlog.post.message.burn_amount;
+    let message_raw = devlog.post.message.message.clone();
+    let error = devlog.error.clone();
     
     // Get parsed devlog data
-    let parsed = devlog.parsed.clone();
-    let title = parsed.as_ref().map(|p| p.title.clone()).unwrap_or_else(|| "Untitled".to_string());
-    let content = parsed.as_ref().map(|p| p.content.clone()).unwrap_or_default();
-    let image = parsed.as_ref().map(|p| p.image.clone()).unwrap_or_default();
+    let ParsedDevlog { title, content, image, .. } = devlog.post.parsed.clone();
     
     // Clone for retry
     let title_for_retry = title.clone();
@@ -1228,21 +1629,32 @@ fn DevlogCard(
     
     // Format timestamp
     let time_display = if timestamp > 0 {
-        let date = web_sys::js_sys::Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0));
-        format!(
-            "{}-{:02}-{:02} {:02}:{:02}",
-            date.get_full_year(),
-            date.get_month() + 1,
-            date.get_date(),
-            date.get_hours(),
-            date.get_minutes()
-        )
+        format_timestamp(timestamp)
     } else {
         "Just now".to_string()
     };
     
     // Format burn amount
-    let burn_display = format!("{}", burn_amount / 1_000_000);
+    let burn_display = format_memo(burn_amount);
+
+    // Copy the devlog as Markdown for sharing elsewhere
+    let (copied, set_copied) = create_signal(false);
+    let markdown = devlog_markdown(&DevlogMarkdown {
+        project_name: &project_name.get_untracked(),
+        title: &title,
+        content: &content,
+        image: &image,
+        author: &shorten_address(&burner),
+        date: &time_display,
+        burned_memo: &burn_display,
+    });
+    let copy_markdown = move |_| {
+        if let Some(window) = window() {
+            let _ = window.navigator().clipboard().write_text(&markdown);
+            set_copied.set(true);
+            set_timeout(move || set_copied.set(false), std::time::Duration::from_secs(2));
+        }
+    };
     
     // Handle retry
     let handle_retry = move |_| {
@@ -1254,8 +1666,9 @@ fn DevlogCard(
         
         // Update status to Sending
         devlogs.update(|logs| {
-            if let Some(devlog) = logs.iter_mut().find(|d| d.message.signature == sig) {
+            if let Some(devlog) = logs.iter_mut().find(|d| d.post.message.signature == sig) {
                 devlog.status = DevlogStatus::Sending;
+                devlog.error = None;
             }
         });
         
@@ -1273,9 +1686,9 @@ fn DevlogCard(
             match result {
                 Ok(new_signature) => {
                     devlogs.update(|logs| {
-                        if let Some(devlog) = logs.iter_mut().find(|d| d.message.signature == sig) {
+                        if let Some(devlog) = logs.iter_mut().find(|d| d.post.message.signature == sig) {
                             devlog.status = DevlogStatus::Sent;
-                            devlog.message.signature = new_signature;
+                            devlog.post.message.signature = new_signature;
                         }
                     });
                     
@@ -1283,10 +1696,13 @@ fn DevlogCard(
                         s.mark_balance_update_needed();
                     });
                 },
-                Err(_) => {
+                Err(e) => {
+                    log::error!("Failed to retry devlog: {}", e);
+                    let reason = classify_tx_error(&e.to_string()).devlog_message();
                     devlogs.update(|logs| {
-                        if let Some(devlog) = logs.iter_mut().find(|d| d.message.signature == sig) {
+                        if let Some(devlog) = logs.iter_mut().find(|d| d.post.message.signature == sig) {
                             devlog.status = DevlogStatus::Failed;
+                            devlog.error = Some(reason.to_string());
                         }
                     });
                 }
@@ -1306,12 +1722,51 @@ fn DevlogCard(
                 <div class="devlog-meta">
                     <span class="devlog-time">
                         <i class="fas fa-clock"></i>
-                        {time_display}
+                        // relative ("3h ago"), with the date as tooltip
+                        {if timestamp > 0 {
+                            view! { <RelativeTime timestamp=timestamp absolute=time_display/> }.into_view()
+                        } else {
+                            time_display.into_view()
+                        }}
                     </span>
                     <span class="devlog-burn">
                         <i class="fas fa-fire"></i>
                         {burn_display}" MEMO"
                     </span>
+                    {edited_at.map(|edited_at| view! {
+                        <span class="devlog-edited" title=format!("Edited {}", format_timestamp(edited_at))>
+                            <i class="fas fa-pen"></i>
+                            " edited"
+                        </span>
+                    })}
+                    {can_edit.then(|| view! {
+                        <button
+                            class="devlog-edit-btn"
+                            on:click=move |_| on_edit.call(devlog_for_edit.clone())
+                            title="Edit this devlog (posts a new version)"
+                        >
+                            <i class="fas fa-edit"></i>
+                            " Edit"
+                        </button>
+                    })}
+                    {(!is_local).then(|| view! {
+                        <button class="devlog-copy-btn" on:click=copy_markdown title="Copy as Markdown">
+                            <i class=move || if copied.get() { "fas fa-check" } else { "fab fa-markdown" }></i>
+                            {move || if copied.get() { " Copied" } else { " Copy" }}
+                        </button>
+                    })}
+                    {explorer_url.map(|url| view! {
+                        <a
+                            class="devlog-explorer-link"
+                            href=url
+                            target="_blank"
+                            rel="noopener noreferrer"
+                            title="View transaction in the explorer"
+                        >
+                            <i class="fas fa-external-link-alt"></i>
+                            " Explorer"
+                        </a>
+                    })}
                 </div>
             </div>
             
@@ -1344,7 +1799,7 @@ fn DevlogCard(
                 <div class="devlog-content-section">
                     {if !content.is_empty() {
                         view! {
-                            <p class="devlog-content">{content}</p>
+                            <p class="devlog-content user-text"><UserText text=content markdown=true/></p>
                         }.into_view()
                     } else {
                         view! {
@@ -1367,6 +1822,9 @@ fn DevlogCard(
                     <div class="devlog-status failed">
                         <i class="fas fa-exclamation-circle"></i>
                         " Failed to send"
+                        {error.clone().map(|reason| view! {
+                            <span class="devlog-failure-reason">{reason}</span>
+                        })}
                         <button 
                             class="retry-btn"
                             on:click=handle_retry
@@ -1379,6 +1837,10 @@ fn DevlogCard(
             } else {
                 view! { <div></div> }.into_view()
             }}
+
+            {(developer_mode && !is_local).then(|| view! {
+                <RawPayloadView signature=signature.clone() payload=message_raw.clone()/>
+            })}
         </div>
     }
 }
@@ -1389,23 +1851,44 @@ struct DevlogData {
     title: String,
     content: String,
     image: String,
+    /// Signature of the devlog this edit replaces
+    edited_of: Option<String>,
 }
 
 impl DevlogData {
     fn new(title: String, content: String, image: String) -> Self {
-        Self { title, content, image }
+        Self { title, content, image, edited_of: None }
+    }
+
+    /// Make this an edit replacing the devlog with `original_signature`
+    fn editing(mut self, original_signature: Option<String>) -> Self {
+        self.edited_of = original_signature;
+        self
     }
     
     /// Convert to JSON string for storage in message field
     fn to_json(&self) -> String {
+        let edited_of = self.edited_of
+            .as_ref()
+            .map(|sig| format!(r#","edited_of":"{}""#, sig))
+            .unwrap_or_default();
         format!(
-            r#"{{"type":"devlog","title":"{}","content":"{}","image":"{}"}}"#,
+            r#"{{"type":"devlog","title":"{}","content":"{}","image":"{}"{}}}"#,
             self.title.replace('\\', "\\\\").replace('"', "\\\""),
             self.content.replace('\\', "\\\\").replace('"', "\\\""),
-            self.image.replace('\\', "\\\\").replace('"', "\\\"")
+            self.image.replace('\\', "\\\\").replace('"', "\\\""),
+            edited_of
         )
     }
     
+    /// Bytes `to_json` adds by escaping quotes and backslashes
+    fn escape_overhead(&self) -> usize {
+        [&self.title, &self.content, &self.image]
+            .iter()
+            .map(|text| text.bytes().filter(|b| matches!(b, b'"' | b'\\')).count())
+            .sum()
+    }
+    
     /// Calculate final memo size (Borsh + Base64) for devlog
     fn calculate_final_memo_size(&self, project_id: u64, burner: &str, burn_amount: u64) -> Result<usize, String> {
         use crate::core::rpc_project::{ProjectBurnData, BurnMemo};
@@ -1448,28 +1931,54 @@ impl DevlogData {
 fn DevlogForm(
     session: RwSignal<Session>,
     project: RwSignal<ProjectRow>,
+    projects: ReadSignal<Vec<ProjectRow>>,
     devlogs: WriteSignal<Vec<LocalDevlogMessage>>,
+    /// Devlog to edit: the form starts from its text and posts a new
+    /// version replacing it
+    #[prop(optional_no_strip)] editing: Option<LocalDevlogMessage>,
     on_close: Rc<dyn Fn()>,
     on_success: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     
     // Get project data
     let original_project = project.get_untracked();
     let project_id = original_project.project_id;
+
+    let editing_parsed = editing.as_ref().map(|devlog| devlog.post.parsed.clone());
+    let editing_signature = store_value(editing.map(|devlog| devlog.post.message.signature));
+    let is_edit = editing_signature.with_value(Option::is_some);
+    let initial_art = editing_parsed
+        .as_ref()
+        .and_then(|parsed| Pixel::from_optimal_string(&parsed.image))
+        .unwrap_or_else(|| Pixel::new_with_size(16));
     
     // Form state signals
-    let (devlog_title, set_devlog_title) = create_signal(String::new());
-    let (devlog_content, set_devlog_content) = create_signal(String::new());
+    let (devlog_title, set_devlog_title) = create_signal(editing_parsed.as_ref().map(|p| p.title.clone()).unwrap_or_default());
+    let (devlog_content, set_devlog_content) = create_signal(editing_parsed.map(|p| p.content).unwrap_or_default());
     let (burn_amount, set_burn_amount) = create_signal(420u64); // Minimum 420 tokens for burn_for_project
-    let (pixel_art, set_pixel_art) = create_signal(Pixel::new_with_size(16));
-    let (grid_size, set_grid_size) = create_signal(16usize);
+    // Estimated leaderboard rank of the project if the entered burn lands
+    let rank_projection = Signal::derive(move || {
+        let proj = project.get();
+        projects.with(|rows| {
+            if rows.is_empty() {
+                return None;
+            }
+            let entries: Vec<(u64, u64)> = rows.iter().map(|row| (row.project_id, row.burned_amount)).collect();
+            Some(leaderboard::project_rank(&entries, proj.project_id, proj.burned_amount, burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN)))
+        })
+    });
+    let (grid_size, set_grid_size) = create_signal(initial_art.dimensions().0);
+    let (pixel_art, set_pixel_art) = create_signal(initial_art);
     
     // UI state signals
     let (is_posting, set_is_posting) = create_signal(false);
     let (error_message, set_error_message) = create_signal(String::new());
     let (show_copied, set_show_copied) = create_signal(false);
+    let (is_simulating, set_is_simulating) = create_signal(false);
+    let (simulation, set_simulation) = create_signal(None::<SimulationReport>);
     
     // Get current image data
     let get_image_data = move || -> String {
@@ -1486,35 +1995,28 @@ fn DevlogForm(
         let title = devlog_title.get().trim().to_string();
         let content = devlog_content.get().trim().to_string();
         let image_data = get_image_data();
-        let amount = burn_amount.get() * 1_000_000; // lamports
+        let amount = burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN); // lamports
         let burner = get_burner_pubkey();
 
-        let devlog_data = DevlogData::new(title, content, image_data);
+        let devlog_data = DevlogData::new(title, content, image_data).editing(editing_signature.get_value());
 
         match devlog_data.calculate_final_memo_size(project_id, &burner, amount) {
             Ok(size) => {
-                let is_valid = size >= 69 && size <= 800;
-                let status = if is_valid {
-                    "✅ Valid".to_string()
-                } else if size < 69 {
-                    "❌ Too short".to_string()
-                } else {
-                    "❌ Too long".to_string()
-                };
+                let (is_valid, status) = memo_size_status(size, grid_size.get());
                 (size, is_valid, status)
             },
             Err(e) => (0, false, format!("❌ Error: {}", e)),
         }
     };
 
-    // Handle form submission
-    let handle_submit = move |ev: leptos::leptos_dom::ev::SubmitEvent| {
-        ev.prevent_default();
-
-        if is_posting.get() {
-            return;
-        }
+    // Bytes the JSON escaping of quotes and backslashes adds to the message
+    let escape_overhead = move || {
+        DevlogData::new(devlog_title.get().trim().to_string(), devlog_content.get().trim().to_string(), get_image_data())
+            .escape_overhead()
+    };
 
+    // Validate the form, returning (title, content, image, amount)
+    let validate_form = move || -> Result<(String, String, String, u64), String> {
         let title = devlog_title.get().trim().to_string();
         let content = devlog_content.get().trim().to_string();
         let image = get_image_data();
@@ -1522,34 +2024,91 @@ fn DevlogForm(
 
         // Validation
         if title.is_empty() || title.len() > 64 {
-            set_error_message.set(format!("❌ Devlog title must be 1-64 characters, got {}", title.len()));
-            return;
+            return Err(format!("❌ Devlog title must be 1-64 characters, got {}", title.len()));
         }
         if content.len() > 500 {
-            set_error_message.set(format!("❌ Devlog content must be at most 500 characters, got {}", content.len()));
-            return;
+            return Err(format!("❌ Devlog content must be at most 500 characters, got {}", content.len()));
         }
         if amount < 420 {
-            set_error_message.set("❌ Burn amount must be at least 420 MEMO tokens".to_string());
-            return;
+            return Err("❌ Burn amount must be at least 420 MEMO tokens".to_string());
         }
 
         // Check memo size
         let (memo_size, is_valid, _) = calculate_memo_size();
         if !is_valid {
-            set_error_message.set(format!("❌ Memo size ({} bytes) must be between 69-800 bytes", memo_size));
+            return Err(format!("❌ Memo size ({} bytes) must be between 69-800 bytes", memo_size));
+        }
+
+        Ok((title, content, image, amount))
+    };
+
+    // Handle form submission
+    let handle_submit = move |ev: leptos::leptos_dom::ev::SubmitEvent| {
+        ev.prevent_default();
+
+        if is_posting.get() {
             return;
         }
 
-        // Check balance
+        let (title, content, image, amount) = match validate_form() {
+            Ok(fields) => fields,
+            Err(e) => {
+                set_error_message.set(e);
+                return;
+            }
+        };
+
+        // Check maximum and balance
         let token_balance = session.with_untracked(|s| s.get_token_balance());
-        if token_balance < amount as f64 {
-            set_error_message.set(format!("❌ Insufficient balance. Required: {} MEMO, Available: {:.2} MEMO", amount, token_balance));
+        if let Err(e) = validate_burn_tokens(amount, token_balance) {
+            set_error_message.set(format!("❌ {}", e));
             return;
         }
 
         set_is_posting.set(true);
         set_error_message.set(String::new());
+        set_simulation.set(None);
+
+        // An edit updates the original's card once it lands, instead of
+        // adding a card of its own
+        if let Some(original_signature) = editing_signature.get_value() {
+            let devlog_data = DevlogData::new(title, content, image).editing(Some(original_signature.clone()));
+            let message = devlog_data.to_json();
+            spawn_local(async move {
+                let result = session.get_untracked().burn_tokens_for_project(project_id, amount, &message).await;
+                set_is_posting.set(false);
+                match result {
+                    Ok(signature) => {
+                        let edit = DevlogPost::from_message(ProjectBurnMessage {
+                            signature: signature.clone(),
+                            burner: session.with_untracked(|s| s.get_public_key().unwrap_or_default()),
+                            message,
+                            timestamp: (js_sys::Date::now() / 1000.0) as i64,
+                            slot: 0,
+                            burn_amount: amount.saturating_mul(LAMPORTS_PER_TOKEN),
+                        });
+                        devlogs.update(|logs| {
+                            let original = logs.iter_mut().find(|d| d.post.message.signature == original_signature);
+                            if let (Some(original), Some(edit)) = (original, edit.as_ref()) {
+                                original.post.apply_edit(edit);
+                            }
+                        });
+                        session.update(|s| s.mark_balance_update_needed());
+                        on_success_signal.with_untracked(|cb_opt| {
+                            if let Some(callback) = cb_opt.as_ref() {
+                                callback(signature);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::error!("Failed to post devlog edit: {}", e);
+                        let reason = classify_tx_error(&e.to_string()).devlog_message();
+                        set_error_message.set(format!("❌ {}", reason));
+                    }
+                }
+            });
+            return;
+        }
 
         // Get user pubkey for local message
         let user_pubkey = session.with_untracked(|s| s.get_public_key().unwrap_or_default());
@@ -1562,7 +2121,7 @@ fn DevlogForm(
             image.clone(),
             amount,
         );
-        let local_signature = local_devlog.message.signature.clone();
+        let local_signature = local_devlog.post.message.signature.clone();
         
         // Add to devlogs list immediately (at the beginning)
         devlogs.update(|logs| {
@@ -1596,10 +2155,10 @@ fn DevlogForm(
                     devlogs.update(|logs| {
                         if let Some(devlog) = logs.iter_mut().find(|d| {
                             d.is_local && 
-                            d.message.signature == local_signature
+                            d.post.message.signature == local_signature
                         }) {
                             devlog.status = DevlogStatus::Sent;
-                            devlog.message.signature = signature.clone();
+                            devlog.post.message.signature = signature.clone();
                         }
                     });
                     
@@ -1619,22 +2178,58 @@ fn DevlogForm(
                     });
                 },
                 Err(e) => {
+                    log::error!("Failed to post devlog: {}", e);
+                    let reason = classify_tx_error(&e.to_string()).devlog_message();
+
                     // 4. Update local devlog status to Failed
                     devlogs.update(|logs| {
                         if let Some(devlog) = logs.iter_mut().find(|d| {
                             d.is_local && 
-                            d.message.signature == local_signature
+                            d.post.message.signature == local_signature
                         }) {
                             devlog.status = DevlogStatus::Failed;
+                            devlog.error = Some(reason.to_string());
                         }
                     });
                     
-                    set_error_message.set(format!("❌ Failed to post devlog: {}", e));
+                    set_error_message.set(format!("❌ {}", reason));
                 }
             }
         });
     };
 
+    // Dry-run the devlog burn: no signing, no fee. The balance isn't checked
+    // here so the simulation can report a shortfall itself.
+    let handle_simulate = move |_| {
+        if is_posting.get_untracked() || is_simulating.get_untracked() {
+            return;
+        }
+        let (title, content, image, amount) = match validate_form() {
+            Ok(fields) => fields,
+            Err(e) => {
+                set_error_message.set(e);
+                return;
+            }
+        };
+        let message = DevlogData::new(title, content, image).editing(editing_signature.get_value()).to_json();
+
+        set_is_simulating.set(true);
+        set_error_message.set(String::new());
+        set_simulation.set(None);
+
+        spawn_local(async move {
+            let result = session.get_untracked()
+                .simulate_burn_tokens_for_project(project_id, amount, &message)
+                .await;
+
+            set_is_simulating.set(false);
+            match result {
+                Ok(report) => set_simulation.set(Some(report)),
+                Err(e) => set_error_message.set(format!("❌ Simulation failed: {}", e)),
+            }
+        });
+    };
+
     // Handle close
     let handle_close = move |_| {
         on_close_signal.with_untracked(|cb_opt| {
@@ -1720,7 +2315,7 @@ fn DevlogForm(
             <div class="form-header">
                 <h3 class="form-title">
                     <i class="fas fa-book-open"></i>
-                    "New Devlog"
+                    {if is_edit { "Edit Devlog" } else { "New Devlog" }}
                 </h3>
                 <button
                     type="button"
@@ -1732,7 +2327,10 @@ fn DevlogForm(
                 </button>
             </div>
             
-            <form class="project-form" on:submit=handle_submit>
+            <form class="project-form" on:keydown=advance_on_enter on:submit=move |ev: leptos::leptos_dom::ev::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                 <div class="form-layout">
                     // Left side: Basic Information
                     <div class="form-left">
@@ -1844,11 +2442,7 @@ fn DevlogForm(
                                     <span class="value">
                                         {move || {
                                             let art_string = pixel_art.get().to_optimal_string();
-                                            if art_string.len() <= 16 {
-                                                art_string
-                                            } else {
-                                                format!("{}...{}", &art_string[..8], &art_string[art_string.len()-6..])
-                                            }
+                                            shorten_middle(&art_string, 8, 6)
                                         }}
                                     </span>
                                     <div class="copy-container">
@@ -1900,6 +2494,7 @@ fn DevlogForm(
                                     }
                                 }}
                             </small>
+                            <RankProjectionHint projection=rank_projection/>
                         </div>
                     </div>
                 </div>
@@ -1923,6 +2518,14 @@ fn DevlogForm(
                                 </span>
                             }
                         }}
+                        {move || {
+                            let overhead = escape_overhead();
+                            (overhead > 0).then(|| view! {
+                                <span class="size-escaping" title="Quotes and backslashes are stored escaped, one extra byte each">
+                                    {format!("incl. {} bytes of escaping", overhead)}
+                                </span>
+                            })
+                        }}
                     </div>
                     <div class="size-progress">
                         {move || {
@@ -1960,11 +2563,34 @@ fn DevlogForm(
                     }
                 }}
 
+                {move || simulation.get().map(|report| view! {
+                    <SimulationResult report=report on_dismiss=Callback::new(move |_| set_simulation.set(None))/>
+                })}
+
+                <BurnNetworkBadge/>
+
                 // Submit button
                 <div class="button-group">
+                    <button
+                        type="button"
+                        class="simulate-btn"
+                        title="Check whether this transaction would succeed, without sending it or paying a fee"
+                        on:click=handle_simulate
+                        prop:disabled=move || {
+                            is_posting.get() ||
+                            is_simulating.get() ||
+                            devlog_title.get().trim().is_empty() ||
+                            burn_amount.get() < 420 ||
+                            !calculate_memo_size().1
+                        }
+                    >
+                        <i class=move || if is_simulating.get() { "fas fa-spinner fa-spin" } else { "fas fa-vial" }></i>
+                        {move || if is_simulating.get() { "Simulating..." } else { "Simulate" }}
+                    </button>
                     <button
                         type="submit"
                         class="post-devlog-btn"
+                        class:mainnet-burn=is_mainnet()
                         prop:disabled=move || {
                             is_posting.get() ||
                             devlog_title.get().trim().is_empty() ||
@@ -1979,6 +2605,8 @@ fn DevlogForm(
                         {move || {
                             if is_posting.get() {
                                 "Posting Devlog...".to_string()
+                            } else if is_edit {
+                                format!("Post Edit (Burn {} MEMO)", burn_amount.get())
                             } else {
                                 format!("Post Devlog (Burn {} MEMO)", burn_amount.get())
                             }
@@ -1998,6 +2626,7 @@ fn UpdateProjectForm(
     on_close: Rc<dyn Fn()>,
     on_success: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
     
@@ -2059,7 +2688,7 @@ fn UpdateProjectForm(
         let image_data = get_image_data();
         let website = project_website.get().trim().to_string();
         let tags: Vec<String> = vec![]; // tags not editable in update for now
-        let amount = burn_amount.get() * 1_000_000; // lamports
+        let amount = burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN); // lamports
 
         let project_data = ProjectCreationData::new(
             original_project.project_id,
@@ -2119,10 +2748,10 @@ fn UpdateProjectForm(
             return;
         }
 
-        // Check balance
+        // Check maximum and balance
         let token_balance = session.with_untracked(|s| s.get_token_balance());
-        if token_balance < amount as f64 {
-            set_error_message.set(format!("❌ Insufficient balance. Required: {} MEMO, Available: {:.2} MEMO", amount, token_balance));
+        if let Err(e) = validate_burn_tokens(amount, token_balance) {
+            set_error_message.set(format!("❌ {}", e));
             return;
         }
 
@@ -2270,7 +2899,10 @@ fn UpdateProjectForm(
                 </button>
             </div>
             
-            <form class="project-form" on:submit=handle_submit>
+            <form class="project-form" on:submit=move |ev: leptos::leptos_dom::ev::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                 <div class="form-layout">
                     // Left side: Basic Information
                     <div class="form-left">
@@ -2435,11 +3067,7 @@ fn UpdateProjectForm(
                                     <span class="value">
                                         {move || {
                                             let art_string = pixel_art.get().to_optimal_string();
-                                            if art_string.len() <= 16 {
-                                                art_string
-                                            } else {
-                                                format!("{}...{}", &art_string[..8], &art_string[art_string.len()-6..])
-                                            }
+                                            shorten_middle(&art_string, 8, 6)
                                         }}
                                     </span>
                                     <div class="copy-container">
@@ -2566,9 +3194,9 @@ fn UpdateProjectForm(
                                     view! {
                                         <li>
                                             "Description: "
-                                            <span class="old-value">{if old_desc.len() > 30 { format!("{}...", &old_desc[..30]) } else { old_desc }}</span>
+                                            <span class="old-value">{safe_truncate(&old_desc, 33)}</span>
                                             " → "
-                                            <span class="new-value">{if new_desc.len() > 30 { format!("{}...", &new_desc[..30]) } else { new_desc }}</span>
+                                            <span class="new-value">{safe_truncate(&new_desc, 33)}</span>
                                         </li>
                                     }.into_view()
                                 } else {
@@ -2617,11 +3245,14 @@ fn UpdateProjectForm(
                     }
                 }}
 
+                <BurnNetworkBadge/>
+
                 // Submit button
                 <div class="button-group">
                     <button
                         type="submit"
                         class="update-project-btn"
+                        class:mainnet-burn=is_mainnet()
                         prop:disabled=move || {
                             is_updating.get() ||
                             !has_changes() ||
@@ -2653,6 +3284,7 @@ fn CreateProjectForm(
     on_success: Rc<dyn Fn(String, u64)>,
     on_error: Rc<dyn Fn(String)>,
 ) -> impl IntoView {
+    let burn_guard = MainnetBurnGuard::from_context();
     // Wrap callbacks in signals for easy access in closures
     let on_close_signal = create_rw_signal(Some(on_close));
     let on_success_signal = create_rw_signal(Some(on_success));
@@ -2679,7 +3311,7 @@ fn CreateProjectForm(
     let parse_tags = move || -> Vec<String> {
         project_tags.get()
             .split(',')
-            .map(|s| s.trim().to_string())
+            .map(sanitize_display_text)
             .filter(|s| !s.is_empty())
             .take(4) // Maximum 4 tags
             .collect()
@@ -2692,12 +3324,12 @@ fn CreateProjectForm(
 
     // Calculate current memo size in bytes (Borsh + Base64) - 参考rpc_project.rs中的实现
     let calculate_memo_size = move || -> (usize, bool, String) {
-        let name = project_name.get().trim().to_string();
+        let name = sanitize_display_text(&project_name.get());
         let description = project_description.get().trim().to_string();
         let image_data = get_image_data();
         let website = project_website.get().trim().to_string();
         let tags = parse_tags();
-        let amount = burn_amount.get() * 1_000_000; // Convert to lamports
+        let amount = burn_amount.get().saturating_mul(LAMPORTS_PER_TOKEN); // Convert to lamports
         
         // Create temporary ProjectCreationData for size calculation
         let project_data = ProjectCreationData::new(
@@ -2734,7 +3366,7 @@ fn CreateProjectForm(
         }
 
         // Validate form
-        let name = project_name.get().trim().to_string();
+        let name = sanitize_display_text(&project_name.get());
         let description = project_description.get().trim().to_string();
         let image = get_image_data();
         let website = project_website.get().trim().to_string();
@@ -2746,6 +3378,10 @@ fn CreateProjectForm(
             set_error_message.set(format!("❌ Project name must be 1-64 characters, got {}", name.len()));
             return;
         }
+        if !has_visible_text(&name) {
+            set_error_message.set("❌ Project name must contain a letter, digit or emoji".to_string());
+            return;
+        }
         if description.len() > 256 {
             set_error_message.set(format!("❌ Project description must be at most 256 characters, got {}", description.len()));
             return;
@@ -2771,12 +3407,16 @@ fn CreateProjectForm(
                 set_error_message.set("❌ Each tag must be at most 32 characters".to_string());
                 return;
             }
+            if !has_visible_text(tag) {
+                set_error_message.set(format!("❌ Tag \"{}\" must contain a letter, digit or emoji", tag));
+                return;
+            }
         }
 
-        // Check balance
+        // Check maximum and balance
         let token_balance = session.with_untracked(|s| s.get_token_balance());
-        if token_balance < amount as f64 {
-            set_error_message.set(format!("❌ Insufficient balance. Required: {} MEMO, Available: {:.2} MEMO", amount, token_balance));
+        if let Err(e) = validate_burn_tokens(amount, token_balance) {
+            set_error_message.set(format!("❌ {}", e));
             return;
         }
 
@@ -2931,7 +3571,10 @@ fn CreateProjectForm(
                 </button>
             </div>
             
-            <form class="project-form" on:submit=handle_submit>
+            <form class="project-form" on:keydown=advance_on_enter on:submit=move |ev: leptos::leptos_dom::ev::SubmitEvent| {
+                ev.prevent_default();
+                burn_guard.run(move || handle_submit(ev.clone()));
+            }>
                 <div class="form-layout">
                     // Left side: Basic Information
                     <div class="form-left">
@@ -3086,11 +3729,7 @@ fn CreateProjectForm(
                                     <span class="value">
                                         {move || {
                                             let art_string = pixel_art.get().to_optimal_string();
-                                            if art_string.len() <= 20 {
-                                                art_string
-                                            } else {
-                                                format!("{}...{}", &art_string[..10], &art_string[art_string.len()-10..])
-                                            }
+                                            shorten_middle(&art_string, 10, 10)
                                         }}
                                     </span>
                                     <div class="copy-container">
@@ -3236,11 +3875,14 @@ fn CreateProjectForm(
                     }
                 }}
 
+                <BurnNetworkBadge/>
+
                 // Submit button - 完全参考chat page设计
                 <div class="button-group">
                     <button
                         type="submit"
                         class="create-project-btn"
+                        class:mainnet-burn=is_mainnet()
                         prop:disabled=move || {
                             is_creating.get() ||
                             project_name.get().trim().is_empty() ||
@@ -3268,20 +3910,55 @@ fn CreateProjectForm(
     }
 }
 
-/// Format number with comma separators
-fn format_number_with_commas(num: u64) -> String {
-    let num_str = num.to_string();
-    let mut result = String::new();
-    let chars: Vec<char> = num_str.chars().collect();
-    
-    for (i, ch) in chars.iter().enumerate() {
-        if i > 0 && (chars.len() - i) % 3 == 0 {
-            result.push(',');
-        }
-        result.push(*ch);
+/// Project card for the grid view of the leaderboard
+#[component]
+fn ProjectGridCard(
+    project: ProjectRow,
+    /// Shown instead of the name until the project's info is loaded
+    status: &'static str,
+    visited_ids: ReadSignal<Vec<u64>>,
+    on_details: impl Fn(ProjectRow) + 'static + Copy,
+) -> impl IntoView {
+    let rank = project.rank;
+    let project_id = project.project_id;
+    let burned_display = format_memo(project.burned_amount);
+    let details_loaded = project.details_loaded;
+    let name_display = if details_loaded { project.name.clone() } else { status.to_string() };
+    let project_clone = project.clone();
+
+    view! {
+        <div
+            class="project-grid-card"
+            class:clickable=details_loaded
+            class:details-pending=!details_loaded
+            class:visited=move || visited_ids.get().contains(&project_id)
+            class:rank-1=move || rank == 1
+            class:rank-2=move || rank == 2
+            class:rank-3=move || rank == 3
+            on:click=move |_| if details_loaded { on_details(project_clone.clone()) }
+        >
+            <div class="project-grid-card-header">
+                <span class="project-grid-rank">{if rank == 0 { "-".to_string() } else { format!("#{}", rank) }}</span>
+                <span class="project-grid-id">{format!("ID {}", project.project_id)}</span>
+            </div>
+            <div class="project-grid-image">
+                {if project.image.starts_with("c:") || project.image.starts_with("n:") {
+                    view! { <LazyPixelView art={project.image.clone()} size=64/> }.into_view()
+                } else if !project.image.is_empty() {
+                    view! { <img src={project.image.clone()} alt="Project" loading="lazy"/> }.into_view()
+                } else {
+                    view! { <i class="fas fa-cube"></i> }.into_view()
+                }}
+            </div>
+            <h3 class="project-grid-name">{name_display}</h3>
+            <p class="project-grid-description">{truncate_description(&project.description)}</p>
+            <div class="project-grid-burned">
+                <i class="fas fa-fire burned-fire-icon"></i>
+                <span class="burned-number">{burned_display}</span>
+                " MEMO"
+            </div>
+        </div>
     }
-    
-    result
 }
 
 /// Truncate description to first 128 bytes and add ellipsis if longer
@@ -3408,20 +4085,12 @@ fn FeaturedCard(
     transaction: ProjectContractTransaction,
     session: RwSignal<Session>,
 ) -> impl IntoView {
-    let burn_amount_display = transaction.burn_amount / 1_000_000;
+    let burn_amount_display = format_memo(transaction.burn_amount);
     
     // Format timestamp
     let timestamp = transaction.timestamp;
     let time_display = if timestamp > 0 {
-        let date = web_sys::js_sys::Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0));
-        format!(
-            "{}-{:02}-{:02} {:02}:{:02}",
-            date.get_full_year(),
-            date.get_month() + 1,
-            date.get_date(),
-            date.get_hours(),
-            date.get_minutes()
-        )
+        format_timestamp(timestamp)
     } else {
         "Just now".to_string()
     };
@@ -3678,7 +4347,7 @@ fn FeaturedCard(
                                         
                                         {if !devlog.content.is_empty() {
                                             view! {
-                                                <p class="devlog-content">{devlog.content}</p>
+                                                <p class="devlog-content user-text"><UserText text=devlog.content markdown=true/></p>
                                             }.into_view()
                                         } else {
                                             view! { <div></div> }.into_view()
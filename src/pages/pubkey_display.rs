@@ -0,0 +1,135 @@
+use leptos::*;
+use crate::core::i18n::{t, tf};
+use crate::core::nicknames::{self, NicknameMap, MAX_NICKNAME_CHARS};
+use crate::pages::log_view::add_log_entry;
+
+/// The user's local nicknames, shared by every `PubkeyDisplay` so a rename
+/// shows up everywhere the address does. Provided by the main page.
+#[derive(Clone, Copy)]
+pub struct Nicknames {
+    map: RwSignal<NicknameMap>,
+}
+
+impl Nicknames {
+    /// Load the stored nicknames and make them available to child pages
+    pub fn provide() -> Self {
+        let nicknames = Self {
+            map: create_rw_signal(nicknames::load_nicknames()),
+        };
+        provide_context(nicknames);
+        nicknames
+    }
+
+    /// The nicknames provided by the main page
+    pub fn from_context() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Nickname of `address`, if the user set one (reactive)
+    pub fn get(self, address: &str) -> Option<String> {
+        self.map.with(|map| map.get(address).map(str::to_string))
+    }
+
+    /// Set the nickname of `address`; a blank nickname clears it
+    pub fn set(self, address: &str, nickname: &str) {
+        match nicknames::set_nickname(address, nickname) {
+            Ok(map) => self.map.set(map),
+            Err(e) => add_log_entry("ERROR", &format!("Failed to save nickname: {}", e)),
+        }
+    }
+}
+
+/// An address shown by `label` (username, domain or shortened address), or
+/// by the user's local nickname when they set one. Nicknames are tagged as
+/// local and the hover text always names the real identity. The pencil
+/// button edits the nickname in place; saving it blank clears it.
+#[component]
+pub fn PubkeyDisplay(
+    #[prop(into)] address: String,
+    /// What the address shows without a nickname
+    #[prop(into)] label: Signal<String>,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let nicknames = Nicknames::from_context();
+    let address = store_value(address);
+    let nickname = Signal::derive(move || address.with_value(|address| nicknames.get(address)));
+    let (editing, set_editing) = create_signal(false);
+    let (draft, set_draft) = create_signal(String::new());
+
+    let start_edit = move |_| {
+        set_draft.set(nickname.get_untracked().unwrap_or_default());
+        set_editing.set(true);
+    };
+    let save = move || {
+        address.with_value(|address| nicknames.set(address, &draft.get_untracked()));
+        set_editing.set(false);
+    };
+    let clear = move |_| {
+        address.with_value(|address| nicknames.set(address, ""));
+        set_editing.set(false);
+    };
+
+    let title = move || {
+        let identity = format!("{} - {}", label.get(), address.get_value());
+        if nickname.get().is_some() {
+            tf("nickname.local_title", &[identity.as_str()])
+        } else {
+            identity
+        }
+    };
+
+    view! {
+        <span class=format!("pubkey-display {}", class)>
+            <Show
+                when=move || editing.get()
+                fallback=move || view! {
+                    <span
+                        class="pubkey-display-name"
+                        class:pubkey-display-nickname=move || nickname.get().is_some()
+                        title=title
+                    >
+                        {move || nickname.get().map(|_| view! { <i class="fas fa-tag"></i>" " })}
+                        {move || nickname.get().unwrap_or_else(|| label.get())}
+                    </span>
+                    <button
+                        class="pubkey-display-edit"
+                        title=move || if nickname.get().is_some() { t("nickname.edit") } else { t("nickname.add") }
+                        on:click=start_edit
+                    >
+                        <i class="fas fa-pen"></i>
+                    </button>
+                }
+            >
+                <form
+                    class="pubkey-display-form"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        save();
+                    }
+                >
+                    <input
+                        type="text"
+                        class="pubkey-display-input"
+                        maxlength=MAX_NICKNAME_CHARS
+                        placeholder=t("nickname.placeholder")
+                        prop:value=move || draft.get()
+                        on:input=move |ev| set_draft.set(event_target_value(&ev))
+                        on:keydown=move |ev| {
+                            if ev.key() == "Escape" {
+                                set_editing.set(false);
+                            }
+                        }
+                    />
+                    <button type="submit" class="pubkey-display-save" title=t("nickname.save")>
+                        <i class="fas fa-check"></i>
+                    </button>
+                    <Show when=move || nickname.get().is_some()>
+                        <button type="button" class="pubkey-display-clear" title=t("nickname.clear") on:click=clear>
+                            <i class="fas fa-times"></i>
+                        </button>
+                    </Show>
+                </form>
+            </Show>
+        </span>
+    }
+}
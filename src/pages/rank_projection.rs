@@ -0,0 +1,42 @@
+use leptos::*;
+use crate::core::leaderboard::RankProjection;
+
+fn rank_label(rank: Option<usize>) -> String {
+    match rank {
+        Some(rank) => format!("#{}", rank),
+        None => "Unranked".to_string(),
+    }
+}
+
+/// Estimated leaderboard rank change for the burn amount being entered.
+/// Renders nothing while there is no projection (no amount, leaderboard not loaded).
+#[component]
+pub fn RankProjectionHint(projection: Signal<Option<RankProjection>>) -> impl IntoView {
+    move || projection.get().map(|p| {
+        let change = if p.current_rank == p.projected_rank {
+            format!("stays {}", rank_label(p.projected_rank))
+        } else {
+            format!("{} → {}", rank_label(p.current_rank), rank_label(p.projected_rank))
+        };
+        let next = p.to_next_rank.map(|lamports| {
+            let target = match p.projected_rank {
+                Some(rank) => format!("#{}", rank - 1),
+                None => "the leaderboard".to_string(),
+            };
+            format!("+{} MEMO more to reach {}", lamports.div_ceil(1_000_000), target)
+        });
+
+        view! {
+            <div
+                class="rank-projection"
+                class:improves=p.improves()
+                title="Estimated from the loaded leaderboard; other burns may land before yours"
+            >
+                <i class="fas fa-chart-line"></i>
+                <span class="rank-projection-label">"Estimated rank: "</span>
+                <span class="rank-projection-change">{change}</span>
+                {next.map(|next| view! { <span class="rank-projection-next">{next}</span> })}
+            </div>
+        }
+    })
+}
@@ -0,0 +1,40 @@
+use leptos::*;
+use crate::core::format::{format_number_with_commas, hex_dump};
+
+/// Pretty-print `payload` if it is JSON, None otherwise
+fn decode_json(payload: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(payload).ok()?;
+    serde_json::to_string_pretty(&value).ok()
+}
+
+/// Developer-mode view of an already loaded on-chain memo: signature, byte
+/// length, hex dump and decoded JSON. Collapsed by default and rendered only
+/// when the developer preference is on.
+#[component]
+pub fn RawPayloadView(signature: String, payload: String) -> impl IntoView {
+    let byte_len = payload.len();
+    let hex = hex_dump(payload.as_bytes());
+    let json = decode_json(&payload);
+
+    view! {
+        <details class="raw-payload-view">
+            <summary>
+                <i class="fas fa-bug"></i>
+                {format!(" Raw payload ({} bytes)", format_number_with_commas(byte_len as u64))}
+            </summary>
+            <div class="raw-payload-body">
+                <div class="raw-payload-signature">
+                    <span class="raw-payload-label">"Signature: "</span>
+                    <code>{signature}</code>
+                </div>
+                <div class="raw-payload-label">"Hex"</div>
+                <pre class="raw-payload-hex">{hex}</pre>
+                <div class="raw-payload-label">"Decoded JSON"</div>
+                {match json {
+                    Some(json) => view! { <pre class="raw-payload-json">{json}</pre> }.into_view(),
+                    None => view! { <p class="raw-payload-empty">"Not JSON"</p> }.into_view(),
+                }}
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,75 @@
+use leptos::*;
+use std::time::Duration;
+use crate::core::format::{format_date_time, format_relative_time};
+use crate::core::preferences::UiPreferences;
+
+/// Current unix time in seconds
+fn now_secs() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+/// A unix timestamp as a date and time in the user's timezone, in the 12h
+/// or 24h format chosen in settings; "Unknown" if it isn't set (0 or less)
+pub fn format_timestamp(timestamp: i64) -> String {
+    if timestamp <= 0 {
+        return "Unknown".to_string();
+    }
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp as f64 * 1000.0));
+    format_date_time(
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes(),
+        UiPreferences::load().use_12_hour_clock,
+    )
+}
+
+/// The current time, ticking once a minute so relative times ("5m ago") age
+/// while a page stays open. Provided by the main page.
+#[derive(Clone, Copy)]
+pub struct MinuteClock {
+    now: ReadSignal<i64>,
+}
+
+impl MinuteClock {
+    /// Start the clock and make it available to child pages
+    pub fn provide() -> Self {
+        let (now, set_now) = create_signal(now_secs());
+        if let Ok(handle) = set_interval_with_handle(move || set_now.set(now_secs()), Duration::from_secs(60)) {
+            on_cleanup(move || handle.clear());
+        }
+        let clock = Self { now };
+        provide_context(clock);
+        clock
+    }
+
+    /// The clock provided by the main page
+    pub fn from_context() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Current unix time in seconds (reactive, updated every minute)
+    pub fn now(self) -> i64 {
+        // a message newer than the last tick still reads "just now"
+        self.now.get().max(now_secs())
+    }
+}
+
+/// `timestamp` relative to now ("5m ago"), or `absolute` once it is a week
+/// old. The absolute time is always the tooltip.
+#[component]
+pub fn RelativeTime(
+    timestamp: i64,
+    /// The time as a date, e.g. "2024-05-01 12:30"
+    #[prop(into)] absolute: String,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let clock = MinuteClock::from_context();
+    let title = absolute.clone();
+    view! {
+        <span class=class title=title>
+            {move || format_relative_time(timestamp, clock.now()).unwrap_or_else(|| absolute.clone())}
+        </span>
+    }
+}
@@ -0,0 +1,64 @@
+use leptos::*;
+use std::time::Duration;
+use crate::core::rpc_base::{RpcConnection, RpcHealth};
+use crate::pages::message_alerts;
+
+// how often the RPC is pinged while the app is visible
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Small colored dot in the header telling whether the RPC answers:
+/// green healthy, yellow slow, red unreachable (with the error on hover)
+#[component]
+pub fn RpcHealthIndicator() -> impl IntoView {
+    let (health, set_health) = create_signal(Option::<RpcHealth>::None);
+    let checking = store_value(false);
+
+    let check = move || {
+        if checking.try_get_value().unwrap_or(true) {
+            return;
+        }
+        checking.set_value(true);
+        spawn_local(async move {
+            let result = RpcConnection::new().check_health().await;
+            if let RpcHealth::Unreachable(error) = &result {
+                log::warn!("RPC health check failed: {}", error);
+            }
+            let _ = set_health.try_set(Some(result));
+            let _ = checking.try_set_value(false);
+        });
+    };
+    check();
+
+    let interval_handle = set_interval_with_handle(
+        move || {
+            if message_alerts::page_visible() {
+                check();
+            }
+        },
+        HEALTH_CHECK_INTERVAL,
+    );
+    on_cleanup(move || {
+        if let Ok(handle) = interval_handle {
+            handle.clear();
+        }
+    });
+
+    let state_class = move || match health.get() {
+        None => "rpc-health checking",
+        Some(RpcHealth::Healthy) => "rpc-health healthy",
+        Some(RpcHealth::Slow) => "rpc-health slow",
+        Some(RpcHealth::Unreachable(_)) => "rpc-health unreachable",
+    };
+    let title = move || match health.get() {
+        None => "Checking RPC connection...".to_string(),
+        Some(RpcHealth::Healthy) => "RPC connection healthy".to_string(),
+        Some(RpcHealth::Slow) => "RPC is responding slowly; sends may take longer".to_string(),
+        Some(RpcHealth::Unreachable(error)) => format!("RPC unreachable: {}", error),
+    };
+
+    view! {
+        <span class=state_class title=title on:click=move |_| check()>
+            <i class="fas fa-circle"></i>
+        </span>
+    }
+}
@@ -0,0 +1,113 @@
+use leptos::*;
+use std::time::Duration;
+use crate::core::rpc_inspector::{self, RpcCall};
+
+/// Wall-clock time of a call, e.g. "14:03:07.251"
+fn format_call_time(started_at: f64) -> String {
+    let date = js_sys::Date::new(&started_at.into());
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        date.get_hours(),
+        date.get_minutes(),
+        date.get_seconds(),
+        date.get_milliseconds()
+    )
+}
+
+/// Developer panel listing recorded RPC requests, newest first. Collapsed to
+/// a small tab in the corner; the list only refreshes while expanded.
+#[component]
+pub fn RpcInspectorPanel() -> impl IntoView {
+    let (is_expanded, set_is_expanded) = create_signal(false);
+    let (calls, set_calls) = create_signal(Vec::<RpcCall>::new());
+
+    let reload = move || set_calls.set(rpc_inspector::calls());
+
+    let interval_handle = set_interval_with_handle(
+        move || {
+            if is_expanded.get_untracked() {
+                reload();
+            }
+        },
+        Duration::from_secs(1),
+    );
+    on_cleanup(move || {
+        if let Ok(handle) = interval_handle {
+            handle.clear();
+        }
+    });
+
+    let toggle = move |_| {
+        if !is_expanded.get_untracked() {
+            reload();
+        }
+        set_is_expanded.update(|expanded| *expanded = !*expanded);
+    };
+
+    let clear = move |e: ev::MouseEvent| {
+        e.stop_propagation();
+        rpc_inspector::clear();
+        reload();
+    };
+
+    view! {
+        <div class="rpc-inspector" class:expanded=move || is_expanded.get()>
+            <div class="rpc-inspector-header" on:click=toggle>
+                <i class="fas fa-network-wired"></i>
+                <span>"RPC Inspector"</span>
+                <Show when=move || is_expanded.get()>
+                    <span class="rpc-inspector-count">
+                        {move || format!("{} of max {}", calls.get().len(), rpc_inspector::MAX_RPC_CALLS)}
+                    </span>
+                    <button class="rpc-inspector-clear" on:click=clear title="Clear recorded requests">
+                        <i class="fas fa-trash"></i>
+                    </button>
+                </Show>
+                <i class=move || if is_expanded.get() { "fas fa-chevron-down" } else { "fas fa-chevron-up" }></i>
+            </div>
+            <Show when=move || is_expanded.get()>
+                <div class="rpc-inspector-body">
+                    <Show
+                        when=move || !calls.get().is_empty()
+                        fallback=|| view! { <p class="rpc-inspector-empty">"No requests recorded yet"</p> }
+                    >
+                        <table class="rpc-inspector-table">
+                            <thead>
+                                <tr>
+                                    <th>"Time"</th>
+                                    <th>"Method"</th>
+                                    <th>"Params"</th>
+                                    <th>"Duration"</th>
+                                    <th>"Status"</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                <For
+                                    each=move || calls.get()
+                                    key=|call| call.id
+                                    children=move |call| {
+                                        let status_title = call.error.clone().unwrap_or_default();
+                                        view! {
+                                            <tr class:failed=!call.is_ok()>
+                                                <td class="rpc-call-time">{format_call_time(call.started_at)}</td>
+                                                <td class="rpc-call-method">{call.method.clone()}</td>
+                                                <td class="rpc-call-params" title=call.params.clone()>{call.params.clone()}</td>
+                                                <td class="rpc-call-duration">{format!("{:.0} ms", call.duration_ms)}</td>
+                                                <td class="rpc-call-status" title=status_title>
+                                                    {match &call.error {
+                                                        None => "OK".to_string(),
+                                                        Some(error) => error.clone(),
+                                                    }}
+                                                </td>
+                                            </tr>
+                                        }
+                                    }
+                                />
+                            </tbody>
+                        </table>
+                    </Show>
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -1,10 +1,34 @@
 use leptos::*;
 use crate::core::network_config::{try_get_network_config, NetworkType};
-use crate::core::settings::{RpcSelection, UserSettings, load_settings_for_network, save_settings_for_network};
+use crate::core::settings::{
+    clear_settings_for_network, load_settings_for_network, save_settings_for_network, validate_endpoint_url, RpcSelection,
+    UserSettings,
+};
+use crate::core::rpc_base::RpcConnection;
+use crate::core::moderation::{self, ModeratedMessage};
+use crate::core::visited;
+use crate::core::cache;
+use crate::core::rpc_domain;
+use crate::core::preferences::{
+    clamp_to, GroupsDisplayMode, ThemePreference, UiPreferences, AUTO_REFRESH_SECS_RANGE, GROUPS_PER_PAGE_RANGE,
+    MESSAGES_PER_PAGE_RANGE,
+};
+use crate::core::theme;
+use crate::core::rpc_inspector;
+use crate::core::text::shorten_pubkey;
+use crate::core::settings_backup::{self, ImportSummary};
+use crate::pages::message_alerts;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, FileReader, HtmlInputElement, ProgressEvent};
 use std::time::Duration;
 
 #[component]
-pub fn SettingsPage() -> impl IntoView {
+pub fn SettingsPage(
+    /// Called with the applied dark/light state when the theme setting changes
+    on_theme_change: impl Fn(bool) + 'static + Copy,
+    /// Called when the RPC inspector setting is switched on or off
+    on_rpc_inspector_change: impl Fn(bool) + 'static + Copy,
+) -> impl IntoView {
     let Some(network_config) = try_get_network_config() else {
         return view! {
             <div class="settings-page">
@@ -33,6 +57,7 @@ pub fn SettingsPage() -> impl IntoView {
         format!("Default ({default_rpc_url})")
     };
     let default_rpc_for_current = default_rpc_url.clone();
+    let default_explorer_url = network_config.explorer_url;
     let network_style_class = match network_type {
         NetworkType::Testnet => "settings-network-testnet",
         NetworkType::ProdStaging => "settings-network-staging",
@@ -60,6 +85,11 @@ pub fn SettingsPage() -> impl IntoView {
         .map(|s| s.custom_rpc_url.clone())
         .unwrap_or_default();
 
+    let initial_custom_explorer = stored
+        .as_ref()
+        .map(|s| s.custom_explorer_url.clone())
+        .unwrap_or_default();
+
     let initial_compute_buffer = stored
         .as_ref()
         .map(|s| s.compute_unit_buffer_percentage.min(100))
@@ -72,11 +102,20 @@ pub fn SettingsPage() -> impl IntoView {
 
     let (rpc_selection, set_rpc_selection) = create_signal(initial_rpc_selection);
     let (custom_rpc_url, set_custom_rpc_url) = create_signal(initial_custom_rpc);
+    let (custom_explorer_url, set_custom_explorer_url) = create_signal(initial_custom_explorer);
     let (compute_unit_buffer_percentage, set_compute_unit_buffer_percentage) =
         create_signal(initial_compute_buffer);
     let (compute_unit_price_micro_lamports, set_compute_unit_price_micro_lamports) =
         create_signal(initial_compute_price);
     let (save_feedback, set_save_feedback) = create_signal(Option::<String>::None);
+    // (message, css state) of the connection check run before saving a custom RPC
+    let (connection_test, set_connection_test) = create_signal(Option::<(String, &'static str)>::None);
+    let (is_saving, set_is_saving) = create_signal(false);
+
+    let show_feedback = move |message: String| {
+        let _ = set_save_feedback.try_set(Some(message));
+        set_timeout(move || { let _ = set_save_feedback.try_set(None); }, Duration::from_secs(3));
+    };
 
     let current_rpc_url = move || match rpc_selection.get() {
         RpcSelection::Default => default_rpc_for_current.clone(),
@@ -90,39 +129,82 @@ pub fn SettingsPage() -> impl IntoView {
         }
     };
 
-    let save_settings_action = {
-        move |_| {
-            let settings = UserSettings {
-                rpc_selection: rpc_selection.get_untracked(),
-                custom_rpc_url: custom_rpc_url.get_untracked(),
-                compute_unit_buffer_percentage: compute_unit_buffer_percentage.get_untracked(),
-                compute_unit_price_micro_lamports: compute_unit_price_micro_lamports.get_untracked(),
-            };
+    let save_settings_action = move |_| {
+        if is_saving.get_untracked() {
+            return;
+        }
+        let selection = rpc_selection.get_untracked();
+        let custom_explorer = custom_explorer_url.get_untracked();
+        if !custom_explorer.trim().is_empty() {
+            if let Err(err) = validate_endpoint_url(&custom_explorer) {
+                show_feedback(format!("Invalid explorer URL: {err}"));
+                return;
+            }
+        }
+        let custom_rpc = if selection == RpcSelection::Custom {
+            match validate_endpoint_url(&custom_rpc_url.get_untracked()) {
+                Ok(url) => Some(url),
+                Err(err) => {
+                    set_connection_test.set(Some((format!("Invalid RPC URL: {err}"), "error")));
+                    return;
+                }
+            }
+        } else {
+            None
+        };
 
-            match save_settings_for_network(network_type, &settings) {
-                Ok(_) => {
-                    let message = format!("{network_display_name} settings saved to browser storage.");
-                    set_save_feedback.set(Some(message));
-                    set_timeout(
-                        {
-                            let set_save_feedback = set_save_feedback.clone();
-                            move || set_save_feedback.set(None)
-                        },
-                        Duration::from_secs(3),
-                    );
+        let settings = UserSettings {
+            rpc_selection: selection,
+            custom_rpc_url: custom_rpc.clone().unwrap_or_else(|| custom_rpc_url.get_untracked()),
+            compute_unit_buffer_percentage: compute_unit_buffer_percentage.get_untracked(),
+            compute_unit_price_micro_lamports: compute_unit_price_micro_lamports.get_untracked(),
+            custom_explorer_url: custom_explorer.trim().to_string(),
+        };
+
+        set_is_saving.set(true);
+        spawn_local(async move {
+            if let Some(url) = custom_rpc {
+                let _ = set_connection_test.try_set(Some((format!("Testing connection to {url}..."), "testing")));
+                if let Err(err) = RpcConnection::with_endpoint(&url).get_health().await {
+                    log::warn!("Custom RPC health check failed: {err}");
+                    let _ = set_connection_test.try_set(Some((format!("Not saved: {url} did not respond ({err})"), "error")));
+                    let _ = set_is_saving.try_set(false);
+                    return;
                 }
+                let _ = set_connection_test.try_set(Some((format!("Connected to {url}"), "success")));
+            } else {
+                let _ = set_connection_test.try_set(None);
+            }
+
+            match save_settings_for_network(network_type, &settings) {
+                Ok(_) => show_feedback(format!("{network_display_name} settings saved to browser storage.")),
                 Err(err) => {
                     log::error!("Failed to save settings: {err}");
-                    set_save_feedback.set(Some("Failed to save settings.".to_string()));
-                    set_timeout(
-                        {
-                            let set_save_feedback = set_save_feedback.clone();
-                            move || set_save_feedback.set(None)
-                        },
-                        Duration::from_secs(3),
-                    );
+                    show_feedback("Failed to save settings.".to_string());
                 }
             }
+            let _ = set_is_saving.try_set(false);
+        });
+    };
+
+    // The network itself stays locked until logout, so resetting only drops
+    // the stored overrides and goes back to the network's own endpoints
+    let reset_settings_action = move |_| {
+        let defaults = UserSettings::default();
+        match clear_settings_for_network(network_type) {
+            Ok(_) => {
+                set_rpc_selection.set(defaults.rpc_selection);
+                set_custom_rpc_url.set(defaults.custom_rpc_url);
+                set_custom_explorer_url.set(defaults.custom_explorer_url);
+                set_compute_unit_buffer_percentage.set(defaults.compute_unit_buffer_percentage);
+                set_compute_unit_price_micro_lamports.set(defaults.compute_unit_price_micro_lamports);
+                set_connection_test.set(None);
+                show_feedback(format!("{network_display_name} settings reset to defaults."));
+            }
+            Err(err) => {
+                log::error!("Failed to reset settings: {err}");
+                show_feedback("Failed to reset settings.".to_string());
+            }
         }
     };
 
@@ -171,10 +253,31 @@ pub fn SettingsPage() -> impl IntoView {
                         </div>
                     </div>
 
+                    <Show when=move || connection_test.get().is_some()>
+                        {move || connection_test.get().map(|(message, state)| view! {
+                            <div class=format!("connection-test {state}")>{message}</div>
+                        })}
+                    </Show>
+
                     <div class="current-rpc">
                         <h4>"Current RPC URL:"</h4>
                         <div class="rpc-url">{current_rpc_url}</div>
                     </div>
+
+                    <div class="form-field explorer-field">
+                        <label for="custom-explorer">"Block Explorer URL"</label>
+                        <input
+                            type="text"
+                            id="custom-explorer"
+                            class="custom-rpc-input"
+                            placeholder=default_explorer_url
+                            prop:value=move || custom_explorer_url.get()
+                            on:input=move |ev| set_custom_explorer_url.set(event_target_value(&ev))
+                        />
+                        <small class="field-help">
+                            {format!("Transaction and address links open here. Leave empty for {default_explorer_url}")}
+                        </small>
+                    </div>
                 </div>
             </div>
 
@@ -219,19 +322,719 @@ pub fn SettingsPage() -> impl IntoView {
             </div>
 
             <div class="settings-actions">
+                <button
+                    class="settings-btn reset-btn"
+                    type="button"
+                    title="Forget the custom RPC, explorer and compute settings for this network"
+                    disabled=move || is_saving.get()
+                    on:click=reset_settings_action
+                >
+                    <i class="fas fa-undo"></i>
+                    <span>"Reset to default"</span>
+                </button>
                 <button
                     class="settings-btn save-btn"
                     type="button"
+                    disabled=move || is_saving.get()
                     on:click=save_settings_action
                 >
-                    <i class="fas fa-save"></i>
-                    <span>"Save"</span>
+                    <i class=move || if is_saving.get() { "fas fa-spinner fa-spin" } else { "fas fa-save" }></i>
+                    <span>{move || if is_saving.get() { "Testing..." } else { "Save" }}</span>
                 </button>
             </div>
 
             <Show when=move || save_feedback.get().is_some()>
                 <p class="save-feedback">{move || save_feedback.get().unwrap_or_default()}</p>
             </Show>
+
+            <AppearanceSettings on_theme_change=on_theme_change/>
+            <ChatPreferencesSettings/>
+            <ModerationSettings/>
+            <VisitedHistorySettings/>
+            <CacheSettings/>
+            <BackupSettings/>
+            <DeveloperSettings on_rpc_inspector_change=on_rpc_inspector_change/>
+        </div>
+    }
+}
+
+/// Color theme (not network specific, saved and applied on change)
+#[component]
+fn AppearanceSettings(on_theme_change: impl Fn(bool) + 'static + Copy) -> impl IntoView {
+    let (theme_preference, set_theme_preference) = create_signal(UiPreferences::load().theme);
+    let (twelve_hour, set_twelve_hour) = create_signal(UiPreferences::load().use_12_hour_clock);
+    let (feedback, set_feedback) = create_signal(Option::<String>::None);
+
+    let on_theme_change = move |ev| {
+        let preference = ThemePreference::from_label(&event_target_value(&ev));
+        on_theme_change(theme::apply_theme_preference(preference));
+        set_theme_preference.set(preference);
+        if let Err(err) = UiPreferences::update(|p| p.theme = preference) {
+            log::error!("Failed to save preferences: {err}");
+            set_feedback.set(Some("Failed to save preference.".to_string()));
+            set_timeout(move || set_feedback.set(None), Duration::from_secs(3));
+        }
+    };
+
+    let on_clock_toggle = move |ev| {
+        let enabled = event_target_checked(&ev);
+        let message = match UiPreferences::update(|p| p.use_12_hour_clock = enabled) {
+            Ok(_) => {
+                set_twelve_hour.set(enabled);
+                "Preference saved. It applies as messages and devlogs load.".to_string()
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                "Failed to save preference.".to_string()
+            }
+        };
+        set_feedback.set(Some(message));
+        set_timeout(move || set_feedback.set(None), Duration::from_secs(3));
+    };
+
+    view! {
+        <div class="settings-section settings-section-appearance">
+            <h3>"Appearance"</h3>
+            <div class="form-field">
+                <label for="theme-preference">"Theme"</label>
+                <select id="theme-preference" on:change=on_theme_change>
+                    {ThemePreference::ALL.into_iter().map(|preference| view! {
+                        <option value=preference.label() prop:selected=move || theme_preference.get() == preference>
+                            {preference.label()}
+                        </option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <small class="field-help">"System follows your browser or OS setting. The sun/moon button in the header also switches the theme."</small>
+            </div>
+            <div class="form-field">
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || twelve_hour.get()
+                        on:change=on_clock_toggle
+                    />
+                    " Use 12-hour time"
+                </label>
+                <small class="field-help">"Show times as 2:05 PM instead of 14:05. Times are always in your device's timezone."</small>
+            </div>
+            <Show when=move || feedback.get().is_some()>
+                <p class="save-feedback">{move || feedback.get().unwrap_or_default()}</p>
+            </Show>
+        </div>
+    }
+}
+
+/// Chat page preferences (not network specific, saved on change)
+#[component]
+fn ChatPreferencesSettings() -> impl IntoView {
+    let (default_mode, set_default_mode) = create_signal(UiPreferences::load().default_groups_display_mode);
+    let (keep_order, set_keep_order) = create_signal(UiPreferences::load().keep_leaderboard_order);
+    let (groups_per_page, set_groups_per_page) = create_signal(UiPreferences::load().groups_page_size());
+    let (messages_per_page, set_messages_per_page) = create_signal(UiPreferences::load().messages_page_size());
+    let (notifications, set_notifications) = create_signal(
+        UiPreferences::load().message_notifications && message_alerts::notifications_allowed(),
+    );
+    let (message_sound, set_message_sound) = create_signal(UiPreferences::load().message_sound);
+    let (auto_refresh, set_auto_refresh) = create_signal(UiPreferences::load().auto_refresh_messages);
+    let (auto_refresh_secs, set_auto_refresh_secs) = create_signal(UiPreferences::load().auto_refresh_interval());
+    let (feedback, set_feedback) = create_signal(Option::<String>::None);
+
+    let show_feedback = move |message: String| {
+        set_feedback.set(Some(message));
+        set_timeout(move || set_feedback.set(None), Duration::from_secs(3));
+    };
+
+    let on_mode_change = move |ev| {
+        let mode = GroupsDisplayMode::from_label(&event_target_value(&ev));
+        match UiPreferences::update(|p| p.default_groups_display_mode = mode) {
+            Ok(_) => {
+                set_default_mode.set(mode);
+                show_feedback("Preference saved. It applies the next time the chat page opens.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    let on_keep_order_toggle = move |ev| {
+        let enabled = event_target_checked(&ev);
+        match UiPreferences::update(|p| p.keep_leaderboard_order = enabled) {
+            Ok(_) => {
+                set_keep_order.set(enabled);
+                show_feedback("Preference saved. It applies the next time a leaderboard loads.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    // Out-of-range input is moved to the nearest bound before saving
+    let on_groups_per_page_change = move |ev| {
+        let Ok(value) = event_target_value(&ev).trim().parse::<usize>() else {
+            set_groups_per_page.update(|_| {});
+            return;
+        };
+        let per_page = clamp_to(value, &GROUPS_PER_PAGE_RANGE);
+        match UiPreferences::update(|p| p.groups_per_page = per_page) {
+            Ok(_) => {
+                set_groups_per_page.set(per_page);
+                show_feedback("Preference saved. It applies the next time the groups list loads, starting on page 1.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    let on_messages_per_page_change = move |ev| {
+        let Ok(value) = event_target_value(&ev).trim().parse::<usize>() else {
+            set_messages_per_page.update(|_| {});
+            return;
+        };
+        let per_page = clamp_to(value, &MESSAGES_PER_PAGE_RANGE);
+        match UiPreferences::update(|p| p.messages_per_page = per_page) {
+            Ok(_) => {
+                set_messages_per_page.set(per_page);
+                show_feedback("Preference saved. It applies the next time a chat room loads.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    let on_auto_refresh_toggle = move |ev| {
+        let enabled = event_target_checked(&ev);
+        match UiPreferences::update(|p| p.auto_refresh_messages = enabled) {
+            Ok(_) => {
+                set_auto_refresh.set(enabled);
+                show_feedback("Preference saved. It applies the next time a chat room opens.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    let on_auto_refresh_secs_change = move |ev| {
+        let Ok(value) = event_target_value(&ev).trim().parse::<usize>() else {
+            set_auto_refresh_secs.update(|_| {});
+            return;
+        };
+        let secs = clamp_to(value, &AUTO_REFRESH_SECS_RANGE);
+        match UiPreferences::update(|p| p.auto_refresh_secs = secs) {
+            Ok(_) => {
+                set_auto_refresh_secs.set(secs);
+                show_feedback("Preference saved. It applies the next time a chat room opens.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    let save_notifications = move |enabled: bool| {
+        match UiPreferences::update(|p| p.message_notifications = enabled) {
+            Ok(_) => {
+                set_notifications.set(enabled);
+                show_feedback("Preference saved.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    // Permission is only asked for when notifications are first turned on
+    let on_notifications_toggle = move |ev| {
+        if !event_target_checked(&ev) {
+            save_notifications(false);
+            return;
+        }
+        spawn_local(async move {
+            if message_alerts::request_notification_permission().await {
+                save_notifications(true);
+            } else {
+                set_notifications.set(false);
+                show_feedback("Notifications are blocked or not supported by this browser.".to_string());
+            }
+        });
+    };
+
+    let on_sound_toggle = move |ev| {
+        let enabled = event_target_checked(&ev);
+        match UiPreferences::update(|p| p.message_sound = enabled) {
+            Ok(_) => {
+                set_message_sound.set(enabled);
+                show_feedback("Preference saved.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    view! {
+        <div class="settings-section settings-section-chat">
+            <h3>"Chat"</h3>
+            <div class="form-field">
+                <label for="default-display-mode">"Default Groups Display Mode"</label>
+                <select id="default-display-mode" on:change=on_mode_change>
+                    {GroupsDisplayMode::ALL.into_iter().map(|mode| view! {
+                        <option value=mode.label() prop:selected=move || default_mode.get() == mode>
+                            {mode.label()}
+                        </option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <small class="field-help">"How the chat groups list opens. Favorites are saved in this browser."</small>
+            </div>
+            <div class="form-field">
+                <label for="groups-per-page">"Groups Per Page"</label>
+                <input
+                    type="number"
+                    id="groups-per-page"
+                    min=*GROUPS_PER_PAGE_RANGE.start()
+                    max=*GROUPS_PER_PAGE_RANGE.end()
+                    prop:value=move || groups_per_page.get().to_string()
+                    on:change=on_groups_per_page_change
+                />
+                <small class="field-help">
+                    {format!("Chat groups shown per page ({}-{}).", GROUPS_PER_PAGE_RANGE.start(), GROUPS_PER_PAGE_RANGE.end())}
+                </small>
+            </div>
+            <div class="form-field">
+                <label for="messages-per-page">"Messages Per Page"</label>
+                <input
+                    type="number"
+                    id="messages-per-page"
+                    min=*MESSAGES_PER_PAGE_RANGE.start()
+                    max=*MESSAGES_PER_PAGE_RANGE.end()
+                    prop:value=move || messages_per_page.get().to_string()
+                    on:change=on_messages_per_page_change
+                />
+                <small class="field-help">
+                    {format!("Chat messages loaded when a room opens and each time older messages are loaded ({}-{}).", MESSAGES_PER_PAGE_RANGE.start(), MESSAGES_PER_PAGE_RANGE.end())}
+                </small>
+            </div>
+            <div class="form-field">
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || keep_order.get()
+                        on:change=on_keep_order_toggle
+                    />
+                    " Keep the on-chain leaderboard order"
+                </label>
+                <small class="field-help">
+                    "Show the group and project burn leaderboards in the rank order stored on chain. When off, entries are re-sorted by burned amount, with ties ordered by id."
+                </small>
+            </div>
+            <div class="form-field">
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || auto_refresh.get()
+                        on:change=on_auto_refresh_toggle
+                    />
+                    " Auto-refresh chat rooms"
+                </label>
+                <label for="auto-refresh-secs">"Refresh Every (seconds)"</label>
+                <input
+                    type="number"
+                    id="auto-refresh-secs"
+                    min=*AUTO_REFRESH_SECS_RANGE.start()
+                    max=*AUTO_REFRESH_SECS_RANGE.end()
+                    prop:disabled=move || !auto_refresh.get()
+                    prop:value=move || auto_refresh_secs.get().to_string()
+                    on:change=on_auto_refresh_secs_change
+                />
+                <small class="field-help">
+                    {format!("Load new messages in the open chat room automatically ({}-{} seconds). Paused while the tab is hidden.", AUTO_REFRESH_SECS_RANGE.start(), AUTO_REFRESH_SECS_RANGE.end())}
+                </small>
+            </div>
+            <div class="form-field">
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || notifications.get()
+                        on:change=on_notifications_toggle
+                    />
+                    " Notify me about new messages"
+                </label>
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || message_sound.get()
+                        on:change=on_sound_toggle
+                    />
+                    " Play a sound for new messages"
+                </label>
+                <small class="field-help">
+                    "While a chat room is open in a background tab, it is checked for new messages every 30 seconds (or as often as auto-refresh is set). Messages from others then show a browser notification and/or play a sound. Your own messages never do."
+                </small>
+            </div>
+            <Show when=move || feedback.get().is_some()>
+                <p class="save-feedback">{move || feedback.get().unwrap_or_default()}</p>
+            </Show>
+        </div>
+    }
+}
+
+/// Locally hidden and reported chat messages
+#[component]
+fn ModerationSettings() -> impl IntoView {
+    let (hidden_messages, set_hidden_messages) = create_signal(moderation::load_hidden_messages().entries);
+    let (reported_messages, set_reported_messages) = create_signal(moderation::load_reported_messages().entries);
+
+    let unhide = move |signature: String| {
+        match moderation::unhide_message(&signature) {
+            Ok(()) => set_hidden_messages.update(|list| list.retain(|m| m.signature != signature)),
+            Err(err) => log::error!("Failed to unhide message: {err}"),
+        }
+    };
+
+    let clear_reports = move |_| {
+        match moderation::clear_reported_messages() {
+            Ok(()) => set_reported_messages.set(Vec::new()),
+            Err(err) => log::error!("Failed to clear reported messages: {err}"),
+        }
+    };
+
+    let render_entry = |entry: &ModeratedMessage| {
+        let short_sender = shorten_pubkey(&entry.sender);
+        view! {
+            <div class="moderated-message-info">
+                <span class="moderated-message-meta">
+                    {format!("Group #{} · {}", entry.group_id, short_sender)}
+                </span>
+                <span class="moderated-message-preview" title=entry.signature.clone()>
+                    {entry.preview.clone()}
+                </span>
+            </div>
+        }
+    };
+
+    view! {
+        <div class="settings-section settings-section-moderation">
+            <h3>"Hidden Messages"</h3>
+            <p class="field-help">
+                "Hiding and reporting only affect this browser. Nothing is sent to any server and messages remain on-chain."
+            </p>
+
+            <Show
+                when=move || !hidden_messages.get().is_empty()
+                fallback=|| view! { <p class="settings-empty">"No hidden messages."</p> }
+            >
+                <div class="moderated-message-list">
+                    <For
+                        each=move || hidden_messages.get()
+                        key=|entry| entry.signature.clone()
+                        children=move |entry: ModeratedMessage| {
+                            let signature = entry.signature.clone();
+                            view! {
+                                <div class="moderated-message">
+                                    {render_entry(&entry)}
+                                    <button
+                                        class="settings-btn unhide-btn"
+                                        type="button"
+                                        on:click=move |_| unhide(signature.clone())
+                                    >
+                                        <i class="fas fa-eye"></i>
+                                        <span>"Unhide"</span>
+                                    </button>
+                                </div>
+                            }
+                        }
+                    />
+                </div>
+            </Show>
+
+            <h4>"Reported Messages"</h4>
+            <Show
+                when=move || !reported_messages.get().is_empty()
+                fallback=|| view! { <p class="settings-empty">"No reported messages."</p> }
+            >
+                <div class="moderated-message-list">
+                    <For
+                        each=move || reported_messages.get()
+                        key=|entry| entry.signature.clone()
+                        children=move |entry: ModeratedMessage| {
+                            view! {
+                                <div class="moderated-message">
+                                    {render_entry(&entry)}
+                                </div>
+                            }
+                        }
+                    />
+                </div>
+                <button class="settings-btn clear-btn" type="button" on:click=clear_reports>
+                    <i class="fas fa-trash"></i>
+                    <span>"Clear Report Record"</span>
+                </button>
+            </Show>
+        </div>
+    }
+} 
+
+/// Locally remembered visited chat groups and projects
+#[component]
+fn VisitedHistorySettings() -> impl IntoView {
+    let (count, set_count) = create_signal(visited::load_visited_groups().len() + visited::load_visited_projects().len());
+
+    let clear_history = move |_| {
+        match visited::clear_visited_history() {
+            Ok(()) => set_count.set(0),
+            Err(err) => log::error!("Failed to clear visited history: {err}"),
+        }
+    };
+
+    view! {
+        <div class="settings-section settings-section-history">
+            <h3>"Visited History"</h3>
+            <p class="field-help">
+                "Chat groups and projects you have opened are marked as visited in the leaderboards. The history is kept only in this browser."
+            </p>
+            <Show
+                when=move || { count.get() > 0 }
+                fallback=|| view! { <p class="settings-empty">"No visited groups or projects."</p> }
+            >
+                <p class="settings-empty">{move || format!("{} visited entries remembered.", count.get())}</p>
+                <button class="settings-btn clear-btn" type="button" on:click=clear_history>
+                    <i class="fas fa-trash"></i>
+                    <span>"Clear Visited History"</span>
+                </button>
+            </Show>
+        </div>
+    }
+}
+
+/// In-memory caches of chain data and X1NS names
+#[component]
+fn CacheSettings() -> impl IntoView {
+    let (cleared, set_cleared) = create_signal(false);
+
+    let clear_cache = move |_| {
+        cache::clear_all();
+        rpc_domain::clear_domain_cache();
+        set_cleared.set(true);
+    };
+
+    view! {
+        <div class="settings-section settings-section-cache">
+            <h3>"Cached Data"</h3>
+            <p class="field-help">
+                "Token supply, chat group details and X1NS domain names are kept in memory for a few minutes to avoid repeated requests. Clear them to fetch fresh values."
+            </p>
+            <Show when=move || cleared.get()>
+                <p class="settings-empty">"Cache cleared."</p>
+            </Show>
+            <button class="settings-btn clear-btn" type="button" on:click=clear_cache>
+                <i class="fas fa-trash"></i>
+                <span>"Clear Cache"</span>
+            </button>
+        </div>
+    }
+}
+
+/// Export all local settings to a JSON file and merge them back from one
+#[component]
+fn BackupSettings() -> impl IntoView {
+    let (feedback, set_feedback) = create_signal(Option::<String>::None);
+
+    let export_settings = move |_| {
+        let json = match settings_backup::export_settings_json() {
+            Ok(json) => json,
+            Err(err) => {
+                log::error!("Failed to export settings: {err}");
+                set_feedback.set(Some("Failed to export settings.".to_string()));
+                return;
+            }
+        };
+        let link = web_sys::window()
+            .and_then(|win| win.document())
+            .and_then(|doc| doc.create_element("a").ok())
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+        let Some(link) = link else {
+            set_feedback.set(Some("Failed to export settings.".to_string()));
+            return;
+        };
+        let href = format!("data:application/json;charset=utf-8,{}", js_sys::encode_uri_component(&json));
+        let _ = link.set_attribute("href", &href);
+        let _ = link.set_attribute("download", "memo-app-settings.json");
+        link.click();
+        set_feedback.set(Some("Settings exported. The file contains no wallet keys.".to_string()));
+    };
+
+    let apply_import = move |json: String| {
+        let result = settings_backup::parse_settings_export(&json).and_then(settings_backup::import_settings);
+        match result {
+            Ok(ImportSummary { imported, skipped, endpoint_changes }) => {
+                let mut message = format!("Imported {} settings: {}.", imported.len(), imported.join(", "));
+                if !skipped.is_empty() {
+                    message.push_str(&format!(" Skipped: {}.", skipped.join(", ")));
+                }
+                if !endpoint_changes.is_empty() {
+                    message.push_str(&format!(
+                        " Check the new endpoints before using them: {}.",
+                        endpoint_changes.join(", ")
+                    ));
+                }
+                message.push_str(" Reload the app to apply them.");
+                set_feedback.set(Some(message));
+            }
+            Err(err) => {
+                log::error!("Failed to import settings: {err}");
+                set_feedback.set(Some(format!("Import failed: {err}")));
+            }
+        }
+    };
+
+    let import_settings = move |_| {
+        let Some(input) = web_sys::window()
+            .and_then(|win| win.document())
+            .and_then(|doc| doc.create_element("input").ok())
+            .and_then(|el| el.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        input.set_type("file");
+        input.set_accept("application/json,.json");
+
+        let onchange = Closure::wrap(Box::new(move |event: Event| {
+            let input: HtmlInputElement = event.target().unwrap().dyn_into().unwrap();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                let reader = FileReader::new().unwrap();
+                let reader_clone = reader.clone();
+
+                let onload = Closure::wrap(Box::new(move |_: ProgressEvent| {
+                    match reader_clone.result().ok().and_then(|result| result.as_string()) {
+                        Some(json) => apply_import(json),
+                        None => set_feedback.set(Some("Import failed: could not read the file.".to_string())),
+                    }
+                }) as Box<dyn FnMut(ProgressEvent)>);
+
+                reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+                onload.forget();
+
+                let _ = reader.read_as_text(&file);
+            }
+        }) as Box<dyn FnMut(_)>);
+
+        input.set_onchange(Some(onchange.as_ref().unchecked_ref()));
+        onchange.forget();
+
+        input.click();
+    };
+
+    view! {
+        <div class="settings-section settings-section-backup">
+            <h3>"Backup"</h3>
+            <p class="field-help">
+                "Export preferences, favorites, hidden messages, visited history and RPC settings for every network to a file, and merge them into another browser. Wallet keys are never included."
+            </p>
+            <div class="settings-backup-actions">
+                <button class="settings-btn" type="button" on:click=export_settings>
+                    <i class="fas fa-file-export"></i>
+                    <span>"Export Settings"</span>
+                </button>
+                <button class="settings-btn" type="button" on:click=import_settings>
+                    <i class="fas fa-file-import"></i>
+                    <span>"Import Settings"</span>
+                </button>
+            </div>
+            <Show when=move || feedback.get().is_some()>
+                <p class="save-feedback">{move || feedback.get().unwrap_or_default()}</p>
+            </Show>
+        </div>
+    }
+}
+
+/// Debug-only options (not network specific, saved on change)
+#[component]
+fn DeveloperSettings(on_rpc_inspector_change: impl Fn(bool) + 'static + Copy) -> impl IntoView {
+    let (developer_mode, set_developer_mode) = create_signal(UiPreferences::load().developer_mode);
+    let (rpc_inspector_enabled, set_rpc_inspector_enabled) = create_signal(UiPreferences::load().rpc_inspector);
+    let (feedback, set_feedback) = create_signal(Option::<String>::None);
+
+    let show_feedback = move |message: String| {
+        set_feedback.set(Some(message));
+        set_timeout(move || set_feedback.set(None), Duration::from_secs(3));
+    };
+
+    let on_toggle = move |ev| {
+        let enabled = event_target_checked(&ev);
+        match UiPreferences::update(|p| p.developer_mode = enabled) {
+            Ok(_) => {
+                set_developer_mode.set(enabled);
+                show_feedback("Preference saved. It applies the next time the chat or project page opens.".to_string());
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    let on_inspector_toggle = move |ev| {
+        let enabled = event_target_checked(&ev);
+        match UiPreferences::update(|p| p.rpc_inspector = enabled) {
+            Ok(_) => {
+                // applies right away, unlike the payload view
+                rpc_inspector::set_enabled(enabled);
+                set_rpc_inspector_enabled.set(enabled);
+                on_rpc_inspector_change(enabled);
+            }
+            Err(err) => {
+                log::error!("Failed to save preferences: {err}");
+                show_feedback("Failed to save preference.".to_string());
+            }
+        }
+    };
+
+    view! {
+        <div class="settings-section settings-section-developer">
+            <h3>"Developer"</h3>
+            <div class="form-field">
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || developer_mode.get()
+                        on:change=on_toggle
+                    />
+                    <i class="fas fa-bug"></i>
+                    " Show raw message payloads"
+                </label>
+                <small class="field-help">
+                    "Debug feature. Adds an expandable hex and decoded JSON view of the on-chain memo under chat messages and devlogs."
+                </small>
+            </div>
+            <div class="form-field">
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || rpc_inspector_enabled.get()
+                        on:change=on_inspector_toggle
+                    />
+                    <i class="fas fa-network-wired"></i>
+                    " Record RPC requests"
+                </label>
+                <small class="field-help">
+                    {format!("Debug feature. Lists the method, params, duration and result of the last {} RPC requests in a panel at the bottom of the window. Nothing is recorded while this is off.", rpc_inspector::MAX_RPC_CALLS)}
+                </small>
+            </div>
+            <Show when=move || feedback.get().is_some()>
+                <p class="save-feedback">{move || feedback.get().unwrap_or_default()}</p>
+            </Show>
         </div>
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,47 @@
+use leptos::*;
+use crate::core::rpc_base::SimulationReport;
+
+/// Outcome of a simulated (dry-run) transaction: whether it would succeed,
+/// the program error if not, and the program logs
+#[component]
+pub fn SimulationResult(
+    report: SimulationReport,
+    on_dismiss: Callback<()>,
+) -> impl IntoView {
+    let ok = report.is_ok();
+    let summary = match &report.error {
+        None => "Simulation succeeded: this transaction should go through".to_string(),
+        Some(error) => format!("Simulation failed: {}", error),
+    };
+    let log_count = report.logs.len();
+    let has_logs = log_count > 0;
+    let logs = report.logs.join("\n");
+
+    view! {
+        <div class="simulation-result" class:success=ok class:error=!ok>
+            <div class="simulation-summary">
+                <i class=if ok { "fas fa-check-circle" } else { "fas fa-times-circle" }></i>
+                <span>{summary}</span>
+                <button
+                    type="button"
+                    class="simulation-dismiss"
+                    title="Dismiss"
+                    on:click=move |_| on_dismiss.call(())
+                >
+                    <i class="fas fa-times"></i>
+                </button>
+            </div>
+            <div class="simulation-details">
+                {report.error_code.map(|code| view! { <span>{format!("Program error code: {}", code)}</span> })}
+                {report.units_consumed.map(|units| view! { <span>{format!("Compute units: {}", units)}</span> })}
+                <span>"No fee was charged"</span>
+            </div>
+            <Show when=move || has_logs>
+                <details class="simulation-logs">
+                    <summary>{format!("Program logs ({})", log_count)}</summary>
+                    <pre>{logs.clone()}</pre>
+                </details>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,85 @@
+use leptos::*;
+use crate::core::markdown::{parse_inline, Inline};
+use crate::core::text::{split_links, split_matches, TextSegment};
+
+/// Render user-authored text with line breaks preserved and http(s) URLs as links.
+/// All content is emitted as text nodes, so markup in the text is never interpreted.
+/// Parts matching `highlight` (ignoring case) are wrapped in `<mark>`. With
+/// `markdown`, `**bold**`, `*italic*` and `` `code` `` are rendered too.
+#[component]
+pub fn UserText(
+    text: String,
+    /// Search term to highlight, if any
+    #[prop(optional)] highlight: Option<Signal<String>>,
+    /// Render inline markdown (see `core::markdown`)
+    #[prop(optional)] markdown: bool,
+) -> impl IntoView {
+    let render = move |query: String| {
+        if markdown {
+            return parse_inline(&text)
+                .into_iter()
+                .map(|inline| match inline {
+                    Inline::Text { text, bold, italic } => {
+                        let mut node = highlighted(&text, &query);
+                        if italic {
+                            node = view! { <em>{node}</em> }.into_view();
+                        }
+                        if bold {
+                            node = view! { <strong>{node}</strong> }.into_view();
+                        }
+                        node
+                    }
+                    Inline::Code(code) => view! {
+                        <code class="user-text-code">{highlighted(&code, &query)}</code>
+                    }.into_view(),
+                    Inline::Link(url) => link(url, &query),
+                })
+                .collect::<Vec<_>>();
+        }
+        split_links(&text)
+            .into_iter()
+            .map(|segment| match segment {
+                TextSegment::Text(text) => highlighted(&text, &query),
+                TextSegment::Link(url) => link(url, &query),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    match highlight {
+        Some(query) => (move || render(query.get())).into_view(),
+        None => render(String::new()).into_view(),
+    }
+}
+
+/// A link opening `url` in a new tab
+fn link(url: String, query: &str) -> View {
+    view! {
+        <a
+            href=url.clone()
+            class="user-text-link"
+            target="_blank"
+            rel="noopener noreferrer"
+            on:click=|ev| ev.stop_propagation()
+        >
+            {highlighted(&url, query)}
+        </a>
+    }.into_view()
+}
+
+/// Text nodes for `text`, with the parts matching `query` marked
+fn highlighted(text: &str, query: &str) -> View {
+    if query.is_empty() {
+        return text.to_string().into_view();
+    }
+    split_matches(text, query)
+        .into_iter()
+        .map(|(piece, matched)| {
+            if matched {
+                view! { <mark class="search-highlight">{piece.to_string()}</mark> }.into_view()
+            } else {
+                piece.to_string().into_view()
+            }
+        })
+        .collect::<Vec<_>>()
+        .into_view()
+}
@@ -0,0 +1,26 @@
+use leptos::*;
+use crate::core::preferences::ListViewMode;
+
+/// Grid/list toggle shown above a list of groups or projects.
+/// The caller owns the signal and decides where the choice is persisted.
+#[component]
+pub fn ViewModeToggle(
+    view_mode: ReadSignal<ListViewMode>,
+    on_change: impl Fn(ListViewMode) + 'static + Copy,
+) -> impl IntoView {
+    view! {
+        <div class="view-mode-toggle" role="group" aria-label="View mode">
+            {[ListViewMode::Grid, ListViewMode::List].into_iter().map(|mode| view! {
+                <button
+                    class="view-mode-btn"
+                    class:active=move || view_mode.get() == mode
+                    title=mode.label()
+                    aria-pressed=move || (view_mode.get() == mode).to_string()
+                    on:click=move |_| on_change(mode)
+                >
+                    <i class=mode.icon()></i>
+                </button>
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}